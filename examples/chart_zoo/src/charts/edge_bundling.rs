@@ -3,6 +3,8 @@
 //! Circular layout with bundled edges showing relationships between nodes.
 //! Implements D3's hierarchical edge bundling visualization.
 
+use makepad_d3::shape::curve::{BundleCurve, Curve};
+use makepad_d3::shape::{Path as ShapePath, Point};
 use makepad_widgets::*;
 use std::f64::consts::PI;
 use std::collections::HashMap;
@@ -370,30 +372,22 @@ impl EdgeBundlingWidget {
         let tgt_x = self.center_x + radius * target_angle.cos();
         let tgt_y = self.center_y + radius * target_angle.sin();
 
-        // Control point - D3's curveBundle.beta interpretation:
-        // beta = 0.0 means straight line (control at midpoint)
-        // beta = 1.0 means maximum bundling (control at center)
-        let mid_x = (src_x + tgt_x) / 2.0;
-        let mid_y = (src_y + tgt_y) / 2.0;
-
-        // Blend: (1-beta)*midpoint + beta*center
-        let ctrl_x = mid_x * (1.0 - beta) + self.center_x * beta;
-        let ctrl_y = mid_y * (1.0 - beta) + self.center_y * beta;
-
-        // Draw curve as line segments
-        let segments = 20;
-        for i in 0..segments {
-            let t0 = i as f64 / segments as f64;
-            let t1 = (i + 1) as f64 / segments as f64;
-
-            // Quadratic bezier
-            let x0 = (1.0 - t0).powi(2) * src_x + 2.0 * (1.0 - t0) * t0 * ctrl_x + t0.powi(2) * tgt_x;
-            let y0 = (1.0 - t0).powi(2) * src_y + 2.0 * (1.0 - t0) * t0 * ctrl_y + t0.powi(2) * tgt_y;
-            let x1 = (1.0 - t1).powi(2) * src_x + 2.0 * (1.0 - t1) * t1 * ctrl_x + t1.powi(2) * tgt_x;
-            let y1 = (1.0 - t1).powi(2) * src_y + 2.0 * (1.0 - t1) * t1 * ctrl_y + t1.powi(2) * tgt_y;
-
-            // Draw line segment
-            self.draw_line_segment(cx, x0, y0, x1, y1, color);
+        // Route the edge through the root at the circle center and let
+        // curveBundle straighten it according to beta.
+        let control = [
+            Point::new(src_x, src_y),
+            Point::new(self.center_x, self.center_y),
+            Point::new(tgt_x, tgt_y),
+        ];
+        let path: ShapePath = BundleCurve::new(beta)
+            .generate(&control)
+            .into_iter()
+            .collect();
+
+        for line in path.flatten(0.5) {
+            for pair in line.points.windows(2) {
+                self.draw_line_segment(cx, pair[0].x, pair[0].y, pair[1].x, pair[1].y, color);
+            }
         }
     }
 
@@ -4,6 +4,7 @@
 
 use super::format::NumberFormat;
 use super::grid::GridConfig;
+use super::label_overlap::{LabelOverlap, OverlapStrategy};
 use crate::scale::{BandScale, DiscreteScale, PointScale, Scale, Tick, TickOptions};
 
 /// Axis orientation
@@ -80,7 +81,7 @@ pub enum LabelAlign {
 }
 
 /// Label rotation specification
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct LabelRotation {
     /// Rotation angle in degrees (positive = clockwise)
     pub angle: f64,
@@ -183,6 +184,8 @@ pub struct AxisConfig {
     pub band_offset: f64,
     /// Enhanced grid configuration
     pub grid_config: GridConfig,
+    /// Tick label collision avoidance (used by [`Axis::compute_layout_measured`])
+    pub label_overlap: Option<LabelOverlap>,
 }
 
 impl Default for AxisConfig {
@@ -203,6 +206,7 @@ impl Default for AxisConfig {
             text_anchor: None,
             band_offset: 0.0,
             grid_config: GridConfig::default(),
+            label_overlap: None,
         }
     }
 }
@@ -342,6 +346,12 @@ impl AxisConfig {
         self
     }
 
+    /// Enable tick label collision avoidance
+    pub fn with_label_overlap(mut self, overlap: LabelOverlap) -> Self {
+        self.label_overlap = Some(overlap);
+        self
+    }
+
     /// Get effective text anchor (explicit or default for orientation)
     pub fn effective_text_anchor(&self) -> TextAnchor {
         if self.label_rotation.is_rotated() {
@@ -389,6 +399,8 @@ pub struct AxisTick {
     pub text_anchor: TextAnchor,
     /// Whether this is a minor tick
     pub is_minor: bool,
+    /// Whether the label should be drawn (false when hidden to avoid overlap)
+    pub label_visible: bool,
 }
 
 /// Computed axis layout ready for rendering
@@ -414,6 +426,10 @@ pub struct AxisLayout {
     pub label_align: LabelAlign,
     /// Grid configuration
     pub grid_config: GridConfig,
+    /// Indices of ticks whose labels were hidden to avoid overlap
+    pub hidden_labels: Vec<usize>,
+    /// Strategy applied to resolve label overlaps, if any
+    pub overlap_strategy: Option<OverlapStrategy>,
 }
 
 /// Axis instance that computes layout from scale
@@ -557,7 +573,37 @@ impl Axis {
             text_anchor: self.config.effective_text_anchor(),
             label_align: self.config.effective_label_align(),
             grid_config: self.config.grid_config.clone(),
+            hidden_labels: Vec::new(),
+            overlap_strategy: None,
+        }
+    }
+
+    /// Compute axis layout, resolving overlapping tick labels
+    ///
+    /// `measure` returns the rendered width of a line of label text. When
+    /// [`AxisConfig::label_overlap`] is set, overlaps are resolved with its
+    /// strategies; hidden labels are listed in [`AxisLayout::hidden_labels`].
+    /// Without it this is the same as [`compute_layout`](Self::compute_layout).
+    pub fn compute_layout_measured<F>(&self, axis_position: f64, measure: F) -> AxisLayout
+    where
+        F: Fn(&str) -> f64,
+    {
+        let mut layout = self.compute_layout(axis_position);
+        let Some(overlap) = &self.config.label_overlap else {
+            return layout;
+        };
+
+        let resolution = overlap.resolve(&mut layout.ticks, layout.orientation, measure);
+        if let Some(rotation) = resolution.rotation {
+            let mut config = self.config.clone();
+            config.label_rotation = rotation;
+            layout.label_rotation = rotation.angle;
+            layout.text_anchor = config.effective_text_anchor();
+            layout.label_align = config.effective_label_align();
         }
+        layout.hidden_labels = resolution.hidden;
+        layout.overlap_strategy = resolution.strategy;
+        layout
     }
 
     /// Compute layout for a single tick
@@ -640,6 +686,7 @@ impl Axis {
             label_rotation: self.config.label_rotation.angle,
             text_anchor: self.config.effective_text_anchor(),
             is_minor,
+            label_visible: true,
         }
     }
 }
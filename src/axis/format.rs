@@ -2,6 +2,9 @@
 //!
 //! Provides flexible formatting options for numeric and time values displayed on axes.
 
+use super::format_spec::{LocaleFormatter, NumberLocale};
+use crate::error::D3Result;
+use crate::scale::{TimeFormatter, TimeLocale};
 use chrono::{DateTime, Datelike, Timelike, Utc};
 use std::sync::Arc;
 
@@ -25,6 +28,8 @@ pub enum NumberFormat {
         /// Number of decimal places
         decimals: usize,
     },
+    /// d3-format specifier bound to a locale (e.g. `",.2f"`)
+    Spec(Arc<LocaleFormatter>),
     /// Custom formatter function
     Custom(Arc<dyn Fn(f64) -> String + Send + Sync>),
 }
@@ -44,6 +49,7 @@ impl std::fmt::Debug for NumberFormat {
                     prefix, decimals
                 )
             }
+            Self::Spec(formatter) => write!(f, "Spec({:?})", formatter.specifier().to_string()),
             Self::Custom(_) => write!(f, "Custom(<fn>)"),
         }
     }
@@ -67,6 +73,7 @@ impl NumberFormat {
             Self::Currency { prefix, decimals } => {
                 format!("{}{:.*}", prefix, *decimals, value)
             }
+            Self::Spec(formatter) => formatter.format(value),
             Self::Custom(f) => f(value),
         }
     }
@@ -89,6 +96,26 @@ impl NumberFormat {
         }
     }
 
+    /// Create a format from a d3-format specifier (e.g. `",.2f"`, `".3s"`)
+    ///
+    /// Uses the en-US locale. Returns a parse error for invalid specifiers.
+    ///
+    /// # Example
+    /// ```
+    /// use makepad_d3::axis::NumberFormat;
+    ///
+    /// let fmt = NumberFormat::from_spec("$,.2f").unwrap();
+    /// assert_eq!(fmt.format(1234.5), "$1,234.50");
+    /// ```
+    pub fn from_spec(spec: &str) -> D3Result<Self> {
+        Self::from_spec_with_locale(spec, &NumberLocale::en_us())
+    }
+
+    /// Create a format from a d3-format specifier and a number locale
+    pub fn from_spec_with_locale(spec: &str, locale: &NumberLocale) -> D3Result<Self> {
+        Ok(Self::Spec(Arc::new(locale.formatter(spec)?)))
+    }
+
     /// Create a custom formatter
     pub fn custom<F>(f: F) -> Self
     where
@@ -277,6 +304,8 @@ pub enum TimeFormat {
     Custom(String),
     /// Custom formatter function
     CustomFn(Arc<dyn Fn(DateTime<Utc>) -> String + Send + Sync>),
+    /// d3-time-format specifier bound to a [`TimeLocale`]
+    Locale(Arc<TimeFormatter>),
 }
 
 impl std::fmt::Debug for TimeFormat {
@@ -293,6 +322,7 @@ impl std::fmt::Debug for TimeFormat {
             Self::HourMinute => write!(f, "HourMinute"),
             Self::Custom(s) => write!(f, "Custom({:?})", s),
             Self::CustomFn(_) => write!(f, "CustomFn(<fn>)"),
+            Self::Locale(formatter) => write!(f, "Locale({:?})", formatter.spec()),
         }
     }
 }
@@ -318,6 +348,7 @@ impl TimeFormat {
             Self::HourMinute => dt.format("%H:%M").to_string(),
             Self::Custom(fmt) => dt.format(fmt).to_string(),
             Self::CustomFn(f) => f(dt),
+            Self::Locale(formatter) => formatter.format(dt),
         }
     }
 
@@ -342,6 +373,20 @@ impl TimeFormat {
     {
         Self::CustomFn(Arc::new(f))
     }
+
+    /// Create a localized format from a d3-time-format specifier
+    ///
+    /// ```
+    /// use makepad_d3::axis::{TimeFormat, TimeLocale};
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let fmt = TimeFormat::localized("%B %Y", &TimeLocale::fr_fr());
+    /// let dt = Utc.with_ymd_and_hms(2024, 8, 1, 0, 0, 0).unwrap();
+    /// assert_eq!(fmt.format(dt), "août 2024");
+    /// ```
+    pub fn localized(spec: impl Into<String>, locale: &TimeLocale) -> Self {
+        Self::Locale(Arc::new(locale.formatter(spec)))
+    }
 }
 
 /// Convert milliseconds timestamp to DateTime
@@ -406,6 +451,8 @@ pub struct MultiScaleTimeFormat {
     pub month: String,
     /// Format for year intervals
     pub year: String,
+    /// Locale for month/day names; `None` uses chrono's English formatting
+    pub locale: Option<TimeLocale>,
 }
 
 impl Default for MultiScaleTimeFormat {
@@ -419,6 +466,7 @@ impl Default for MultiScaleTimeFormat {
             week: "%b %d".to_string(),
             month: "%b %Y".to_string(),
             year: "%Y".to_string(),
+            locale: None,
         }
     }
 }
@@ -432,7 +480,10 @@ impl MultiScaleTimeFormat {
     /// Format a datetime based on the interval duration (in milliseconds)
     pub fn format(&self, dt: DateTime<Utc>, interval_ms: f64) -> String {
         let format_str = self.get_format_for_interval(interval_ms);
-        dt.format(&format_str).to_string()
+        match &self.locale {
+            Some(locale) => locale.format(format_str, dt),
+            None => dt.format(format_str).to_string(),
+        }
     }
 
     /// Get the format string for a given interval
@@ -511,6 +562,12 @@ impl MultiScaleTimeFormat {
         self.year = fmt.into();
         self
     }
+
+    /// Format with a locale's month, day and period names
+    pub fn with_locale(mut self, locale: TimeLocale) -> Self {
+        self.locale = Some(locale);
+        self
+    }
 }
 
 /// Relative time format (e.g., "2 hours ago", "in 3 days")
@@ -595,6 +652,17 @@ mod tests {
         assert_eq!(fmt.format(0.5), "$0.50");
     }
 
+    #[test]
+    fn test_spec_format() {
+        let fmt = NumberFormat::from_spec(",.1f").unwrap();
+        assert_eq!(fmt.format(1234.56), "1,234.6");
+
+        let fmt = NumberFormat::from_spec_with_locale("$,.1f", &NumberLocale::de_de()).unwrap();
+        assert_eq!(fmt.format(1234.5), "1.234,5\u{a0}€");
+
+        assert!(NumberFormat::from_spec("bogus!").is_err());
+    }
+
     #[test]
     fn test_custom_format() {
         let fmt = NumberFormat::custom(|v| format!("Value: {:.1}", v));
@@ -702,6 +770,19 @@ mod tests {
         assert_eq!(fmt.month, "%B %Y");
     }
 
+    #[test]
+    fn test_multi_scale_time_format_locale() {
+        let fmt = MultiScaleTimeFormat::new().with_locale(TimeLocale::de_de());
+        // 2024-03-15 14:30:45
+        let dt = DateTime::from_timestamp(1710513045, 0).unwrap();
+        let month = 30.0 * 24.0 * 3600.0 * 1000.0;
+        assert_eq!(fmt.format(dt, month), "Mrz 2024");
+        assert_eq!(fmt.format(dt, 500.0), "14:30:45.000");
+
+        let ja = TimeFormat::localized("%Y年%B%-d日 %p", &TimeLocale::ja_jp());
+        assert_eq!(ja.format(dt), "2024年3月15日 午後");
+    }
+
     #[test]
     fn test_relative_time_format() {
         let now = DateTime::from_timestamp(1705329045, 0).unwrap();
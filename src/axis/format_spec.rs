@@ -0,0 +1,975 @@
+//! d3-format specifier strings and number locales
+//!
+//! Parses the d3-format mini-language
+//! (`[[fill]align][sign][symbol][0][width][,][.precision][~][type]`) and
+//! formats numbers with locale-specific decimal/group separators and
+//! currency placement.
+//!
+//! # Example
+//! ```
+//! use makepad_d3::axis::NumberLocale;
+//!
+//! let en = NumberLocale::en_us();
+//! assert_eq!(en.format(",.2f", 1234.5).unwrap(), "1,234.50");
+//! assert_eq!(en.format("+.1%", 0.123).unwrap(), "+12.3%");
+//! assert_eq!(en.format(".3s", 1500.0).unwrap(), "1.50k");
+//! assert_eq!(en.format("$,.0f", 1234.5).unwrap(), "$1,235");
+//!
+//! let de = NumberLocale::de_de();
+//! assert_eq!(de.format("$,.1f", 1234.5).unwrap(), "1.234,5\u{a0}€");
+//! ```
+
+use crate::error::{D3Error, D3Result};
+use std::fmt;
+
+/// SI prefixes indexed by `8 + exponent / 3`
+const SI_PREFIXES: [&str; 17] = [
+    "y", "z", "a", "f", "p", "n", "µ", "m", "", "k", "M", "G", "T", "P", "E", "Z", "Y",
+];
+
+/// A parsed d3-format specifier
+///
+/// Fields mirror d3's `FormatSpecifier`. Use [`FormatSpecifier::parse`] to
+/// build one from a string and `to_string()` to serialize it back.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FormatSpecifier {
+    /// Fill character used for padding (default space)
+    pub fill: char,
+    /// Alignment: `>` right, `<` left, `^` center, `=` pad after sign
+    pub align: char,
+    /// Sign: `-` minus only, `+` always, `(` parentheses, ` ` space for positive
+    pub sign: char,
+    /// Symbol: `$` currency, `#` base prefix for `b`/`o`/`x`/`X`
+    pub symbol: Option<char>,
+    /// Zero padding (implies fill `0` and align `=`)
+    pub zero: bool,
+    /// Minimum field width
+    pub width: Option<usize>,
+    /// Use the locale group separator
+    pub comma: bool,
+    /// Precision (digits after the decimal point or significant digits)
+    pub precision: Option<usize>,
+    /// Trim insignificant trailing zeros
+    pub trim: bool,
+    /// Format type (`e f g r s % p b o d x X c n`), `None` for the default
+    pub format_type: Option<char>,
+}
+
+impl Default for FormatSpecifier {
+    fn default() -> Self {
+        Self {
+            fill: ' ',
+            align: '>',
+            sign: '-',
+            symbol: None,
+            zero: false,
+            width: None,
+            comma: false,
+            precision: None,
+            trim: false,
+            format_type: None,
+        }
+    }
+}
+
+impl FormatSpecifier {
+    /// Parse a d3-format specifier string
+    ///
+    /// Returns a parse error if the string does not match the grammar.
+    pub fn parse(spec: &str) -> D3Result<Self> {
+        let chars: Vec<char> = spec.chars().collect();
+        let invalid = || D3Error::parse_error(format!("invalid format: {}", spec));
+        let mut out = Self::default();
+        let mut i = 0;
+
+        let is_align = |c: char| matches!(c, '<' | '>' | '=' | '^');
+        if chars.len() >= 2 && is_align(chars[1]) {
+            out.fill = chars[0];
+            out.align = chars[1];
+            i = 2;
+        } else if !chars.is_empty() && is_align(chars[0]) {
+            out.align = chars[0];
+            i = 1;
+        }
+
+        if let Some(&c) = chars.get(i) {
+            if matches!(c, '+' | '-' | '(' | ' ') {
+                out.sign = c;
+                i += 1;
+            }
+        }
+
+        if let Some(&c) = chars.get(i) {
+            if matches!(c, '$' | '#') {
+                out.symbol = Some(c);
+                i += 1;
+            }
+        }
+
+        if chars.get(i) == Some(&'0') {
+            out.zero = true;
+            i += 1;
+        }
+
+        let start = i;
+        while chars.get(i).is_some_and(|c| c.is_ascii_digit()) {
+            i += 1;
+        }
+        if i > start {
+            let digits: String = chars[start..i].iter().collect();
+            out.width = Some(digits.parse().map_err(|_| invalid())?);
+        }
+
+        if chars.get(i) == Some(&',') {
+            out.comma = true;
+            i += 1;
+        }
+
+        if chars.get(i) == Some(&'.') {
+            i += 1;
+            let start = i;
+            while chars.get(i).is_some_and(|c| c.is_ascii_digit()) {
+                i += 1;
+            }
+            if i == start {
+                return Err(invalid());
+            }
+            let digits: String = chars[start..i].iter().collect();
+            out.precision = Some(digits.parse().map_err(|_| invalid())?);
+        }
+
+        if chars.get(i) == Some(&'~') {
+            out.trim = true;
+            i += 1;
+        }
+
+        if let Some(&c) = chars.get(i) {
+            if c.is_ascii_alphabetic() || c == '%' {
+                out.format_type = Some(c);
+                i += 1;
+            }
+        }
+
+        if i != chars.len() {
+            return Err(invalid());
+        }
+
+        Ok(out)
+    }
+}
+
+impl fmt::Display for FormatSpecifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{}", self.fill, self.align, self.sign)?;
+        if let Some(symbol) = self.symbol {
+            write!(f, "{}", symbol)?;
+        }
+        if self.zero {
+            write!(f, "0")?;
+        }
+        if let Some(width) = self.width {
+            write!(f, "{}", width.max(1))?;
+        }
+        if self.comma {
+            write!(f, ",")?;
+        }
+        if let Some(precision) = self.precision {
+            write!(f, ".{}", precision)?;
+        }
+        if self.trim {
+            write!(f, "~")?;
+        }
+        if let Some(ty) = self.format_type {
+            write!(f, "{}", ty)?;
+        }
+        Ok(())
+    }
+}
+
+/// Locale definition for number formatting
+///
+/// Mirrors d3's `formatLocale` definition object. The minus sign defaults
+/// to ASCII hyphen-minus (d3 ≥ 2 uses U+2212); use
+/// [`with_minus`](Self::with_minus) to change it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NumberLocale {
+    /// Decimal point (e.g. `"."` or `","`)
+    pub decimal: String,
+    /// Group separator (e.g. `","`, `"."`, or a no-break space)
+    pub thousands: String,
+    /// Group sizes from the right, cycled when exhausted (e.g. `[3]`)
+    pub grouping: Vec<usize>,
+    /// Currency prefix (e.g. `"$"`)
+    pub currency_prefix: String,
+    /// Currency suffix (e.g. `"\u{a0}€"`)
+    pub currency_suffix: String,
+    /// Percent sign
+    pub percent: String,
+    /// Minus sign
+    pub minus: String,
+    /// Representation of NaN
+    pub nan: String,
+}
+
+impl Default for NumberLocale {
+    fn default() -> Self {
+        Self::en_us()
+    }
+}
+
+impl NumberLocale {
+    /// Build a locale from separators, grouping and currency affixes
+    fn define(
+        decimal: &str,
+        thousands: &str,
+        grouping: &[usize],
+        currency_prefix: &str,
+        currency_suffix: &str,
+    ) -> Self {
+        Self {
+            decimal: decimal.to_string(),
+            thousands: thousands.to_string(),
+            grouping: grouping.to_vec(),
+            currency_prefix: currency_prefix.to_string(),
+            currency_suffix: currency_suffix.to_string(),
+            percent: "%".to_string(),
+            minus: "-".to_string(),
+            nan: "NaN".to_string(),
+        }
+    }
+
+    /// English (United States): `$1,234.56`
+    pub fn en_us() -> Self {
+        Self::define(".", ",", &[3], "$", "")
+    }
+
+    /// English (United Kingdom): `£1,234.56`
+    pub fn en_gb() -> Self {
+        Self::define(".", ",", &[3], "£", "")
+    }
+
+    /// English (India): `₹12,34,567.89`
+    pub fn en_in() -> Self {
+        Self::define(".", ",", &[3, 2, 2, 2, 2, 2, 2, 2, 2, 2], "₹", "")
+    }
+
+    /// German (Germany): `1.234,56 €`
+    pub fn de_de() -> Self {
+        Self::define(",", ".", &[3], "", "\u{a0}€")
+    }
+
+    /// German (Switzerland): `CHF 1'234.56`
+    pub fn de_ch() -> Self {
+        Self::define(".", "'", &[3], "CHF\u{a0}", "")
+    }
+
+    /// French (France): `1 234,56 €`
+    pub fn fr_fr() -> Self {
+        Self::define(",", "\u{a0}", &[3], "", "\u{a0}€").with_percent("\u{202f}%")
+    }
+
+    /// Spanish (Spain): `1.234,56 €`
+    pub fn es_es() -> Self {
+        Self::define(",", ".", &[3], "", "\u{a0}€")
+    }
+
+    /// Italian (Italy): `€1.234,56`
+    pub fn it_it() -> Self {
+        Self::define(",", ".", &[3], "€", "")
+    }
+
+    /// Portuguese (Brazil): `R$1.234,56`
+    pub fn pt_br() -> Self {
+        Self::define(",", ".", &[3], "R$", "")
+    }
+
+    /// Russian (Russia): `1 234,56 ₽`
+    pub fn ru_ru() -> Self {
+        Self::define(",", "\u{a0}", &[3], "", "\u{a0}₽")
+    }
+
+    /// Japanese (Japan): `1,234円`
+    pub fn ja_jp() -> Self {
+        Self::define(".", ",", &[3], "", "円")
+    }
+
+    /// Chinese (China): `¥1,234.56`
+    pub fn zh_cn() -> Self {
+        Self::define(".", ",", &[3], "¥", "")
+    }
+
+    /// Look up a built-in locale by BCP 47 tag (e.g. `"de-DE"`, `"fr"`)
+    ///
+    /// A bare language tag selects the first built-in region for it.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.replace('_', "-").to_ascii_lowercase();
+        let locale = match name.as_str() {
+            "en" | "en-us" => Self::en_us(),
+            "en-gb" => Self::en_gb(),
+            "en-in" => Self::en_in(),
+            "de" | "de-de" => Self::de_de(),
+            "de-ch" => Self::de_ch(),
+            "fr" | "fr-fr" => Self::fr_fr(),
+            "es" | "es-es" => Self::es_es(),
+            "it" | "it-it" => Self::it_it(),
+            "pt" | "pt-br" => Self::pt_br(),
+            "ru" | "ru-ru" => Self::ru_ru(),
+            "ja" | "ja-jp" => Self::ja_jp(),
+            "zh" | "zh-cn" => Self::zh_cn(),
+            _ => return None,
+        };
+        Some(locale)
+    }
+
+    /// Set the decimal point
+    pub fn with_decimal(mut self, decimal: impl Into<String>) -> Self {
+        self.decimal = decimal.into();
+        self
+    }
+
+    /// Set the group separator
+    pub fn with_thousands(mut self, thousands: impl Into<String>) -> Self {
+        self.thousands = thousands.into();
+        self
+    }
+
+    /// Set the group sizes
+    pub fn with_grouping(mut self, grouping: Vec<usize>) -> Self {
+        self.grouping = grouping;
+        self
+    }
+
+    /// Set the currency prefix and suffix
+    pub fn with_currency(mut self, prefix: impl Into<String>, suffix: impl Into<String>) -> Self {
+        self.currency_prefix = prefix.into();
+        self.currency_suffix = suffix.into();
+        self
+    }
+
+    /// Set the percent sign
+    pub fn with_percent(mut self, percent: impl Into<String>) -> Self {
+        self.percent = percent.into();
+        self
+    }
+
+    /// Set the minus sign
+    pub fn with_minus(mut self, minus: impl Into<String>) -> Self {
+        self.minus = minus.into();
+        self
+    }
+
+    /// Set the NaN representation
+    pub fn with_nan(mut self, nan: impl Into<String>) -> Self {
+        self.nan = nan.into();
+        self
+    }
+
+    /// Create a formatter for a d3-format specifier string
+    pub fn formatter(&self, spec: &str) -> D3Result<LocaleFormatter> {
+        Ok(LocaleFormatter::new(
+            FormatSpecifier::parse(spec)?,
+            self.clone(),
+        ))
+    }
+
+    /// Format a single value with a d3-format specifier string
+    pub fn format(&self, spec: &str, value: f64) -> D3Result<String> {
+        Ok(self.formatter(spec)?.format(value))
+    }
+
+    /// Insert group separators into a string of integer digits
+    ///
+    /// `width` limits the grouped length, used when zero-padding.
+    fn group(&self, value: &str, width: usize) -> String {
+        if self.grouping.is_empty() || self.thousands.is_empty() {
+            return value.to_string();
+        }
+        let chars: Vec<char> = value.chars().collect();
+        let mut i = chars.len();
+        let mut parts: Vec<String> = Vec::new();
+        let mut j = 0;
+        let mut g = self.grouping[0];
+        let mut length = 0;
+
+        while i > 0 && g > 0 {
+            if length + g + 1 > width {
+                g = width.saturating_sub(length).max(1);
+            }
+            let start = i.saturating_sub(g);
+            parts.push(chars[start..i].iter().collect());
+            i = start;
+            length += g + 1;
+            if length > width {
+                break;
+            }
+            j = (j + 1) % self.grouping.len();
+            g = self.grouping[j];
+        }
+
+        parts.reverse();
+        parts.join(&self.thousands)
+    }
+}
+
+/// A compiled number formatter: a [`FormatSpecifier`] bound to a locale
+#[derive(Clone, Debug, PartialEq)]
+pub struct LocaleFormatter {
+    spec: FormatSpecifier,
+    locale: NumberLocale,
+}
+
+impl LocaleFormatter {
+    /// Create a formatter, normalizing the specifier the way d3 does
+    pub fn new(mut spec: FormatSpecifier, locale: NumberLocale) -> Self {
+        if spec.zero || (spec.fill == '0' && spec.align == '=') {
+            spec.zero = true;
+            spec.fill = '0';
+            spec.align = '=';
+        }
+
+        match spec.format_type {
+            Some('n') => {
+                spec.comma = true;
+                spec.format_type = Some('g');
+            }
+            Some(ty) if is_format_type(ty) => {}
+            _ => {
+                if spec.precision.is_none() {
+                    spec.precision = Some(12);
+                }
+                spec.trim = true;
+                spec.format_type = Some('g');
+            }
+        }
+
+        let ty = spec.format_type.unwrap_or('g');
+        spec.precision = Some(match spec.precision {
+            None => 6,
+            Some(p) if matches!(ty, 'g' | 'p' | 'r' | 's') => p.clamp(1, 21),
+            Some(p) => p.min(20),
+        });
+
+        Self { spec, locale }
+    }
+
+    /// Get the normalized specifier
+    pub fn specifier(&self) -> &FormatSpecifier {
+        &self.spec
+    }
+
+    /// Get the locale
+    pub fn locale(&self) -> &NumberLocale {
+        &self.locale
+    }
+
+    /// Format a value
+    pub fn format(&self, value: f64) -> String {
+        let spec = &self.spec;
+        let locale = &self.locale;
+        let ty = spec.format_type.unwrap_or('g');
+        let precision = spec.precision.unwrap_or(6);
+
+        let mut value_prefix = match spec.symbol {
+            Some('$') => locale.currency_prefix.clone(),
+            Some('#') if matches!(ty, 'b' | 'o' | 'x' | 'X') => {
+                format!("0{}", ty.to_ascii_lowercase())
+            }
+            _ => String::new(),
+        };
+        let mut value_suffix = if spec.symbol == Some('$') {
+            locale.currency_suffix.clone()
+        } else if matches!(ty, '%' | 'p') {
+            locale.percent.clone()
+        } else {
+            String::new()
+        };
+
+        let mut body;
+        if ty == 'c' {
+            value_suffix = format!("{}{}", value, value_suffix);
+            body = String::new();
+        } else {
+            let mut negative = value < 0.0 || (value == 0.0 && value.is_sign_negative());
+            let mut prefix_exponent = 0;
+            body = if value.is_nan() {
+                locale.nan.clone()
+            } else {
+                format_type(ty, value.abs(), precision, &mut prefix_exponent)
+            };
+            if spec.trim {
+                body = format_trim(&body);
+            }
+            if negative && body.parse::<f64>() == Ok(0.0) && spec.sign != '+' {
+                negative = false;
+            }
+
+            let sign = if negative {
+                if spec.sign == '(' {
+                    "(".to_string()
+                } else {
+                    locale.minus.clone()
+                }
+            } else if spec.sign == '-' || spec.sign == '(' {
+                String::new()
+            } else {
+                spec.sign.to_string()
+            };
+            value_prefix = sign + &value_prefix;
+
+            let si = if ty == 's' {
+                SI_PREFIXES[(8 + prefix_exponent / 3) as usize]
+            } else {
+                ""
+            };
+            let close = if negative && spec.sign == '(' {
+                ")"
+            } else {
+                ""
+            };
+            value_suffix = format!("{}{}{}", si, value_suffix, close);
+
+            // Split off the decimal part (and exponent) so only the integer
+            // digits are grouped and padded.
+            if matches!(ty, 'd' | 'e' | 'f' | 'g' | 'p' | 'r' | 's' | '%') {
+                if let Some(i) = body.find(|c: char| !c.is_ascii_digit()) {
+                    let rest = if body[i..].starts_with('.') {
+                        format!("{}{}", locale.decimal, &body[i + 1..])
+                    } else {
+                        body[i..].to_string()
+                    };
+                    value_suffix = rest + &value_suffix;
+                    body.truncate(i);
+                }
+            }
+        }
+
+        let width = spec.width.unwrap_or(0);
+        if spec.comma && !spec.zero {
+            body = locale.group(&body, usize::MAX);
+        }
+
+        let length =
+            value_prefix.chars().count() + body.chars().count() + value_suffix.chars().count();
+        let mut padding = if length < width {
+            spec.fill.to_string().repeat(width - length)
+        } else {
+            String::new()
+        };
+
+        if spec.comma && spec.zero {
+            let limit = if padding.is_empty() {
+                usize::MAX
+            } else {
+                width - value_suffix.chars().count()
+            };
+            body = locale.group(&(padding + &body), limit);
+            padding = String::new();
+        }
+
+        match spec.align {
+            '<' => format!("{}{}{}{}", value_prefix, body, value_suffix, padding),
+            '=' => format!("{}{}{}{}", value_prefix, padding, body, value_suffix),
+            '^' => {
+                let half = padding.chars().count() / 2;
+                let left: String = padding.chars().take(half).collect();
+                let right: String = padding.chars().skip(half).collect();
+                format!("{}{}{}{}{}", left, value_prefix, body, value_suffix, right)
+            }
+            _ => format!("{}{}{}{}", padding, value_prefix, body, value_suffix),
+        }
+    }
+}
+
+/// Whether `ty` is a recognized d3-format type
+fn is_format_type(ty: char) -> bool {
+    matches!(
+        ty,
+        '%' | 'b' | 'c' | 'd' | 'e' | 'f' | 'g' | 'o' | 'p' | 'r' | 's' | 'x' | 'X'
+    )
+}
+
+/// Format a non-negative value for a d3-format type
+fn format_type(ty: char, x: f64, p: usize, prefix_exponent: &mut i32) -> String {
+    if x.is_infinite() {
+        return "Infinity".to_string();
+    }
+    match ty {
+        '%' => to_fixed(x * 100.0, p),
+        'b' => format!("{:b}", round_half_up(x)),
+        'o' => format!("{:o}", round_half_up(x)),
+        'x' => format!("{:x}", round_half_up(x)),
+        'X' => format!("{:X}", round_half_up(x)),
+        'd' => to_fixed(x, 0),
+        'e' => to_exponential(x, p),
+        'f' => to_fixed(x, p),
+        'p' => format_rounded(x * 100.0, p),
+        'r' => format_rounded(x, p),
+        's' => format_prefix_auto(x, p, prefix_exponent),
+        _ => to_precision(x, p),
+    }
+}
+
+/// Round to the nearest integer, ties away from zero (JavaScript `Math.round`)
+fn round_half_up(x: f64) -> u64 {
+    (x + 0.5).floor() as u64
+}
+
+/// Exact decimal expansion of a finite non-negative value
+///
+/// Returns the significant digits (without trailing zeros) and the power
+/// of ten of the first digit. Zero has no digits.
+fn decimal_digits(x: f64) -> (Vec<u8>, i32) {
+    if x == 0.0 {
+        return (Vec::new(), 0);
+    }
+    // 800 fractional digits cover the longest exact expansion of an f64.
+    let s = format!("{:.800e}", x);
+    let (mantissa, exponent) = s.split_once('e').unwrap_or((&s, "0"));
+    let exponent = exponent.parse().unwrap_or(0);
+    let mut digits: Vec<u8> = mantissa
+        .bytes()
+        .filter(u8::is_ascii_digit)
+        .map(|b| b - b'0')
+        .collect();
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+    (digits, exponent)
+}
+
+/// Round an exact decimal expansion to `keep` significant digits
+///
+/// Ties round away from zero, matching JavaScript's `toFixed`,
+/// `toExponential` and `toPrecision`. The result has exactly `keep` digits
+/// (zero-padded) unless it rounded to zero, in which case it is empty.
+fn round_digits(digits: &[u8], exponent: i32, keep: i64) -> (Vec<u8>, i32) {
+    if digits.is_empty() || keep < 0 {
+        return (Vec::new(), 0);
+    }
+    if keep == 0 {
+        return if digits[0] >= 5 {
+            (vec![1], exponent + 1)
+        } else {
+            (Vec::new(), 0)
+        };
+    }
+
+    let keep = keep as usize;
+    let mut out: Vec<u8> = digits.iter().copied().take(keep).collect();
+    out.resize(keep, 0);
+    let mut exponent = exponent;
+
+    if digits.get(keep).is_some_and(|&d| d >= 5) {
+        let mut i = keep;
+        loop {
+            if i == 0 {
+                out.insert(0, 1);
+                out.truncate(keep);
+                exponent += 1;
+                break;
+            }
+            i -= 1;
+            if out[i] == 9 {
+                out[i] = 0;
+            } else {
+                out[i] += 1;
+                break;
+            }
+        }
+    }
+
+    (out, exponent)
+}
+
+/// Render rounded digits in fixed notation with `frac` decimals
+fn fixed_string(digits: &[u8], exponent: i32, frac: usize) -> String {
+    let digit_at = |place: i64| -> char {
+        let index = exponent as i64 - place;
+        if index < 0 {
+            '0'
+        } else {
+            digits
+                .get(index as usize)
+                .map_or('0', |&d| (b'0' + d) as char)
+        }
+    };
+
+    let mut s = String::new();
+    if digits.is_empty() || exponent < 0 {
+        s.push('0');
+    } else {
+        for place in (0..=exponent as i64).rev() {
+            s.push(digit_at(place));
+        }
+    }
+    if frac > 0 {
+        s.push('.');
+        for place in 1..=frac as i64 {
+            s.push(digit_at(-place));
+        }
+    }
+    s
+}
+
+/// Render rounded digits in exponential notation (`1.23e+4`)
+fn exponential_string(digits: &[u8], exponent: i32, frac: usize) -> String {
+    let mut s = String::new();
+    s.push((b'0' + digits.first().copied().unwrap_or(0)) as char);
+    if frac > 0 {
+        s.push('.');
+        for i in 1..=frac {
+            s.push((b'0' + digits.get(i).copied().unwrap_or(0)) as char);
+        }
+    }
+    let sign = if exponent < 0 { '-' } else { '+' };
+    s.push_str(&format!("e{}{}", sign, exponent.abs()));
+    s
+}
+
+/// JavaScript `Number.prototype.toFixed`
+fn to_fixed(x: f64, p: usize) -> String {
+    let (digits, exponent) = decimal_digits(x);
+    let keep = exponent as i64 + 1 + p as i64;
+    let (digits, exponent) = round_digits(&digits, exponent, keep);
+    fixed_string(&digits, exponent, p)
+}
+
+/// JavaScript `Number.prototype.toExponential`
+fn to_exponential(x: f64, p: usize) -> String {
+    let (digits, exponent) = decimal_digits(x);
+    let (digits, exponent) = round_digits(&digits, exponent, p as i64 + 1);
+    exponential_string(&digits, exponent, p)
+}
+
+/// JavaScript `Number.prototype.toPrecision`
+fn to_precision(x: f64, p: usize) -> String {
+    let (digits, exponent) = decimal_digits(x);
+    if digits.is_empty() {
+        return fixed_string(&[], 0, p - 1);
+    }
+    let (digits, exponent) = round_digits(&digits, exponent, p as i64);
+    if exponent < -6 || exponent >= p as i32 {
+        exponential_string(&digits, exponent, p - 1)
+    } else {
+        fixed_string(&digits, exponent, (p as i32 - 1 - exponent) as usize)
+    }
+}
+
+/// Coefficient digits (exactly `p` of them) and exponent of `x`
+///
+/// Equivalent to d3's `formatDecimalParts(x, p)`; `p == 0` yields the
+/// shortest round-tripping digits.
+fn decimal_parts(x: f64, p: usize) -> (String, i32) {
+    if p == 0 {
+        let s = format!("{:e}", x);
+        let (mantissa, exponent) = s.split_once('e').unwrap_or((&s, "0"));
+        let coefficient = mantissa.chars().filter(char::is_ascii_digit).collect();
+        return (coefficient, exponent.parse().unwrap_or(0));
+    }
+    let (digits, exponent) = decimal_digits(x);
+    let (mut digits, exponent) = round_digits(&digits, exponent, p as i64);
+    digits.resize(p, 0);
+    let coefficient = digits.iter().map(|&d| (b'0' + d) as char).collect();
+    (coefficient, exponent)
+}
+
+/// Format with `p` significant digits in fixed notation (d3 type `r`)
+fn format_rounded(x: f64, p: usize) -> String {
+    let (coefficient, exponent) = decimal_parts(x, p);
+    if exponent < 0 {
+        format!("0.{}{}", "0".repeat((-exponent - 1) as usize), coefficient)
+    } else if coefficient.len() > exponent as usize + 1 {
+        let split = exponent as usize + 1;
+        format!("{}.{}", &coefficient[..split], &coefficient[split..])
+    } else {
+        let zeros = exponent as usize + 1 - coefficient.len();
+        format!("{}{}", coefficient, "0".repeat(zeros))
+    }
+}
+
+/// Format with `p` significant digits scaled to an SI prefix (d3 type `s`)
+fn format_prefix_auto(x: f64, p: usize, prefix_exponent: &mut i32) -> String {
+    let (coefficient, exponent) = decimal_parts(x, p);
+    *prefix_exponent = exponent.div_euclid(3).clamp(-8, 8) * 3;
+    let i = exponent - *prefix_exponent + 1;
+    let n = coefficient.len() as i32;
+
+    if i == n {
+        coefficient
+    } else if i > n {
+        format!("{}{}", coefficient, "0".repeat((i - n) as usize))
+    } else if i > 0 {
+        let i = i as usize;
+        format!("{}.{}", &coefficient[..i], &coefficient[i..])
+    } else {
+        // Less than 1 yocto
+        let (tail, _) = decimal_parts(x, (p as i32 + i - 1).max(0) as usize);
+        format!("0.{}{}", "0".repeat((-i) as usize), tail)
+    }
+}
+
+/// Remove insignificant trailing zeros (d3 `~` option)
+fn format_trim(s: &str) -> String {
+    let chars: Vec<char> = s.chars().collect();
+    let mut i0: isize = -1;
+    let mut i1 = 0;
+
+    for (i, &c) in chars.iter().enumerate().skip(1) {
+        match c {
+            '.' => {
+                i0 = i as isize;
+                i1 = i;
+            }
+            '0' => {
+                if i0 == 0 {
+                    i0 = i as isize;
+                }
+                i1 = i;
+            }
+            c if c.is_ascii_digit() => {
+                if i0 > 0 {
+                    i0 = 0;
+                }
+            }
+            _ => break,
+        }
+    }
+
+    if i0 > 0 {
+        let head: String = chars[..i0 as usize].iter().collect();
+        let tail: String = chars[i1 + 1..].iter().collect();
+        head + &tail
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fmt(spec: &str, value: f64) -> String {
+        NumberLocale::en_us().format(spec, value).unwrap()
+    }
+
+    #[test]
+    fn test_parse_specifier() {
+        let spec = FormatSpecifier::parse("*^+$012,.3~f").unwrap();
+        assert_eq!(spec.fill, '*');
+        assert_eq!(spec.align, '^');
+        assert_eq!(spec.sign, '+');
+        assert_eq!(spec.symbol, Some('$'));
+        assert!(spec.zero);
+        assert_eq!(spec.width, Some(12));
+        assert!(spec.comma);
+        assert_eq!(spec.precision, Some(3));
+        assert!(spec.trim);
+        assert_eq!(spec.format_type, Some('f'));
+        assert_eq!(spec.to_string(), "*^+$012,.3~f");
+    }
+
+    #[test]
+    fn test_parse_specifier_errors() {
+        assert!(FormatSpecifier::parse("..2f").is_err());
+        assert!(FormatSpecifier::parse("3.f").is_err());
+        assert!(FormatSpecifier::parse(",.2ff").is_err());
+        assert_eq!(
+            FormatSpecifier::parse(",.2f").unwrap().to_string(),
+            " >-,.2f"
+        );
+    }
+
+    #[test]
+    fn test_fixed_and_comma() {
+        assert_eq!(fmt(",.2f", 1234.5), "1,234.50");
+        assert_eq!(fmt("$,.0f", 1234.5), "$1,235");
+        assert_eq!(fmt(",", 1234567.0), "1,234,567");
+        assert_eq!(fmt(".2f", 1.005), "1.00");
+        assert_eq!(fmt(".0f", 2.5), "3");
+        assert_eq!(fmt("d", 2.5), "3");
+    }
+
+    #[test]
+    fn test_percent_and_sign() {
+        assert_eq!(fmt("+.1%", 0.123), "+12.3%");
+        assert_eq!(fmt(".0%", 0.123), "12%");
+        assert_eq!(fmt("(.2f", -1.5), "(1.50)");
+        assert_eq!(fmt(" .1f", 1.0), " 1.0");
+        assert_eq!(fmt(".1f", -0.0001), "0.0");
+        assert_eq!(fmt("+.1f", -0.0001), "-0.0");
+    }
+
+    #[test]
+    fn test_si_prefix() {
+        assert_eq!(fmt(".3s", 1500.0), "1.50k");
+        assert_eq!(fmt("~s", 1500.0), "1.5k");
+        assert_eq!(fmt(".3s", 0.0012), "1.20m");
+        assert_eq!(fmt(".2s", 42e6), "42M");
+        assert_eq!(fmt("s", 0.0), "0.00000");
+    }
+
+    #[test]
+    fn test_exponent_precision_rounded() {
+        assert_eq!(fmt("e", 123.0), "1.230000e+2");
+        assert_eq!(fmt(".1e", 0.49), "4.9e-1");
+        assert_eq!(fmt("r", 0.049), "0.0490000");
+        assert_eq!(fmt(".2r", 12345.0), "12000");
+        assert_eq!(fmt(".3g", 1234.0), "1.23e+3");
+        assert_eq!(fmt(".3p", 0.1234), "12.3%");
+        assert_eq!(fmt("", 0.1 + 0.2), "0.3");
+        assert_eq!(fmt(".2~f", 1.5), "1.5");
+    }
+
+    #[test]
+    fn test_integer_types() {
+        assert_eq!(fmt("x", 255.0), "ff");
+        assert_eq!(fmt("#x", 255.0), "0xff");
+        assert_eq!(fmt("X", 255.0), "FF");
+        assert_eq!(fmt("b", 5.0), "101");
+        assert_eq!(fmt("o", 8.0), "10");
+        assert_eq!(fmt("c", 42.0), "42");
+    }
+
+    #[test]
+    fn test_width_fill_align() {
+        assert_eq!(fmt("^7d", 42.0), "  42   ");
+        assert_eq!(fmt("*<6d", 42.0), "42****");
+        assert_eq!(fmt("8d", 42.0), "      42");
+        assert_eq!(fmt("08.2f", -3.14259), "-0003.14");
+        assert_eq!(fmt("012,.2f", 1234.5), "0,001,234.50");
+        assert_eq!(fmt("=+8d", 42.0), "+     42");
+    }
+
+    #[test]
+    fn test_nan() {
+        assert_eq!(fmt(".2f", f64::NAN), "NaN");
+        let locale = NumberLocale::en_us().with_nan("–");
+        assert_eq!(locale.format(".2f", f64::NAN).unwrap(), "–");
+    }
+
+    #[test]
+    fn test_locales() {
+        let de = NumberLocale::de_de();
+        assert_eq!(de.format("$,.1f", 1234.5).unwrap(), "1.234,5\u{a0}€");
+
+        let fr = NumberLocale::from_name("fr-FR").unwrap();
+        assert_eq!(
+            fr.format(",.2f", 1234567.891).unwrap(),
+            "1\u{a0}234\u{a0}567,89"
+        );
+
+        let india = NumberLocale::en_in();
+        assert_eq!(india.format(",d", 12345678.0).unwrap(), "1,23,45,678");
+
+        let ja = NumberLocale::from_name("ja").unwrap();
+        assert_eq!(ja.format("$,d", 1234.0).unwrap(), "1,234円");
+
+        assert!(NumberLocale::from_name("xx-YY").is_none());
+    }
+
+    #[test]
+    fn test_custom_minus() {
+        let locale = NumberLocale::en_us().with_minus("\u{2212}");
+        assert_eq!(locale.format(".1f", -2.0).unwrap(), "\u{2212}2.0");
+    }
+}
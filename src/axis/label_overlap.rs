@@ -0,0 +1,477 @@
+//! Tick label collision avoidance
+//!
+//! Detects overlapping tick labels using a caller-supplied text measurement
+//! function and resolves them by trying strategies in order: thinning,
+//! rotation, wrapping and truncation.
+//!
+//! # Example
+//! ```
+//! use makepad_d3::axis::{Axis, AxisConfig, LabelOverlap, OverlapStrategy};
+//! use makepad_d3::scale::Tick;
+//!
+//! let mut axis = Axis::with_config(AxisConfig::bottom().with_label_overlap(LabelOverlap::new()));
+//! axis.set_range((0.0, 250.0));
+//! axis.set_ticks(
+//!     (0..10)
+//!         .map(|i| Tick::new(i as f64, format!("Label {}", i)).with_position(i as f64 * 25.0))
+//!         .collect(),
+//! );
+//!
+//! // Roughly 7 pixels per character
+//! let layout = axis.compute_layout_measured(300.0, |s| s.chars().count() as f64 * 7.0);
+//! assert_eq!(layout.overlap_strategy, Some(OverlapStrategy::Rotate));
+//! assert_eq!(layout.label_rotation, -45.0);
+//! ```
+
+use super::axis::{AxisOrientation, AxisTick, LabelAlign, LabelRotation, TextAnchor};
+
+/// A way of resolving overlapping tick labels
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OverlapStrategy {
+    /// Hide labels greedily until the remaining ones fit
+    Thin,
+    /// Rotate labels to 45° or 90° (horizontal axes only)
+    Rotate,
+    /// Wrap labels at whitespace onto multiple lines (horizontal axes only)
+    Wrap,
+    /// Shorten labels with an ellipsis (horizontal axes only)
+    Truncate,
+}
+
+/// Configuration for tick label collision avoidance
+///
+/// Strategies are tried in order and the first one that removes every
+/// overlap wins. If none succeeds, labels are thinned greedily regardless of
+/// [`max_hidden_ratio`](Self::max_hidden_ratio) so the result never overlaps.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LabelOverlap {
+    /// Strategies to try, in order
+    pub strategies: Vec<OverlapStrategy>,
+    /// Minimum gap between adjacent labels in pixels
+    pub min_gap: f64,
+    /// Height of one line of label text in pixels
+    pub line_height: f64,
+    /// Maximum number of lines when wrapping
+    pub max_lines: usize,
+    /// Largest fraction of labels that [`OverlapStrategy::Thin`] may hide
+    /// before the next strategy is tried
+    pub max_hidden_ratio: f64,
+    /// Suffix appended to truncated labels
+    pub ellipsis: String,
+}
+
+impl Default for LabelOverlap {
+    fn default() -> Self {
+        Self {
+            strategies: vec![
+                OverlapStrategy::Thin,
+                OverlapStrategy::Rotate,
+                OverlapStrategy::Wrap,
+                OverlapStrategy::Truncate,
+            ],
+            min_gap: 4.0,
+            line_height: 12.0,
+            max_lines: 2,
+            max_hidden_ratio: 0.5,
+            ellipsis: "…".to_string(),
+        }
+    }
+}
+
+/// Outcome of resolving label overlaps
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct OverlapResolution {
+    /// Strategy that was applied (`None` if nothing overlapped)
+    pub strategy: Option<OverlapStrategy>,
+    /// Indices of ticks whose labels were hidden
+    pub hidden: Vec<usize>,
+    /// Rotation chosen by [`OverlapStrategy::Rotate`]
+    pub rotation: Option<LabelRotation>,
+}
+
+impl LabelOverlap {
+    /// Create a configuration with the default strategy order
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the strategies to try, in order
+    pub fn with_strategies(mut self, strategies: Vec<OverlapStrategy>) -> Self {
+        self.strategies = strategies;
+        self
+    }
+
+    /// Set the minimum gap between labels
+    pub fn with_min_gap(mut self, gap: f64) -> Self {
+        self.min_gap = gap.max(0.0);
+        self
+    }
+
+    /// Set the line height of label text
+    pub fn with_line_height(mut self, height: f64) -> Self {
+        self.line_height = height.max(0.0);
+        self
+    }
+
+    /// Set the maximum number of wrapped lines
+    pub fn with_max_lines(mut self, lines: usize) -> Self {
+        self.max_lines = lines.max(1);
+        self
+    }
+
+    /// Set the largest fraction of labels thinning may hide
+    pub fn with_max_hidden_ratio(mut self, ratio: f64) -> Self {
+        self.max_hidden_ratio = ratio.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the truncation suffix
+    pub fn with_ellipsis(mut self, ellipsis: impl Into<String>) -> Self {
+        self.ellipsis = ellipsis.into();
+        self
+    }
+
+    /// Resolve overlaps among the visible labels of `ticks` in place
+    ///
+    /// `measure` returns the rendered width of a single line of text.
+    /// Hidden labels get `label_visible = false`; wrapped labels contain
+    /// `'\n'` line breaks. A chosen rotation is applied to every tick.
+    pub fn resolve<F>(
+        &self,
+        ticks: &mut [AxisTick],
+        orientation: AxisOrientation,
+        measure: F,
+    ) -> OverlapResolution
+    where
+        F: Fn(&str) -> f64,
+    {
+        let mut order: Vec<usize> = (0..ticks.len())
+            .filter(|&i| ticks[i].label_visible && !ticks[i].label.is_empty())
+            .collect();
+        order.sort_by(|&a, &b| ticks[a].position.total_cmp(&ticks[b].position));
+
+        let extents = |ticks: &[AxisTick]| -> Vec<(f64, f64)> {
+            order
+                .iter()
+                .map(|&i| self.extent(&ticks[i], orientation, &measure))
+                .collect()
+        };
+
+        if !self.overlaps(&extents(ticks)) {
+            return OverlapResolution::default();
+        }
+
+        let spacing = order
+            .windows(2)
+            .map(|w| (ticks[w[1]].position - ticks[w[0]].position).abs())
+            .fold(f64::INFINITY, f64::min);
+        let available = spacing - self.min_gap;
+        let horizontal = orientation.is_horizontal();
+
+        for &strategy in &self.strategies {
+            match strategy {
+                OverlapStrategy::Thin => {
+                    let hidden = self.thin(&order, &extents(ticks));
+                    let ratio = hidden.len() as f64 / order.len() as f64;
+                    if ratio <= self.max_hidden_ratio {
+                        return Self::hide(ticks, hidden);
+                    }
+                }
+                OverlapStrategy::Rotate if horizontal => {
+                    if let Some(rotation) = self.rotation_for(spacing, orientation) {
+                        for tick in ticks.iter_mut() {
+                            tick.label_rotation = rotation.angle;
+                            tick.text_anchor = rotation.anchor.unwrap_or(tick.text_anchor);
+                        }
+                        return OverlapResolution {
+                            strategy: Some(strategy),
+                            hidden: Vec::new(),
+                            rotation: Some(rotation),
+                        };
+                    }
+                }
+                OverlapStrategy::Wrap if horizontal => {
+                    let wrapped: Option<Vec<String>> = order
+                        .iter()
+                        .map(|&i| self.wrap(&ticks[i].label, available, &measure))
+                        .collect();
+                    if let Some(wrapped) = wrapped {
+                        for (&i, label) in order.iter().zip(wrapped) {
+                            ticks[i].label = label;
+                        }
+                        return Self::applied(strategy);
+                    }
+                }
+                OverlapStrategy::Truncate if horizontal => {
+                    let truncated: Option<Vec<String>> = order
+                        .iter()
+                        .map(|&i| self.truncate(&ticks[i].label, available, &measure))
+                        .collect();
+                    if let Some(truncated) = truncated {
+                        for (&i, label) in order.iter().zip(truncated) {
+                            ticks[i].label = label;
+                        }
+                        return Self::applied(strategy);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let hidden = self.thin(&order, &extents(ticks));
+        Self::hide(ticks, hidden)
+    }
+
+    /// Extent of a label along the axis
+    fn extent<F: Fn(&str) -> f64>(
+        &self,
+        tick: &AxisTick,
+        orientation: AxisOrientation,
+        measure: &F,
+    ) -> (f64, f64) {
+        let p = tick.position;
+        if orientation.is_vertical() {
+            let half = self.line_height * tick.label.lines().count() as f64 / 2.0;
+            return (p - half, p + half);
+        }
+        let width = tick.label.lines().map(measure).fold(0.0, f64::max);
+        match tick.text_anchor {
+            TextAnchor::Start => (p, p + width),
+            TextAnchor::Middle => (p - width / 2.0, p + width / 2.0),
+            TextAnchor::End => (p - width, p),
+        }
+    }
+
+    /// Check sorted extents for overlaps
+    fn overlaps(&self, extents: &[(f64, f64)]) -> bool {
+        extents
+            .windows(2)
+            .any(|w| w[0].1 + self.min_gap > w[1].0 + 1e-9)
+    }
+
+    /// Greedily keep labels that clear the previously kept one
+    fn thin(&self, order: &[usize], extents: &[(f64, f64)]) -> Vec<usize> {
+        let mut hidden = Vec::new();
+        let mut last_end = f64::NEG_INFINITY;
+        for (&index, &(start, end)) in order.iter().zip(extents) {
+            if start + 1e-9 >= last_end + self.min_gap {
+                last_end = end;
+            } else {
+                hidden.push(index);
+            }
+        }
+        hidden.sort_unstable();
+        hidden
+    }
+
+    /// Pick the smallest rotation whose parallel labels clear each other
+    fn rotation_for(&self, spacing: f64, orientation: AxisOrientation) -> Option<LabelRotation> {
+        let needed = self.line_height + self.min_gap;
+        [45.0_f64, 90.0]
+            .into_iter()
+            .find(|angle| spacing * angle.to_radians().sin() + 1e-9 >= needed)
+            .map(|angle| match orientation {
+                AxisOrientation::Top => LabelRotation::degrees(-angle)
+                    .with_anchor(TextAnchor::Start)
+                    .with_align(if angle < 90.0 {
+                        LabelAlign::Bottom
+                    } else {
+                        LabelAlign::Middle
+                    }),
+                _ if angle < 90.0 => LabelRotation::diagonal(),
+                _ => LabelRotation::vertical(),
+            })
+    }
+
+    /// Word-wrap a label into at most `max_lines` lines of `width`
+    fn wrap<F: Fn(&str) -> f64>(&self, label: &str, width: f64, measure: &F) -> Option<String> {
+        let mut lines: Vec<String> = Vec::new();
+        let mut current = String::new();
+        for word in label.split_whitespace() {
+            let candidate = if current.is_empty() {
+                word.to_string()
+            } else {
+                format!("{} {}", current, word)
+            };
+            if measure(&candidate) <= width {
+                current = candidate;
+            } else if measure(word) <= width && !current.is_empty() {
+                lines.push(std::mem::replace(&mut current, word.to_string()));
+            } else {
+                return None;
+            }
+        }
+        lines.push(current);
+        (lines.len() <= self.max_lines).then(|| lines.join("\n"))
+    }
+
+    /// Shorten a label with an ellipsis so it fits in `width`
+    fn truncate<F: Fn(&str) -> f64>(&self, label: &str, width: f64, measure: &F) -> Option<String> {
+        if measure(label) <= width {
+            return Some(label.to_string());
+        }
+        let chars: Vec<char> = label.chars().collect();
+        (0..chars.len())
+            .rev()
+            .map(|n| {
+                let head: String = chars[..n].iter().collect();
+                format!("{}{}", head.trim_end(), self.ellipsis)
+            })
+            .find(|s| measure(s) <= width)
+    }
+
+    /// Hide the given labels and report them
+    fn hide(ticks: &mut [AxisTick], hidden: Vec<usize>) -> OverlapResolution {
+        for &i in &hidden {
+            ticks[i].label_visible = false;
+        }
+        OverlapResolution {
+            strategy: Some(OverlapStrategy::Thin),
+            hidden,
+            rotation: None,
+        }
+    }
+
+    /// Report a strategy that changed labels without hiding any
+    fn applied(strategy: OverlapStrategy) -> OverlapResolution {
+        OverlapResolution {
+            strategy: Some(strategy),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::axis::{Axis, AxisConfig};
+    use crate::scale::Tick;
+
+    fn measure(s: &str) -> f64 {
+        s.chars().count() as f64 * 6.0
+    }
+
+    fn axis(config: AxisConfig, labels: &[&str], step: f64) -> Axis {
+        let mut axis = Axis::with_config(config);
+        axis.set_range((0.0, step * labels.len() as f64));
+        axis.set_ticks(
+            labels
+                .iter()
+                .enumerate()
+                .map(|(i, l)| Tick::new(i as f64, *l).with_position(i as f64 * step))
+                .collect(),
+        );
+        axis
+    }
+
+    #[test]
+    fn test_no_overlap_leaves_labels() {
+        let axis = axis(
+            AxisConfig::bottom().with_label_overlap(LabelOverlap::new()),
+            &["A", "B", "C"],
+            50.0,
+        );
+        let layout = axis.compute_layout_measured(0.0, measure);
+        assert_eq!(layout.overlap_strategy, None);
+        assert!(layout.hidden_labels.is_empty());
+        assert!(layout.ticks.iter().all(|t| t.label_visible));
+    }
+
+    #[test]
+    fn test_thin_greedy() {
+        // 18px labels every 12px: every other label must go
+        let axis = axis(
+            AxisConfig::bottom().with_label_overlap(LabelOverlap::new()),
+            &["100", "200", "300", "400", "500", "600"],
+            12.0,
+        );
+        let layout = axis.compute_layout_measured(0.0, measure);
+        assert_eq!(layout.overlap_strategy, Some(OverlapStrategy::Thin));
+        assert_eq!(layout.hidden_labels, vec![1, 3, 5]);
+        assert!(!layout.ticks[1].label_visible);
+        assert!(layout.ticks[2].label_visible);
+    }
+
+    #[test]
+    fn test_rotate_when_thinning_hides_too_many() {
+        let labels = ["January", "February", "March", "April", "May", "June"];
+        let overlap = LabelOverlap::new().with_max_hidden_ratio(0.25);
+        let axis = axis(
+            AxisConfig::bottom().with_label_overlap(overlap.clone()),
+            &labels,
+            24.0,
+        );
+        let layout = axis.compute_layout_measured(0.0, measure);
+        assert_eq!(layout.overlap_strategy, Some(OverlapStrategy::Rotate));
+        assert_eq!(layout.label_rotation, -45.0);
+        assert!(layout
+            .ticks
+            .iter()
+            .all(|t| t.label_rotation == -45.0 && t.text_anchor == TextAnchor::End));
+
+        // Tighter spacing needs vertical labels
+        let axis = self::axis(
+            AxisConfig::bottom().with_label_overlap(overlap),
+            &labels,
+            18.0,
+        );
+        let layout = axis.compute_layout_measured(0.0, measure);
+        assert_eq!(layout.label_rotation, -90.0);
+    }
+
+    #[test]
+    fn test_wrap() {
+        let overlap = LabelOverlap::new().with_strategies(vec![OverlapStrategy::Wrap]);
+        let axis = axis(
+            AxisConfig::bottom().with_label_overlap(overlap),
+            &["North America", "South America", "Europe"],
+            60.0,
+        );
+        let layout = axis.compute_layout_measured(0.0, measure);
+        assert_eq!(layout.overlap_strategy, Some(OverlapStrategy::Wrap));
+        assert_eq!(layout.ticks[0].label, "North\nAmerica");
+        assert_eq!(layout.ticks[2].label, "Europe");
+    }
+
+    #[test]
+    fn test_truncate() {
+        let overlap = LabelOverlap::new().with_strategies(vec![OverlapStrategy::Truncate]);
+        let axis = axis(
+            AxisConfig::bottom().with_label_overlap(overlap),
+            &["Alphabetical", "Bo", "Characteristic"],
+            40.0,
+        );
+        let layout = axis.compute_layout_measured(0.0, measure);
+        assert_eq!(layout.overlap_strategy, Some(OverlapStrategy::Truncate));
+        assert_eq!(layout.ticks[0].label, "Alpha…");
+        assert_eq!(layout.ticks[1].label, "Bo");
+        assert!(layout.ticks.iter().all(|t| measure(&t.label) <= 36.0));
+    }
+
+    #[test]
+    fn test_vertical_axis_only_thins() {
+        let overlap = LabelOverlap::new().with_strategies(vec![OverlapStrategy::Rotate]);
+        let axis = axis(
+            AxisConfig::left().with_label_overlap(overlap),
+            &["0", "1", "2", "3", "4"],
+            8.0,
+        );
+        let layout = axis.compute_layout_measured(0.0, measure);
+        // Rotation does not apply to vertical axes, so the fallback thins
+        assert_eq!(layout.overlap_strategy, Some(OverlapStrategy::Thin));
+        assert_eq!(layout.hidden_labels, vec![1, 3]);
+        assert_eq!(layout.label_rotation, 0.0);
+    }
+
+    #[test]
+    fn test_resolution_report() {
+        let mut ticks = axis(AxisConfig::bottom(), &["aaaa", "bbbb", "cccc"], 10.0)
+            .compute_layout(0.0)
+            .ticks;
+        let overlap = LabelOverlap::new().with_strategies(vec![]);
+        let result = overlap.resolve(&mut ticks, AxisOrientation::Bottom, measure);
+        assert_eq!(result.strategy, Some(OverlapStrategy::Thin));
+        assert_eq!(result.hidden, vec![1, 2]);
+        assert!(result.rotation.is_none());
+    }
+}
@@ -7,9 +7,15 @@
 //!
 //! - **Orientation**: Bottom, Top, Left, Right axis placement
 //! - **Label Rotation**: Support for diagonal and vertical labels on crowded axes
+//! - **Label Collision Avoidance**: Thin, rotate, wrap or truncate overlapping
+//!   labels given a text measurement callback
 //! - **Grid Lines**: Configurable grid with styles (solid, dashed, dotted)
 //! - **Minor Ticks**: Sub-division ticks between major ticks
 //! - **Time Formatting**: Multi-scale time formatting for time-series charts
+//! - **Time Locales**: Localized month/day names and d3-time-format parsing
+//!   for turning CSV date columns into time scale domains
+//! - **Number Locales**: d3-format specifier strings (`",.2f"`, `".3s"`) with
+//!   locale decimal/group separators and currency placement
 //! - **Discrete Scale Support**: Integration with BandScale and PointScale
 //!
 //! # Example
@@ -52,7 +58,9 @@
 
 mod axis;
 mod format;
+mod format_spec;
 mod grid;
+mod label_overlap;
 mod tick;
 
 // Core axis types
@@ -66,6 +74,15 @@ pub use format::{
     MultiScaleTimeFormat, NumberFormat, TimeFormat,
 };
 
+// d3-format specifier strings and number locales
+pub use format_spec::{FormatSpecifier, LocaleFormatter, NumberLocale};
+
+// d3-time-format specifiers with localized names and parsing
+pub use crate::scale::{time_format, time_parse, TimeFormatter, TimeLocale, TimeParser};
+
+// Tick label collision avoidance
+pub use label_overlap::{LabelOverlap, OverlapResolution, OverlapStrategy};
+
 // Enhanced tick configuration
 pub use tick::{generate_ticks, MinorTick, TickConfig, TickFilter, TickResult};
 
@@ -13,7 +13,17 @@
 //! - Hard Light: Strong lighting effect
 //! - Difference: Absolute difference
 //! - Dodge/Burn: Extreme lightening/darkening
-
+//!
+//! # Linear Light
+//!
+//! [`blend`] and [`composite_over`] work on sRGB values directly, like most
+//! graphics software. Compositing physically adds light, so overlapping
+//! translucent marks come out too dark that way. [`blend_in_space`] and
+//! [`composite_over_linear`] convert to linear light first, and
+//! [`composite_over_premultiplied`] composites premultiplied colors such
+//! as those produced by [`Rgba::premultiply`].
+
+use super::contrast::relative_luminance;
 use super::lab::Lab;
 use super::types::Rgba;
 
@@ -47,6 +57,16 @@ pub enum BlendMode {
     Lighten,
 }
 
+/// Color space that blending happens in
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BlendSpace {
+    /// Blend gamma-encoded sRGB values (matches CSS and most editors)
+    #[default]
+    Srgb,
+    /// Blend in linear light (physically correct, no darkened overlaps)
+    Linear,
+}
+
 impl BlendMode {
     /// Blend two color components
     fn blend_component(&self, base: f32, blend: f32) -> f32 {
@@ -119,12 +139,46 @@ pub fn blend(base: &Rgba, blend_color: &Rgba, mode: BlendMode) -> Rgba {
     )
 }
 
+/// Blend two colors using the specified blend mode in the given space
+///
+/// With [`BlendSpace::Linear`] both colors are converted to linear light,
+/// blended, and converted back to sRGB.
+pub fn blend_in_space(base: &Rgba, blend_color: &Rgba, mode: BlendMode, space: BlendSpace) -> Rgba {
+    match space {
+        BlendSpace::Srgb => blend(base, blend_color, mode),
+        BlendSpace::Linear => {
+            Rgba::from_linear(&blend(&base.to_linear(), &blend_color.to_linear(), mode))
+        }
+    }
+}
+
 /// Blend with opacity (t=0 returns base, t=1 returns full blend)
 pub fn blend_with_opacity(base: &Rgba, blend_color: &Rgba, mode: BlendMode, opacity: f32) -> Rgba {
     let blended = blend(base, blend_color, mode);
     base.lerp(&blended, opacity)
 }
 
+/// Blend with opacity in the given space
+///
+/// The opacity fade is also done in that space.
+pub fn blend_with_opacity_in_space(
+    base: &Rgba,
+    blend_color: &Rgba,
+    mode: BlendMode,
+    opacity: f32,
+    space: BlendSpace,
+) -> Rgba {
+    match space {
+        BlendSpace::Srgb => blend_with_opacity(base, blend_color, mode, opacity),
+        BlendSpace::Linear => Rgba::from_linear(&blend_with_opacity(
+            &base.to_linear(),
+            &blend_color.to_linear(),
+            mode,
+            opacity,
+        )),
+    }
+}
+
 /// Alpha composite two colors (Porter-Duff "over" operation)
 pub fn composite_over(base: &Rgba, overlay: &Rgba) -> Rgba {
     let a_out = overlay.a + base.a * (1.0 - overlay.a);
@@ -140,6 +194,42 @@ pub fn composite_over(base: &Rgba, overlay: &Rgba) -> Rgba {
     Rgba::new(r, g, b, a_out)
 }
 
+/// Porter-Duff "over" for premultiplied colors
+///
+/// Both inputs and the result are premultiplied, which makes repeated
+/// compositing associative and avoids the division in [`composite_over`].
+pub fn composite_over_premultiplied(base: &Rgba, overlay: &Rgba) -> Rgba {
+    let k = 1.0 - overlay.a;
+    Rgba::new(
+        overlay.r + base.r * k,
+        overlay.g + base.g * k,
+        overlay.b + base.b * k,
+        overlay.a + base.a * k,
+    )
+}
+
+/// Alpha composite two sRGB colors in linear light
+///
+/// Inputs and result are straight (not premultiplied) sRGB. Overlapping
+/// translucent colors keep their brightness instead of muddying.
+pub fn composite_over_linear(base: &Rgba, overlay: &Rgba) -> Rgba {
+    let out = composite_over_premultiplied(
+        &base.to_linear().premultiply(),
+        &overlay.to_linear().premultiply(),
+    );
+    Rgba::from_linear(&out.unpremultiply())
+}
+
+/// Composite a stack of sRGB colors bottom to top in linear light
+///
+/// Returns [`Rgba::TRANSPARENT`] for an empty stack.
+pub fn composite_stack_linear(colors: &[Rgba]) -> Rgba {
+    let out = colors.iter().fold(Rgba::TRANSPARENT, |acc, c| {
+        composite_over_premultiplied(&acc, &c.to_linear().premultiply())
+    });
+    Rgba::from_linear(&out.unpremultiply())
+}
+
 /// Mix multiple colors with equal weights
 pub fn mix(colors: &[Rgba]) -> Rgba {
     if colors.is_empty() {
@@ -277,10 +367,15 @@ pub fn luminance(color: &Rgba) -> f32 {
     0.2126 * color.r + 0.7152 * color.g + 0.0722 * color.b
 }
 
-/// Calculate contrast ratio between two colors (WCAG)
+/// Calculate contrast ratio between two colors (WCAG 2)
+///
+/// Uses [`relative_luminance`]; see also [`apca_contrast`] and
+/// [`readable_text_color`](super::readable_text_color).
+///
+/// [`apca_contrast`]: super::apca_contrast
 pub fn contrast_ratio(color1: &Rgba, color2: &Rgba) -> f32 {
-    let l1 = luminance(color1) + 0.05;
-    let l2 = luminance(color2) + 0.05;
+    let l1 = relative_luminance(color1) + 0.05;
+    let l2 = relative_luminance(color2) + 0.05;
 
     if l1 > l2 {
         l1 / l2
@@ -345,6 +440,59 @@ mod tests {
         assert!(result.r > 0.0 && result.b > 0.0);
     }
 
+    #[test]
+    fn test_composite_premultiplied() {
+        let base = Rgba::RED.premultiply();
+        let overlay = Rgba::BLUE.with_alpha(0.5).premultiply();
+        let out = composite_over_premultiplied(&base, &overlay);
+        assert_eq!(out, Rgba::new(0.5, 0.0, 0.5, 1.0));
+
+        // Straight-alpha compositing agrees once unpremultiplied
+        let straight = composite_over(&Rgba::RED.with_alpha(0.6), &Rgba::BLUE.with_alpha(0.5));
+        let premul = composite_over_premultiplied(
+            &Rgba::RED.with_alpha(0.6).premultiply(),
+            &Rgba::BLUE.with_alpha(0.5).premultiply(),
+        )
+        .unpremultiply();
+        assert!((straight.r - premul.r).abs() < 1e-5);
+        assert!((straight.b - premul.b).abs() < 1e-5);
+        assert!((straight.a - premul.a).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_composite_linear_is_brighter() {
+        let base = Rgba::RED;
+        let overlay = Rgba::rgb(0.0, 1.0, 0.0).with_alpha(0.5);
+        let srgb = composite_over(&base, &overlay);
+        let linear = composite_over_linear(&base, &overlay);
+        assert!(luminance(&linear) > luminance(&srgb));
+        assert!((linear.r - 0.735).abs() < 0.01);
+
+        let stacked = composite_stack_linear(&[base, overlay]);
+        assert!((stacked.r - linear.r).abs() < 1e-5);
+        assert!((stacked.g - linear.g).abs() < 1e-5);
+        assert_eq!(composite_stack_linear(&[]), Rgba::TRANSPARENT);
+    }
+
+    #[test]
+    fn test_blend_in_space() {
+        let a = Rgba::rgb(0.5, 0.5, 0.5);
+        let srgb = blend_in_space(&a, &a, BlendMode::Multiply, BlendSpace::Srgb);
+        assert_eq!(srgb, blend(&a, &a, BlendMode::Multiply));
+        let linear = blend_in_space(&a, &a, BlendMode::Multiply, BlendSpace::Linear);
+        // 0.214^2 in linear light re-encodes to 0.237, not 0.25
+        assert!((linear.r - 0.237).abs() < 0.002);
+
+        let half = blend_with_opacity_in_space(
+            &Rgba::BLACK,
+            &Rgba::WHITE,
+            BlendMode::Normal,
+            0.5,
+            BlendSpace::Linear,
+        );
+        assert!((half.r - 0.735).abs() < 0.01);
+    }
+
     #[test]
     fn test_mix() {
         let colors = vec![Rgba::RED, Rgba::GREEN, Rgba::BLUE];
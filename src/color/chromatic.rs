@@ -0,0 +1,692 @@
+//! Color scheme registry (d3-scale-chromatic)
+//!
+//! Every d3-scale-chromatic scheme, looked up by name:
+//!
+//! - Sequential, single hue: Blues, Greens, Greys, Oranges, Purples, Reds
+//! - Sequential, multi-hue: BuGn, BuPu, GnBu, OrRd, PuBuGn, PuBu, PuRd,
+//!   RdPu, YlGnBu, YlGn, YlOrBr, YlOrRd, viridis, magma, inferno, plasma,
+//!   cividis, turbo, cubehelix, warm, cool
+//! - Diverging: BrBG, PRGn, PiYG, PuOr, RdBu, RdGy, RdYlBu, RdYlGn,
+//!   Spectral
+//! - Cyclical: rainbow, sinebow
+//! - Categorical: category10, Accent, Dark2, Paired, Pastel1, Pastel2,
+//!   Set1, Set2, Set3, Tableau10
+//!
+//! Two more schemes back the 3D chart colormaps, so their names mean the
+//! same colors everywhere: coolwarm (diverging, blue to red through light
+//! gray) and gray (sequential, black to white).
+//!
+//! Every scheme has discrete arrays for 3 to 11 classes (ColorBrewer's
+//! sequential schemes stop at 9, as in ColorBrewer itself). ColorBrewer
+//! schemes interpolate with a basis spline through their largest array,
+//! like d3's `interpolateBlues` and friends.
+//!
+//! # Example
+//! ```
+//! use makepad_d3::color::{color_scheme, ColorScale, SchemeKind};
+//!
+//! let blues = color_scheme("Blues").unwrap();
+//! assert_eq!(blues.kind(), SchemeKind::Sequential);
+//! assert_eq!(blues.discrete(5).unwrap().len(), 5);
+//!
+//! // Names are case-insensitive and accept d3's prefixes
+//! let rdylbu = color_scheme("interpolateRdYlBu").unwrap();
+//! let mid = rdylbu.color(0.5);
+//! ```
+
+use super::cubehelix::{
+    cubehelix_cool, cubehelix_default, cubehelix_rainbow, cubehelix_warm, sinebow, turbo,
+};
+use super::scale::{CategoricalScale, ColorScale, DivergingScale, SequentialScale};
+use super::types::Rgba;
+use std::ops::RangeInclusive;
+
+/// Kind of color scheme
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SchemeKind {
+    /// Ordered low to high
+    Sequential,
+    /// Two ramps meeting at a neutral midpoint
+    Diverging,
+    /// Wraps around: t = 0 and t = 1 are the same color
+    Cyclical,
+    /// Unordered distinct colors
+    Categorical,
+}
+
+impl SchemeKind {
+    /// Get the kind name
+    pub fn name(&self) -> &'static str {
+        match self {
+            SchemeKind::Sequential => "sequential",
+            SchemeKind::Diverging => "diverging",
+            SchemeKind::Cyclical => "cyclical",
+            SchemeKind::Categorical => "categorical",
+        }
+    }
+}
+
+/// Where a scheme's colors come from
+#[derive(Clone, Copy, Debug)]
+enum Source {
+    /// ColorBrewer sequential letters A–M
+    Sequential(&'static [u32; 13]),
+    /// ColorBrewer diverging letters A–O
+    Diverging(&'static [u32; 15]),
+    /// Evenly spaced stops, interpolated linearly
+    Ramp(&'static [u32]),
+    /// Continuous function of t
+    Function(fn(f64) -> Rgba),
+    /// Fixed list of category colors
+    Categorical(fn() -> CategoricalScale),
+}
+
+/// A named color scheme from the registry
+///
+/// Obtained with [`color_scheme`] or [`color_schemes`]. Implements
+/// [`ColorScale`], so it can be used wherever a continuous scale is
+/// expected.
+#[derive(Clone, Copy, Debug)]
+pub struct ColorScheme {
+    name: &'static str,
+    kind: SchemeKind,
+    source: Source,
+}
+
+impl PartialEq for ColorScheme {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl Eq for ColorScheme {}
+
+impl ColorScheme {
+    const fn new(name: &'static str, kind: SchemeKind, source: Source) -> Self {
+        Self { name, kind, source }
+    }
+
+    /// Get the scheme name as spelled by d3 (e.g. `"RdYlBu"`)
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Get the scheme kind
+    pub fn kind(&self) -> SchemeKind {
+        self.kind
+    }
+
+    /// Get the class counts [`discrete`](ColorScheme::discrete) supports
+    ///
+    /// 3–9 for ColorBrewer sequential schemes (as ColorBrewer defines
+    /// them), 3–11 for diverging and continuous schemes, and 1 up to the
+    /// palette size for categorical schemes.
+    pub fn classes(&self) -> RangeInclusive<usize> {
+        match self.source {
+            Source::Sequential(_) => 3..=9,
+            Source::Diverging(_) | Source::Ramp(_) | Source::Function(_) => 3..=11,
+            Source::Categorical(palette) => 1..=palette().len(),
+        }
+    }
+
+    /// Get the discrete array with `k` classes, like d3's `schemeBlues[k]`
+    ///
+    /// Continuous schemes are sampled evenly (cyclical ones without
+    /// repeating the start color) and categorical schemes return their
+    /// first `k` colors. Returns None if `k` is outside [`classes`].
+    ///
+    /// [`classes`]: ColorScheme::classes
+    pub fn discrete(&self, k: usize) -> Option<Vec<Rgba>> {
+        if !self.classes().contains(&k) {
+            return None;
+        }
+        match self.source {
+            Source::Sequential(letters) => {
+                let pattern = SEQUENTIAL_CLASSES.get(k.checked_sub(3)?)?;
+                Some(
+                    pattern
+                        .iter()
+                        .map(|&i| Rgba::from_hex(letters[i]))
+                        .collect(),
+                )
+            }
+            Source::Diverging(letters) => {
+                let pattern = DIVERGING_CLASSES.get(k.checked_sub(3)?)?;
+                Some(
+                    pattern
+                        .iter()
+                        .map(|&i| Rgba::from_hex(letters[i]))
+                        .collect(),
+                )
+            }
+            Source::Categorical(palette) => Some(palette().colors()[..k].to_vec()),
+            Source::Ramp(_) | Source::Function(_) => Some(self.sample(k)),
+        }
+    }
+
+    /// Get `n` colors from the scheme
+    ///
+    /// Uses the discrete array when one exists; otherwise samples the
+    /// scheme evenly. Categorical schemes repeat when `n` exceeds the
+    /// palette size.
+    pub fn colors(&self, n: usize) -> Vec<Rgba> {
+        if let Some(colors) = self.discrete(n) {
+            return colors;
+        }
+        match self.source {
+            Source::Categorical(palette) => {
+                let palette = palette();
+                (0..n).map(|i| palette.get(i)).collect()
+            }
+            _ => self.sample(n),
+        }
+    }
+
+    /// Sample `n` evenly spaced colors from a continuous scheme
+    fn sample(&self, n: usize) -> Vec<Rgba> {
+        let steps = match self.kind {
+            SchemeKind::Cyclical => n,
+            _ => n.saturating_sub(1),
+        };
+        if steps == 0 {
+            return (0..n).map(|_| self.interpolate(0.5)).collect();
+        }
+        (0..n)
+            .map(|i| self.interpolate(i as f64 / steps as f64))
+            .collect()
+    }
+
+    /// Sample the scheme at t in [0, 1]
+    ///
+    /// Cyclical schemes wrap t; categorical schemes pick the color whose
+    /// band contains t.
+    pub fn interpolate(&self, t: f64) -> Rgba {
+        match self.source {
+            Source::Sequential(letters) => basis(SEQUENTIAL_CLASSES[6], letters, t),
+            Source::Diverging(letters) => basis(DIVERGING_CLASSES[8], letters, t),
+            Source::Ramp(stops) => {
+                let t = t.clamp(0.0, 1.0);
+                let n = stops.len() - 1;
+                let i = ((t * n as f64) as usize).min(n - 1);
+                let f = (t * n as f64 - i as f64) as f32;
+                Rgba::from_hex(stops[i]).lerp(&Rgba::from_hex(stops[i + 1]), f)
+            }
+            Source::Function(f) => f(t),
+            Source::Categorical(palette) => palette().color(t),
+        }
+    }
+
+    /// Build a [`SequentialScale`] following this scheme
+    pub fn sequential_scale(&self) -> SequentialScale {
+        let colors = match self.source {
+            Source::Ramp(stops) => stops.iter().map(|&h| Rgba::from_hex(h)).collect(),
+            Source::Sequential(_) => self.colors(9),
+            Source::Diverging(_) => self.colors(11),
+            _ => self.colors(16),
+        };
+        SequentialScale::new(colors)
+    }
+
+    /// Build a [`DivergingScale`] from a diverging scheme's 11-class array
+    ///
+    /// Returns None for other kinds.
+    pub fn diverging_scale(&self) -> Option<DivergingScale> {
+        if self.kind != SchemeKind::Diverging {
+            return None;
+        }
+        let colors = self.colors(11);
+        Some(DivergingScale::new(
+            colors[..5].to_vec(),
+            colors[5],
+            colors[6..].to_vec(),
+        ))
+    }
+
+    /// Build a [`CategoricalScale`] of `n` colors (at least one)
+    pub fn categorical_scale(&self, n: usize) -> CategoricalScale {
+        CategoricalScale::new(self.colors(n.max(1)))
+    }
+}
+
+impl ColorScale for ColorScheme {
+    fn color(&self, t: f64) -> Rgba {
+        self.interpolate(t)
+    }
+
+    fn scale_type(&self) -> &'static str {
+        self.kind.name()
+    }
+}
+
+/// Look up a color scheme by name
+///
+/// Matching ignores case, `-`, `_` and spaces, and accepts d3's
+/// `interpolate` and `scheme` prefixes, so `"RdYlBu"`, `"rd-yl-bu"` and
+/// `"interpolateRdYlBu"` all find the same scheme.
+pub fn color_scheme(name: &str) -> Option<&'static ColorScheme> {
+    let key: String = name
+        .chars()
+        .filter(|c| !matches!(c, '-' | '_' | ' '))
+        .flat_map(char::to_lowercase)
+        .collect();
+    let key = key
+        .strip_prefix("interpolate")
+        .or_else(|| key.strip_prefix("scheme"))
+        .unwrap_or(&key);
+    SCHEMES.iter().find(|s| s.name.eq_ignore_ascii_case(key))
+}
+
+/// Get all registered color schemes
+pub fn color_schemes() -> &'static [ColorScheme] {
+    &SCHEMES
+}
+
+/// Letters used by each ColorBrewer sequential class count (3 to 9)
+const SEQUENTIAL_CLASSES: [&[usize]; 7] = [
+    &[2, 5, 8],
+    &[1, 4, 6, 9],
+    &[1, 4, 6, 8, 10],
+    &[1, 3, 5, 6, 8, 10],
+    &[1, 3, 5, 6, 7, 9, 11],
+    &[0, 2, 3, 5, 6, 7, 9, 11],
+    &[0, 2, 3, 5, 6, 7, 9, 10, 12],
+];
+
+/// Letters used by each ColorBrewer diverging class count (3 to 11)
+const DIVERGING_CLASSES: [&[usize]; 9] = [
+    &[4, 7, 10],
+    &[2, 5, 9, 12],
+    &[2, 5, 7, 9, 12],
+    &[1, 4, 6, 8, 10, 13],
+    &[1, 4, 6, 7, 8, 10, 13],
+    &[1, 3, 5, 6, 8, 9, 11, 13],
+    &[1, 3, 5, 6, 7, 8, 9, 11, 13],
+    &[0, 1, 3, 5, 6, 8, 9, 11, 13, 14],
+    &[0, 1, 3, 5, 6, 7, 8, 9, 11, 13, 14],
+];
+
+/// The 9-class array of a ColorBrewer sequential scheme, like d3's
+/// `schemeBlues[9]`
+pub(super) fn sequential_nine(letters: &[u32; 13]) -> [u32; 9] {
+    let pattern = SEQUENTIAL_CLASSES[6];
+    std::array::from_fn(|i| letters[pattern[i]])
+}
+
+// ColorBrewer colors by letter, from Cynthia Brewer's ColorBrewer 2.0
+
+pub(super) const BLUES: [u32; 13] = [
+    0xf7fbff, 0xeff3ff, 0xdeebf7, 0xc6dbef, 0xbdd7e7, 0x9ecae1, 0x6baed6, 0x4292c6, 0x3182bd,
+    0x2171b5, 0x08519c, 0x084594, 0x08306b,
+];
+pub(super) const GREENS: [u32; 13] = [
+    0xf7fcf5, 0xedf8e9, 0xe5f5e0, 0xc7e9c0, 0xbae4b3, 0xa1d99b, 0x74c476, 0x41ab5d, 0x31a354,
+    0x238b45, 0x006d2c, 0x005a32, 0x00441b,
+];
+pub(super) const GREYS: [u32; 13] = [
+    0xffffff, 0xf7f7f7, 0xf0f0f0, 0xd9d9d9, 0xcccccc, 0xbdbdbd, 0x969696, 0x737373, 0x636363,
+    0x525252, 0x252525, 0x252525, 0x000000,
+];
+pub(super) const ORANGES: [u32; 13] = [
+    0xfff5eb, 0xfeedde, 0xfee6ce, 0xfdd0a2, 0xfdbe85, 0xfdae6b, 0xfd8d3c, 0xf16913, 0xe6550d,
+    0xd94801, 0xa63603, 0x8c2d04, 0x7f2704,
+];
+pub(super) const PURPLES: [u32; 13] = [
+    0xfcfbfd, 0xf2f0f7, 0xefedf5, 0xdadaeb, 0xcbc9e2, 0xbcbddc, 0x9e9ac8, 0x807dba, 0x756bb1,
+    0x6a51a3, 0x54278f, 0x4a1486, 0x3f007d,
+];
+pub(super) const REDS: [u32; 13] = [
+    0xfff5f0, 0xfee5d9, 0xfee0d2, 0xfcbba1, 0xfcae91, 0xfc9272, 0xfb6a4a, 0xef3b2c, 0xde2d26,
+    0xcb181d, 0xa50f15, 0x99000d, 0x67000d,
+];
+pub(super) const BUGN: [u32; 13] = [
+    0xf7fcfd, 0xedf8fb, 0xe5f5f9, 0xccece6, 0xb2e2e2, 0x99d8c9, 0x66c2a4, 0x41ae76, 0x2ca25f,
+    0x238b45, 0x006d2c, 0x005824, 0x00441b,
+];
+pub(super) const BUPU: [u32; 13] = [
+    0xf7fcfd, 0xedf8fb, 0xe0ecf4, 0xbfd3e6, 0xb3cde3, 0x9ebcda, 0x8c96c6, 0x8c6bb1, 0x8856a7,
+    0x88419d, 0x810f7c, 0x6e016b, 0x4d004b,
+];
+const GNBU: [u32; 13] = [
+    0xf7fcf0, 0xf0f9e8, 0xe0f3db, 0xccebc5, 0xbae4bc, 0xa8ddb5, 0x7bccc4, 0x4eb3d3, 0x43a2ca,
+    0x2b8cbe, 0x0868ac, 0x08589e, 0x084081,
+];
+const ORRD: [u32; 13] = [
+    0xfff7ec, 0xfef0d9, 0xfee8c8, 0xfdd49e, 0xfdcc8a, 0xfdbb84, 0xfc8d59, 0xef6548, 0xe34a33,
+    0xd7301f, 0xb30000, 0x990000, 0x7f0000,
+];
+const PUBUGN: [u32; 13] = [
+    0xfff7fb, 0xf6eff7, 0xece2f0, 0xd0d1e6, 0xbdc9e1, 0xa6bddb, 0x67a9cf, 0x3690c0, 0x1c9099,
+    0x02818a, 0x016c59, 0x016450, 0x014636,
+];
+const PUBU: [u32; 13] = [
+    0xfff7fb, 0xf1eef6, 0xece7f2, 0xd0d1e6, 0xbdc9e1, 0xa6bddb, 0x74a9cf, 0x3690c0, 0x2b8cbe,
+    0x0570b0, 0x045a8d, 0x034e7b, 0x023858,
+];
+const PURD: [u32; 13] = [
+    0xf7f4f9, 0xf1eef6, 0xe7e1ef, 0xd4b9da, 0xd7b5d8, 0xc994c7, 0xdf65b0, 0xe7298a, 0xdd1c77,
+    0xce1256, 0x980043, 0x91003f, 0x67001f,
+];
+const RDPU: [u32; 13] = [
+    0xfff7f3, 0xfeebe2, 0xfde0dd, 0xfcc5c0, 0xfbb4b9, 0xfa9fb5, 0xf768a1, 0xdd3497, 0xc51b8a,
+    0xae017e, 0x7a0177, 0x7a0177, 0x49006a,
+];
+const YLGNBU: [u32; 13] = [
+    0xffffd9, 0xffffcc, 0xedf8b1, 0xc7e9b4, 0xa1dab4, 0x7fcdbb, 0x41b6c4, 0x1d91c0, 0x2c7fb8,
+    0x225ea8, 0x253494, 0x0c2c84, 0x081d58,
+];
+pub(super) const YLGN: [u32; 13] = [
+    0xffffe5, 0xffffcc, 0xf7fcb9, 0xd9f0a3, 0xc2e699, 0xaddd8e, 0x78c679, 0x41ab5d, 0x31a354,
+    0x238443, 0x006837, 0x005a32, 0x004529,
+];
+const YLORBR: [u32; 13] = [
+    0xffffe5, 0xffffd4, 0xfff7bc, 0xfee391, 0xfed98e, 0xfec44f, 0xfe9929, 0xec7014, 0xd95f0e,
+    0xcc4c02, 0x993404, 0x8c2d04, 0x662506,
+];
+pub(super) const YLORRD: [u32; 13] = [
+    0xffffcc, 0xffffb2, 0xffeda0, 0xfed976, 0xfecc5c, 0xfeb24c, 0xfd8d3c, 0xfc4e2a, 0xf03b20,
+    0xe31a1c, 0xbd0026, 0xb10026, 0x800026,
+];
+
+const BRBG: [u32; 15] = [
+    0x543005, 0x8c510a, 0xa6611a, 0xbf812d, 0xd8b365, 0xdfc27d, 0xf6e8c3, 0xf5f5f5, 0xc7eae5,
+    0x80cdc1, 0x5ab4ac, 0x35978f, 0x018571, 0x01665e, 0x003c30,
+];
+const PRGN: [u32; 15] = [
+    0x40004b, 0x762a83, 0x7b3294, 0x9970ab, 0xaf8dc3, 0xc2a5cf, 0xe7d4e8, 0xf7f7f7, 0xd9f0d3,
+    0xa6dba0, 0x7fbf7b, 0x5aae61, 0x008837, 0x1b7837, 0x00441b,
+];
+const PIYG: [u32; 15] = [
+    0x8e0152, 0xc51b7d, 0xd01c8b, 0xde77ae, 0xe9a3c9, 0xf1b6da, 0xfde0ef, 0xf7f7f7, 0xe6f5d0,
+    0xb8e186, 0xa1d76a, 0x7fbc41, 0x4dac26, 0x4d9221, 0x276419,
+];
+const PUOR: [u32; 15] = [
+    0x7f3b08, 0xb35806, 0xe66101, 0xe08214, 0xf1a340, 0xfdb863, 0xfee0b6, 0xf7f7f7, 0xd8daeb,
+    0xb2abd2, 0x998ec3, 0x8073ac, 0x5e3c99, 0x542788, 0x2d004b,
+];
+const RDBU: [u32; 15] = [
+    0x67001f, 0xb2182b, 0xca0020, 0xd6604d, 0xef8a62, 0xf4a582, 0xfddbc7, 0xf7f7f7, 0xd1e5f0,
+    0x92c5de, 0x67a9cf, 0x4393c3, 0x0571b0, 0x2166ac, 0x053061,
+];
+const RDGY: [u32; 15] = [
+    0x67001f, 0xb2182b, 0xca0020, 0xd6604d, 0xef8a62, 0xf4a582, 0xfddbc7, 0xffffff, 0xe0e0e0,
+    0xbababa, 0x999999, 0x878787, 0x404040, 0x4d4d4d, 0x1a1a1a,
+];
+const RDYLBU: [u32; 15] = [
+    0xa50026, 0xd73027, 0xd7191c, 0xf46d43, 0xfc8d59, 0xfdae61, 0xfee090, 0xffffbf, 0xe0f3f8,
+    0xabd9e9, 0x91bfdb, 0x74add1, 0x2c7bb6, 0x4575b4, 0x313695,
+];
+const RDYLGN: [u32; 15] = [
+    0xa50026, 0xd73027, 0xd7191c, 0xf46d43, 0xfc8d59, 0xfdae61, 0xfee08b, 0xffffbf, 0xd9ef8b,
+    0xa6d96a, 0x91cf60, 0x66bd63, 0x1a9641, 0x1a9850, 0x006837,
+];
+const SPECTRAL: [u32; 15] = [
+    0x9e0142, 0xd53e4f, 0xd7191c, 0xf46d43, 0xfc8d59, 0xfdae61, 0xfee08b, 0xffffbf, 0xe6f598,
+    0xabdda4, 0x99d594, 0x66c2a5, 0x2b83ba, 0x3288bd, 0x5e4fa2,
+];
+
+/// Viridis stops (perceptually uniform, colorblind safe)
+pub(super) const VIRIDIS: [u32; 10] = [
+    0x440154, 0x482878, 0x3e4a89, 0x31688e, 0x26838e, 0x1f9e89, 0x35b779, 0x6dcd59, 0xb4de2c,
+    0xfde725,
+];
+/// Magma stops
+pub(super) const MAGMA: [u32; 10] = [
+    0x000004, 0x180f3d, 0x440f76, 0x721f81, 0x9e2f7f, 0xcd4071, 0xf1605d, 0xfd9668, 0xfeca8e,
+    0xfcfdbf,
+];
+/// Inferno stops
+pub(super) const INFERNO: [u32; 10] = [
+    0x000004, 0x1b0c41, 0x4a0c6b, 0x781c6d, 0xa52c60, 0xcf4446, 0xed6925, 0xfb9a06, 0xf7d03c,
+    0xfcffa4,
+];
+/// Plasma stops
+pub(super) const PLASMA: [u32; 10] = [
+    0x0d0887, 0x46039f, 0x7201a8, 0x9c179e, 0xbd3786, 0xd8576b, 0xed7953, 0xfb9f3a, 0xfdca26,
+    0xf0f921,
+];
+/// Cividis stops (colorblind safe)
+pub(super) const CIVIDIS: [u32; 10] = [
+    0x002051, 0x0a326a, 0x2b446e, 0x4a5568, 0x636763, 0x7c7b57, 0x97904b, 0xb4a73e, 0xd3c038,
+    0xfdea45,
+];
+
+static SCHEMES: [ColorScheme; 50] = [
+    sequential("Blues", &BLUES),
+    sequential("Greens", &GREENS),
+    sequential("Greys", &GREYS),
+    sequential("Oranges", &ORANGES),
+    sequential("Purples", &PURPLES),
+    sequential("Reds", &REDS),
+    sequential("BuGn", &BUGN),
+    sequential("BuPu", &BUPU),
+    sequential("GnBu", &GNBU),
+    sequential("OrRd", &ORRD),
+    sequential("PuBuGn", &PUBUGN),
+    sequential("PuBu", &PUBU),
+    sequential("PuRd", &PURD),
+    sequential("RdPu", &RDPU),
+    sequential("YlGnBu", &YLGNBU),
+    sequential("YlGn", &YLGN),
+    sequential("YlOrBr", &YLORBR),
+    sequential("YlOrRd", &YLORRD),
+    ramp("viridis", &VIRIDIS),
+    ramp("magma", &MAGMA),
+    ramp("inferno", &INFERNO),
+    ramp("plasma", &PLASMA),
+    ramp("cividis", &CIVIDIS),
+    function("turbo", SchemeKind::Sequential, turbo),
+    function("cubehelix", SchemeKind::Sequential, cubehelix_default),
+    function("warm", SchemeKind::Sequential, cubehelix_warm),
+    function("cool", SchemeKind::Sequential, cubehelix_cool),
+    function("gray", SchemeKind::Sequential, gray),
+    diverging("BrBG", &BRBG),
+    diverging("PRGn", &PRGN),
+    diverging("PiYG", &PIYG),
+    diverging("PuOr", &PUOR),
+    diverging("RdBu", &RDBU),
+    diverging("RdGy", &RDGY),
+    diverging("RdYlBu", &RDYLBU),
+    diverging("RdYlGn", &RDYLGN),
+    diverging("Spectral", &SPECTRAL),
+    function("coolwarm", SchemeKind::Diverging, cool_warm),
+    function("rainbow", SchemeKind::Cyclical, cubehelix_rainbow),
+    function("sinebow", SchemeKind::Cyclical, sinebow),
+    categorical("category10", CategoricalScale::category10),
+    categorical("Accent", CategoricalScale::accent),
+    categorical("Dark2", CategoricalScale::dark2),
+    categorical("Paired", CategoricalScale::paired),
+    categorical("Pastel1", CategoricalScale::pastel1),
+    categorical("Pastel2", CategoricalScale::pastel2),
+    categorical("Set1", CategoricalScale::set1),
+    categorical("Set2", CategoricalScale::set2),
+    categorical("Set3", CategoricalScale::set3),
+    categorical("Tableau10", CategoricalScale::tableau10),
+];
+
+const fn sequential(name: &'static str, letters: &'static [u32; 13]) -> ColorScheme {
+    ColorScheme::new(name, SchemeKind::Sequential, Source::Sequential(letters))
+}
+
+const fn diverging(name: &'static str, letters: &'static [u32; 15]) -> ColorScheme {
+    ColorScheme::new(name, SchemeKind::Diverging, Source::Diverging(letters))
+}
+
+const fn ramp(name: &'static str, stops: &'static [u32]) -> ColorScheme {
+    ColorScheme::new(name, SchemeKind::Sequential, Source::Ramp(stops))
+}
+
+const fn function(name: &'static str, kind: SchemeKind, f: fn(f64) -> Rgba) -> ColorScheme {
+    ColorScheme::new(name, kind, Source::Function(f))
+}
+
+const fn categorical(name: &'static str, palette: fn() -> CategoricalScale) -> ColorScheme {
+    ColorScheme::new(name, SchemeKind::Categorical, Source::Categorical(palette))
+}
+
+/// Linear black to white ramp
+fn gray(t: f64) -> Rgba {
+    let t = t.clamp(0.0, 1.0) as f32;
+    Rgba::rgb(t, t, t)
+}
+
+/// Blue to red through light gray, with Moreland's cool-warm endpoints
+fn cool_warm(t: f64) -> Rgba {
+    const COOL: Rgba = Rgba::rgb(0.230, 0.299, 0.754);
+    const MID: Rgba = Rgba::rgb(0.865, 0.865, 0.865);
+    const WARM: Rgba = Rgba::rgb(0.706, 0.016, 0.150);
+    let t = t.clamp(0.0, 1.0) as f32;
+    if t < 0.5 {
+        COOL.lerp(&MID, t * 2.0)
+    } else {
+        MID.lerp(&WARM, (t - 0.5) * 2.0)
+    }
+}
+
+/// Uniform B-spline through a ColorBrewer array, as d3's
+/// `interpolateRgbBasis`: passes through the end colors exactly
+fn basis(pattern: &[usize], letters: &[u32], t: f64) -> Rgba {
+    let values: Vec<[f64; 3]> = pattern
+        .iter()
+        .map(|&i| {
+            let c = Rgba::from_hex(letters[i]);
+            [c.r as f64, c.g as f64, c.b as f64]
+        })
+        .collect();
+    let n = values.len() - 1;
+    let t = t.clamp(0.0, 1.0);
+    let i = ((t * n as f64).floor() as usize).min(n - 1);
+    let (v1, v2) = (values[i], values[i + 1]);
+    let channel = |k: usize| {
+        let v0 = if i > 0 {
+            values[i - 1][k]
+        } else {
+            2.0 * v1[k] - v2[k]
+        };
+        let v3 = if i < n - 1 {
+            values[i + 2][k]
+        } else {
+            2.0 * v2[k] - v1[k]
+        };
+        let t1 = (t - i as f64 / n as f64) * n as f64;
+        let (t2, t3) = (t1 * t1, t1 * t1 * t1);
+        let v = ((1.0 - 3.0 * t1 + 3.0 * t2 - t3) * v0
+            + (4.0 - 6.0 * t2 + 3.0 * t3) * v1[k]
+            + (1.0 + 3.0 * t1 + 3.0 * t2 - 3.0 * t3) * v2[k]
+            + t3 * v3)
+            / 6.0;
+        v.clamp(0.0, 1.0) as f32
+    };
+    Rgba::rgb(channel(0), channel(1), channel(2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup() {
+        assert_eq!(color_scheme("Blues").unwrap().name(), "Blues");
+        assert_eq!(color_scheme("blues").unwrap().name(), "Blues");
+        assert_eq!(color_scheme("interpolateRdYlBu").unwrap().name(), "RdYlBu");
+        assert_eq!(color_scheme("schemeSet3").unwrap().name(), "Set3");
+        assert_eq!(color_scheme("yl-or-rd").unwrap().name(), "YlOrRd");
+        assert!(color_scheme("nope").is_none());
+
+        // Names are unique after normalization
+        for (i, a) in color_schemes().iter().enumerate() {
+            assert_eq!(color_scheme(a.name()).unwrap(), a);
+            for b in &color_schemes()[i + 1..] {
+                assert!(!a.name().eq_ignore_ascii_case(b.name()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_brewer_classes() {
+        let blues = color_scheme("Blues").unwrap();
+        assert_eq!(blues.classes(), 3..=9);
+        let hex = |k| -> Vec<u32> {
+            blues
+                .discrete(k)
+                .unwrap()
+                .iter()
+                .map(|c| c.to_hex())
+                .collect()
+        };
+        assert_eq!(hex(3), vec![0xdeebf7, 0x9ecae1, 0x3182bd]);
+        assert_eq!(
+            hex(5),
+            vec![0xeff3ff, 0xbdd7e7, 0x6baed6, 0x3182bd, 0x08519c]
+        );
+        assert_eq!(hex(9)[8], 0x08306b);
+        assert!(blues.discrete(2).is_none() && blues.discrete(10).is_none());
+
+        let rdbu = color_scheme("RdBu").unwrap();
+        let rdbu_hex = |k| -> Vec<u32> {
+            rdbu.discrete(k)
+                .unwrap()
+                .iter()
+                .map(|c| c.to_hex())
+                .collect()
+        };
+        assert_eq!(rdbu_hex(3), vec![0xef8a62, 0xf7f7f7, 0x67a9cf]);
+        assert_eq!(rdbu_hex(4), vec![0xca0020, 0xf4a582, 0x92c5de, 0x0571b0]);
+        assert_eq!(rdbu_hex(11).len(), 11);
+        assert_eq!(rdbu_hex(11)[5], 0xf7f7f7);
+
+        for scheme in color_schemes() {
+            for k in scheme.classes() {
+                assert_eq!(scheme.discrete(k).unwrap().len(), k, "{}", scheme.name());
+            }
+        }
+
+        // Continuous schemes are sampled; cyclical ones don't repeat the start
+        let viridis = color_scheme("viridis").unwrap().discrete(11).unwrap();
+        assert_eq!(viridis[0].to_hex(), 0x440154);
+        assert_eq!(viridis[10].to_hex(), 0xfde725);
+        let rainbow = color_scheme("rainbow").unwrap().discrete(4).unwrap();
+        assert_ne!(rainbow[0].to_hex(), rainbow[3].to_hex());
+    }
+
+    #[test]
+    fn test_interpolate_endpoints() {
+        let blues = color_scheme("Blues").unwrap();
+        assert_eq!(blues.color(0.0).to_hex(), 0xf7fbff);
+        assert_eq!(blues.color(1.0).to_hex(), 0x08306b);
+        assert_eq!(
+            color_scheme("viridis").unwrap().color(0.0).to_hex(),
+            0x440154
+        );
+        assert_eq!(color_scheme("turbo").unwrap().color(0.0).to_hex(), 0x23171b);
+
+        // Cyclical schemes wrap around
+        let rainbow = color_scheme("rainbow").unwrap();
+        assert_eq!(rainbow.color(0.0).to_hex(), rainbow.color(1.0).to_hex());
+        let sinebow = color_scheme("sinebow").unwrap();
+        assert_eq!(sinebow.color(0.25).to_hex(), sinebow.color(1.25).to_hex());
+
+        // The registry and the free functions share one implementation
+        for t in [0.0, 0.3, 0.7, 1.0] {
+            assert_eq!(color_scheme("turbo").unwrap().color(t), turbo(t));
+            assert_eq!(color_scheme("warm").unwrap().color(t), cubehelix_warm(t));
+        }
+    }
+
+    #[test]
+    fn test_scheme_scales() {
+        let spectral = color_scheme("Spectral").unwrap();
+        let diverging = spectral.diverging_scale().unwrap();
+        assert_eq!(diverging.color(0.5).to_hex(), 0xffffbf);
+        assert!(color_scheme("Blues").unwrap().diverging_scale().is_none());
+        assert!(DivergingScale::scheme("RdGy").is_some());
+        assert!(DivergingScale::scheme("Blues").is_none());
+        assert!(SequentialScale::scheme("interpolateYlGnBu").is_some());
+        assert!(SequentialScale::scheme("Set1").is_none());
+
+        assert_eq!(color_scheme("viridis").unwrap().colors(7).len(), 7);
+        let set1 = color_scheme("Set1").unwrap();
+        assert_eq!(set1.categorical_scale(3).len(), 3);
+        assert_eq!(set1.colors(12)[9], set1.colors(12)[0]);
+        assert_eq!(set1.kind(), SchemeKind::Categorical);
+    }
+}
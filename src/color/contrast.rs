@@ -0,0 +1,281 @@
+//! Text contrast and readable label colors
+//!
+//! Picks label colors that stay readable over arbitrary fills, such as
+//! treemap tiles or heatmap cells colored by data. Two contrast measures
+//! are available:
+//!
+//! - [`relative_luminance`] / [`contrast_ratio`](super::contrast_ratio):
+//!   WCAG 2 contrast ratio, 1 to 21 (4.5 passes AA for body text)
+//! - [`apca_contrast`]: APCA lightness contrast Lc, roughly -108 to 106
+//!   (|Lc| 60 is comparable to AA for body text). APCA models polarity
+//!   and is more accurate for mid-tone and dark backgrounds.
+//!
+//! # Example
+//! ```
+//! use makepad_d3::color::{readable_text_color, Rgba, TextColorPicker};
+//!
+//! // Black reads better on yellow, white on dark blue
+//! let yellow = Rgba::from_hex(0xfde725);
+//! assert_eq!(readable_text_color(&yellow, &[Rgba::WHITE, Rgba::BLACK]), Rgba::BLACK);
+//! let navy = Rgba::from_hex(0x1f2a6b);
+//! assert_eq!(readable_text_color(&navy, &[Rgba::WHITE, Rgba::BLACK]), Rgba::WHITE);
+//!
+//! // Mid-tone fills may need a halo behind the text
+//! let choice = TextColorPicker::new().pick(&Rgba::from_hex(0x808080));
+//! if let Some(halo) = choice.halo {
+//!     // draw the label outline in `halo` first
+//! }
+//! ```
+
+use super::blend::{composite_over, contrast_ratio};
+use super::types::Rgba;
+
+/// WCAG 2 relative luminance of a color (0 for black to 1 for white)
+///
+/// Unlike [`luminance`](super::luminance), the channels are linearized
+/// first, as WCAG specifies.
+pub fn relative_luminance(color: &Rgba) -> f32 {
+    let linear = color.to_linear();
+    0.2126 * linear.r + 0.7152 * linear.g + 0.0722 * linear.b
+}
+
+/// APCA lightness contrast (Lc) of text over a background
+///
+/// Follows APCA-W3 0.0.98G. Positive values are dark text on a light
+/// background, negative values light text on a dark background; the
+/// magnitude is what matters for readability. Translucent text is
+/// composited over the background first.
+pub fn apca_contrast(text: &Rgba, background: &Rgba) -> f32 {
+    let text = composite_over(background, text);
+    let y = |c: &Rgba| {
+        let y = 0.2126729 * c.r.clamp(0.0, 1.0).powf(2.4)
+            + 0.7151522 * c.g.clamp(0.0, 1.0).powf(2.4)
+            + 0.0721750 * c.b.clamp(0.0, 1.0).powf(2.4);
+        // Soft clamp near black
+        if y < 0.022 {
+            y + (0.022 - y).powf(1.414)
+        } else {
+            y
+        }
+    };
+    let (y_text, y_bg) = (y(&text), y(background));
+    if (y_bg - y_text).abs() < 0.0005 {
+        return 0.0;
+    }
+
+    let lc = if y_bg > y_text {
+        let s = (y_bg.powf(0.56) - y_text.powf(0.57)) * 1.14;
+        if s < 0.1 {
+            0.0
+        } else {
+            s - 0.027
+        }
+    } else {
+        let s = (y_bg.powf(0.65) - y_text.powf(0.62)) * 1.14;
+        if s > -0.1 {
+            0.0
+        } else {
+            s + 0.027
+        }
+    };
+    lc * 100.0
+}
+
+/// Pick the most readable text color over a background
+///
+/// Shorthand for [`TextColorPicker`] with the given candidates and the
+/// default thresholds. Black and white are used if `candidates` is empty.
+pub fn readable_text_color(background: &Rgba, candidates: &[Rgba]) -> Rgba {
+    TextColorPicker::new()
+        .candidates(candidates)
+        .pick(background)
+        .color
+}
+
+/// Result of [`TextColorPicker::pick`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TextColorChoice {
+    /// Chosen text color
+    pub color: Rgba,
+    /// WCAG 2 contrast ratio against the background
+    pub wcag: f32,
+    /// APCA Lc against the background
+    pub apca: f32,
+    /// Halo/outline color to draw behind the text, when the text alone
+    /// falls short of the thresholds
+    pub halo: Option<Rgba>,
+}
+
+impl TextColorChoice {
+    /// Check whether the text meets both thresholds without a halo
+    pub fn passes(&self) -> bool {
+        self.halo.is_none()
+    }
+}
+
+/// Chooser for label colors over data-colored backgrounds
+///
+/// Each candidate is scored by how well it meets the WCAG 2 ratio and
+/// APCA Lc thresholds (the weaker of the two counts), and the best one
+/// wins, earlier candidates winning ties. If even the best candidate falls
+/// short, a halo in the opposite polarity is recommended.
+#[derive(Clone, Debug)]
+pub struct TextColorPicker {
+    /// Candidate text colors
+    candidates: Vec<Rgba>,
+    /// Minimum WCAG 2 contrast ratio
+    min_wcag: f32,
+    /// Minimum APCA |Lc|
+    min_apca: f32,
+    /// Opacity of recommended halos
+    halo_opacity: f32,
+}
+
+impl Default for TextColorPicker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TextColorPicker {
+    /// Create a picker choosing between black and white
+    ///
+    /// Defaults to WCAG AA (4.5:1) and APCA Lc 60, with opaque halos.
+    pub fn new() -> Self {
+        Self {
+            candidates: vec![Rgba::BLACK, Rgba::WHITE],
+            min_wcag: 4.5,
+            min_apca: 60.0,
+            halo_opacity: 1.0,
+        }
+    }
+
+    /// Set the candidate text colors (ignored if empty)
+    pub fn candidates(mut self, candidates: &[Rgba]) -> Self {
+        if !candidates.is_empty() {
+            self.candidates = candidates.to_vec();
+        }
+        self
+    }
+
+    /// Set the minimum WCAG 2 contrast ratio
+    pub fn min_wcag(mut self, ratio: f32) -> Self {
+        self.min_wcag = ratio.max(1.0);
+        self
+    }
+
+    /// Set the minimum APCA |Lc|
+    pub fn min_apca(mut self, lc: f32) -> Self {
+        self.min_apca = lc.abs();
+        self
+    }
+
+    /// Set the opacity of recommended halos
+    pub fn halo_opacity(mut self, opacity: f32) -> Self {
+        self.halo_opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Pick the text color for a background
+    pub fn pick(&self, background: &Rgba) -> TextColorChoice {
+        // Text is drawn over whatever the translucent fill sits on; assume
+        // an opaque fill so the result doesn't depend on the canvas.
+        let background = background.with_alpha(1.0);
+        let score = |c: &Rgba| {
+            let text = composite_over(&background, c);
+            let wcag = contrast_ratio(&text, &background);
+            let apca = apca_contrast(c, &background);
+            let s = (wcag / self.min_wcag).min(apca.abs() / self.min_apca.max(f32::EPSILON));
+            (s, wcag, apca)
+        };
+
+        let mut best = (self.candidates[0], score(&self.candidates[0]));
+        for c in &self.candidates[1..] {
+            let s = score(c);
+            if s.0 > best.1 .0 {
+                best = (*c, s);
+            }
+        }
+        let (color, (s, wcag, apca)) = best;
+
+        let halo = (s < 1.0).then(|| {
+            // Opposite polarity to the text: dark text gets a light halo
+            let text = composite_over(&background, &color);
+            let halo = if relative_luminance(&text) > 0.18 {
+                Rgba::BLACK
+            } else {
+                Rgba::WHITE
+            };
+            halo.with_alpha(self.halo_opacity)
+        });
+
+        TextColorChoice {
+            color,
+            wcag,
+            apca,
+            halo,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relative_luminance() {
+        assert_eq!(relative_luminance(&Rgba::BLACK), 0.0);
+        assert!((relative_luminance(&Rgba::WHITE) - 1.0).abs() < 1e-6);
+        // Mid gray is much darker than 0.5 once linearized
+        assert!((relative_luminance(&Rgba::rgb(0.5, 0.5, 0.5)) - 0.214).abs() < 0.001);
+        assert!((contrast_ratio(&Rgba::BLACK, &Rgba::WHITE) - 21.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_apca_reference_values() {
+        // Reference values from the APCA-W3 documentation
+        let lc = apca_contrast(&Rgba::BLACK, &Rgba::WHITE);
+        assert!((lc - 106.04).abs() < 0.1, "{}", lc);
+        let lc = apca_contrast(&Rgba::WHITE, &Rgba::BLACK);
+        assert!((lc + 107.88).abs() < 0.1, "{}", lc);
+        let lc = apca_contrast(&Rgba::from_hex(0x888888), &Rgba::WHITE);
+        assert!((lc - 63.06).abs() < 0.1, "{}", lc);
+        assert_eq!(apca_contrast(&Rgba::WHITE, &Rgba::WHITE), 0.0);
+    }
+
+    #[test]
+    fn test_readable_text_color() {
+        let light = [0xfde725, 0xffffbf, 0xa6d96a];
+        let dark = [0x440154, 0x1f2a6b, 0xa50026];
+        for hex in light {
+            let c = readable_text_color(&Rgba::from_hex(hex), &[]);
+            assert_eq!(c, Rgba::BLACK, "{:06x}", hex);
+        }
+        for hex in dark {
+            let c = readable_text_color(&Rgba::from_hex(hex), &[]);
+            assert_eq!(c, Rgba::WHITE, "{:06x}", hex);
+        }
+
+        // Custom candidates
+        let candidates = [Rgba::from_hex(0x333333), Rgba::from_hex(0xeeeeee)];
+        let c = readable_text_color(&Rgba::from_hex(0x1f77b4), &candidates);
+        assert_eq!(c, candidates[1]);
+    }
+
+    #[test]
+    fn test_halo() {
+        let choice = TextColorPicker::new().pick(&Rgba::WHITE);
+        assert_eq!(choice.color, Rgba::BLACK);
+        assert!(choice.passes());
+        assert!(choice.wcag > 20.0 && choice.apca > 100.0);
+
+        // Mid-tone fill: neither black nor white reaches Lc 60
+        let choice = TextColorPicker::new()
+            .halo_opacity(0.8)
+            .pick(&Rgba::from_hex(0x808080));
+        let halo = choice.halo.unwrap();
+        assert_ne!(halo.to_hex(), choice.color.to_hex());
+        assert_eq!(halo.a, 0.8);
+        assert!(!choice.passes());
+    }
+}
@@ -0,0 +1,715 @@
+//! CSS color parsing and serialization
+//!
+//! Parses the CSS Color Level 4 syntaxes into [`Rgba`]:
+//!
+//! - Hex: `#rgb`, `#rgba`, `#rrggbb`, `#rrggbbaa`
+//! - Named colors (all 148 CSS names) and `transparent`
+//! - `rgb()` / `rgba()`, `hsl()` / `hsla()` and `hwb()`
+//! - `lab()`, `lch()`, `oklab()` and `oklch()`
+//! - `color(srgb ...)` and `color(srgb-linear ...)`
+//!
+//! Both the legacy comma syntax (`rgb(59, 130, 246)`) and the modern space
+//! syntax with a slash before alpha (`rgb(59 130 246 / 50%)`) are accepted,
+//! as is the `none` keyword for missing components.
+//!
+//! CSS defines `lab()` and `lch()` against the D50 white point, while this
+//! crate's [`Lab`] and [`Hcl`] use D65; values are converted with Bradford
+//! chromatic adaptation when parsing and in [`Lab::to_css_string`] and
+//! [`Hcl::to_css_string`], so strings match what browsers render. Colors
+//! outside the sRGB gamut are clipped.
+
+use super::hcl::Hcl;
+use super::lab::Lab;
+use super::oklab::{linear_to_srgb, Oklab, Oklch};
+use super::types::{Hsl, Rgba};
+use crate::error::{D3Error, D3Result};
+use std::str::FromStr;
+
+/// CSS named colors, sorted by name
+const NAMED_COLORS: [(&str, u32); 148] = [
+    ("aliceblue", 0xf0f8ff),
+    ("antiquewhite", 0xfaebd7),
+    ("aqua", 0x00ffff),
+    ("aquamarine", 0x7fffd4),
+    ("azure", 0xf0ffff),
+    ("beige", 0xf5f5dc),
+    ("bisque", 0xffe4c4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xffebcd),
+    ("blue", 0x0000ff),
+    ("blueviolet", 0x8a2be2),
+    ("brown", 0xa52a2a),
+    ("burlywood", 0xdeb887),
+    ("cadetblue", 0x5f9ea0),
+    ("chartreuse", 0x7fff00),
+    ("chocolate", 0xd2691e),
+    ("coral", 0xff7f50),
+    ("cornflowerblue", 0x6495ed),
+    ("cornsilk", 0xfff8dc),
+    ("crimson", 0xdc143c),
+    ("cyan", 0x00ffff),
+    ("darkblue", 0x00008b),
+    ("darkcyan", 0x008b8b),
+    ("darkgoldenrod", 0xb8860b),
+    ("darkgray", 0xa9a9a9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xa9a9a9),
+    ("darkkhaki", 0xbdb76b),
+    ("darkmagenta", 0x8b008b),
+    ("darkolivegreen", 0x556b2f),
+    ("darkorange", 0xff8c00),
+    ("darkorchid", 0x9932cc),
+    ("darkred", 0x8b0000),
+    ("darksalmon", 0xe9967a),
+    ("darkseagreen", 0x8fbc8f),
+    ("darkslateblue", 0x483d8b),
+    ("darkslategray", 0x2f4f4f),
+    ("darkslategrey", 0x2f4f4f),
+    ("darkturquoise", 0x00ced1),
+    ("darkviolet", 0x9400d3),
+    ("deeppink", 0xff1493),
+    ("deepskyblue", 0x00bfff),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1e90ff),
+    ("firebrick", 0xb22222),
+    ("floralwhite", 0xfffaf0),
+    ("forestgreen", 0x228b22),
+    ("fuchsia", 0xff00ff),
+    ("gainsboro", 0xdcdcdc),
+    ("ghostwhite", 0xf8f8ff),
+    ("gold", 0xffd700),
+    ("goldenrod", 0xdaa520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xadff2f),
+    ("grey", 0x808080),
+    ("honeydew", 0xf0fff0),
+    ("hotpink", 0xff69b4),
+    ("indianred", 0xcd5c5c),
+    ("indigo", 0x4b0082),
+    ("ivory", 0xfffff0),
+    ("khaki", 0xf0e68c),
+    ("lavender", 0xe6e6fa),
+    ("lavenderblush", 0xfff0f5),
+    ("lawngreen", 0x7cfc00),
+    ("lemonchiffon", 0xfffacd),
+    ("lightblue", 0xadd8e6),
+    ("lightcoral", 0xf08080),
+    ("lightcyan", 0xe0ffff),
+    ("lightgoldenrodyellow", 0xfafad2),
+    ("lightgray", 0xd3d3d3),
+    ("lightgreen", 0x90ee90),
+    ("lightgrey", 0xd3d3d3),
+    ("lightpink", 0xffb6c1),
+    ("lightsalmon", 0xffa07a),
+    ("lightseagreen", 0x20b2aa),
+    ("lightskyblue", 0x87cefa),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xb0c4de),
+    ("lightyellow", 0xffffe0),
+    ("lime", 0x00ff00),
+    ("limegreen", 0x32cd32),
+    ("linen", 0xfaf0e6),
+    ("magenta", 0xff00ff),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66cdaa),
+    ("mediumblue", 0x0000cd),
+    ("mediumorchid", 0xba55d3),
+    ("mediumpurple", 0x9370db),
+    ("mediumseagreen", 0x3cb371),
+    ("mediumslateblue", 0x7b68ee),
+    ("mediumspringgreen", 0x00fa9a),
+    ("mediumturquoise", 0x48d1cc),
+    ("mediumvioletred", 0xc71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xf5fffa),
+    ("mistyrose", 0xffe4e1),
+    ("moccasin", 0xffe4b5),
+    ("navajowhite", 0xffdead),
+    ("navy", 0x000080),
+    ("oldlace", 0xfdf5e6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6b8e23),
+    ("orange", 0xffa500),
+    ("orangered", 0xff4500),
+    ("orchid", 0xda70d6),
+    ("palegoldenrod", 0xeee8aa),
+    ("palegreen", 0x98fb98),
+    ("paleturquoise", 0xafeeee),
+    ("palevioletred", 0xdb7093),
+    ("papayawhip", 0xffefd5),
+    ("peachpuff", 0xffdab9),
+    ("peru", 0xcd853f),
+    ("pink", 0xffc0cb),
+    ("plum", 0xdda0dd),
+    ("powderblue", 0xb0e0e6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xff0000),
+    ("rosybrown", 0xbc8f8f),
+    ("royalblue", 0x4169e1),
+    ("saddlebrown", 0x8b4513),
+    ("salmon", 0xfa8072),
+    ("sandybrown", 0xf4a460),
+    ("seagreen", 0x2e8b57),
+    ("seashell", 0xfff5ee),
+    ("sienna", 0xa0522d),
+    ("silver", 0xc0c0c0),
+    ("skyblue", 0x87ceeb),
+    ("slateblue", 0x6a5acd),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xfffafa),
+    ("springgreen", 0x00ff7f),
+    ("steelblue", 0x4682b4),
+    ("tan", 0xd2b48c),
+    ("teal", 0x008080),
+    ("thistle", 0xd8bfd8),
+    ("tomato", 0xff6347),
+    ("turquoise", 0x40e0d0),
+    ("violet", 0xee82ee),
+    ("wheat", 0xf5deb3),
+    ("white", 0xffffff),
+    ("whitesmoke", 0xf5f5f5),
+    ("yellow", 0xffff00),
+    ("yellowgreen", 0x9acd32),
+];
+
+/// A single component of a CSS color function
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Component {
+    Number(f64),
+    Percent(f64),
+    /// Angle in degrees
+    Angle(f64),
+    None,
+}
+
+impl Component {
+    fn parse(token: &str) -> Option<Self> {
+        if token == "none" {
+            return Some(Component::None);
+        }
+        if let Some(value) = token.strip_suffix('%') {
+            return number(value).map(Component::Percent);
+        }
+        for (unit, to_degrees) in [
+            ("deg", 1.0),
+            ("grad", 0.9),
+            ("rad", 180.0 / std::f64::consts::PI),
+            ("turn", 360.0),
+        ] {
+            if let Some(value) = token.strip_suffix(unit) {
+                return number(value).map(|v| Component::Angle(v * to_degrees));
+            }
+        }
+        number(token).map(Component::Number)
+    }
+
+    /// Resolve a channel; percentages are relative to `reference`
+    fn channel(self, reference: f64) -> Option<f64> {
+        match self {
+            Component::Number(v) => Some(v),
+            Component::Percent(p) => Some(p / 100.0 * reference),
+            Component::None => Some(0.0),
+            Component::Angle(_) => None,
+        }
+    }
+
+    /// Resolve a hue in degrees
+    fn hue(self) -> Option<f64> {
+        match self {
+            Component::Number(v) | Component::Angle(v) => Some(v),
+            Component::None => Some(0.0),
+            Component::Percent(_) => None,
+        }
+    }
+}
+
+fn number(s: &str) -> Option<f64> {
+    s.parse::<f64>().ok().filter(|v| v.is_finite())
+}
+
+impl Rgba {
+    /// Parse a CSS color string
+    ///
+    /// Accepts hex colors, the 148 CSS named colors, `transparent`, and
+    /// the `rgb()`, `hsl()`, `hwb()`, `lab()`, `lch()`, `oklab()`,
+    /// `oklch()` and `color()` functions from CSS Color Level 4. Parsing is
+    /// case-insensitive.
+    ///
+    /// # Example
+    /// ```
+    /// use makepad_d3::color::Rgba;
+    ///
+    /// let blue = Rgba::parse("#3b82f6").unwrap();
+    /// assert_eq!(blue.to_hex(), 0x3b82f6);
+    ///
+    /// let faded = Rgba::parse("rgb(59 130 246 / 50%)").unwrap();
+    /// assert_eq!(faded.to_hex(), 0x3b82f6);
+    /// assert!((faded.a - 0.5).abs() < 1e-6);
+    ///
+    /// assert_eq!(Rgba::parse("steelblue").unwrap().to_hex(), 0x4682b4);
+    /// assert!(Rgba::parse("oklch(0.62 0.19 259)").is_ok());
+    /// assert!(Rgba::parse("not-a-color").is_err());
+    /// ```
+    pub fn parse(input: &str) -> D3Result<Rgba> {
+        let s = input.trim().to_ascii_lowercase();
+        let parsed = if let Some(hex) = s.strip_prefix('#') {
+            parse_hex(hex)
+        } else if let Some(open) = s.find('(') {
+            s.strip_suffix(')')
+                .and_then(|inner| parse_function(&inner[..open], &inner[open + 1..]))
+        } else {
+            Rgba::from_name(&s)
+        };
+        parsed.ok_or_else(|| D3Error::parse_error(format!("invalid color: {:?}", input)))
+    }
+
+    /// Look up a CSS named color (case-insensitive)
+    ///
+    /// Also accepts `transparent`.
+    pub fn from_name(name: &str) -> Option<Rgba> {
+        let name = name.trim().to_ascii_lowercase();
+        if name == "transparent" {
+            return Some(Rgba::TRANSPARENT);
+        }
+        NAMED_COLORS
+            .binary_search_by(|(n, _)| (*n).cmp(name.as_str()))
+            .ok()
+            .map(|i| Rgba::from_hex(NAMED_COLORS[i].1))
+    }
+
+    /// Format as a CSS `rgb()` color, e.g. `rgb(59 130 246 / 0.5)`
+    ///
+    /// Channels are rounded to 8 bits; alpha is omitted when opaque.
+    pub fn to_css_string(&self) -> String {
+        let [r, g, b] = [self.r, self.g, self.b].map(byte);
+        format!("rgb({} {} {}{})", r, g, b, alpha_suffix(self.a))
+    }
+
+    /// Format as a CSS hex color: `#rrggbb`, or `#rrggbbaa` when translucent
+    pub fn to_hex_string(&self) -> String {
+        let [r, g, b, a] = [self.r, self.g, self.b, self.a].map(byte);
+        if a == 255 {
+            format!("#{:02x}{:02x}{:02x}", r, g, b)
+        } else {
+            format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, a)
+        }
+    }
+}
+
+impl FromStr for Rgba {
+    type Err = D3Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Rgba::parse(s)
+    }
+}
+
+impl Hsl {
+    /// Format as a CSS `hsl()` color, e.g. `hsl(217 91% 60%)`
+    pub fn to_css_string(&self) -> String {
+        format!(
+            "hsl({} {}% {}%)",
+            fmt(self.h as f64, 2),
+            fmt(self.s as f64 * 100.0, 2),
+            fmt(self.l as f64 * 100.0, 2)
+        )
+    }
+}
+
+impl Lab {
+    /// Format as a CSS `lab()` color, e.g. `lab(54.29 80.81 69.89)`
+    ///
+    /// The components are adapted to the D50 white point used by CSS.
+    pub fn to_css_string(&self) -> String {
+        let lab = self.to_d50();
+        format!(
+            "lab({} {} {}{})",
+            fmt(lab.l as f64, 2),
+            fmt(lab.a as f64, 2),
+            fmt(lab.b as f64, 2),
+            alpha_suffix(self.alpha)
+        )
+    }
+}
+
+impl Hcl {
+    /// Format as a CSS `lch()` color, e.g. `lch(54.29 106.84 40.86)`
+    ///
+    /// The components are adapted to the D50 white point used by CSS.
+    pub fn to_css_string(&self) -> String {
+        let lch = Hcl::from_lab(&self.to_lab().to_d50());
+        format!(
+            "lch({} {} {}{})",
+            fmt(lch.l as f64, 2),
+            fmt(lch.c as f64, 2),
+            fmt(lch.h as f64, 2),
+            alpha_suffix(self.alpha)
+        )
+    }
+}
+
+impl Oklab {
+    /// Format as a CSS `oklab()` color, e.g. `oklab(0.628 0.2249 0.1258)`
+    pub fn to_css_string(&self) -> String {
+        format!(
+            "oklab({} {} {}{})",
+            fmt(self.l as f64, 4),
+            fmt(self.a as f64, 4),
+            fmt(self.b as f64, 4),
+            alpha_suffix(self.alpha)
+        )
+    }
+}
+
+impl Oklch {
+    /// Format as a CSS `oklch()` color, e.g. `oklch(0.628 0.2577 29.23)`
+    ///
+    /// The hue is written in degrees.
+    pub fn to_css_string(&self) -> String {
+        format!(
+            "oklch({} {} {}{})",
+            fmt(self.l as f64, 4),
+            fmt(self.c as f64, 4),
+            fmt((self.h as f64).to_degrees().rem_euclid(360.0), 2),
+            alpha_suffix(self.alpha)
+        )
+    }
+}
+
+fn parse_hex(hex: &str) -> Option<Rgba> {
+    if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    let value = u32::from_str_radix(hex, 16).ok()?;
+    let nibble = |shift: u32| ((value >> shift) & 0xf) * 0x11;
+    let (r, g, b, a) = match hex.len() {
+        3 => (nibble(8), nibble(4), nibble(0), 0xff),
+        4 => (nibble(12), nibble(8), nibble(4), nibble(0)),
+        6 => (value >> 16, (value >> 8) & 0xff, value & 0xff, 0xff),
+        8 => (
+            value >> 24,
+            (value >> 16) & 0xff,
+            (value >> 8) & 0xff,
+            value & 0xff,
+        ),
+        _ => return None,
+    };
+    Some(Rgba::from_rgba8(r as u8, g as u8, b as u8, a as u8))
+}
+
+fn parse_function(name: &str, args: &str) -> Option<Rgba> {
+    let name = name.trim();
+    if name == "color" {
+        return parse_color_function(args);
+    }
+
+    let (channels, alpha) = split_args(args)?;
+    let [c0, c1, c2]: [Component; 3] = channels.try_into().ok()?;
+    let alpha = alpha.map_or(Some(1.0), |a| a.channel(1.0))?;
+
+    let color = match name {
+        "rgb" | "rgba" => Rgba::new(
+            (c0.channel(255.0)? / 255.0) as f32,
+            (c1.channel(255.0)? / 255.0) as f32,
+            (c2.channel(255.0)? / 255.0) as f32,
+            1.0,
+        ),
+        "hsl" | "hsla" => Hsl::new(
+            c0.hue()?.rem_euclid(360.0) as f32,
+            (c1.channel(100.0)? / 100.0) as f32,
+            (c2.channel(100.0)? / 100.0) as f32,
+        )
+        .to_rgba(),
+        "hwb" => hwb_to_rgba(
+            c0.hue()?.rem_euclid(360.0),
+            c1.channel(100.0)? / 100.0,
+            c2.channel(100.0)? / 100.0,
+        ),
+        "lab" => Lab::from_d50(
+            c0.channel(100.0)?.clamp(0.0, 100.0) as f32,
+            c1.channel(125.0)? as f32,
+            c2.channel(125.0)? as f32,
+            1.0,
+        )
+        .to_rgba(),
+        "lch" => {
+            let lab = Hcl::new(
+                c2.hue()? as f32,
+                c1.channel(150.0)?.max(0.0) as f32,
+                c0.channel(100.0)?.clamp(0.0, 100.0) as f32,
+            )
+            .to_lab();
+            Lab::from_d50(lab.l, lab.a, lab.b, 1.0).to_rgba()
+        }
+        "oklab" => Oklab::new(
+            c0.channel(1.0)?.clamp(0.0, 1.0) as f32,
+            c1.channel(0.4)? as f32,
+            c2.channel(0.4)? as f32,
+        )
+        .to_rgba(),
+        "oklch" => Oklch::new(
+            c0.channel(1.0)?.clamp(0.0, 1.0) as f32,
+            c1.channel(0.4)?.max(0.0) as f32,
+            c2.hue()?.to_radians() as f32,
+        )
+        .to_rgba(),
+        _ => return None,
+    };
+
+    Some(clip(color, alpha))
+}
+
+/// Parse the arguments of `color(<space> r g b [/ a])`
+fn parse_color_function(args: &str) -> Option<Rgba> {
+    let args = args.trim_start();
+    let split = args.find(char::is_whitespace)?;
+    let (space, rest) = args.split_at(split);
+    let (channels, alpha) = split_args(rest)?;
+    if channels.len() != 3 {
+        return None;
+    }
+    let alpha = alpha.map_or(Some(1.0), |a| a.channel(1.0))?;
+    let mut rgb = [0.0f32; 3];
+    for (out, c) in rgb.iter_mut().zip(channels) {
+        let v = c.channel(1.0)?.clamp(0.0, 1.0) as f32;
+        *out = match space {
+            "srgb" => v,
+            "srgb-linear" => linear_to_srgb(v),
+            _ => return None,
+        };
+    }
+    Some(clip(Rgba::rgb(rgb[0], rgb[1], rgb[2]), alpha))
+}
+
+/// Split function arguments into channel components and optional alpha
+///
+/// Handles both `a, b, c[, alpha]` and `a b c[ / alpha]`.
+fn split_args(args: &str) -> Option<(Vec<Component>, Option<Component>)> {
+    let parse_all = |tokens: Vec<&str>| -> Option<Vec<Component>> {
+        tokens.into_iter().map(Component::parse).collect()
+    };
+
+    if args.contains(',') {
+        let mut parts = parse_all(args.split(',').map(str::trim).collect())?;
+        let alpha = match parts.len() {
+            3 => None,
+            4 => parts.pop(),
+            _ => return None,
+        };
+        return Some((parts, alpha));
+    }
+
+    let mut halves = args.splitn(2, '/');
+    let channels = parse_all(halves.next()?.split_whitespace().collect())?;
+    let alpha = match halves.next() {
+        Some(a) => {
+            let tokens: Vec<&str> = a.split_whitespace().collect();
+            if tokens.len() != 1 {
+                return None;
+            }
+            Some(Component::parse(tokens[0])?)
+        }
+        None => None,
+    };
+    Some((channels, alpha))
+}
+
+fn hwb_to_rgba(hue: f64, white: f64, black: f64) -> Rgba {
+    let (white, black) = (white.clamp(0.0, 1.0), black.clamp(0.0, 1.0));
+    if white + black >= 1.0 {
+        let gray = (white / (white + black)) as f32;
+        return Rgba::rgb(gray, gray, gray);
+    }
+    let pure = Hsl::new(hue as f32, 1.0, 0.5).to_rgba();
+    let scale = (1.0 - white - black) as f32;
+    let white = white as f32;
+    Rgba::rgb(
+        pure.r * scale + white,
+        pure.g * scale + white,
+        pure.b * scale + white,
+    )
+}
+
+/// Clip a color into the sRGB gamut and apply alpha
+fn clip(color: Rgba, alpha: f64) -> Rgba {
+    Rgba::new(
+        color.r.clamp(0.0, 1.0),
+        color.g.clamp(0.0, 1.0),
+        color.b.clamp(0.0, 1.0),
+        alpha.clamp(0.0, 1.0) as f32,
+    )
+}
+
+fn byte(v: f32) -> u8 {
+    (v.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+fn alpha_suffix(alpha: f32) -> String {
+    if alpha >= 1.0 {
+        String::new()
+    } else {
+        format!(" / {}", fmt(alpha.max(0.0) as f64, 3))
+    }
+}
+
+/// Format a number with at most `decimals` decimals and no trailing zeros
+fn fmt(value: f64, decimals: usize) -> String {
+    let s = format!("{:.*}", decimals, value);
+    let s = if s.contains('.') {
+        s.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        &s
+    };
+    if s == "-0" {
+        "0".to_string()
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: Rgba, b: Rgba, tolerance: f32) {
+        for (x, y) in a.to_array().iter().zip(b.to_array()) {
+            assert!((x - y).abs() <= tolerance, "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_named_colors() {
+        assert_eq!(NAMED_COLORS.len(), 148);
+        assert!(NAMED_COLORS.windows(2).all(|w| w[0].0 < w[1].0));
+        assert_eq!(Rgba::from_name("RebeccaPurple").unwrap().to_hex(), 0x663399);
+        assert_eq!(Rgba::parse("transparent").unwrap(), Rgba::TRANSPARENT);
+        assert!(Rgba::from_name("notacolor").is_none());
+    }
+
+    #[test]
+    fn test_parse_hex() {
+        assert_eq!(Rgba::parse("#f00").unwrap().to_hex(), 0xff0000);
+        assert_eq!(Rgba::parse("#3B82F6").unwrap().to_hex(), 0x3b82f6);
+        let c = Rgba::parse("#3b82f680").unwrap();
+        assert_eq!(c.to_hex(), 0x3b82f6);
+        assert!((c.a - 128.0 / 255.0).abs() < 1e-6);
+        assert!((Rgba::parse("#0f08").unwrap().a - 0x88 as f32 / 255.0).abs() < 1e-6);
+        assert!(Rgba::parse("#12345").is_err());
+        assert!(Rgba::parse("#gggggg").is_err());
+    }
+
+    #[test]
+    fn test_parse_rgb_and_hsl() {
+        let expected = Rgba::from_rgba8(59, 130, 246, 255);
+        assert_eq!(Rgba::parse("rgb(59, 130, 246)").unwrap(), expected);
+        assert_eq!(Rgba::parse("rgb(59 130 246)").unwrap(), expected);
+        let c = Rgba::parse("rgba(100%, 0%, 0%, 0.25)").unwrap();
+        assert_close(c, Rgba::new(1.0, 0.0, 0.0, 0.25), 1e-6);
+        assert_close(
+            Rgba::parse("rgb(255 none 0 / 50%)").unwrap(),
+            Rgba::new(1.0, 0.0, 0.0, 0.5),
+            1e-6,
+        );
+
+        assert_eq!(Rgba::parse("hsl(120 100% 25%)").unwrap().to_hex(), 0x008000);
+        assert_eq!(
+            Rgba::parse("hsl(-120deg, 100%, 50%)").unwrap().to_hex(),
+            0x0000ff
+        );
+        assert_eq!(
+            Rgba::parse("hsl(0.5turn 100% 50%)").unwrap().to_hex(),
+            0x00ffff
+        );
+        assert_eq!(Rgba::parse("hwb(0 0% 0%)").unwrap().to_hex(), 0xff0000);
+        assert_eq!(Rgba::parse("hwb(0 60% 60%)").unwrap().to_hex(), 0x808080);
+
+        assert!(Rgba::parse("rgb(1 2)").is_err());
+        assert!(Rgba::parse("rgb(1 2 3 / 1 2)").is_err());
+        assert!(Rgba::parse("hsl(10% 50% 50%)").is_err());
+        assert!(Rgba::parse("rgb(1 2 3").is_err());
+    }
+
+    #[test]
+    fn test_parse_lab_family() {
+        let red = Rgba::RED;
+        let lab = Lab::from_rgba(&red).to_d50();
+        let parsed = Rgba::parse(&format!("lab({} {} {})", lab.l, lab.a, lab.b)).unwrap();
+        assert_close(parsed, red, 3e-3);
+
+        // CSS lab()/lch() are D50: browsers render lab(50% 40 30) as
+        // rgb(187 88 70); read as D65 it would be rgb(191 88 70)
+        let rgb = |c: Rgba| (c.r * 255.0, c.g * 255.0, c.b * 255.0);
+        for s in ["lab(50% 40 30)", "lch(50% 50 36.87)"] {
+            let (r, g, b) = rgb(Rgba::parse(s).unwrap());
+            assert!(
+                (r - 187.24).abs() < 0.5 && (g - 87.75).abs() < 0.5 && (b - 70.47).abs() < 0.5,
+                "{} -> ({}, {}, {})",
+                s,
+                r,
+                g,
+                b
+            );
+        }
+        assert_eq!(
+            Lab::from_rgba(&red).to_css_string(),
+            "lab(54.29 80.81 69.89)"
+        );
+
+        let oklch = Rgba::parse("oklch(0.62 0.19 259)").unwrap();
+        let back = Oklch::from_rgba(&oklch);
+        assert!((back.l - 0.62).abs() < 0.01);
+        let hue = (back.h as f64).to_degrees().rem_euclid(360.0);
+        assert!((hue - 259.0).abs() < 1.0);
+
+        assert_close(Rgba::parse("oklab(100% 0 0)").unwrap(), Rgba::WHITE, 1e-3);
+        assert_close(
+            Rgba::parse("color(srgb 1 0.5 0 / 0.5)").unwrap(),
+            Rgba::new(1.0, 0.5, 0.0, 0.5),
+            1e-6,
+        );
+        assert_close(
+            Rgba::parse("color(srgb-linear 1 0 0)").unwrap(),
+            Rgba::RED,
+            1e-6,
+        );
+        assert!(Rgba::parse("color(display-p3 1 0 0)").is_err());
+    }
+
+    #[test]
+    fn test_css_round_trip() {
+        let colors = [
+            Rgba::from_hex(0x3b82f6),
+            Rgba::from_hex(0x4682b4).with_alpha(0.5),
+            Rgba::from_hex(0xfacc15),
+            Rgba::BLACK,
+            Rgba::WHITE,
+        ];
+        for color in colors {
+            let strings = [
+                color.to_css_string(),
+                color.to_hex_string(),
+                Lab::from_rgba(&color).to_css_string(),
+                Hcl::from_rgba(&color).to_css_string(),
+                Oklab::from_rgba(&color).to_css_string(),
+                Oklch::from_rgba(&color).to_css_string(),
+            ];
+            for s in strings {
+                let parsed: Rgba = s.parse().unwrap();
+                assert_close(parsed, color, 3e-3);
+            }
+        }
+        assert_eq!(
+            Rgba::from_hex(0x3b82f6).with_alpha(0.5).to_css_string(),
+            "rgb(59 130 246 / 0.5)"
+        );
+        assert_eq!(
+            Hsl::new(217.0, 0.91, 0.6).to_css_string(),
+            "hsl(217 91% 60%)"
+        );
+    }
+}
@@ -11,7 +11,7 @@
 //!
 //! # Example
 //! ```
-//! use makepad_d3::color::cubehelix::{Cubehelix, cubehelix_default};
+//! use makepad_d3::color::{Cubehelix, cubehelix_default};
 //!
 //! // Get color at position 0.5
 //! let color = cubehelix_default(0.5);
@@ -163,85 +163,88 @@ impl Cubehelix {
     }
 }
 
+/// Convert a d3 cubehelix color (hue in degrees) to RGB
+fn cubehelix(h: f64, s: f64, l: f64) -> Rgba {
+    let h = (h + 120.0).to_radians();
+    let a = s * l * (1.0 - l);
+    let (sin, cos) = h.sin_cos();
+    let channel = |v: f64| v.clamp(0.0, 1.0) as f32;
+    Rgba::rgb(
+        channel(l + a * (-0.14861 * cos + 1.78277 * sin)),
+        channel(l + a * (-0.29227 * cos - 0.90649 * sin)),
+        channel(l + a * (1.97294 * cos)),
+    )
+}
+
+/// Interpolate between two cubehelix colors along the long hue path
+fn cubehelix_long(from: (f64, f64, f64), to: (f64, f64, f64), t: f64) -> Rgba {
+    let t = t.clamp(0.0, 1.0);
+    cubehelix(
+        from.0 + (to.0 - from.0) * t,
+        from.1 + (to.1 - from.1) * t,
+        from.2 + (to.2 - from.2) * t,
+    )
+}
+
 /// Default cubehelix color (Dave Green's original)
+///
+/// Matches d3's `interpolateCubehelixDefault`.
 pub fn cubehelix_default(t: f64) -> Rgba {
-    Cubehelix::new().color(t)
+    cubehelix_long((300.0, 0.5, 0.0), (-240.0, 0.5, 1.0), t)
 }
 
-/// "Cool" cubehelix variant (cyan to magenta)
+/// "Cool" cubehelix variant (purple to green)
+///
+/// Matches d3's `interpolateCool`.
 pub fn cubehelix_cool(t: f64) -> Rgba {
-    Cubehelix::new()
-        .start(260.0)
-        .rotations(1.5)
-        .hue(0.8)
-        .color(t)
+    cubehelix_long((260.0, 0.75, 0.35), (80.0, 1.5, 0.8), t)
 }
 
-/// "Warm" cubehelix variant (magenta to orange)
+/// "Warm" cubehelix variant (purple to orange to green)
+///
+/// Matches d3's `interpolateWarm`.
 pub fn cubehelix_warm(t: f64) -> Rgba {
-    Cubehelix::new()
-        .start(80.0)
-        .rotations(-1.5)
-        .hue(1.0)
-        .color(t)
+    cubehelix_long((-100.0, 0.75, 0.35), (80.0, 1.5, 0.8), t)
 }
 
-/// Rainbow cubehelix (full rotation)
+/// Rainbow cubehelix (cyclical, joining warm and cool)
+///
+/// Matches d3's `interpolateRainbow`; `t` wraps around.
 pub fn cubehelix_rainbow(t: f64) -> Rgba {
-    Cubehelix::new()
-        .start(0.0)
-        .rotations(1.0)
-        .hue(1.5)
-        .gamma(1.0)
-        .color(t)
+    let t = t - t.floor();
+    let ts = (t - 0.5).abs();
+    cubehelix(360.0 * t - 100.0, 1.5 - 1.5 * ts, 0.8 - 0.9 * ts)
 }
 
 /// Sinebow (sinusoidal rainbow) color scheme
 ///
-/// Similar to cubehelix but uses sine waves for each channel.
+/// Similar to cubehelix but uses sine waves for each channel. Matches d3's
+/// `interpolateSinebow`; `t` wraps around.
 pub fn sinebow(t: f64) -> Rgba {
-    let t = t.clamp(0.0, 1.0) as f32;
-    let t = (0.5 - t) * PI;
-
+    let t = (0.5 - (t - t.floor())) * std::f64::consts::PI;
+    let channel = |offset: f64| (t + offset).sin().powi(2) as f32;
     Rgba::rgb(
-        (t.sin()).powi(2),
-        ((t + PI / 3.0).sin()).powi(2),
-        ((t + 2.0 * PI / 3.0).sin()).powi(2),
+        channel(0.0),
+        channel(std::f64::consts::PI / 3.0),
+        channel(2.0 * std::f64::consts::PI / 3.0),
     )
 }
 
 /// Turbo color scheme (like jet but perceptually uniform)
 ///
 /// Google's turbo colormap - starts dark blue, goes through cyan, green, yellow to red.
-/// Uses a simplified lookup with interpolation for efficiency.
+/// Uses d3's `interpolateTurbo` polynomial approximation.
 pub fn turbo(t: f64) -> Rgba {
-    let t = t.clamp(0.0, 1.0) as f32;
-
-    // Key points from Google's turbo colormap (simplified 9-point version)
-    const TURBO_COLORS: [(f32, f32, f32); 9] = [
-        (0.190, 0.072, 0.232), // t=0.0    - dark blue/purple
-        (0.129, 0.298, 0.697), // t=0.125  - blue
-        (0.094, 0.539, 0.750), // t=0.25   - cyan-blue
-        (0.137, 0.718, 0.565), // t=0.375  - cyan-green
-        (0.365, 0.827, 0.322), // t=0.5    - green
-        (0.631, 0.854, 0.133), // t=0.625  - yellow-green
-        (0.892, 0.737, 0.099), // t=0.75   - yellow-orange
-        (0.989, 0.460, 0.157), // t=0.875  - orange
-        (0.479, 0.016, 0.011), // t=1.0    - dark red
-    ];
-
-    // Find segment and interpolate
-    let scaled = t * 8.0;
-    let i = (scaled.floor() as usize).min(7);
-    let local_t = scaled - i as f32;
-
-    let (r1, g1, b1) = TURBO_COLORS[i];
-    let (r2, g2, b2) = TURBO_COLORS[i + 1];
-
+    let t = t.clamp(0.0, 1.0);
+    let channel = |v: f64| (v.round().clamp(0.0, 255.0) / 255.0) as f32;
     Rgba::rgb(
-        r1 + (r2 - r1) * local_t,
-        g1 + (g2 - g1) * local_t,
-        b1 + (b2 - b1) * local_t,
+        channel(
+            34.61 + t * (1172.33 - t * (10793.56 - t * (33300.12 - t * (38394.49 - t * 14825.05)))),
+        ),
+        channel(23.31 + t * (557.33 + t * (1225.33 - t * (3574.96 - t * (1073.77 + t * 707.56))))),
+        channel(
+            27.2 + t * (3211.1 - t * (15327.97 - t * (27814.0 - t * (22569.18 - t * 6838.66)))),
+        ),
     )
 }
 
@@ -349,6 +352,7 @@ mod tests {
 
         // Start and end should be similar (cyclic)
         assert!((start.r - end.r).abs() < 0.1);
+        assert_eq!(sinebow(0.25).to_hex(), sinebow(1.25).to_hex());
     }
 
     #[test]
@@ -362,11 +366,31 @@ mod tests {
         assert!(mid.g >= 0.0 && mid.g <= 1.0);
         assert!(end.b >= 0.0 && end.b <= 1.0);
 
-        // Turbo starts blue-ish and ends red-ish
-        assert!(start.b > start.r);
+        // d3's interpolateTurbo end points
+        assert_eq!(start.to_hex(), 0x23171b);
+        assert_eq!(end.to_hex(), 0x900c00);
+
+        // Turbo runs through blue early and ends red-ish
+        let blue = turbo(0.15);
+        assert!(blue.b > blue.r);
         assert!(end.r > end.b);
     }
 
+    #[test]
+    fn test_d3_cubehelix_schemes() {
+        // d3.interpolateCubehelixDefault(0) is black, (1) is white
+        assert_eq!(cubehelix_default(0.0).to_hex(), 0x000000);
+        assert_eq!(cubehelix_default(1.0).to_hex(), 0xffffff);
+
+        // d3.interpolateWarm(0) and d3.interpolateCool(1) share an end
+        assert_eq!(cubehelix_warm(0.0).to_hex(), 0x6e40aa);
+        assert_eq!(cubehelix_warm(1.0).to_hex(), cubehelix_cool(1.0).to_hex());
+        assert_eq!(
+            cubehelix_rainbow(0.0).to_hex(),
+            cubehelix_rainbow(1.0).to_hex()
+        );
+    }
+
     #[test]
     fn test_cubehelix_light_range() {
         let ch = Cubehelix::new().light_range(0.2, 0.8);
@@ -0,0 +1,139 @@
+//! Density buffer coloring
+//!
+//! Turns an accumulated density buffer (counts per heatmap cell, hexbin
+//! or screen pixel) into colors through a [`ColorScale`]. Raw counts are
+//! usually heavy-tailed: a linear mapping paints a few hot spots and
+//! leaves everything else at the bottom of the scale. [`ToneMapping`]
+//! compresses the range first, like datashader's `how` option.
+//!
+//! # Example
+//! ```
+//! use makepad_d3::color::{tone_map_density, Rgba, SequentialScale, ToneMapping};
+//!
+//! let counts = [0.0, 1.0, 10.0, 1000.0];
+//! let colors = tone_map_density(&counts, &SequentialScale::viridis(), ToneMapping::Log);
+//!
+//! assert_eq!(colors[0], Rgba::TRANSPARENT); // empty cells stay empty
+//! assert_eq!(colors.len(), 4);
+//! ```
+
+use super::scale::ColorScale;
+use super::types::Rgba;
+
+/// How density values are compressed before coloring
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ToneMapping {
+    /// Proportional to density
+    Linear,
+    /// Logarithmic: `ln(1 + v) / ln(1 + max)`
+    #[default]
+    Log,
+    /// Power curve `(v / max)^gamma`; 0.5 is a square root
+    Gamma(f64),
+    /// Histogram equalization: each non-empty cell maps to its rank, so
+    /// every color of the scale is used equally often
+    Equalize,
+}
+
+impl ToneMapping {
+    /// Normalize a density buffer to positions on a color scale
+    ///
+    /// Cells with zero, negative or non-finite density are None. The
+    /// densest cell maps to 1.
+    pub fn normalize(&self, density: &[f64]) -> Vec<Option<f64>> {
+        let filled = |v: f64| v.is_finite() && v > 0.0;
+        let max = density
+            .iter()
+            .copied()
+            .filter(|&v| filled(v))
+            .fold(0.0, f64::max);
+        if max <= 0.0 {
+            return vec![None; density.len()];
+        }
+
+        if let ToneMapping::Equalize = self {
+            let mut sorted: Vec<f64> = density.iter().copied().filter(|&v| filled(v)).collect();
+            sorted.sort_by(f64::total_cmp);
+            let n = sorted.len() as f64;
+            return density
+                .iter()
+                .map(|&v| {
+                    filled(v).then(|| {
+                        // Fraction of cells at or below v
+                        let rank = sorted.partition_point(|&s| s <= v);
+                        rank as f64 / n
+                    })
+                })
+                .collect();
+        }
+
+        density
+            .iter()
+            .map(|&v| {
+                filled(v).then(|| match self {
+                    ToneMapping::Linear => v / max,
+                    ToneMapping::Log => v.ln_1p() / max.ln_1p(),
+                    ToneMapping::Gamma(gamma) => (v / max).powf(gamma.max(f64::EPSILON)),
+                    ToneMapping::Equalize => unreachable!(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Color a density buffer through a scale
+///
+/// Empty cells become [`Rgba::TRANSPARENT`]. Filled cells are colored at
+/// their tone-mapped position, so the sparsest cell still gets a visible
+/// color from the low end of the scale.
+pub fn tone_map_density<S: ColorScale + ?Sized>(
+    density: &[f64],
+    scale: &S,
+    mapping: ToneMapping,
+) -> Vec<Rgba> {
+    mapping
+        .normalize(density)
+        .into_iter()
+        .map(|t| t.map_or(Rgba::TRANSPARENT, |t| scale.color(t)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::SequentialScale;
+
+    #[test]
+    fn test_normalize() {
+        let density = [0.0, 1.0, 3.0, 99.0, f64::NAN];
+        let linear = ToneMapping::Linear.normalize(&density);
+        assert_eq!(linear[0], None);
+        assert_eq!(linear[4], None);
+        assert_eq!(linear[3], Some(1.0));
+        assert!((linear[1].unwrap() - 1.0 / 99.0).abs() < 1e-12);
+
+        let log = ToneMapping::Log.normalize(&density);
+        assert!((log[1].unwrap() - 2f64.ln() / 100f64.ln()).abs() < 1e-12);
+        assert!(log[1].unwrap() > linear[1].unwrap());
+
+        let sqrt = ToneMapping::Gamma(0.5).normalize(&density);
+        assert!((sqrt[2].unwrap() - (3.0f64 / 99.0).sqrt()).abs() < 1e-12);
+
+        // Equalization spreads ranks evenly regardless of magnitude
+        let eq = ToneMapping::Equalize.normalize(&density);
+        assert_eq!(eq[1], Some(1.0 / 3.0));
+        assert_eq!(eq[2], Some(2.0 / 3.0));
+        assert_eq!(eq[3], Some(1.0));
+
+        assert_eq!(ToneMapping::Log.normalize(&[0.0, 0.0]), vec![None, None]);
+    }
+
+    #[test]
+    fn test_tone_map_density() {
+        let scale = SequentialScale::viridis();
+        let colors = tone_map_density(&[0.0, 1.0, 1e6], &scale, ToneMapping::Log);
+        assert_eq!(colors[0], Rgba::TRANSPARENT);
+        assert_eq!(colors[2], scale.color(1.0));
+        assert_ne!(colors[1], scale.color(0.0));
+    }
+}
@@ -16,6 +16,25 @@ const REF_X: f32 = 95.047;
 const REF_Y: f32 = 100.000;
 const REF_Z: f32 = 108.883;
 
+/// D50 reference white, used by CSS `lab()` and `lch()`
+const D50_X: f32 = 96.42957;
+const D50_Y: f32 = 100.000;
+const D50_Z: f32 = 82.51046;
+
+/// Bradford chromatic adaptation from D50 to D65 (CSS Color 4)
+const D50_TO_D65: [[f32; 3]; 3] = [
+    [0.955_473_4, -0.023_098_455, 0.063_259_24],
+    [-0.028_369_71, 1.009_995_4, 0.021_041_441],
+    [0.012_314_015, -0.020_507_65, 1.330_365_9],
+];
+
+/// Bradford chromatic adaptation from D65 to D50 (CSS Color 4)
+const D65_TO_D50: [[f32; 3]; 3] = [
+    [1.047_929_8, 0.022_946_87, -0.050_192_266],
+    [0.029_627_81, 0.990_434_4, -0.017_073_8],
+    [-0.009_243_041, 0.015_055_191, 0.751_874_3],
+];
+
 /// Lab epsilon for linear/non-linear transition
 const EPSILON: f32 = 0.008856;
 /// Lab kappa constant
@@ -75,6 +94,20 @@ impl Lab {
         Rgba::new(r, g, b, self.alpha)
     }
 
+    /// Convert a D50 Lab color, as written in CSS `lab()`, to D65 Lab
+    pub(crate) fn from_d50(l: f32, a: f32, b: f32, alpha: f32) -> Self {
+        let (x, y, z) = lab_to_xyz_white(l, a, b, (D50_X, D50_Y, D50_Z));
+        let (x, y, z) = mul3(&D50_TO_D65, (x, y, z));
+        xyz_to_lab(x, y, z, alpha)
+    }
+
+    /// Convert to D50 Lab, as written in CSS `lab()`
+    pub(crate) fn to_d50(self) -> Self {
+        let (x, y, z) = lab_to_xyz(self.l, self.a, self.b);
+        let (x, y, z) = mul3(&D65_TO_D50, (x, y, z));
+        xyz_to_lab_white(x, y, z, self.alpha, (D50_X, D50_Y, D50_Z))
+    }
+
     /// Linear interpolation in Lab space
     pub fn lerp(&self, other: &Lab, t: f32) -> Lab {
         let t = t.clamp(0.0, 1.0);
@@ -94,6 +127,71 @@ impl Lab {
         (dl * dl + da * da + db * db).sqrt()
     }
 
+    /// Calculate the CIEDE2000 color difference to another Lab color
+    ///
+    /// Corrects the plain Euclidean distance for the eye's uneven
+    /// sensitivity to lightness, chroma and hue. A difference around 2.3 is
+    /// a just-noticeable difference.
+    pub fn delta_e_2000(&self, other: &Lab) -> f32 {
+        let (l1, a1, b1) = (self.l as f64, self.a as f64, self.b as f64);
+        let (l2, a2, b2) = (other.l as f64, other.a as f64, other.b as f64);
+        let pow25_7 = 25f64.powi(7);
+
+        let c_bar = ((a1 * a1 + b1 * b1).sqrt() + (a2 * a2 + b2 * b2).sqrt()) / 2.0;
+        let g = 0.5 * (1.0 - (c_bar.powi(7) / (c_bar.powi(7) + pow25_7)).sqrt());
+        let (a1, a2) = (a1 * (1.0 + g), a2 * (1.0 + g));
+        let (c1, c2) = ((a1 * a1 + b1 * b1).sqrt(), (a2 * a2 + b2 * b2).sqrt());
+        let hue = |b: f64, a: f64| {
+            if a == 0.0 && b == 0.0 {
+                0.0
+            } else {
+                b.atan2(a).to_degrees().rem_euclid(360.0)
+            }
+        };
+        let (h1, h2) = (hue(b1, a1), hue(b2, a2));
+
+        let dl = l2 - l1;
+        let dc = c2 - c1;
+        let dh = if c1 * c2 == 0.0 {
+            0.0
+        } else if (h2 - h1).abs() <= 180.0 {
+            h2 - h1
+        } else if h2 > h1 {
+            h2 - h1 - 360.0
+        } else {
+            h2 - h1 + 360.0
+        };
+        let dh = 2.0 * (c1 * c2).sqrt() * (dh.to_radians() / 2.0).sin();
+
+        let l_bar = (l1 + l2) / 2.0;
+        let c_bar = (c1 + c2) / 2.0;
+        let h_bar = if c1 * c2 == 0.0 {
+            h1 + h2
+        } else if (h1 - h2).abs() <= 180.0 {
+            (h1 + h2) / 2.0
+        } else if h1 + h2 < 360.0 {
+            (h1 + h2 + 360.0) / 2.0
+        } else {
+            (h1 + h2 - 360.0) / 2.0
+        };
+
+        let cos_deg = |d: f64| d.to_radians().cos();
+        let t = 1.0 - 0.17 * cos_deg(h_bar - 30.0)
+            + 0.24 * cos_deg(2.0 * h_bar)
+            + 0.32 * cos_deg(3.0 * h_bar + 6.0)
+            - 0.20 * cos_deg(4.0 * h_bar - 63.0);
+        let d_theta = 30.0 * (-((h_bar - 275.0) / 25.0).powi(2)).exp();
+        let rc = 2.0 * (c_bar.powi(7) / (c_bar.powi(7) + pow25_7)).sqrt();
+        let l_50 = (l_bar - 50.0).powi(2);
+        let sl = 1.0 + 0.015 * l_50 / (20.0 + l_50).sqrt();
+        let sc = 1.0 + 0.045 * c_bar;
+        let sh = 1.0 + 0.015 * c_bar * t;
+        let rt = -(2.0 * d_theta).to_radians().sin() * rc;
+
+        let (l_term, c_term, h_term) = (dl / sl, dc / sc, dh / sh);
+        (l_term * l_term + c_term * c_term + h_term * h_term + rt * c_term * h_term).sqrt() as f32
+    }
+
     /// Darken the color by a factor (0-1)
     pub fn darken(&self, amount: f32) -> Lab {
         Lab {
@@ -162,9 +260,14 @@ fn xyz_to_rgb(x: f32, y: f32, z: f32) -> (f32, f32, f32) {
 
 /// Convert XYZ to Lab
 fn xyz_to_lab(x: f32, y: f32, z: f32, alpha: f32) -> Lab {
-    let x = x / REF_X;
-    let y = y / REF_Y;
-    let z = z / REF_Z;
+    xyz_to_lab_white(x, y, z, alpha, (REF_X, REF_Y, REF_Z))
+}
+
+/// Convert XYZ to Lab relative to a reference white
+fn xyz_to_lab_white(x: f32, y: f32, z: f32, alpha: f32, white: (f32, f32, f32)) -> Lab {
+    let x = x / white.0;
+    let y = y / white.1;
+    let z = z / white.2;
 
     let x = lab_f(x);
     let y = lab_f(y);
@@ -180,17 +283,31 @@ fn xyz_to_lab(x: f32, y: f32, z: f32, alpha: f32) -> Lab {
 
 /// Convert Lab to XYZ
 fn lab_to_xyz(l: f32, a: f32, b: f32) -> (f32, f32, f32) {
+    lab_to_xyz_white(l, a, b, (REF_X, REF_Y, REF_Z))
+}
+
+/// Convert Lab to XYZ relative to a reference white
+fn lab_to_xyz_white(l: f32, a: f32, b: f32, white: (f32, f32, f32)) -> (f32, f32, f32) {
     let y = (l + 16.0) / 116.0;
     let x = a / 500.0 + y;
     let z = y - b / 200.0;
 
-    let x = lab_f_inv(x) * REF_X;
-    let y = lab_f_inv(y) * REF_Y;
-    let z = lab_f_inv(z) * REF_Z;
+    let x = lab_f_inv(x) * white.0;
+    let y = lab_f_inv(y) * white.1;
+    let z = lab_f_inv(z) * white.2;
 
     (x, y, z)
 }
 
+/// Multiply a 3x3 matrix by a vector
+fn mul3(m: &[[f32; 3]; 3], (x, y, z): (f32, f32, f32)) -> (f32, f32, f32) {
+    (
+        m[0][0] * x + m[0][1] * y + m[0][2] * z,
+        m[1][0] * x + m[1][1] * y + m[1][2] * z,
+        m[2][0] * x + m[2][1] * y + m[2][2] * z,
+    )
+}
+
 /// Lab f function (cube root with linear segment)
 fn lab_f(t: f32) -> f32 {
     if t > EPSILON {
@@ -228,6 +345,21 @@ fn linear_to_srgb(c: f32) -> f32 {
     }
 }
 
+/// CIE76 color difference: Euclidean distance in Lab
+///
+/// Cheap, but overstates differences between saturated colors. Prefer
+/// [`delta_e_2000`] when judging whether two colors look distinct.
+pub fn delta_e_76(a: &Rgba, b: &Rgba) -> f32 {
+    Lab::from_rgba(a).distance(&Lab::from_rgba(b))
+}
+
+/// CIEDE2000 color difference
+///
+/// See [`Lab::delta_e_2000`].
+pub fn delta_e_2000(a: &Rgba, b: &Rgba) -> f32 {
+    Lab::from_rgba(a).delta_e_2000(&Lab::from_rgba(b))
+}
+
 impl From<Rgba> for Lab {
     fn from(rgba: Rgba) -> Self {
         Lab::from_rgba(&rgba)
@@ -317,6 +449,39 @@ mod tests {
         assert!(red.distance(&green) > 0.0);
     }
 
+    #[test]
+    fn test_delta_e_2000() {
+        // Reference pairs from Sharma, Wu & Dalal (2005)
+        let pairs = [
+            ((50.0, 2.6772, -79.7751), (50.0, 0.0, -82.7485), 2.0425),
+            ((50.0, 0.0, 0.0), (50.0, -1.0, 2.0), 2.3669),
+            ((50.0, 2.5, 0.0), (73.0, 25.0, -18.0), 27.1492),
+            (
+                (60.2574, -34.0099, 36.2677),
+                (60.4626, -34.1751, 39.4387),
+                1.2644,
+            ),
+            ((2.0776, 0.0795, -1.135), (0.9033, -0.0636, -0.5514), 0.9082),
+        ];
+        for ((l1, a1, b1), (l2, a2, b2), expected) in pairs {
+            let d = Lab::new(l1, a1, b1).delta_e_2000(&Lab::new(l2, a2, b2));
+            assert!((d - expected).abs() < 1e-3, "{} != {}", d, expected);
+        }
+        let gray = Lab::new(50.0, 0.0, 0.0);
+        assert_eq!(gray.delta_e_2000(&gray), 0.0);
+    }
+
+    #[test]
+    fn test_delta_e_functions() {
+        let blue = Rgba::from_hex(0x1f77b4);
+        let blue2 = Rgba::from_hex(0x2077b4);
+        assert!(delta_e_76(&blue, &blue) < 1e-4);
+        assert!(delta_e_2000(&blue, &blue2) < 1.0);
+        // CIE76 overstates the difference between saturated colors
+        let (red, orange) = (Rgba::RED, Rgba::from_hex(0xff4000));
+        assert!(delta_e_76(&red, &orange) > delta_e_2000(&red, &orange));
+    }
+
     #[test]
     fn test_darken_lighten() {
         let gray = Lab::new(50.0, 0.0, 0.0);
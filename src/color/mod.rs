@@ -9,11 +9,16 @@
 //! - [`Lab`]: CIELAB perceptually uniform color space
 //! - [`Hcl`]: HCL (polar Lab) for intuitive hue manipulation
 //!
+//! Colors can be parsed from any CSS Color Level 4 string with
+//! [`Rgba::parse`] and written back with each type's `to_css_string`.
+//!
 //! # Color Scales
 //!
 //! - [`SequentialScale`]: Continuous interpolation for quantitative data
 //! - [`DivergingScale`]: Two-sided scales for data with a midpoint
 //! - [`CategoricalScale`]: Distinct colors for categorical data
+//! - [`PaletteGenerator`]: Distinct palettes and ramps from a seed color
+//! - [`color_scheme`]: Every d3-scale-chromatic scheme, looked up by name
 //!
 //! # Color Interpolation
 //!
@@ -28,10 +33,13 @@
 //!
 //! # Color Operations
 //!
-//! - Blending modes (multiply, screen, overlay, etc.)
+//! - Blending modes (multiply, screen, overlay, etc.), in sRGB or linear light
+//! - Premultiplied and linear-light alpha compositing
+//! - Density buffer tone mapping ([`tone_map_density`])
 //! - Color mixing in RGB and Lab spaces
 //! - Tint, shade, tone adjustments
-//! - WCAG contrast checking
+//! - WCAG 2 and APCA contrast, readable label colors ([`readable_text_color`])
+//! - Color vision deficiency simulation and palette auditing
 //!
 //! # Example
 //!
@@ -65,13 +73,19 @@
 //! ```
 
 mod blend;
+mod chromatic;
+mod contrast;
+mod css;
 mod cubehelix;
+mod density;
 mod hcl;
 mod interpolate;
 mod lab;
 mod oklab;
+mod palette;
 mod scale;
 mod types;
+mod vision;
 
 // Core color types
 pub use types::{Hsl, Rgba};
@@ -81,8 +95,10 @@ pub use scale::{CategoricalScale, ColorScale, DivergingScale, SequentialScale};
 
 // Perceptually uniform color spaces
 pub use hcl::{Hcl, HueInterpolation};
-pub use lab::Lab;
-pub use oklab::{interpolate_oklab, interpolate_oklch, Oklab, Oklch};
+pub use lab::{delta_e_2000, delta_e_76, Lab};
+pub use oklab::{
+    interpolate_oklab, interpolate_oklch, linear_to_srgb, srgb_to_linear, Oklab, Oklch,
+};
 
 // Interpolation functions
 pub use interpolate::{
@@ -98,11 +114,29 @@ pub use cubehelix::{
     sinebow, turbo, Cubehelix,
 };
 
+// Named color scheme registry
+pub use chromatic::{color_scheme, color_schemes, ColorScheme, SchemeKind};
+
+// Palette generation
+pub use palette::PaletteGenerator;
+
+// Color vision deficiency simulation and palette auditing
+pub use vision::{ColorVisionDeficiency, PaletteAudit, PaletteAuditor, PaletteConflict};
+
+// Text contrast and readable label colors
+pub use contrast::{
+    apca_contrast, readable_text_color, relative_luminance, TextColorChoice, TextColorPicker,
+};
+
+// Density buffer tone mapping
+pub use density::{tone_map_density, ToneMapping};
+
 // Color blending and operations
 pub use blend::{
-    blend, blend_with_opacity, brightness, composite_over, contrast, contrast_ratio, grayscale,
-    invert, luminance, meets_wcag_aa, meets_wcag_aaa, mix, mix_lab, mix_weighted, sepia, shade,
-    tint, tone, BlendMode,
+    blend, blend_in_space, blend_with_opacity, blend_with_opacity_in_space, brightness,
+    composite_over, composite_over_linear, composite_over_premultiplied, composite_stack_linear,
+    contrast, contrast_ratio, grayscale, invert, luminance, meets_wcag_aa, meets_wcag_aaa, mix,
+    mix_lab, mix_weighted, sepia, shade, tint, tone, BlendMode, BlendSpace,
 };
 
 /// Interpolate between two colors
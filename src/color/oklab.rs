@@ -278,8 +278,8 @@ fn normalize_hue(h: f32) -> f32 {
     h
 }
 
-/// Convert sRGB component to linear RGB
-fn srgb_to_linear(c: f32) -> f32 {
+/// Convert an sRGB component (0 to 1) to linear light
+pub fn srgb_to_linear(c: f32) -> f32 {
     if c <= 0.04045 {
         c / 12.92
     } else {
@@ -287,8 +287,8 @@ fn srgb_to_linear(c: f32) -> f32 {
     }
 }
 
-/// Convert linear RGB component to sRGB
-fn linear_to_srgb(c: f32) -> f32 {
+/// Convert a linear-light component to sRGB, clamped to 0 to 1
+pub fn linear_to_srgb(c: f32) -> f32 {
     if c <= 0.0031308 {
         c * 12.92
     } else {
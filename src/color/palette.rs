@@ -0,0 +1,317 @@
+//! Palette generation
+//!
+//! Builds categorical palettes and sequential ramps around a seed color,
+//! typically a brand color:
+//!
+//! - [`PaletteGenerator::distinct`]: maximally distinct colors (CIEDE2000)
+//!   within lightness and chroma bounds in Oklch
+//! - [`PaletteGenerator::evenly_spaced`]: hues evenly spaced in HCL at the
+//!   seed's chroma and lightness
+//! - [`PaletteGenerator::ramp`]: a light-to-dark ramp through the seed
+//!
+//! # Example
+//! ```
+//! use makepad_d3::color::{ColorScale, PaletteGenerator, Rgba};
+//!
+//! let brand = Rgba::from_hex(0x3b82f6);
+//! let generator = PaletteGenerator::new().seed(brand);
+//!
+//! let categories = generator.distinct(6);
+//! assert_eq!(categories.len(), 6);
+//! assert_eq!(categories.get(0), brand);
+//!
+//! let ramp = generator.ramp(9);
+//! let light = ramp.color(0.0);
+//! let dark = ramp.color(1.0);
+//! ```
+
+use super::hcl::Hcl;
+use super::lab::Lab;
+use super::oklab::{Oklab, Oklch};
+use super::scale::{CategoricalScale, SequentialScale};
+use super::types::Rgba;
+
+/// Number of lightness levels sampled by [`PaletteGenerator::distinct`]
+const LIGHTNESS_STEPS: usize = 5;
+/// Number of chroma levels sampled by [`PaletteGenerator::distinct`]
+const CHROMA_STEPS: usize = 3;
+/// Number of hues sampled by [`PaletteGenerator::distinct`]
+const HUE_STEPS: usize = 72;
+
+/// Generator for categorical palettes and sequential ramps
+#[derive(Clone, Debug)]
+pub struct PaletteGenerator {
+    /// Seed color
+    seed: Option<Rgba>,
+    /// Oklch lightness bounds for distinct palettes
+    lightness: (f32, f32),
+    /// Oklch chroma bounds for distinct palettes
+    chroma: (f32, f32),
+}
+
+impl Default for PaletteGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PaletteGenerator {
+    /// Create a generator without a seed color
+    ///
+    /// Distinct palettes default to Oklch lightness 0.45–0.85 and chroma
+    /// 0.08–0.2, which stays readable on both light and dark backgrounds.
+    pub fn new() -> Self {
+        Self {
+            seed: None,
+            lightness: (0.45, 0.85),
+            chroma: (0.08, 0.2),
+        }
+    }
+
+    /// Set the seed color
+    ///
+    /// The seed is the first color of distinct and evenly spaced palettes
+    /// and the midpoint of ramps.
+    pub fn seed(mut self, color: Rgba) -> Self {
+        self.seed = Some(color);
+        self
+    }
+
+    /// Set the Oklch lightness bounds (0 to 1) for distinct palettes
+    pub fn lightness_range(mut self, min: f32, max: f32) -> Self {
+        let (min, max) = (min.clamp(0.0, 1.0), max.clamp(0.0, 1.0));
+        self.lightness = (min.min(max), min.max(max));
+        self
+    }
+
+    /// Set the Oklch chroma bounds (0 to ~0.4) for distinct palettes
+    pub fn chroma_range(mut self, min: f32, max: f32) -> Self {
+        let (min, max) = (min.max(0.0), max.max(0.0));
+        self.chroma = (min.min(max), min.max(max));
+        self
+    }
+
+    /// Generate `n` maximally distinct colors
+    ///
+    /// Candidates are sampled on a grid of in-gamut Oklch colors within the
+    /// lightness and chroma bounds. Each color is then picked greedily to
+    /// maximize its smallest CIEDE2000 difference to the colors already
+    /// chosen, starting from the seed (or the most saturated candidate).
+    /// At least one color is always returned.
+    pub fn distinct(&self, n: usize) -> CategoricalScale {
+        let n = n.max(1);
+        let candidates = self.candidates();
+        let labs: Vec<Lab> = candidates.iter().map(Lab::from_rgba).collect();
+
+        let first = self.seed.unwrap_or_else(|| {
+            candidates
+                .iter()
+                .copied()
+                .max_by(|a, b| {
+                    let chroma = |c: &Rgba| Oklch::from_rgba(c).c;
+                    chroma(a).total_cmp(&chroma(b))
+                })
+                .unwrap_or(Rgba::rgb(0.5, 0.5, 0.5))
+        });
+
+        let mut colors = vec![first];
+        let first_lab = Lab::from_rgba(&first);
+        let mut nearest: Vec<f32> = labs.iter().map(|l| l.delta_e_2000(&first_lab)).collect();
+
+        while colors.len() < n {
+            let Some(best) = (0..candidates.len())
+                .filter(|&i| nearest[i] > 0.0)
+                .max_by(|&a, &b| nearest[a].total_cmp(&nearest[b]))
+            else {
+                break;
+            };
+            colors.push(candidates[best]);
+            for (i, lab) in labs.iter().enumerate() {
+                nearest[i] = nearest[i].min(lab.delta_e_2000(&labs[best]));
+            }
+        }
+
+        CategoricalScale::new(colors)
+    }
+
+    /// Generate `n` colors with hues evenly spaced in HCL
+    ///
+    /// All colors share the seed's HCL chroma and lightness, and the hues
+    /// start at the seed's hue. Without a seed, chroma 50 and lightness 65
+    /// are used, starting at hue 0. At least one color is returned.
+    pub fn evenly_spaced(&self, n: usize) -> CategoricalScale {
+        let n = n.max(1);
+        let base = self
+            .seed
+            .map(|c| Hcl::from_rgba(&c))
+            .unwrap_or_else(|| Hcl::new(0.0, 50.0, 65.0));
+        let colors = (0..n)
+            .map(|i| {
+                let hcl = base.rotate(360.0 * i as f32 / n as f32);
+                if i == 0 {
+                    self.seed.unwrap_or_else(|| hcl.to_rgba())
+                } else {
+                    hcl.to_rgba()
+                }
+            })
+            .collect();
+        CategoricalScale::new(colors)
+    }
+
+    /// Generate a light-to-dark ramp of `n` colors through the seed
+    ///
+    /// Lightness runs evenly in Oklch from a near-white tint to a deep
+    /// shade of the seed's hue, passing through the seed's lightness.
+    /// Chroma peaks at the seed and fades towards both ends. Without a
+    /// seed the ramp is neutral gray. At least two colors are returned.
+    pub fn ramp(&self, n: usize) -> SequentialScale {
+        let n = n.max(2);
+        let seed = Oklch::from_rgba(&self.seed.unwrap_or(Rgba::rgb(0.5, 0.5, 0.5)));
+        let light = seed.l.max(0.97);
+        let dark = seed.l.min(0.25);
+
+        let colors = (0..n)
+            .map(|i| {
+                let t = i as f32 / (n - 1) as f32;
+                let l = light + (dark - light) * t;
+                let c = if l >= seed.l {
+                    let f = (l - seed.l) / (light - seed.l).max(f32::EPSILON);
+                    seed.c * (1.0 - 0.9 * f)
+                } else {
+                    let f = (seed.l - l) / (seed.l - dark).max(f32::EPSILON);
+                    seed.c * (1.0 - 0.5 * f)
+                };
+                Oklch::with_alpha(l, c, seed.h, seed.alpha).to_rgba()
+            })
+            .collect();
+        SequentialScale::new(colors)
+    }
+
+    /// Sample in-gamut Oklch candidates within the bounds
+    fn candidates(&self) -> Vec<Rgba> {
+        let steps = |(min, max): (f32, f32), n: usize| -> Vec<f32> {
+            if max <= min {
+                return vec![min];
+            }
+            (0..n)
+                .map(|i| min + (max - min) * i as f32 / (n - 1) as f32)
+                .collect()
+        };
+
+        let mut in_gamut = Vec::new();
+        let mut clipped = Vec::new();
+        for l in steps(self.lightness, LIGHTNESS_STEPS) {
+            for c in steps(self.chroma, CHROMA_STEPS) {
+                for h in 0..HUE_STEPS {
+                    let h = (h as f32 / HUE_STEPS as f32) * std::f32::consts::TAU;
+                    let oklab = Oklch::new(l, c, h).to_oklab();
+                    let rgba = oklab.to_rgba();
+                    if Oklab::from_rgba(&rgba).distance(&oklab) < 2e-3 {
+                        in_gamut.push(rgba);
+                    } else {
+                        clipped.push(rgba);
+                    }
+                }
+            }
+        }
+
+        // Bounds entirely outside the gamut: fall back to clipped colors
+        if in_gamut.is_empty() {
+            clipped
+        } else {
+            in_gamut
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::{delta_e_2000, ColorScale};
+
+    fn min_delta_e(colors: &[Rgba]) -> f32 {
+        let mut min = f32::MAX;
+        for i in 0..colors.len() {
+            for j in i + 1..colors.len() {
+                min = min.min(delta_e_2000(&colors[i], &colors[j]));
+            }
+        }
+        min
+    }
+
+    #[test]
+    fn test_distinct_palette() {
+        let generator = PaletteGenerator::new();
+        let palette = generator.distinct(8);
+        assert_eq!(palette.len(), 8);
+        assert!(min_delta_e(palette.colors()) > 15.0);
+
+        for color in palette.colors() {
+            let l = Oklch::from_rgba(color).l;
+            assert!((0.44..=0.86).contains(&l), "lightness {}", l);
+        }
+        assert_eq!(generator.distinct(0).len(), 1);
+    }
+
+    #[test]
+    fn test_distinct_keeps_seed() {
+        let brand = Rgba::from_hex(0x3b82f6);
+        let palette = PaletteGenerator::new()
+            .seed(brand)
+            .lightness_range(0.6, 0.6)
+            .chroma_range(0.1, 0.1)
+            .distinct(4);
+        assert_eq!(palette.get(0), brand);
+        for color in &palette.colors()[1..] {
+            let oklch = Oklch::from_rgba(color);
+            assert!((oklch.l - 0.6).abs() < 0.01);
+            assert!((oklch.c - 0.1).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn test_evenly_spaced() {
+        let seed = Hcl::new(40.0, 30.0, 60.0).to_rgba();
+        let palette = PaletteGenerator::new().seed(seed).evenly_spaced(4);
+        assert_eq!(palette.len(), 4);
+        assert_eq!(palette.get(0), seed);
+
+        let hues: Vec<f32> = palette
+            .colors()
+            .iter()
+            .map(|c| Hcl::from_rgba(c).h)
+            .collect();
+        for (i, h) in hues.iter().enumerate() {
+            let expected = (40.0 + 90.0 * i as f32) % 360.0;
+            assert!((h - expected).abs() < 2.0, "hue {} != {}", h, expected);
+        }
+    }
+
+    #[test]
+    fn test_ramp() {
+        let seed = Rgba::from_hex(0x3b82f6);
+        let ramp = PaletteGenerator::new().seed(seed).ramp(9);
+        assert_eq!(ramp.colors().len(), 9);
+
+        let lightness: Vec<f32> = ramp
+            .colors()
+            .iter()
+            .map(|c| Oklch::from_rgba(c).l)
+            .collect();
+        assert!(lightness.windows(2).all(|w| w[0] > w[1]));
+        assert!(lightness[0] > 0.95 && lightness[8] < 0.3);
+
+        // The seed's lightness sits on the ramp with the seed's hue
+        let seed_l = Oklch::from_rgba(&seed).l;
+        let closest = ramp
+            .colors()
+            .iter()
+            .min_by(|a, b| {
+                let d = |c: &Rgba| (Oklch::from_rgba(c).l - seed_l).abs();
+                d(a).total_cmp(&d(b))
+            })
+            .unwrap();
+        assert!(delta_e_2000(closest, &seed) < 10.0);
+        assert!(ramp.color(0.0).r > 0.9);
+    }
+}
@@ -3,7 +3,9 @@
 //! Provides sequential, diverging, and categorical color scales for
 //! mapping data values to colors.
 
+use super::chromatic::{self, color_scheme, SchemeKind};
 use super::types::Rgba;
+use super::vision::{PaletteAudit, PaletteAuditor};
 
 /// Trait for color scales that map values to colors
 pub trait ColorScale: Send + Sync {
@@ -51,126 +53,92 @@ impl SequentialScale {
         &self.colors
     }
 
+    /// Look up a scheme from the [`color_schemes`](super::color_schemes)
+    /// registry by name (e.g. `"YlGnBu"` or `"interpolateMagma"`)
+    ///
+    /// Diverging schemes are returned as a single continuous ramp.
+    /// Returns None for unknown names and categorical schemes.
+    pub fn scheme(name: &str) -> Option<Self> {
+        color_scheme(name)
+            .filter(|s| s.kind() != SchemeKind::Categorical)
+            .map(|s| s.sequential_scale())
+    }
+
     // ==================== D3 Sequential Scales ====================
 
     /// Viridis color scheme (perceptually uniform, colorblind safe)
     pub fn viridis() -> Self {
-        Self::from_hex(&[
-            0x440154, 0x482878, 0x3E4A89, 0x31688E, 0x26838E, 0x1F9E89, 0x35B779, 0x6DCD59,
-            0xB4DE2C, 0xFDE725,
-        ])
+        Self::from_hex(&chromatic::VIRIDIS)
     }
 
     /// Plasma color scheme
     pub fn plasma() -> Self {
-        Self::from_hex(&[
-            0x0D0887, 0x46039F, 0x7201A8, 0x9C179E, 0xBD3786, 0xD8576B, 0xED7953, 0xFB9F3A,
-            0xFDCA26, 0xF0F921,
-        ])
+        Self::from_hex(&chromatic::PLASMA)
     }
 
     /// Inferno color scheme
     pub fn inferno() -> Self {
-        Self::from_hex(&[
-            0x000004, 0x1B0C41, 0x4A0C6B, 0x781C6D, 0xA52C60, 0xCF4446, 0xED6925, 0xFB9A06,
-            0xF7D03C, 0xFCFFA4,
-        ])
+        Self::from_hex(&chromatic::INFERNO)
     }
 
     /// Magma color scheme
     pub fn magma() -> Self {
-        Self::from_hex(&[
-            0x000004, 0x180F3D, 0x440F76, 0x721F81, 0x9E2F7F, 0xCD4071, 0xF1605D, 0xFD9668,
-            0xFECA8E, 0xFCFDBF,
-        ])
+        Self::from_hex(&chromatic::MAGMA)
     }
 
     /// Cividis color scheme (colorblind safe)
     pub fn cividis() -> Self {
-        Self::from_hex(&[
-            0x002051, 0x0A326A, 0x2B446E, 0x4A5568, 0x636763, 0x7C7B57, 0x97904B, 0xB4A73E,
-            0xD3C038, 0xFDEA45,
-        ])
+        Self::from_hex(&chromatic::CIVIDIS)
     }
 
     /// Blues single-hue sequential
     pub fn blues() -> Self {
-        Self::from_hex(&[
-            0xF7FBFF, 0xDEEBF7, 0xC6DBEF, 0x9ECAE1, 0x6BAED6, 0x4292C6, 0x2171B5, 0x08519C,
-            0x08306B,
-        ])
+        Self::from_hex(&chromatic::sequential_nine(&chromatic::BLUES))
     }
 
     /// Greens single-hue sequential
     pub fn greens() -> Self {
-        Self::from_hex(&[
-            0xF7FCF5, 0xE5F5E0, 0xC7E9C0, 0xA1D99B, 0x74C476, 0x41AB5D, 0x238B45, 0x006D2C,
-            0x00441B,
-        ])
+        Self::from_hex(&chromatic::sequential_nine(&chromatic::GREENS))
     }
 
     /// Oranges single-hue sequential
     pub fn oranges() -> Self {
-        Self::from_hex(&[
-            0xFFF5EB, 0xFEE6CE, 0xFDD0A2, 0xFDAE6B, 0xFD8D3C, 0xF16913, 0xD94801, 0xA63603,
-            0x7F2704,
-        ])
+        Self::from_hex(&chromatic::sequential_nine(&chromatic::ORANGES))
     }
 
     /// Purples single-hue sequential
     pub fn purples() -> Self {
-        Self::from_hex(&[
-            0xFCFBFD, 0xEFEDF5, 0xDADAEB, 0xBCBDDC, 0x9E9AC8, 0x807DBA, 0x6A51A3, 0x54278F,
-            0x3F007D,
-        ])
+        Self::from_hex(&chromatic::sequential_nine(&chromatic::PURPLES))
     }
 
     /// Reds single-hue sequential
     pub fn reds() -> Self {
-        Self::from_hex(&[
-            0xFFF5F0, 0xFEE0D2, 0xFCBBA1, 0xFC9272, 0xFB6A4A, 0xEF3B2C, 0xCB181D, 0xA50F15,
-            0x67000D,
-        ])
+        Self::from_hex(&chromatic::sequential_nine(&chromatic::REDS))
     }
 
     /// Greys single-hue sequential
     pub fn greys() -> Self {
-        Self::from_hex(&[
-            0xFFFFFF, 0xF0F0F0, 0xD9D9D9, 0xBDBDBD, 0x969696, 0x737373, 0x525252, 0x252525,
-            0x000000,
-        ])
+        Self::from_hex(&chromatic::sequential_nine(&chromatic::GREYS))
     }
 
     /// Blue-Green sequential
     pub fn blue_green() -> Self {
-        Self::from_hex(&[
-            0xF7FCFD, 0xE5F5F9, 0xCCECE6, 0x99D8C9, 0x66C2A4, 0x41AE76, 0x238B45, 0x006D2C,
-            0x00441B,
-        ])
+        Self::from_hex(&chromatic::sequential_nine(&chromatic::BUGN))
     }
 
     /// Blue-Purple sequential
     pub fn blue_purple() -> Self {
-        Self::from_hex(&[
-            0xF7FCFD, 0xE0ECF4, 0xBFD3E6, 0x9EBCDA, 0x8C96C6, 0x8C6BB1, 0x88419D, 0x810F7C,
-            0x4D004B,
-        ])
+        Self::from_hex(&chromatic::sequential_nine(&chromatic::BUPU))
     }
 
     /// Yellow-Green sequential
     pub fn yellow_green() -> Self {
-        Self::from_hex(&[
-            0xFFFFE5, 0xF7FCB9, 0xD9F0A3, 0xADDD8E, 0x78C679, 0x41AB5D, 0x238B45, 0x006837,
-            0x004529,
-        ])
+        Self::from_hex(&chromatic::sequential_nine(&chromatic::YLGN))
     }
 
     /// Yellow-Orange-Red sequential (heat)
     pub fn yellow_orange_red() -> Self {
-        Self::from_hex(&[
-            0xFFFFCC, 0xFFEDA0, 0xFED976, 0xFEB24C, 0xFD8D3C, 0xFC4E2A, 0xE31A1C, 0xBD0026,
-            0x800026,
-        ])
+        Self::from_hex(&chromatic::sequential_nine(&chromatic::YLORRD))
     }
 
     /// Warm sequential (brown to yellow)
@@ -247,6 +215,15 @@ impl DivergingScale {
         )
     }
 
+    /// Look up a diverging scheme from the
+    /// [`color_schemes`](super::color_schemes) registry by name
+    /// (e.g. `"RdGy"`)
+    ///
+    /// Returns None for unknown names and non-diverging schemes.
+    pub fn scheme(name: &str) -> Option<Self> {
+        color_scheme(name).and_then(|s| s.diverging_scale())
+    }
+
     // ==================== D3 Diverging Scales ====================
 
     /// Red-White-Blue diverging
@@ -409,6 +386,14 @@ impl CategoricalScale {
         &self.colors
     }
 
+    /// Find color pairs closer than `threshold` (CIEDE2000) under normal
+    /// vision or any simulated color vision deficiency
+    ///
+    /// See [`PaletteAuditor`] for more options.
+    pub fn audit(&self, threshold: f32) -> PaletteAudit {
+        PaletteAuditor::new().threshold(threshold).audit(self)
+    }
+
     // ==================== D3 Categorical Scales ====================
 
     /// D3 Category10 - 10 distinct colors
@@ -4,6 +4,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::oklab::{linear_to_srgb, srgb_to_linear};
+
 /// RGBA color with f32 components (0.0 to 1.0)
 #[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct Rgba {
@@ -48,6 +50,19 @@ impl Rgba {
         }
     }
 
+    /// Create from hex value with alpha (0xRRGGBBAA)
+    ///
+    /// Unlike [`from_hex`](Rgba::from_hex), the alpha byte is always read,
+    /// so colors with a zero red channel such as `0x0000FF80` work.
+    pub fn from_hex_alpha(hex: u32) -> Self {
+        Self {
+            r: ((hex >> 24) & 0xFF) as f32 / 255.0,
+            g: ((hex >> 16) & 0xFF) as f32 / 255.0,
+            b: ((hex >> 8) & 0xFF) as f32 / 255.0,
+            a: (hex & 0xFF) as f32 / 255.0,
+        }
+    }
+
     /// Create from RGB bytes (0-255)
     pub fn from_rgb8(r: u8, g: u8, b: u8) -> Self {
         Self {
@@ -113,6 +128,41 @@ impl Rgba {
         self
     }
 
+    /// Convert the color channels to linear light (alpha unchanged)
+    pub fn to_linear(&self) -> Rgba {
+        Rgba::new(
+            srgb_to_linear(self.r),
+            srgb_to_linear(self.g),
+            srgb_to_linear(self.b),
+            self.a,
+        )
+    }
+
+    /// Convert linear-light channels back to sRGB (alpha unchanged)
+    pub fn from_linear(linear: &Rgba) -> Rgba {
+        Rgba::new(
+            linear_to_srgb(linear.r),
+            linear_to_srgb(linear.g),
+            linear_to_srgb(linear.b),
+            linear.a,
+        )
+    }
+
+    /// Multiply the color channels by alpha
+    pub fn premultiply(&self) -> Rgba {
+        Rgba::new(self.r * self.a, self.g * self.a, self.b * self.a, self.a)
+    }
+
+    /// Divide premultiplied color channels by alpha
+    ///
+    /// Fully transparent colors become [`Rgba::TRANSPARENT`].
+    pub fn unpremultiply(&self) -> Rgba {
+        if self.a < f32::EPSILON {
+            return Rgba::TRANSPARENT;
+        }
+        Rgba::new(self.r / self.a, self.g / self.a, self.b / self.a, self.a)
+    }
+
     /// Common color constants
     pub const BLACK: Rgba = Rgba::rgb(0.0, 0.0, 0.0);
     /// White color
@@ -324,4 +374,21 @@ mod tests {
         assert!((original.g - back.g).abs() < 0.02);
         assert!((original.b - back.b).abs() < 0.02);
     }
+
+    #[test]
+    fn test_linear_roundtrip() {
+        let c = Rgba::new(0.2, 0.5, 0.8, 0.4);
+        let back = Rgba::from_linear(&c.to_linear());
+        assert!((back.r - c.r).abs() < 1e-5);
+        assert!((back.g - c.g).abs() < 1e-5);
+        assert!((back.b - c.b).abs() < 1e-5);
+        assert_eq!(back.a, 0.4);
+        assert!(c.to_linear().g < c.g);
+
+        let p = c.premultiply();
+        assert!((p.r - 0.08).abs() < 1e-6);
+        let u = p.unpremultiply();
+        assert!((u.r - c.r).abs() < 1e-6 && (u.b - c.b).abs() < 1e-6);
+        assert_eq!(Rgba::TRANSPARENT.unpremultiply(), Rgba::TRANSPARENT);
+    }
 }
@@ -0,0 +1,309 @@
+//! Color vision deficiency simulation and palette auditing
+//!
+//! Simulates how colors appear to viewers with dichromatic color vision
+//! using the Machado, Oliveira & Fernandes (2009) model, and audits
+//! categorical palettes for colors that become hard to tell apart.
+//!
+//! # Example
+//!
+//! ```
+//! use makepad_d3::color::{CategoricalScale, ColorVisionDeficiency, Lab, PaletteAuditor, Rgba};
+//!
+//! // Red and green look alike to a deuteranope
+//! let red = ColorVisionDeficiency::Deuteranopia.simulate(&Rgba::from_hex(0xd62728));
+//! let green = ColorVisionDeficiency::Deuteranopia.simulate(&Rgba::from_hex(0x2ca02c));
+//! assert!(Lab::from_rgba(&red).delta_e_2000(&Lab::from_rgba(&green)) < 10.0);
+//!
+//! let audit = PaletteAuditor::new()
+//!     .threshold(10.0)
+//!     .audit(&CategoricalScale::category10());
+//! assert!(!audit.passes());
+//!
+//! // category10's green (2) and red (3) clash for deuteranopes
+//! let clash = audit
+//!     .conflicts_for(Some(ColorVisionDeficiency::Deuteranopia))
+//!     .any(|c| (c.first, c.second) == (2, 3));
+//! assert!(clash);
+//! ```
+
+use super::lab::Lab;
+use super::oklab::{linear_to_srgb, srgb_to_linear};
+use super::scale::CategoricalScale;
+use super::types::Rgba;
+
+/// Type of dichromatic color vision deficiency
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ColorVisionDeficiency {
+    /// Missing long-wavelength (red) cones
+    Protanopia,
+    /// Missing medium-wavelength (green) cones, the most common form
+    Deuteranopia,
+    /// Missing short-wavelength (blue) cones
+    Tritanopia,
+}
+
+impl ColorVisionDeficiency {
+    /// All simulated deficiencies
+    pub const ALL: [ColorVisionDeficiency; 3] = [
+        ColorVisionDeficiency::Protanopia,
+        ColorVisionDeficiency::Deuteranopia,
+        ColorVisionDeficiency::Tritanopia,
+    ];
+
+    /// Get the deficiency name
+    pub fn name(&self) -> &'static str {
+        match self {
+            ColorVisionDeficiency::Protanopia => "protanopia",
+            ColorVisionDeficiency::Deuteranopia => "deuteranopia",
+            ColorVisionDeficiency::Tritanopia => "tritanopia",
+        }
+    }
+
+    /// Simulate how a color appears with this deficiency
+    pub fn simulate(&self, color: &Rgba) -> Rgba {
+        self.simulate_with_severity(color, 1.0)
+    }
+
+    /// Simulate a partial deficiency (anomalous trichromacy)
+    ///
+    /// A severity of 0 returns the color unchanged and 1 is full
+    /// dichromacy. Intermediate severities blend the two in linear light.
+    pub fn simulate_with_severity(&self, color: &Rgba, severity: f32) -> Rgba {
+        let severity = severity.clamp(0.0, 1.0);
+        let m = self.matrix();
+        let rgb = [color.r, color.g, color.b].map(srgb_to_linear);
+        let [r, g, b] = [0, 1, 2].map(|i| {
+            let simulated = m[i][0] * rgb[0] + m[i][1] * rgb[1] + m[i][2] * rgb[2];
+            let mixed = rgb[i] + (simulated - rgb[i]) * severity;
+            linear_to_srgb(mixed.clamp(0.0, 1.0))
+        });
+        Rgba::new(r, g, b, color.a)
+    }
+
+    /// Machado et al. simulation matrix for linear RGB at full severity
+    fn matrix(&self) -> [[f32; 3]; 3] {
+        match self {
+            ColorVisionDeficiency::Protanopia => [
+                [0.152286, 1.052583, -0.204868],
+                [0.114503, 0.786281, 0.099216],
+                [-0.003882, -0.048116, 1.051998],
+            ],
+            ColorVisionDeficiency::Deuteranopia => [
+                [0.367322, 0.860646, -0.227968],
+                [0.280085, 0.672501, 0.047413],
+                [-0.011820, 0.042940, 0.968881],
+            ],
+            ColorVisionDeficiency::Tritanopia => [
+                [1.255528, -0.076749, -0.178779],
+                [-0.078411, 0.930809, 0.147602],
+                [0.004733, 0.691367, 0.303900],
+            ],
+        }
+    }
+}
+
+/// A pair of palette colors that are too similar
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PaletteConflict {
+    /// Vision condition under which the pair conflicts (None = normal vision)
+    pub vision: Option<ColorVisionDeficiency>,
+    /// Index of the first color
+    pub first: usize,
+    /// Index of the second color
+    pub second: usize,
+    /// CIEDE2000 difference between the two colors as seen
+    pub delta_e: f32,
+}
+
+/// Result of auditing a palette
+#[derive(Clone, Debug, PartialEq)]
+pub struct PaletteAudit {
+    /// Minimum acceptable CIEDE2000 difference
+    pub threshold: f32,
+    conflicts: Vec<PaletteConflict>,
+}
+
+impl PaletteAudit {
+    /// Check whether no pair fell below the threshold
+    pub fn passes(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+
+    /// Get all conflicting pairs, closest pairs first
+    pub fn conflicts(&self) -> &[PaletteConflict] {
+        &self.conflicts
+    }
+
+    /// Get the conflicting pairs for one vision condition
+    pub fn conflicts_for(
+        &self,
+        vision: Option<ColorVisionDeficiency>,
+    ) -> impl Iterator<Item = &PaletteConflict> {
+        self.conflicts.iter().filter(move |c| c.vision == vision)
+    }
+}
+
+/// Auditor for categorical palette distinguishability
+///
+/// Compares every pair of colors in a [`CategoricalScale`] under normal
+/// vision and each simulated [`ColorVisionDeficiency`], and reports pairs
+/// whose CIEDE2000 difference drops below the threshold.
+///
+/// # Example
+/// ```
+/// use makepad_d3::color::{CategoricalScale, PaletteAuditor};
+///
+/// let palette = CategoricalScale::from_hex(&[0x000000, 0xffffff, 0x0072b2, 0xe69f00]);
+/// assert!(PaletteAuditor::new().threshold(10.0).audit(&palette).passes());
+/// ```
+#[derive(Clone, Debug)]
+pub struct PaletteAuditor {
+    /// Minimum acceptable CIEDE2000 difference
+    threshold: f32,
+    /// Deficiencies to simulate
+    deficiencies: Vec<ColorVisionDeficiency>,
+    /// Whether to also check normal vision
+    normal_vision: bool,
+}
+
+impl Default for PaletteAuditor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PaletteAuditor {
+    /// Create an auditor checking normal vision and all deficiencies
+    ///
+    /// The default threshold is a CIEDE2000 difference of 10.
+    pub fn new() -> Self {
+        Self {
+            threshold: 10.0,
+            deficiencies: ColorVisionDeficiency::ALL.to_vec(),
+            normal_vision: true,
+        }
+    }
+
+    /// Set the minimum acceptable CIEDE2000 difference
+    pub fn threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold.max(0.0);
+        self
+    }
+
+    /// Set the deficiencies to simulate
+    pub fn deficiencies(mut self, deficiencies: &[ColorVisionDeficiency]) -> Self {
+        self.deficiencies = deficiencies.to_vec();
+        self
+    }
+
+    /// Set whether to also check pairs under normal vision
+    pub fn normal_vision(mut self, check: bool) -> Self {
+        self.normal_vision = check;
+        self
+    }
+
+    /// Audit a categorical palette
+    pub fn audit(&self, scale: &CategoricalScale) -> PaletteAudit {
+        self.audit_colors(scale.colors())
+    }
+
+    /// Audit a list of colors
+    pub fn audit_colors(&self, colors: &[Rgba]) -> PaletteAudit {
+        let normal = self.normal_vision.then_some(None);
+        let visions = normal
+            .into_iter()
+            .chain(self.deficiencies.iter().copied().map(Some));
+
+        let mut conflicts = Vec::new();
+        for vision in visions {
+            let seen: Vec<Lab> = colors
+                .iter()
+                .map(|c| match vision {
+                    Some(deficiency) => Lab::from_rgba(&deficiency.simulate(c)),
+                    None => Lab::from_rgba(c),
+                })
+                .collect();
+            for first in 0..seen.len() {
+                for second in first + 1..seen.len() {
+                    let delta_e = seen[first].delta_e_2000(&seen[second]);
+                    if delta_e < self.threshold {
+                        conflicts.push(PaletteConflict {
+                            vision,
+                            first,
+                            second,
+                            delta_e,
+                        });
+                    }
+                }
+            }
+        }
+        conflicts.sort_by(|a, b| a.delta_e.total_cmp(&b.delta_e));
+
+        PaletteAudit {
+            threshold: self.threshold,
+            conflicts,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simulate_preserves_grays() {
+        for deficiency in ColorVisionDeficiency::ALL {
+            for gray in [Rgba::BLACK, Rgba::WHITE, Rgba::rgb(0.5, 0.5, 0.5)] {
+                let seen = deficiency.simulate(&gray.with_alpha(0.4));
+                assert!((seen.r - gray.r).abs() < 0.01, "{}", deficiency.name());
+                assert!((seen.g - gray.g).abs() < 0.01);
+                assert!((seen.b - gray.b).abs() < 0.01);
+                assert_eq!(seen.a, 0.4);
+            }
+        }
+    }
+
+    #[test]
+    fn test_simulate_severity() {
+        let red = Rgba::from_hex(0xd62728);
+        let full = ColorVisionDeficiency::Protanopia.simulate(&red);
+        let none = ColorVisionDeficiency::Protanopia.simulate_with_severity(&red, 0.0);
+        assert_eq!(none.to_hex(), red.to_hex());
+        assert_ne!(full.to_hex(), red.to_hex());
+        // Protanopes perceive red as a dark olive
+        assert!(full.r < red.r);
+    }
+
+    #[test]
+    fn test_audit_red_green() {
+        let palette = CategoricalScale::from_hex(&[0xd62728, 0x2ca02c]);
+        let audit = PaletteAuditor::new().threshold(10.0).audit(&palette);
+
+        assert!(!audit.passes());
+        assert_eq!(audit.conflicts_for(None).count(), 0);
+        let conflict = audit
+            .conflicts_for(Some(ColorVisionDeficiency::Deuteranopia))
+            .next()
+            .unwrap();
+        assert_eq!((conflict.first, conflict.second), (0, 1));
+        assert!(conflict.delta_e < 10.0);
+        assert!(
+            audit
+                .conflicts_for(Some(ColorVisionDeficiency::Tritanopia))
+                .count()
+                == 0
+        );
+    }
+
+    #[test]
+    fn test_audit_options() {
+        let palette = CategoricalScale::from_hex(&[0x1f77b4, 0x1f77b5]);
+        let audit = PaletteAuditor::new().deficiencies(&[]).audit(&palette);
+        assert_eq!(audit.conflicts().len(), 1);
+        assert_eq!(audit.conflicts()[0].vision, None);
+
+        let audit = PaletteAuditor::new().normal_vision(false).audit(&palette);
+        assert_eq!(audit.conflicts().len(), 3);
+        assert!(audit.conflicts().iter().all(|c| c.vision.is_some()));
+    }
+}
@@ -20,6 +20,7 @@
 //! ```
 
 use crate::color::Rgba;
+use crate::shape::{PathSegment, SymbolGenerator, SymbolScale, SymbolType};
 use serde::{Deserialize, Serialize};
 
 /// Shape of the legend symbol
@@ -38,6 +39,38 @@ pub enum LegendSymbol {
     Triangle,
     /// Diamond symbol
     Diamond,
+    /// Any d3 symbol shape, for matching scatter series markers
+    Symbol(SymbolType),
+}
+
+impl LegendSymbol {
+    /// The d3 symbol used to draw this legend symbol, if it is a marker
+    ///
+    /// Returns `None` for [`LegendSymbol::Line`] and
+    /// [`LegendSymbol::DashedLine`], which are drawn as line swatches.
+    pub fn symbol_type(&self) -> Option<SymbolType> {
+        match self {
+            LegendSymbol::Square => Some(SymbolType::Square),
+            LegendSymbol::Circle => Some(SymbolType::Circle),
+            LegendSymbol::Triangle => Some(SymbolType::Triangle),
+            LegendSymbol::Diamond => Some(SymbolType::Diamond),
+            LegendSymbol::Symbol(symbol) => Some(*symbol),
+            LegendSymbol::Line | LegendSymbol::DashedLine => None,
+        }
+    }
+
+    /// Generate the marker path centered at `(x, y)`
+    ///
+    /// `size` is the symbol's width in pixels (as in
+    /// [`LegendStyle::symbol_size`]); the symbol area is derived from it.
+    pub fn path(&self, x: f64, y: f64, size: f64) -> Option<Vec<PathSegment>> {
+        self.symbol_type().map(|symbol| {
+            SymbolGenerator::new()
+                .symbol_type(symbol)
+                .size(size * size)
+                .generate_at(x, y)
+        })
+    }
 }
 
 /// Orientation of the legend layout
@@ -304,6 +337,21 @@ impl Legend {
         self
     }
 
+    /// Assign item symbols from a symbol scale, in item order
+    ///
+    /// Use the same [`SymbolScale`] as the scatter series so legend markers
+    /// match the plotted points.
+    pub fn symbols(mut self, scale: &SymbolScale) -> Self {
+        for (index, item) in self.items.iter_mut().enumerate() {
+            item.symbol = LegendSymbol::Symbol(
+                scale
+                    .symbol_for(&item.label)
+                    .unwrap_or_else(|| scale.get(index)),
+            );
+        }
+        self
+    }
+
     /// Add a pre-built item
     pub fn add(mut self, item: LegendItem) -> Self {
         self.items.push(item);
@@ -666,6 +714,37 @@ mod tests {
         assert_eq!(item.symbol, LegendSymbol::Square);
     }
 
+    #[test]
+    fn test_legend_symbols_from_scale() {
+        let scale = SymbolScale::fill().with_domain(["B", "A"]);
+        let legend = Legend::new()
+            .add_item("A", Rgba::RED)
+            .add_item("B", Rgba::GREEN)
+            .add_item("C", Rgba::BLUE)
+            .symbols(&scale);
+
+        assert_eq!(
+            legend.items[0].symbol,
+            LegendSymbol::Symbol(SymbolType::Cross)
+        );
+        assert_eq!(
+            legend.items[1].symbol,
+            LegendSymbol::Symbol(SymbolType::Circle)
+        );
+        assert_eq!(
+            legend.items[2].symbol,
+            LegendSymbol::Symbol(SymbolType::Diamond)
+        );
+
+        assert_eq!(
+            LegendSymbol::Diamond.symbol_type(),
+            Some(SymbolType::Diamond)
+        );
+        assert!(LegendSymbol::Line.path(0.0, 0.0, 12.0).is_none());
+        let square = LegendSymbol::Square.path(10.0, 10.0, 12.0).unwrap();
+        assert_eq!(square[0], PathSegment::move_to(4.0, 4.0));
+    }
+
     #[test]
     fn test_legend_item_toggle() {
         let mut item = LegendItem::new("Test", Rgba::RED);
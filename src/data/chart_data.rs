@@ -1,6 +1,6 @@
 //! Chart data container
 
-use super::{DataPoint, Dataset};
+use super::{ColorAssignment, DataPoint, Dataset};
 use crate::error::D3Error;
 use serde::{Deserialize, Serialize};
 
@@ -48,6 +48,17 @@ impl ChartData {
         self
     }
 
+    /// Assign series colors with the given strategy
+    pub fn with_color_assignment(mut self, assignment: &ColorAssignment) -> Self {
+        self.assign_colors(assignment);
+        self
+    }
+
+    /// Assign series colors to all datasets with the given strategy
+    pub fn assign_colors(&mut self, assignment: &ColorAssignment) {
+        assignment.assign(&mut self.datasets);
+    }
+
     /// Get Y extent across all visible datasets
     pub fn y_extent(&self) -> Option<(f64, f64)> {
         let mut min = f64::MAX;
@@ -0,0 +1,183 @@
+//! Automatic dataset color assignment
+
+use super::Dataset;
+use crate::color::{delta_e_2000, CategoricalScale, ColorScheme, Rgba};
+
+/// Strategy for assigning series colors to datasets
+///
+/// Colors come from a categorical palette (category10 by default) in
+/// dataset order. When a background is set, palette colors closer to it
+/// than the minimum CIEDE2000 difference are skipped, so a white or
+/// near-black series never disappears into the plot background.
+///
+/// # Example
+/// ```
+/// use makepad_d3::color::{color_scheme, Rgba};
+/// use makepad_d3::data::{ChartData, ColorAssignment, Dataset};
+///
+/// let assignment = ColorAssignment::new()
+///     .with_scheme(color_scheme("Pastel1").unwrap())
+///     .with_background(Rgba::WHITE);
+///
+/// let data = ChartData::new()
+///     .add_dataset(Dataset::new("A").with_data(vec![1.0, 2.0]))
+///     .add_dataset(Dataset::new("B").with_data(vec![3.0, 4.0]))
+///     .with_color_assignment(&assignment);
+/// assert!(data.datasets[1].background_color.is_some());
+/// ```
+#[derive(Clone, Debug)]
+pub struct ColorAssignment {
+    /// Palette to draw colors from
+    pub palette: CategoricalScale,
+    /// Plot background to keep series colors away from
+    pub background: Option<Rgba>,
+    /// Minimum CIEDE2000 difference from the background
+    pub min_distance: f32,
+    /// Whether to replace colors that were set explicitly
+    pub overwrite: bool,
+}
+
+impl Default for ColorAssignment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ColorAssignment {
+    /// Create an assignment drawing from category10
+    ///
+    /// The default minimum distance from the background is a CIEDE2000
+    /// difference of 10.
+    pub fn new() -> Self {
+        Self {
+            palette: CategoricalScale::category10(),
+            background: None,
+            min_distance: 10.0,
+            overwrite: false,
+        }
+    }
+
+    /// Set the palette
+    pub fn with_palette(mut self, palette: CategoricalScale) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// Draw colors from a registry scheme
+    ///
+    /// Categorical schemes are used as-is; continuous schemes contribute
+    /// their discrete array at the largest available class count.
+    pub fn with_scheme(mut self, scheme: &ColorScheme) -> Self {
+        let n = *scheme.classes().end();
+        self.palette = scheme.categorical_scale(n);
+        self
+    }
+
+    /// Set the background that series colors must stand out from
+    pub fn with_background(mut self, background: Rgba) -> Self {
+        self.background = Some(background);
+        self
+    }
+
+    /// Set the minimum CIEDE2000 difference from the background
+    pub fn with_min_distance(mut self, delta_e: f32) -> Self {
+        self.min_distance = delta_e.max(0.0);
+        self
+    }
+
+    /// Set whether explicitly set dataset colors are replaced
+    pub fn with_overwrite(mut self, overwrite: bool) -> Self {
+        self.overwrite = overwrite;
+        self
+    }
+
+    /// Get the palette colors that stand out from the background
+    ///
+    /// Falls back to the whole palette if every color is too close.
+    pub fn usable_colors(&self) -> Vec<Rgba> {
+        let colors = self.palette.colors();
+        let Some(background) = self.background else {
+            return colors.to_vec();
+        };
+        let usable: Vec<Rgba> = colors
+            .iter()
+            .copied()
+            .filter(|c| delta_e_2000(c, &background) >= self.min_distance)
+            .collect();
+        if usable.is_empty() {
+            colors.to_vec()
+        } else {
+            usable
+        }
+    }
+
+    /// Get the color for the dataset at `index`, cycling through the
+    /// usable colors
+    pub fn color(&self, index: usize) -> Rgba {
+        let colors = self.usable_colors();
+        colors[index % colors.len()]
+    }
+
+    /// Assign colors to datasets by position
+    ///
+    /// Fills the background and border colors of each dataset, leaving
+    /// explicitly set colors alone unless overwrite is enabled.
+    pub fn assign(&self, datasets: &mut [Dataset]) {
+        let colors = self.usable_colors();
+        for (i, dataset) in datasets.iter_mut().enumerate() {
+            let color = colors[i % colors.len()];
+            if self.overwrite || dataset.background_color.is_none() {
+                dataset.background_color = Some(color);
+            }
+            if self.overwrite || dataset.border_color.is_none() {
+                dataset.border_color = Some(color);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_assign_in_order() {
+        let mut datasets = vec![
+            Dataset::new("A"),
+            Dataset::new("B").with_hex_color(0x123456),
+        ];
+        ColorAssignment::new().assign(&mut datasets);
+
+        let palette = CategoricalScale::category10();
+        assert_eq!(datasets[0].background_color, Some(palette.get(0)));
+        assert_eq!(datasets[0].border_color, Some(palette.get(0)));
+        // Explicit colors are kept
+        assert_eq!(datasets[1].background_color.unwrap().to_hex(), 0x123456);
+        assert_eq!(datasets[1].border_color, Some(palette.get(1)));
+
+        ColorAssignment::new()
+            .with_overwrite(true)
+            .assign(&mut datasets);
+        assert_eq!(datasets[1].background_color, Some(palette.get(1)));
+    }
+
+    #[test]
+    fn test_skips_background() {
+        let palette = CategoricalScale::from_hex(&[0xffffff, 0x1f77b4, 0xfafafa, 0xd62728]);
+        let assignment = ColorAssignment::new()
+            .with_palette(palette)
+            .with_background(Rgba::WHITE);
+
+        let colors = assignment.usable_colors();
+        assert_eq!(colors.len(), 2);
+        assert_eq!(assignment.color(0).to_hex(), 0x1f77b4);
+        assert_eq!(assignment.color(1).to_hex(), 0xd62728);
+        assert_eq!(assignment.color(2).to_hex(), 0x1f77b4);
+
+        // Everything too close: fall back to the full palette
+        let gray = ColorAssignment::new()
+            .with_palette(CategoricalScale::from_hex(&[0xfefefe]))
+            .with_background(Rgba::WHITE);
+        assert_eq!(gray.usable_colors().len(), 1);
+    }
+}
@@ -1,6 +1,8 @@
 //! Dataset representation
 
 use super::DataPoint;
+use crate::color::Rgba;
+use crate::shape::SymbolType;
 use serde::{Deserialize, Serialize};
 
 /// Point marker styles for scatter/line charts
@@ -16,82 +18,27 @@ pub enum PointStyle {
     None,
 }
 
-/// RGBA color representation for serialization
-#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
-pub struct Color {
-    pub r: f32,
-    pub g: f32,
-    pub b: f32,
-    pub a: f32,
-}
-
-impl Color {
-    /// Create a new color from RGBA values (0.0 - 1.0)
-    pub fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
-        Self { r, g, b, a }
-    }
-
-    /// Create a color from RGB values with full opacity
-    pub fn rgb(r: f32, g: f32, b: f32) -> Self {
-        Self { r, g, b, a: 1.0 }
-    }
-
-    /// Create a color from hex value (0xRRGGBB)
-    pub fn from_hex(hex: u32) -> Self {
-        let r = ((hex >> 16) & 0xFF) as f32 / 255.0;
-        let g = ((hex >> 8) & 0xFF) as f32 / 255.0;
-        let b = (hex & 0xFF) as f32 / 255.0;
-        Self { r, g, b, a: 1.0 }
-    }
-
-    /// Create a color from hex value with alpha (0xRRGGBBAA)
-    pub fn from_hex_alpha(hex: u32) -> Self {
-        let r = ((hex >> 24) & 0xFF) as f32 / 255.0;
-        let g = ((hex >> 16) & 0xFF) as f32 / 255.0;
-        let b = ((hex >> 8) & 0xFF) as f32 / 255.0;
-        let a = (hex & 0xFF) as f32 / 255.0;
-        Self { r, g, b, a }
+impl PointStyle {
+    /// The d3 symbol used to draw this marker, or `None` for no marker
+    pub fn symbol_type(&self) -> Option<SymbolType> {
+        match self {
+            PointStyle::Circle => Some(SymbolType::Circle),
+            PointStyle::Square => Some(SymbolType::Square),
+            PointStyle::Triangle => Some(SymbolType::Triangle),
+            PointStyle::Diamond => Some(SymbolType::Diamond),
+            PointStyle::Cross => Some(SymbolType::Cross),
+            PointStyle::Star => Some(SymbolType::Star),
+            PointStyle::None => None,
+        }
     }
-
-    /// Standard colors
-    pub const RED: Color = Color {
-        r: 1.0,
-        g: 0.0,
-        b: 0.0,
-        a: 1.0,
-    };
-    pub const GREEN: Color = Color {
-        r: 0.0,
-        g: 1.0,
-        b: 0.0,
-        a: 1.0,
-    };
-    pub const BLUE: Color = Color {
-        r: 0.0,
-        g: 0.0,
-        b: 1.0,
-        a: 1.0,
-    };
-    pub const BLACK: Color = Color {
-        r: 0.0,
-        g: 0.0,
-        b: 0.0,
-        a: 1.0,
-    };
-    pub const WHITE: Color = Color {
-        r: 1.0,
-        g: 1.0,
-        b: 1.0,
-        a: 1.0,
-    };
-    pub const TRANSPARENT: Color = Color {
-        r: 0.0,
-        g: 0.0,
-        b: 0.0,
-        a: 0.0,
-    };
 }
 
+/// Color type used by datasets
+///
+/// This is [`Rgba`], so dataset colors work directly with color scales,
+/// blending and the perceptual color spaces in [`crate::color`].
+pub type Color = Rgba;
+
 /// A dataset containing multiple data points with styling
 ///
 /// # Example
@@ -356,6 +303,7 @@ mod tests {
     #[test]
     fn test_color_from_hex() {
         let c = Color::from_hex(0xFF0000);
+        assert_eq!(c, Rgba::RED);
         assert!((c.r - 1.0).abs() < 0.01);
         assert!((c.g - 0.0).abs() < 0.01);
         assert!((c.b - 0.0).abs() < 0.01);
@@ -364,6 +312,10 @@ mod tests {
         assert!((c2.r - 0.259).abs() < 0.01);
         assert!((c2.g - 0.522).abs() < 0.01);
         assert!((c2.b - 0.957).abs() < 0.01);
+
+        let c3 = Color::from_hex_alpha(0x0000FF80);
+        assert_eq!(c3.to_hex(), 0x0000FF);
+        assert!((c3.a - 0.502).abs() < 0.01);
     }
 
     #[test]
@@ -2,6 +2,7 @@
 //!
 //! This module provides:
 //! - Core data structures ([`DataPoint`], [`Dataset`], [`ChartData`])
+//! - Automatic series colors ([`ColorAssignment`])
 //! - Dynamic data sources ([`DataSource`], [`BufferedDataSource`], [`StreamingDataSource`])
 //! - Observable datasets with change tracking ([`ObservableDataset`])
 //! - Data transformation pipelines ([`DataPipeline`])
@@ -45,6 +46,7 @@
 //! ```
 
 mod chart_data;
+mod color_assignment;
 mod dataset;
 mod observable;
 mod pipeline;
@@ -55,6 +57,7 @@ mod streaming;
 
 // Core data structures
 pub use chart_data::ChartData;
+pub use color_assignment::ColorAssignment;
 pub use dataset::{Color, Dataset, PointStyle};
 pub use point::DataPoint;
 
@@ -1,7 +1,8 @@
 //! Spherical area calculation
 //!
 //! Computes the spherical area of GeoJSON geometries in steradians.
-//! This is similar to D3's `d3.geoArea()`.
+//! This is similar to D3's `d3.geoArea()`. Also provides great-circle
+//! lengths, distances and interpolation.
 //!
 //! # Limitations
 //!
@@ -169,6 +170,70 @@ fn line_length(coordinates: &[Position]) -> f64 {
     length
 }
 
+/// Calculate the great-circle distance between two points in radians.
+///
+/// Multiply by Earth's radius (about 6371 km) for a distance on Earth.
+/// Equivalent to D3's `d3.geoDistance()`.
+///
+/// # Example
+///
+/// ```
+/// use makepad_d3::geo::geo_distance;
+///
+/// // A quarter of the way around the equator
+/// let d = geo_distance([0.0, 0.0], [90.0, 0.0]);
+/// assert!((d - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+/// ```
+pub fn geo_distance(a: Position, b: Position) -> f64 {
+    haversine_distance(a[0], a[1], b[0], b[1])
+}
+
+/// Create an interpolator along the great circle between two points.
+///
+/// The returned function maps t in [0, 1] to a (longitude, latitude)
+/// position in degrees, moving at constant speed from `a` to `b`. The path
+/// between antipodal points is undefined. Equivalent to D3's
+/// `d3.geoInterpolate()`.
+///
+/// # Example
+///
+/// ```
+/// use makepad_d3::geo::geo_interpolate;
+///
+/// // Flight route from San Francisco to Tokyo
+/// let route = geo_interpolate([-122.4, 37.8], [139.7, 35.7]);
+/// let path: Vec<[f64; 2]> = (0..=32).map(|i| route(i as f64 / 32.0)).collect();
+///
+/// // The great circle arcs north over the Pacific
+/// assert!(path[16][1] > 45.0);
+/// ```
+pub fn geo_interpolate(a: Position, b: Position) -> impl Fn(f64) -> Position + Send + Sync {
+    let (lambda0, phi0) = (a[0].to_radians(), a[1].to_radians());
+    let (lambda1, phi1) = (b[0].to_radians(), b[1].to_radians());
+    let (cy0, sy0) = (phi0.cos(), phi0.sin());
+    let (cy1, sy1) = (phi1.cos(), phi1.sin());
+    let (kx0, ky0) = (cy0 * lambda0.cos(), cy0 * lambda0.sin());
+    let (kx1, ky1) = (cy1 * lambda1.cos(), cy1 * lambda1.sin());
+    let d = haversine_distance(a[0], a[1], b[0], b[1]);
+    let k = d.sin();
+
+    move |t: f64| {
+        if d == 0.0 {
+            return a;
+        }
+        let t = t * d;
+        let wb = t.sin() / k;
+        let wa = (d - t).sin() / k;
+        let x = wa * kx0 + wb * kx1;
+        let y = wa * ky0 + wb * ky1;
+        let z = wa * sy0 + wb * sy1;
+        [
+            y.atan2(x).to_degrees(),
+            z.atan2((x * x + y * y).sqrt()).to_degrees(),
+        ]
+    }
+}
+
 /// Calculate the great-circle distance between two points using the Haversine formula.
 /// Returns distance in radians.
 fn haversine_distance(lon1: f64, lat1: f64, lon2: f64, lat2: f64) -> f64 {
@@ -498,6 +563,39 @@ mod tests {
         assert!(length < 0.8);
     }
 
+    #[test]
+    fn test_geo_distance() {
+        assert_eq!(geo_distance([10.0, 20.0], [10.0, 20.0]), 0.0);
+        assert!((geo_distance([0.0, -90.0], [0.0, 90.0]) - PI).abs() < 1e-12);
+        // Across the antimeridian is short
+        let d = geo_distance([179.0, 0.0], [-179.0, 0.0]);
+        assert!((d - 2f64.to_radians()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_geo_interpolate() {
+        let a = [-10.0, 0.0];
+        let b = [50.0, 40.0];
+        let f = geo_interpolate(a, b);
+        let start = f(0.0);
+        let end = f(1.0);
+        assert!((start[0] - a[0]).abs() < 1e-9 && (start[1] - a[1]).abs() < 1e-9);
+        assert!((end[0] - b[0]).abs() < 1e-9 && (end[1] - b[1]).abs() < 1e-9);
+
+        // Constant speed along the great circle
+        let mid = f(0.5);
+        let d = geo_distance(a, b);
+        assert!((geo_distance(a, mid) - d / 2.0).abs() < 1e-12);
+        assert!((geo_distance(mid, b) - d / 2.0).abs() < 1e-12);
+
+        // Meridians are great circles
+        let f = geo_interpolate([30.0, 0.0], [30.0, 60.0]);
+        let p = f(0.25);
+        assert!((p[0] - 30.0).abs() < 1e-9 && (p[1] - 15.0).abs() < 1e-9);
+
+        assert_eq!(geo_interpolate(a, a)(0.7), a);
+    }
+
     #[test]
     fn test_haversine_distance() {
         // 90 degrees along equator
@@ -0,0 +1,1073 @@
+//! Spherical clipping
+//!
+//! Port of d3-geo's stream clipping. Lines and polygons are clipped on the
+//! sphere before projection, so nothing smears across a map or shows through
+//! from the back of a globe:
+//!
+//! - [`Preclip::Antimeridian`] cuts geometry along ±180° longitude, for
+//!   cylindrical and conic projections
+//! - [`Preclip::Circle`] keeps geometry within a small circle around the
+//!   origin, for azimuthal projections with a clip angle
+//!
+//! Cut polygon rings are rejoined along the clip edge, so the result is made
+//! of closed rings. A polygon containing a pole becomes a ring that follows
+//! the clip edge around the pole.
+//!
+//! Coordinates are in degrees, in the clip frame of the projection (see
+//! [`Projection::to_clip_frame`](super::Projection::to_clip_frame)).
+//!
+//! # Winding order
+//!
+//! d3-geo reads clockwise exterior rings as the enclosed area, and
+//! counter-clockwise ones as everything but that area. GeoJSON written to
+//! RFC 7946 uses the opposite order. To handle both, polygons are rewound so
+//! that each exterior ring encloses less than a hemisphere and each hole is
+//! wound opposite to its exterior.
+//!
+//! # Example
+//!
+//! ```
+//! use makepad_d3::geo::Preclip;
+//!
+//! // A strip crossing the antimeridian is cut in two
+//! let ring = vec![
+//!     [170.0, -10.0],
+//!     [170.0, 10.0],
+//!     [-170.0, 10.0],
+//!     [-170.0, -10.0],
+//!     [170.0, -10.0],
+//! ];
+//! let rings = Preclip::Antimeridian.clip_polygon(&[ring]);
+//! assert_eq!(rings.len(), 2);
+//! ```
+
+use super::geojson::Position;
+use std::cmp::Ordering;
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI, TAU};
+
+const EPSILON: f64 = 1e-6;
+const EPSILON2: f64 = 1e-12;
+
+/// Step between points interpolated along a clip circle
+const CIRCLE_STEP: f64 = 6.0 * PI / 180.0;
+
+/// Spherical clipping applied before projection
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Preclip {
+    /// No clipping
+    None,
+    /// Cut along the antimeridian (±180° longitude)
+    #[default]
+    Antimeridian,
+    /// Keep geometry within this angle (degrees) of the origin
+    Circle(f64),
+}
+
+impl Preclip {
+    /// Check whether a point survives clipping
+    pub fn is_visible(&self, lon: f64, lat: f64) -> bool {
+        match self {
+            Preclip::None | Preclip::Antimeridian => true,
+            Preclip::Circle(angle) => {
+                let (lambda, phi) = (lon.to_radians(), lat.to_radians());
+                lambda.cos() * phi.cos() > angle.to_radians().cos()
+            }
+        }
+    }
+
+    /// Clip a line string, returning the visible pieces
+    pub fn clip_line(&self, coordinates: &[Position]) -> Vec<Vec<Position>> {
+        let Some(edge) = self.edge() else {
+            return vec![coordinates.to_vec()];
+        };
+        let mut buffer = LineBuffer::default();
+        let mut line = LineClip::new(&edge);
+        line.line_start(&mut buffer);
+        for c in coordinates {
+            line.point(to_radians(c), &mut buffer);
+        }
+        line.line_end();
+
+        buffer
+            .lines
+            .into_iter()
+            .filter(|l| l.len() > 1)
+            .map(|l| l.iter().map(|p| to_degrees(&p.0)).collect())
+            .collect()
+    }
+
+    /// Clip a polygon (exterior ring then holes), returning closed rings
+    ///
+    /// The rings of the result are not grouped by polygon; draw them
+    /// together with a non-zero or even-odd fill.
+    pub fn clip_polygon(&self, rings: &[Vec<Position>]) -> Vec<Vec<Position>> {
+        let rings: Vec<Vec<[f64; 2]>> = rewind(rings);
+        let mut output = Vec::new();
+
+        match self.edge() {
+            None => output = rings,
+            Some(edge) => clip_rings(&edge, &rings, &mut output),
+        }
+
+        output
+            .into_iter()
+            .filter(|ring| !ring.is_empty())
+            .map(|ring| {
+                let mut ring: Vec<Position> = ring.iter().map(to_degrees).collect();
+                ring.push(ring[0]);
+                ring
+            })
+            .collect()
+    }
+
+    fn edge(&self) -> Option<Edge> {
+        match *self {
+            Preclip::None => None,
+            Preclip::Antimeridian => Some(Edge::Antimeridian),
+            Preclip::Circle(angle) => {
+                let radius = angle.to_radians();
+                let cr = radius.cos();
+                Some(Edge::Circle {
+                    radius,
+                    cr,
+                    small_radius: cr > 0.0,
+                    not_hemisphere: cr.abs() > EPSILON,
+                })
+            }
+        }
+    }
+}
+
+/// Wrap a longitude in degrees into [-180, 180]
+///
+/// Longitudes already in range, including ±180 itself, are unchanged.
+pub(crate) fn wrap_longitude(lon: f64) -> f64 {
+    if lon.abs() <= 180.0 {
+        lon
+    } else {
+        (lon + 180.0).rem_euclid(360.0) - 180.0
+    }
+}
+
+fn to_radians(p: &Position) -> [f64; 2] {
+    [p[0].to_radians(), p[1].to_radians()]
+}
+
+fn to_degrees(p: &[f64; 2]) -> Position {
+    [p[0].to_degrees(), p[1].to_degrees()]
+}
+
+fn cartesian(p: [f64; 2]) -> [f64; 3] {
+    let cos_phi = p[1].cos();
+    [cos_phi * p[0].cos(), cos_phi * p[0].sin(), p[1].sin()]
+}
+
+fn spherical(v: [f64; 3]) -> [f64; 2] {
+    [v[1].atan2(v[0]), v[2].clamp(-1.0, 1.0).asin()]
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn scale(a: [f64; 3], k: f64) -> [f64; 3] {
+    [a[0] * k, a[1] * k, a[2] * k]
+}
+
+fn add(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn normalize(a: [f64; 3]) -> [f64; 3] {
+    let l = dot(a, a).sqrt();
+    if l > 0.0 {
+        scale(a, 1.0 / l)
+    } else {
+        a
+    }
+}
+
+fn point_equal(a: [f64; 2], b: [f64; 2]) -> bool {
+    (a[0] - b[0]).abs() < EPSILON && (a[1] - b[1]).abs() < EPSILON
+}
+
+/// Signed spherical area term of a ring, as accumulated by d3's geoArea
+///
+/// The ring's area is twice this, plus 4π when negative.
+fn ring_area_sum(ring: &[[f64; 2]]) -> f64 {
+    let Some(&last) = ring.last() else {
+        return 0.0;
+    };
+    let mut lambda0 = last[0];
+    let phi0 = last[1] / 2.0 + FRAC_PI_4;
+    let (mut sin_phi0, mut cos_phi0) = phi0.sin_cos();
+    let mut sum = 0.0;
+    for p in ring {
+        let phi = p[1] / 2.0 + FRAC_PI_4;
+        let (sin_phi, cos_phi) = phi.sin_cos();
+        let d_lambda = p[0] - lambda0;
+        let sign = if d_lambda >= 0.0 { 1.0 } else { -1.0 };
+        let ad_lambda = sign * d_lambda;
+        let k = sin_phi0 * sin_phi;
+        let u = cos_phi0 * cos_phi + k * ad_lambda.cos();
+        let v = k * sign * ad_lambda.sin();
+        sum += v.atan2(u);
+        lambda0 = p[0];
+        sin_phi0 = sin_phi;
+        cos_phi0 = cos_phi;
+    }
+    sum
+}
+
+/// Convert rings to open radian rings wound the way d3-geo expects
+fn rewind(rings: &[Vec<Position>]) -> Vec<Vec<[f64; 2]>> {
+    rings
+        .iter()
+        .enumerate()
+        .filter_map(|(i, ring)| {
+            let mut ring: Vec<[f64; 2]> = ring.iter().map(to_radians).collect();
+            if ring.len() > 1 && point_equal(ring[0], ring[ring.len() - 1]) {
+                ring.pop();
+            }
+            if ring.is_empty() {
+                return None;
+            }
+            let sum = ring_area_sum(&ring);
+            let area = if sum < 0.0 { TAU + sum } else { sum };
+            // Exterior rings cover less than a hemisphere, holes more
+            if (i == 0) == (area > PI) {
+                ring.reverse();
+            }
+            Some(ring)
+        })
+        .collect()
+}
+
+/// Whether `point` is inside a polygon of open rings (d3's polygonContains)
+fn polygon_contains(polygon: &[Vec<[f64; 2]>], point: [f64; 2]) -> bool {
+    fn longitude(p: [f64; 2]) -> f64 {
+        if p[0].abs() <= PI {
+            p[0]
+        } else {
+            p[0].signum() * ((p[0].abs() + PI) % TAU - PI)
+        }
+    }
+
+    let lambda = longitude(point);
+    let mut phi = point[1];
+    let sin_phi = phi.sin();
+    let normal = [lambda.sin(), -lambda.cos(), 0.0];
+    let mut angle = 0.0;
+    let mut winding = 0i32;
+    let mut sum = 0.0;
+
+    if sin_phi == 1.0 {
+        phi = FRAC_PI_2 + EPSILON;
+    } else if sin_phi == -1.0 {
+        phi = -FRAC_PI_2 - EPSILON;
+    }
+
+    for ring in polygon {
+        let Some(&last) = ring.last() else {
+            continue;
+        };
+        let mut point0 = last;
+        let mut lambda0 = longitude(point0);
+        let (mut sin_phi0, mut cos_phi0) = (point0[1] / 2.0 + FRAC_PI_4).sin_cos();
+
+        for &point1 in ring {
+            let lambda1 = longitude(point1);
+            let (sin_phi1, cos_phi1) = (point1[1] / 2.0 + FRAC_PI_4).sin_cos();
+            let delta = lambda1 - lambda0;
+            let sign = if delta >= 0.0 { 1.0 } else { -1.0 };
+            let abs_delta = sign * delta;
+            let antimeridian = abs_delta > PI;
+            let k = sin_phi0 * sin_phi1;
+
+            sum += (k * sign * abs_delta.sin()).atan2(cos_phi0 * cos_phi1 + k * abs_delta.cos());
+            angle += if antimeridian {
+                delta + sign * TAU
+            } else {
+                delta
+            };
+
+            // Does the edge straddle the point's meridian below its parallel?
+            if antimeridian ^ (lambda0 >= lambda) ^ (lambda1 >= lambda) {
+                let arc = normalize(cross(cartesian(point0), cartesian(point1)));
+                let intersection = normalize(cross(normal, arc));
+                let flip = antimeridian ^ (delta >= 0.0);
+                let phi_arc = if flip { -1.0 } else { 1.0 } * intersection[2].asin();
+                if phi > phi_arc || (phi == phi_arc && (arc[0] != 0.0 || arc[1] != 0.0)) {
+                    winding += if flip { 1 } else { -1 };
+                }
+            }
+
+            lambda0 = lambda1;
+            sin_phi0 = sin_phi1;
+            cos_phi0 = cos_phi1;
+            point0 = point1;
+        }
+    }
+
+    (angle < -EPSILON || (angle < EPSILON && sum < -EPSILON2)) ^ (winding & 1 != 0)
+}
+
+/// A point emitted by a line clipper; the flag marks clip-edge exits
+type ClipPoint = ([f64; 2], bool);
+
+/// Collects the pieces of a clipped line
+#[derive(Default)]
+struct LineBuffer {
+    lines: Vec<Vec<ClipPoint>>,
+}
+
+impl LineBuffer {
+    fn line_start(&mut self) {
+        self.lines.push(Vec::new());
+    }
+
+    fn point(&mut self, p: [f64; 2], flagged: bool) {
+        if let Some(line) = self.lines.last_mut() {
+            line.push((p, flagged));
+        }
+    }
+}
+
+/// The clip edge on the sphere
+enum Edge {
+    Antimeridian,
+    Circle {
+        radius: f64,
+        cr: f64,
+        small_radius: bool,
+        not_hemisphere: bool,
+    },
+}
+
+impl Edge {
+    /// A point known to be inside or outside, used to decide whether a
+    /// polygon without intersections covers the whole clip region
+    fn start(&self) -> [f64; 2] {
+        match *self {
+            Edge::Antimeridian => [-PI, -FRAC_PI_2],
+            Edge::Circle {
+                radius,
+                small_radius,
+                ..
+            } => {
+                if small_radius {
+                    [0.0, -radius]
+                } else {
+                    [-PI, radius - PI]
+                }
+            }
+        }
+    }
+
+    fn visible(&self, p: [f64; 2]) -> bool {
+        match *self {
+            Edge::Antimeridian => true,
+            Edge::Circle { cr, .. } => p[0].cos() * p[1].cos() > cr,
+        }
+    }
+
+    /// Append points following the clip edge from `from` to `to`, or the
+    /// whole edge when `None`
+    fn interpolate(
+        &self,
+        span: Option<([f64; 2], [f64; 2])>,
+        direction: f64,
+        out: &mut Vec<[f64; 2]>,
+    ) {
+        match *self {
+            Edge::Antimeridian => match span {
+                None => {
+                    let phi = direction * FRAC_PI_2;
+                    out.extend_from_slice(&[
+                        [-PI, phi],
+                        [0.0, phi],
+                        [PI, phi],
+                        [PI, 0.0],
+                        [PI, -phi],
+                        [0.0, -phi],
+                        [-PI, -phi],
+                        [-PI, 0.0],
+                        [-PI, phi],
+                    ]);
+                }
+                Some((from, to)) => {
+                    if (from[0] - to[0]).abs() > EPSILON {
+                        let lambda = if from[0] < to[0] { PI } else { -PI };
+                        let phi = direction * lambda / 2.0;
+                        out.extend_from_slice(&[[-lambda, phi], [0.0, phi], [lambda, phi]]);
+                    } else {
+                        out.push(to);
+                    }
+                }
+            },
+            Edge::Circle { radius, .. } => circle_arc(radius, direction, span, out),
+        }
+    }
+
+    /// Bounding-box code of a point relative to the clip circle
+    fn code(&self, p: [f64; 2]) -> u8 {
+        let Edge::Circle {
+            radius,
+            small_radius,
+            ..
+        } = *self
+        else {
+            return 0;
+        };
+        let r = if small_radius { radius } else { PI - radius };
+        let mut code = 0;
+        if p[0] < -r {
+            code |= 1;
+        } else if p[0] > r {
+            code |= 2;
+        }
+        if p[1] < -r {
+            code |= 4;
+        } else if p[1] > r {
+            code |= 8;
+        }
+        code
+    }
+
+    /// Intersect the clip plane with the plane of the great circle
+    /// through `a` and `b`
+    fn circle_line(cr: f64, a: [f64; 2], b: [f64; 2]) -> CircleLine {
+        let n1 = [1.0, 0.0, 0.0];
+        let n2 = cross(cartesian(a), cartesian(b));
+        let n2n2 = dot(n2, n2);
+        let n1n2 = n2[0];
+        let determinant = n2n2 - n1n2 * n1n2;
+
+        // Two polar points
+        if determinant == 0.0 {
+            return CircleLine::Polar;
+        }
+
+        let c1 = cr * n2n2 / determinant;
+        let c2 = -cr * n1n2 / determinant;
+        let u = cross(n1, n2);
+        let origin = add(scale(n1, c1), scale(n2, c2));
+
+        // Solve |p(t)|² = 1
+        let w = dot(origin, u);
+        let uu = dot(u, u);
+        let t2 = w * w - uu * (dot(origin, origin) - 1.0);
+        if t2 < 0.0 {
+            return CircleLine::Miss;
+        }
+        let t = t2.sqrt();
+        CircleLine::Cross(
+            spherical(add(scale(u, (-w - t) / uu), origin)),
+            spherical(add(scale(u, (-w + t) / uu), origin)),
+        )
+    }
+
+    /// First intersection of the segment from `a` to `b` with the clip circle
+    fn intersect(cr: f64, a: [f64; 2], b: [f64; 2]) -> Option<[f64; 2]> {
+        match Self::circle_line(cr, a, b) {
+            CircleLine::Polar => Some(a),
+            CircleLine::Miss => None,
+            CircleLine::Cross(q, _) => Some(q),
+        }
+    }
+
+    /// Both intersections of the segment from `a` to `b` with the clip
+    /// circle, if the segment crosses it twice
+    fn intersect_two(cr: f64, a: [f64; 2], b: [f64; 2]) -> Option<([f64; 2], [f64; 2])> {
+        let CircleLine::Cross(q, q1) = Self::circle_line(cr, a, b) else {
+            return None;
+        };
+
+        let (mut lambda0, mut lambda1) = (a[0], b[0]);
+        let (mut phi0, mut phi1) = (a[1], b[1]);
+        if lambda1 < lambda0 {
+            std::mem::swap(&mut lambda0, &mut lambda1);
+        }
+        let delta = lambda1 - lambda0;
+        let polar = (delta - PI).abs() < EPSILON;
+        let meridian = polar || delta < EPSILON;
+        if !polar && phi1 < phi0 {
+            std::mem::swap(&mut phi0, &mut phi1);
+        }
+
+        // Check that the first point is between a and b
+        let between = if meridian {
+            if polar {
+                let limit = if (q[0] - lambda0).abs() < EPSILON {
+                    phi0
+                } else {
+                    phi1
+                };
+                (phi0 + phi1 > 0.0) ^ (q[1] < limit)
+            } else {
+                phi0 <= q[1] && q[1] <= phi1
+            }
+        } else {
+            (delta > PI) ^ (lambda0 <= q[0] && q[0] <= lambda1)
+        };
+
+        between.then_some((q, q1))
+    }
+}
+
+/// Where a great circle meets the clip circle
+enum CircleLine {
+    /// The great circle is undefined (antipodal or equal endpoints)
+    Polar,
+    /// The circles don't meet
+    Miss,
+    /// The two crossing points, in order along the great circle
+    Cross([f64; 2], [f64; 2]),
+}
+
+/// Append points along a small circle of `radius` around the origin
+fn circle_arc(
+    radius: f64,
+    direction: f64,
+    span: Option<([f64; 2], [f64; 2])>,
+    out: &mut Vec<[f64; 2]>,
+) {
+    let (sin_radius, cos_radius) = radius.sin_cos();
+    let step = direction * CIRCLE_STEP;
+
+    // Signed angle of a point around the circle's center
+    let angle = |p: [f64; 2]| {
+        let mut v = cartesian(p);
+        v[0] -= cos_radius;
+        let v = normalize(v);
+        let r = (-v[1]).clamp(-1.0, 1.0).acos();
+        ((if -v[2] < 0.0 { -r } else { r }) + TAU - EPSILON) % TAU
+    };
+
+    let (mut t0, t1) = match span {
+        None => (radius + direction * TAU, radius - step / 2.0),
+        Some((from, to)) => {
+            let (mut t0, t1) = (angle(from), angle(to));
+            if if direction > 0.0 { t0 < t1 } else { t0 > t1 } {
+                t0 += direction * TAU;
+            }
+            (t0, t1)
+        }
+    };
+
+    while if direction > 0.0 { t0 > t1 } else { t0 < t1 } {
+        out.push(spherical([
+            cos_radius,
+            -sin_radius * t0.cos(),
+            -sin_radius * t0.sin(),
+        ]));
+        t0 -= step;
+    }
+}
+
+/// Clips one line or ring against an edge, writing pieces to a buffer
+struct LineClip<'a> {
+    edge: &'a Edge,
+    point0: Option<[f64; 2]>,
+    // Antimeridian: side of the previous point
+    sign0: f64,
+    // Circle: visibility and code of the previous and first points
+    v0: bool,
+    v00: bool,
+    c0: u8,
+    // No intersections so far
+    clean: bool,
+}
+
+impl<'a> LineClip<'a> {
+    fn new(edge: &'a Edge) -> Self {
+        Self {
+            edge,
+            point0: None,
+            sign0: 0.0,
+            v0: false,
+            v00: false,
+            c0: 0,
+            clean: true,
+        }
+    }
+
+    fn line_start(&mut self, out: &mut LineBuffer) {
+        self.clean = true;
+        self.point0 = None;
+        match self.edge {
+            Edge::Antimeridian => out.line_start(),
+            Edge::Circle { .. } => {
+                self.v0 = false;
+                self.v00 = false;
+            }
+        }
+    }
+
+    fn line_end(&mut self) {
+        self.point0 = None;
+    }
+
+    /// 1: no intersections; 2: first and last pieces should be joined
+    fn clean(&self) -> u8 {
+        match self.edge {
+            Edge::Antimeridian => 2 - self.clean as u8,
+            Edge::Circle { .. } => self.clean as u8 | (((self.v00 && self.v0) as u8) << 1),
+        }
+    }
+
+    fn point(&mut self, p: [f64; 2], out: &mut LineBuffer) {
+        match *self.edge {
+            Edge::Antimeridian => self.point_antimeridian(p, out),
+            Edge::Circle {
+                cr,
+                small_radius,
+                not_hemisphere,
+                ..
+            } => self.point_circle(p, cr, small_radius, not_hemisphere, out),
+        }
+    }
+
+    fn point_antimeridian(&mut self, p: [f64; 2], out: &mut LineBuffer) {
+        let [mut lambda1, phi1] = p;
+        let sign1 = if lambda1 > 0.0 { PI } else { -PI };
+
+        if let Some([mut lambda0, phi0]) = self.point0 {
+            let sign0 = self.sign0;
+            let delta = (lambda1 - lambda0).abs();
+            if (delta - PI).abs() < EPSILON {
+                // Crosses a pole
+                let phi = if (phi0 + phi1) / 2.0 > 0.0 {
+                    FRAC_PI_2
+                } else {
+                    -FRAC_PI_2
+                };
+                out.point([lambda0, phi], false);
+                out.point([sign0, phi], false);
+                out.line_start();
+                out.point([sign1, phi], false);
+                out.point([lambda1, phi], false);
+                self.clean = false;
+            } else if sign0 != sign1 && delta >= PI {
+                // Crosses the antimeridian; nudge points lying on it
+                if (lambda0 - sign0).abs() < EPSILON {
+                    lambda0 -= sign0 * EPSILON;
+                }
+                if (lambda1 - sign1).abs() < EPSILON {
+                    lambda1 -= sign1 * EPSILON;
+                }
+                let phi = antimeridian_intersect(lambda0, phi0, lambda1, phi1);
+                out.point([sign0, phi], false);
+                out.line_start();
+                out.point([sign1, phi], false);
+                self.clean = false;
+            }
+        }
+
+        out.point([lambda1, phi1], false);
+        self.point0 = Some([lambda1, phi1]);
+        self.sign0 = sign1;
+    }
+
+    fn point_circle(
+        &mut self,
+        p: [f64; 2],
+        cr: f64,
+        small_radius: bool,
+        not_hemisphere: bool,
+        out: &mut LineBuffer,
+    ) {
+        let v = self.edge.visible(p);
+        let c = if small_radius {
+            if v {
+                0
+            } else {
+                self.edge.code(p)
+            }
+        } else if v {
+            self.edge
+                .code([p[0] + if p[0] < 0.0 { PI } else { -PI }, p[1]])
+        } else {
+            0
+        };
+
+        if self.point0.is_none() {
+            self.v0 = v;
+            self.v00 = v;
+            if v {
+                out.line_start();
+            }
+        }
+
+        if let (true, Some(point0)) = (v != self.v0, self.point0) {
+            self.clean = false;
+            if v {
+                // Outside going in
+                out.line_start();
+                let point2 = Edge::intersect(cr, p, point0).unwrap_or(p);
+                out.point(point2, false);
+                self.point0 = Some(point2);
+            } else {
+                // Inside going out
+                let point2 = Edge::intersect(cr, point0, p).unwrap_or(point0);
+                out.point(point2, true);
+                self.point0 = Some(point2);
+            }
+        } else if let (true, Some(point0)) = (not_hemisphere && (small_radius ^ v), self.point0) {
+            // The segment may dip into (or out of) the circle between
+            // two points on the same side
+            if c & self.c0 == 0 {
+                if let Some((t0, t1)) = Edge::intersect_two(cr, p, point0) {
+                    self.clean = false;
+                    if small_radius {
+                        out.line_start();
+                        out.point(t0, false);
+                        out.point(t1, false);
+                    } else {
+                        out.point(t1, false);
+                        out.line_start();
+                        out.point(t0, true);
+                    }
+                }
+            }
+        }
+
+        if v && !matches!(self.point0, Some(p0) if point_equal(p0, p)) {
+            out.point(p, false);
+        }
+        self.point0 = Some(p);
+        self.v0 = v;
+        self.c0 = c;
+    }
+}
+
+/// Latitude where the great circle through two points crosses the
+/// antimeridian
+fn antimeridian_intersect(lambda0: f64, phi0: f64, lambda1: f64, phi1: f64) -> f64 {
+    let sin_lambda0_lambda1 = (lambda0 - lambda1).sin();
+    if sin_lambda0_lambda1.abs() > EPSILON {
+        let (cos_phi0, cos_phi1) = (phi0.cos(), phi1.cos());
+        ((phi0.sin() * cos_phi1 * lambda1.sin() - phi1.sin() * cos_phi0 * lambda0.sin())
+            / (cos_phi0 * cos_phi1 * sin_lambda0_lambda1))
+            .atan()
+    } else {
+        (phi0 + phi1) / 2.0
+    }
+}
+
+/// Clip polygon rings and rejoin the cut pieces along the clip edge
+fn clip_rings(edge: &Edge, rings: &[Vec<[f64; 2]>], output: &mut Vec<Vec<[f64; 2]>>) {
+    let mut segments: Vec<Vec<ClipPoint>> = Vec::new();
+
+    for ring in rings {
+        let mut buffer = LineBuffer::default();
+        let mut line = LineClip::new(edge);
+        line.line_start(&mut buffer);
+        for &p in ring {
+            line.point(p, &mut buffer);
+        }
+        line.point(ring[0], &mut buffer);
+        line.line_end();
+
+        let clean = line.clean();
+        let mut pieces = buffer.lines;
+        if pieces.is_empty() {
+            continue;
+        }
+
+        // No intersections: the ring is kept or dropped whole
+        if clean & 1 != 0 {
+            let piece = &pieces[0];
+            if piece.len() > 1 {
+                output.push(piece[..piece.len() - 1].iter().map(|p| p.0).collect());
+            }
+            continue;
+        }
+
+        // The ring started inside: its first and last pieces are one
+        if pieces.len() > 1 && clean & 2 != 0 {
+            let first = pieces.remove(0);
+            if let Some(last) = pieces.last_mut() {
+                last.extend(first);
+            }
+        }
+        segments.extend(pieces.into_iter().filter(|p| p.len() > 1));
+    }
+
+    let start_inside = polygon_contains(rings, edge.start());
+    if !segments.is_empty() {
+        rejoin(edge, segments, start_inside, output);
+    } else if start_inside {
+        // The polygon covers the whole clip region
+        let mut ring = Vec::new();
+        edge.interpolate(None, 1.0, &mut ring);
+        output.push(ring);
+    }
+}
+
+/// A segment end on the clip edge, linked into the subject (segment) list
+/// and the clip (edge) list
+struct Intersection {
+    x: [f64; 2],
+    segment: Option<usize>,
+    other: usize,
+    entry: bool,
+    visited: bool,
+    next: usize,
+    prev: usize,
+}
+
+fn compare_intersection(a: [f64; 2], b: [f64; 2]) -> Ordering {
+    let key = |x: [f64; 2]| {
+        if x[0] < 0.0 {
+            x[1] - FRAC_PI_2 - EPSILON
+        } else {
+            FRAC_PI_2 - x[1]
+        }
+    };
+    key(a).total_cmp(&key(b))
+}
+
+/// Join clipped segments into closed rings (d3's clipRejoin)
+fn rejoin(
+    edge: &Edge,
+    mut segments: Vec<Vec<ClipPoint>>,
+    mut start_inside: bool,
+    output: &mut Vec<Vec<[f64; 2]>>,
+) {
+    let mut nodes: Vec<Intersection> = Vec::new();
+    let mut subject = Vec::new();
+    let mut clip = Vec::new();
+
+    let push = |nodes: &mut Vec<Intersection>, x, segment, entry| {
+        nodes.push(Intersection {
+            x,
+            segment,
+            other: 0,
+            entry,
+            visited: false,
+            next: 0,
+            prev: 0,
+        });
+        nodes.len() - 1
+    };
+
+    for (i, segment) in segments.iter_mut().enumerate() {
+        let n = segment.len() - 1;
+        let p0 = segment[0];
+        if point_equal(p0.0, segment[n].0) {
+            if !p0.1 && !segment[n].1 {
+                // Closed ring that never touched the edge
+                output.push(segment[..n].iter().map(|p| p.0).collect());
+                continue;
+            }
+            // Degenerate: move the end so the two are distinct
+            segment[n].0[0] += 2.0 * EPSILON;
+        }
+        let p1 = segment[n];
+
+        let a = push(&mut nodes, p0.0, Some(i), true);
+        let b = push(&mut nodes, p0.0, None, false);
+        nodes[a].other = b;
+        nodes[b].other = a;
+        subject.push(a);
+        clip.push(b);
+
+        let a = push(&mut nodes, p1.0, Some(i), false);
+        let b = push(&mut nodes, p1.0, None, true);
+        nodes[a].other = b;
+        nodes[b].other = a;
+        subject.push(a);
+        clip.push(b);
+    }
+
+    if subject.is_empty() {
+        return;
+    }
+
+    clip.sort_by(|&a, &b| compare_intersection(nodes[a].x, nodes[b].x));
+    for list in [&subject, &clip] {
+        for (k, &i) in list.iter().enumerate() {
+            let next = list[(k + 1) % list.len()];
+            nodes[i].next = next;
+            nodes[next].prev = i;
+        }
+    }
+    for &i in &clip {
+        start_inside = !start_inside;
+        nodes[i].entry = start_inside;
+    }
+
+    let start = subject[0];
+    loop {
+        // Find the first unvisited intersection
+        let mut current = start;
+        let mut is_subject = true;
+        while nodes[current].visited {
+            current = nodes[current].next;
+            if current == start {
+                return;
+            }
+        }
+
+        let mut ring = Vec::new();
+        let mut points = nodes[current].segment;
+        loop {
+            nodes[current].visited = true;
+            let other = nodes[current].other;
+            nodes[other].visited = true;
+
+            if nodes[current].entry {
+                if is_subject {
+                    if let Some(s) = points {
+                        ring.extend(segments[s].iter().map(|p| p.0));
+                    }
+                } else {
+                    let to = nodes[nodes[current].next].x;
+                    edge.interpolate(Some((nodes[current].x, to)), 1.0, &mut ring);
+                }
+                current = nodes[current].next;
+            } else {
+                if is_subject {
+                    points = nodes[nodes[current].prev].segment;
+                    if let Some(s) = points {
+                        ring.extend(segments[s].iter().rev().map(|p| p.0));
+                    }
+                } else {
+                    let to = nodes[nodes[current].prev].x;
+                    edge.interpolate(Some((nodes[current].x, to)), -1.0, &mut ring);
+                }
+                current = nodes[current].prev;
+            }
+
+            current = nodes[current].other;
+            points = nodes[current].segment;
+            is_subject = !is_subject;
+            if nodes[current].visited {
+                break;
+            }
+        }
+        output.push(ring);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(x0: f64, y0: f64, x1: f64, y1: f64) -> Vec<Position> {
+        vec![[x0, y0], [x1, y0], [x1, y1], [x0, y1], [x0, y0]]
+    }
+
+    fn is_closed(ring: &[Position]) -> bool {
+        ring.len() > 3 && ring[0] == ring[ring.len() - 1]
+    }
+
+    #[test]
+    fn test_wrap_longitude() {
+        assert_eq!(wrap_longitude(180.0), 180.0);
+        assert_eq!(wrap_longitude(-180.0), -180.0);
+        assert_eq!(wrap_longitude(190.0), -170.0);
+        assert_eq!(wrap_longitude(-540.0 + 10.0), -170.0);
+    }
+
+    #[test]
+    fn test_antimeridian_line() {
+        let pieces = Preclip::Antimeridian.clip_line(&[[170.0, 0.0], [-170.0, 0.0]]);
+        assert_eq!(pieces.len(), 2);
+        assert!((pieces[0][1][0] - 180.0).abs() < 1e-9);
+        assert!((pieces[1][0][0] + 180.0).abs() < 1e-9);
+
+        let pieces = Preclip::Antimeridian.clip_line(&[[-10.0, 0.0], [10.0, 5.0]]);
+        assert_eq!(pieces, vec![vec![[-10.0, 0.0], [10.0, 5.0]]]);
+    }
+
+    #[test]
+    fn test_antimeridian_polygon() {
+        // Small polygons pass through whole, whichever way they wind
+        let ring = square(0.0, 0.0, 10.0, 10.0);
+        for ring in [ring.clone(), ring.iter().rev().copied().collect()] {
+            let rings = Preclip::Antimeridian.clip_polygon(&[ring]);
+            assert_eq!(rings.len(), 1);
+            assert_eq!(rings[0].len(), 5);
+        }
+
+        // A strip across the antimeridian becomes two closed rings, one per side
+        let rings = Preclip::Antimeridian.clip_polygon(&[square(170.0, -10.0, -170.0, 10.0)]);
+        assert_eq!(rings.len(), 2);
+        for ring in &rings {
+            assert!(is_closed(ring));
+            let east = ring.iter().all(|p| p[0] >= 170.0 - 1e-6);
+            let west = ring.iter().all(|p| p[0] <= -170.0 + 1e-6);
+            assert!(east || west, "{:?}", ring);
+        }
+    }
+
+    #[test]
+    fn test_polar_cap() {
+        // A ring around the north pole encloses the pole: the clip edge
+        // closes it along the top of the map
+        let ring: Vec<Position> = (0..=8).map(|i| [-180.0 + 45.0 * i as f64, 80.0]).collect();
+        let ring: Vec<Position> = ring[..8].iter().copied().chain([ring[0]]).collect();
+        let rings = Preclip::Antimeridian.clip_polygon(&[ring]);
+        assert_eq!(rings.len(), 1);
+        let ring = &rings[0];
+        assert!(is_closed(ring));
+        assert!(ring.iter().any(|p| (p[1] - 90.0).abs() < 1e-6));
+        assert!(ring.iter().all(|p| p[1] >= 80.0 - 1e-6));
+    }
+
+    #[test]
+    fn test_circle_line() {
+        let clip = Preclip::Circle(90.0);
+        assert!(clip.is_visible(0.0, 0.0));
+        assert!(!clip.is_visible(120.0, 0.0));
+
+        // Leaves the visible hemisphere at 90°
+        let pieces = clip.clip_line(&[[0.0, 0.0], [60.0, 0.0], [120.0, 0.0]]);
+        assert_eq!(pieces.len(), 1);
+        let end = pieces[0].last().unwrap();
+        assert!((end[0] - 90.0).abs() < 1e-6);
+
+        // Entirely on the far side
+        assert!(clip.clip_line(&[[150.0, 0.0], [170.0, 10.0]]).is_empty());
+    }
+
+    #[test]
+    fn test_circle_polygon() {
+        let clip = Preclip::Circle(30.0);
+
+        // Inside: untouched
+        let rings = clip.clip_polygon(&[square(-5.0, -5.0, 5.0, 5.0)]);
+        assert_eq!(rings.len(), 1);
+        assert_eq!(rings[0].len(), 5);
+
+        // Outside: dropped
+        assert!(clip
+            .clip_polygon(&[square(100.0, 0.0, 110.0, 10.0)])
+            .is_empty());
+
+        // Straddling: cut along the circle and closed
+        let rings = clip.clip_polygon(&[square(20.0, -10.0, 50.0, 10.0)]);
+        assert_eq!(rings.len(), 1);
+        let ring = &rings[0];
+        assert!(is_closed(ring));
+        for p in ring {
+            let (l, f) = (p[0].to_radians(), p[1].to_radians());
+            assert!(l.cos() * f.cos() >= 30f64.to_radians().cos() - 1e-6);
+        }
+
+        // Covering the whole circle: the clip circle itself
+        let rings = clip.clip_polygon(&[square(-60.0, -60.0, 60.0, 60.0)]);
+        assert_eq!(rings.len(), 1);
+        assert!(rings[0].len() > 50);
+    }
+}
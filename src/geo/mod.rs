@@ -10,6 +10,10 @@
 //! - [`OrthographicProjection`]: Azimuthal projection (globe view)
 //! - [`AlbersProjection`]: Equal-area conic projection (US maps)
 //!
+//! Every projection can [`rotate`](ProjectionBuilder::rotate) the sphere
+//! first with a [`GeoRotation`], e.g. to center a world map on the Pacific
+//! or to turn Mercator into transverse Mercator.
+//!
 //! # GeoJSON Support
 //!
 //! - [`GeoJson`]: Parse and represent GeoJSON data
@@ -18,7 +22,17 @@
 //!
 //! # Path Generation
 //!
-//! - [`GeoPath`]: Generate SVG-like paths from geographic data
+//! - [`GeoPath`]: Generate SVG-like paths from geographic data, resampling
+//!   long edges along great circles to the projection's
+//!   [`precision`](ProjectionBuilder::precision)
+//! - [`Preclip`]: Spherical clipping along the antimeridian or a small
+//!   circle, applied by [`GeoPath`] before projection
+//!
+//! # Spherical Math
+//!
+//! - [`geo_area`], [`geo_length`], [`geo_centroid`], [`geo_bounds`]
+//! - [`geo_distance`]: Great-circle distance between two points
+//! - [`geo_interpolate`]: Points along the great circle between two points
 //!
 //! # Example
 //!
@@ -38,9 +52,12 @@
 //! ```
 
 mod area;
+mod clip;
 mod geojson;
 mod path;
 mod projection;
+mod resample;
+mod rotation;
 
 pub use projection::{
     compute_fit_extent, compute_fit_size, project_bounds, AlbersProjection,
@@ -52,6 +69,10 @@ pub use geojson::{
     BoundingBox, Feature, FeatureCollection, GeoJson, Geometry, GeometryType, Position, Properties,
 };
 
+pub use clip::Preclip;
+
+pub use rotation::GeoRotation;
+
 pub use path::{GeoPath, GeoPathSegment};
 
-pub use area::{geo_area, geo_bounds, geo_centroid, geo_length};
+pub use area::{geo_area, geo_bounds, geo_centroid, geo_distance, geo_interpolate, geo_length};
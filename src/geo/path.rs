@@ -4,6 +4,7 @@
 
 use super::geojson::{Feature, FeatureCollection, GeoJson, Geometry, Position};
 use super::projection::Projection;
+use super::resample::resample;
 
 /// A segment of a geographic path
 #[derive(Clone, Debug, PartialEq)]
@@ -149,11 +150,9 @@ impl<'a, P: Projection> GeoPath<'a, P> {
 
     /// Generate path segments for a point (as a small circle)
     fn point_to_segments(&self, lon: f64, lat: f64, segments: &mut Vec<GeoPathSegment>) {
-        if !self.projection.is_visible(lon, lat) {
+        let Some((x, y)) = self.project_point(&[lon, lat]) else {
             return;
-        }
-
-        let (x, y) = self.projection.project(lon, lat);
+        };
 
         // Generate a circle approximation using 8 line segments
         let n = 8;
@@ -176,144 +175,159 @@ impl<'a, P: Projection> GeoPath<'a, P> {
 
     /// Generate path segments for a line string
     fn line_to_segments(&self, coordinates: &[Position], segments: &mut Vec<GeoPathSegment>) {
-        let mut started = false;
-
-        for coord in coordinates {
-            let lon = coord[0];
-            let lat = coord[1];
-
-            if !self.projection.is_visible(lon, lat) {
-                // End current line segment if we hit invisible point
-                started = false;
-                continue;
-            }
-
-            let (x, y) = self.projection.project(lon, lat);
-
-            if !started {
-                segments.push(GeoPathSegment::MoveTo(x, y));
-                started = true;
-            } else {
-                segments.push(GeoPathSegment::LineTo(x, y));
+        for line in self.project_line(coordinates) {
+            for (i, &(x, y)) in line.iter().enumerate() {
+                if i == 0 {
+                    segments.push(GeoPathSegment::MoveTo(x, y));
+                } else {
+                    segments.push(GeoPathSegment::LineTo(x, y));
+                }
             }
         }
     }
 
     /// Generate path segments for a polygon
     fn polygon_to_segments(&self, rings: &[Vec<Position>], segments: &mut Vec<GeoPathSegment>) {
-        for ring in rings {
-            self.ring_to_segments(ring, segments);
+        for ring in self.project_polygon(rings) {
+            for (i, &(x, y)) in ring.iter().enumerate() {
+                if i == 0 {
+                    segments.push(GeoPathSegment::MoveTo(x, y));
+                } else {
+                    segments.push(GeoPathSegment::LineTo(x, y));
+                }
+            }
+            segments.push(GeoPathSegment::ClosePath);
         }
     }
 
-    /// Generate path segments for a ring (closed line string)
-    fn ring_to_segments(&self, coordinates: &[Position], segments: &mut Vec<GeoPathSegment>) {
-        if coordinates.is_empty() {
-            return;
-        }
-
-        let mut started = false;
-        let mut visible_count = 0;
-
-        for coord in coordinates {
-            let lon = coord[0];
-            let lat = coord[1];
-
-            if !self.projection.is_visible(lon, lat) {
-                continue;
-            }
-
-            visible_count += 1;
-            let (x, y) = self.projection.project(lon, lat);
+    /// Clip a line string on the sphere and project the visible pieces
+    fn project_line(&self, coordinates: &[Position]) -> Vec<Vec<(f64, f64)>> {
+        let frame: Vec<Position> = coordinates
+            .iter()
+            .map(|c| {
+                let (lon, lat) = self.projection.to_clip_frame(c[0], c[1]);
+                [lon, lat]
+            })
+            .collect();
+        self.projection
+            .preclip()
+            .clip_line(&frame)
+            .iter()
+            .map(|line| self.project_frame(line, false))
+            .collect()
+    }
 
-            if !started {
-                segments.push(GeoPathSegment::MoveTo(x, y));
-                started = true;
-            } else {
-                segments.push(GeoPathSegment::LineTo(x, y));
-            }
-        }
+    /// Clip a polygon on the sphere and project the resulting rings
+    ///
+    /// Rings are returned open (without repeating the first point) and
+    /// rings with fewer than 2 points are dropped.
+    fn project_polygon(&self, rings: &[Vec<Position>]) -> Vec<Vec<(f64, f64)>> {
+        let frame: Vec<Vec<Position>> = rings
+            .iter()
+            .map(|ring| {
+                ring.iter()
+                    .map(|c| {
+                        let (lon, lat) = self.projection.to_clip_frame(c[0], c[1]);
+                        [lon, lat]
+                    })
+                    .collect()
+            })
+            .collect();
+        self.projection
+            .preclip()
+            .clip_polygon(&frame)
+            .iter()
+            .filter(|ring| ring.len() > 2)
+            .map(|ring| self.project_frame(&ring[..ring.len() - 1], true))
+            .collect()
+    }
 
-        // Close the path if we drew at least 2 visible points
-        if visible_count >= 2 {
-            segments.push(GeoPathSegment::ClosePath);
-        }
+    /// Project clip frame coordinates to screen coordinates, resampling
+    /// long edges to the projection's precision
+    fn project_frame(&self, coordinates: &[Position], closed: bool) -> Vec<(f64, f64)> {
+        let precision = self.projection.resample_precision();
+        resample(coordinates, closed, precision, |lon, lat| {
+            self.projection.project_clip_frame(lon, lat)
+        })
     }
 
     /// Compute the centroid of a geometry
+    ///
+    /// Averages the clipped, resampled vertices that [`generate`](Self::generate)
+    /// draws, so hidden or cut-away parts don't pull it off the shape.
     pub fn centroid(&self, geometry: &Geometry) -> Option<(f64, f64)> {
-        let coords = self.collect_coordinates(geometry);
-
-        if coords.is_empty() {
+        let points = self.projected_points(geometry);
+        if points.is_empty() {
             return None;
         }
 
-        let mut sum_x = 0.0;
-        let mut sum_y = 0.0;
-        let mut count = 0.0;
-
-        for coord in &coords {
-            if self.projection.is_visible(coord[0], coord[1]) {
-                let (x, y) = self.projection.project(coord[0], coord[1]);
-                sum_x += x;
-                sum_y += y;
-                count += 1.0;
-            }
-        }
-
-        if count > 0.0 {
-            Some((sum_x / count, sum_y / count))
-        } else {
-            None
-        }
+        let count = points.len() as f64;
+        let (sum_x, sum_y) = points
+            .iter()
+            .fold((0.0, 0.0), |(sx, sy), &(x, y)| (sx + x, sy + y));
+        Some((sum_x / count, sum_y / count))
     }
 
-    /// Collect all coordinates from a geometry
-    fn collect_coordinates(&self, geometry: &Geometry) -> Vec<Position> {
-        let mut coords = Vec::new();
+    /// Project the vertices of a geometry as they are drawn
+    ///
+    /// Points are kept when visible; lines and polygons are clipped and
+    /// resampled like in [`generate`](Self::generate).
+    fn projected_points(&self, geometry: &Geometry) -> Vec<(f64, f64)> {
+        let mut points = Vec::new();
 
         match geometry {
             Geometry::Point { coordinates } => {
-                coords.push(*coordinates);
+                points.extend(self.project_point(coordinates));
             }
             Geometry::MultiPoint { coordinates } => {
-                coords.extend(coordinates.iter().copied());
+                points.extend(coordinates.iter().filter_map(|c| self.project_point(c)));
             }
             Geometry::LineString { coordinates } => {
-                coords.extend(coordinates.iter().copied());
+                points.extend(self.project_line(coordinates).into_iter().flatten());
             }
             Geometry::MultiLineString { coordinates } => {
                 for line in coordinates {
-                    coords.extend(line.iter().copied());
+                    points.extend(self.project_line(line).into_iter().flatten());
                 }
             }
             Geometry::Polygon { coordinates } => {
-                for ring in coordinates {
-                    coords.extend(ring.iter().copied());
-                }
+                points.extend(self.project_polygon(coordinates).into_iter().flatten());
             }
             Geometry::MultiPolygon { coordinates } => {
                 for polygon in coordinates {
-                    for ring in polygon {
-                        coords.extend(ring.iter().copied());
-                    }
+                    points.extend(self.project_polygon(polygon).into_iter().flatten());
                 }
             }
             Geometry::GeometryCollection { geometries } => {
                 for geom in geometries {
-                    coords.extend(self.collect_coordinates(geom));
+                    points.extend(self.projected_points(geom));
                 }
             }
         }
 
-        coords
+        points
+    }
+
+    /// Project a point if it is visible
+    ///
+    /// Goes through the clip frame like lines and polygons, so a marker
+    /// lands on the same side of the cut as the shape around it.
+    fn project_point(&self, coordinates: &Position) -> Option<(f64, f64)> {
+        let [lon, lat] = *coordinates;
+        if !self.projection.is_visible(lon, lat) {
+            return None;
+        }
+        let (lon, lat) = self.projection.to_clip_frame(lon, lat);
+        Some(self.projection.project_clip_frame(lon, lat))
     }
 
     /// Compute the bounding box of a geometry in projected coordinates
+    ///
+    /// Covers the clipped, resampled outline, so shapes cut at the
+    /// antimeridian or the horizon are bounded by the cut.
     pub fn bounds(&self, geometry: &Geometry) -> Option<[[f64; 2]; 2]> {
-        let coords = self.collect_coordinates(geometry);
-
-        if coords.is_empty() {
+        let points = self.projected_points(geometry);
+        if points.is_empty() {
             return None;
         }
 
@@ -322,24 +336,20 @@ impl<'a, P: Projection> GeoPath<'a, P> {
         let mut max_x = f64::NEG_INFINITY;
         let mut max_y = f64::NEG_INFINITY;
 
-        for coord in &coords {
-            if self.projection.is_visible(coord[0], coord[1]) {
-                let (x, y) = self.projection.project(coord[0], coord[1]);
-                min_x = min_x.min(x);
-                min_y = min_y.min(y);
-                max_x = max_x.max(x);
-                max_y = max_y.max(y);
-            }
+        for &(x, y) in &points {
+            min_x = min_x.min(x);
+            min_y = min_y.min(y);
+            max_x = max_x.max(x);
+            max_y = max_y.max(y);
         }
 
-        if min_x.is_finite() && min_y.is_finite() && max_x.is_finite() && max_y.is_finite() {
-            Some([[min_x, min_y], [max_x, max_y]])
-        } else {
-            None
-        }
+        Some([[min_x, min_y], [max_x, max_y]])
     }
 
     /// Calculate the approximate area of a geometry in square pixels
+    ///
+    /// Measures the clipped, projected polygons, so only the visible part
+    /// counts.
     pub fn area(&self, geometry: &Geometry) -> f64 {
         match geometry {
             Geometry::Polygon { coordinates } => self.polygon_area(coordinates),
@@ -351,96 +361,75 @@ impl<'a, P: Projection> GeoPath<'a, P> {
     }
 
     /// Calculate the area of a polygon using the shoelace formula
+    ///
+    /// Holes wind opposite to their exterior after clipping, so summing
+    /// the signed ring areas subtracts them.
     fn polygon_area(&self, rings: &[Vec<Position>]) -> f64 {
-        if rings.is_empty() {
-            return 0.0;
-        }
-
-        // Outer ring area
-        let outer_area = self.ring_area(&rings[0]);
-
-        // Subtract hole areas
-        let hole_area: f64 = rings.iter().skip(1).map(|ring| self.ring_area(ring)).sum();
-
-        (outer_area - hole_area).abs()
-    }
-
-    /// Calculate the area of a ring using the shoelace formula
-    fn ring_area(&self, ring: &[Position]) -> f64 {
-        if ring.len() < 3 {
-            return 0.0;
-        }
-
-        // Project all coordinates
-        let projected: Vec<(f64, f64)> = ring
+        self.project_polygon(rings)
             .iter()
-            .filter(|c| self.projection.is_visible(c[0], c[1]))
-            .map(|c| self.projection.project(c[0], c[1]))
-            .collect();
-
-        if projected.len() < 3 {
-            return 0.0;
-        }
+            .map(|ring| Self::ring_area(ring))
+            .sum::<f64>()
+            .abs()
+    }
 
-        // Shoelace formula
+    /// Calculate the signed area of a projected ring
+    fn ring_area(ring: &[(f64, f64)]) -> f64 {
+        let n = ring.len();
         let mut area = 0.0;
-        let n = projected.len();
-
         for i in 0..n {
             let j = (i + 1) % n;
-            area += projected[i].0 * projected[j].1;
-            area -= projected[j].0 * projected[i].1;
+            area += ring[i].0 * ring[j].1;
+            area -= ring[j].0 * ring[i].1;
         }
-
-        area.abs() / 2.0
+        area / 2.0
     }
 
     /// Calculate the approximate length of a geometry in pixels
+    ///
+    /// Polygons are measured by the perimeter of their clipped rings.
     pub fn measure(&self, geometry: &Geometry) -> f64 {
         match geometry {
             Geometry::LineString { coordinates } => self.line_length(coordinates),
             Geometry::MultiLineString { coordinates } => {
                 coordinates.iter().map(|l| self.line_length(l)).sum()
             }
-            Geometry::Polygon { coordinates } => {
-                coordinates.iter().map(|r| self.line_length(r)).sum()
+            Geometry::Polygon { coordinates } => self.perimeter(coordinates),
+            Geometry::MultiPolygon { coordinates } => {
+                coordinates.iter().map(|p| self.perimeter(p)).sum()
             }
-            Geometry::MultiPolygon { coordinates } => coordinates
-                .iter()
-                .flat_map(|p| p.iter())
-                .map(|r| self.line_length(r))
-                .sum(),
             _ => 0.0,
         }
     }
 
     /// Calculate the length of a line string in projected coordinates
     fn line_length(&self, coordinates: &[Position]) -> f64 {
-        if coordinates.len() < 2 {
-            return 0.0;
-        }
-
-        let mut length = 0.0;
-        let mut prev: Option<(f64, f64)> = None;
-
-        for coord in coordinates {
-            if !self.projection.is_visible(coord[0], coord[1]) {
-                prev = None;
-                continue;
-            }
-
-            let (x, y) = self.projection.project(coord[0], coord[1]);
-
-            if let Some((px, py)) = prev {
-                let dx = x - px;
-                let dy = y - py;
-                length += (dx * dx + dy * dy).sqrt();
-            }
+        self.project_line(coordinates)
+            .iter()
+            .map(|line| Self::polyline_length(line))
+            .sum()
+    }
 
-            prev = Some((x, y));
-        }
+    /// Calculate the perimeter of a polygon in projected coordinates
+    fn perimeter(&self, rings: &[Vec<Position>]) -> f64 {
+        self.project_polygon(rings)
+            .iter()
+            .map(|ring| {
+                let closed: Vec<(f64, f64)> = ring.iter().chain(ring.first()).copied().collect();
+                Self::polyline_length(&closed)
+            })
+            .sum()
+    }
 
-        length
+    /// Length of a projected polyline
+    fn polyline_length(points: &[(f64, f64)]) -> f64 {
+        points
+            .windows(2)
+            .map(|w| {
+                let dx = w[1].0 - w[0].0;
+                let dy = w[1].1 - w[0].1;
+                (dx * dx + dy * dy).sqrt()
+            })
+            .sum()
     }
 }
 
@@ -478,7 +467,7 @@ impl<'a, P: Projection> GeoPathBuilder<'a, P> {
 mod tests {
     use super::*;
     use crate::geo::projection::{
-        EquirectangularProjection, MercatorProjection, ProjectionBuilder,
+        EquirectangularProjection, MercatorProjection, OrthographicProjection, ProjectionBuilder,
     };
 
     #[test]
@@ -869,4 +858,200 @@ mod tests {
         // Area should be positive but less than full square
         assert!(area > 0.0);
     }
+
+    /// Projected rings of a path, as lists of points
+    fn rings(segments: &[GeoPathSegment]) -> Vec<Vec<(f64, f64)>> {
+        let mut rings = Vec::new();
+        for segment in segments {
+            match *segment {
+                GeoPathSegment::MoveTo(x, y) => rings.push(vec![(x, y)]),
+                GeoPathSegment::LineTo(x, y) => rings.last_mut().unwrap().push((x, y)),
+                GeoPathSegment::ClosePath => {}
+            }
+        }
+        rings
+    }
+
+    #[test]
+    fn test_geo_path_antimeridian() {
+        let projection = MercatorProjection::new().scale(100.0);
+        let path = GeoPath::new(&projection);
+
+        // Spans 20° across the antimeridian; must not stretch across the map
+        let geometry = Geometry::Polygon {
+            coordinates: vec![vec![
+                [170.0, -10.0],
+                [-170.0, -10.0],
+                [-170.0, 10.0],
+                [170.0, 10.0],
+                [170.0, -10.0],
+            ]],
+        };
+        let segments = path.generate(&GeoJson::Geometry(geometry.clone()));
+        let rings = rings(&segments);
+        assert_eq!(rings.len(), 2);
+        for ring in &rings {
+            let (min, max) = ring.iter().fold((f64::MAX, f64::MIN), |(lo, hi), p| {
+                (lo.min(p.0), hi.max(p.0))
+            });
+            assert!(max - min < 20f64.to_radians() * 100.0, "{:?}", ring);
+        }
+        // About the area of the equivalent box around the prime meridian;
+        // the cut lies on the great circle edge, slightly poleward of 10°
+        let centered = Geometry::Polygon {
+            coordinates: vec![vec![
+                [-10.0, -10.0],
+                [10.0, -10.0],
+                [10.0, 10.0],
+                [-10.0, 10.0],
+                [-10.0, -10.0],
+            ]],
+        };
+        let (a, b) = (path.area(&geometry), path.area(&centered));
+        assert!((a - b).abs() / b < 0.02, "{} {}", a, b);
+
+        let line = Geometry::LineString {
+            coordinates: vec![[170.0, 0.0], [-170.0, 0.0]],
+        };
+        let measured = path.measure(&line);
+        assert!((measured - 20f64.to_radians() * 100.0).abs() < 1e-6);
+
+        // Bounds follow the clipped pieces out to the cut on both sides
+        let half_width = std::f64::consts::PI * 100.0;
+        let [[min_x, _], [max_x, _]] = path.bounds(&geometry).unwrap();
+        assert!((min_x + half_width).abs() < 1e-6, "{}", min_x);
+        assert!((max_x - half_width).abs() < 1e-6, "{}", max_x);
+    }
+
+    #[test]
+    fn test_geo_path_centered_point() {
+        // Centered on 150°E, 170°W is 40° east of center
+        let projection = MercatorProjection::new().scale(100.0).center(150.0, 0.0);
+        let path = GeoPath::new(&projection);
+        let expected = 40f64.to_radians() * 100.0;
+
+        let point = Geometry::Point {
+            coordinates: [-170.0, 0.0],
+        };
+        let (x, _) = path.centroid(&point).unwrap();
+        assert!((x - expected).abs() < 1e-6, "{}", x);
+        assert!((projection.project(-170.0, 0.0).0 - expected).abs() < 1e-6);
+
+        // The marker sits inside the polygon around it
+        let polygon = Geometry::Polygon {
+            coordinates: vec![vec![
+                [-172.0, -2.0],
+                [-168.0, -2.0],
+                [-168.0, 2.0],
+                [-172.0, 2.0],
+                [-172.0, -2.0],
+            ]],
+        };
+        let [[min_x, _], [max_x, _]] = path.bounds(&polygon).unwrap();
+        assert!(
+            min_x < x && x < max_x,
+            "{} not in [{}, {}]",
+            x,
+            min_x,
+            max_x
+        );
+    }
+
+    #[test]
+    fn test_geo_path_rotated_antimeridian() {
+        // Pacific-centered: the date line is mid-map and the cut is at 30°W
+        let projection = MercatorProjection::new()
+            .scale(100.0)
+            .rotate(-150.0, 0.0, 0.0);
+        let path = GeoPath::new(&projection);
+
+        let across_date_line = Geometry::LineString {
+            coordinates: vec![[170.0, 0.0], [-170.0, 0.0]],
+        };
+        let segments = path.generate(&GeoJson::Geometry(across_date_line));
+        assert_eq!(rings(&segments).len(), 1);
+
+        let across_cut = Geometry::LineString {
+            coordinates: vec![[-40.0, 0.0], [-20.0, 0.0]],
+        };
+        let segments = path.generate(&GeoJson::Geometry(across_cut.clone()));
+        let pieces = rings(&segments);
+        assert_eq!(pieces.len(), 2);
+
+        // Both pieces end exactly on the map edges, not wrapped back
+        let edge = std::f64::consts::PI * 100.0;
+        for piece in &pieces {
+            let reaches_edge = piece.iter().any(|p| (p.0.abs() - edge).abs() < 1e-6);
+            assert!(reaches_edge, "{:?}", piece);
+        }
+        let measured = path.measure(&across_cut);
+        assert!((measured - 20f64.to_radians() * 100.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_geo_path_orthographic_clip() {
+        let projection = OrthographicProjection::new().scale(100.0);
+        let path = GeoPath::new(&projection);
+
+        // Straddles the limb at 90°E: the visible half ends on the globe edge
+        let geometry = Geometry::Polygon {
+            coordinates: vec![vec![
+                [60.0, -20.0],
+                [120.0, -20.0],
+                [120.0, 20.0],
+                [60.0, 20.0],
+                [60.0, -20.0],
+            ]],
+        };
+        let segments = path.generate(&GeoJson::Geometry(geometry));
+        assert!(matches!(segments.last(), Some(GeoPathSegment::ClosePath)));
+        let rings = rings(&segments);
+        assert_eq!(rings.len(), 1);
+        for &(x, y) in &rings[0] {
+            assert!((x * x + y * y).sqrt() <= 100.0 + 1e-6);
+        }
+        assert!(rings[0].iter().any(|&(x, _)| (x - 100.0).abs() < 1.0));
+
+        // Entirely on the far side
+        let hidden = Geometry::Polygon {
+            coordinates: vec![vec![
+                [150.0, 0.0],
+                [170.0, 0.0],
+                [170.0, 10.0],
+                [150.0, 0.0],
+            ]],
+        };
+        assert!(path.generate(&GeoJson::Geometry(hidden)).is_empty());
+    }
+
+    #[test]
+    fn test_geo_path_resampling() {
+        // San Francisco to Tokyo: the great circle arcs far to the north
+        let geometry = GeoJson::Geometry(Geometry::LineString {
+            coordinates: vec![[-122.4, 37.8], [139.7, 35.7]],
+        });
+
+        let projection = EquirectangularProjection::new()
+            .scale(100.0)
+            .center(180.0, 0.0);
+        assert_eq!(
+            projection.resample_precision(),
+            std::f64::consts::FRAC_1_SQRT_2
+        );
+        let segments = GeoPath::new(&projection).generate(&geometry);
+        assert!(segments.len() > 5);
+        let north = segments
+            .iter()
+            .filter_map(|s| match *s {
+                GeoPathSegment::LineTo(_, y) => Some(y),
+                _ => None,
+            })
+            .fold(f64::MAX, f64::min);
+        assert!(north < -45f64.to_radians() * 100.0);
+
+        // Precision 0 draws the raw edge
+        let projection = projection.precision(0.0);
+        let segments = GeoPath::new(&projection).generate(&geometry);
+        assert_eq!(segments.len(), 2);
+    }
 }
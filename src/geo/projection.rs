@@ -2,7 +2,12 @@
 //!
 //! Transforms spherical coordinates (longitude, latitude) to planar coordinates.
 
-use std::f64::consts::PI;
+use super::clip::{wrap_longitude, Preclip};
+use super::rotation::GeoRotation;
+use std::f64::consts::{FRAC_1_SQRT_2, PI};
+
+/// Default resampling precision in pixels (d3's √0.5)
+const DEFAULT_PRECISION: f64 = FRAC_1_SQRT_2;
 
 /// Trait for geographic projections
 ///
@@ -42,6 +47,46 @@ pub trait Projection: Send + Sync {
     fn clip_extent(&self) -> Option<((f64, f64), (f64, f64))> {
         None
     }
+
+    /// Get the adaptive resampling threshold in pixels
+    ///
+    /// Projected edges are subdivided until no point of the great circle
+    /// arc strays further than this from the drawn line. Zero disables
+    /// resampling.
+    fn resample_precision(&self) -> f64 {
+        0.0
+    }
+
+    /// Get the spherical clipping applied to lines and polygons
+    ///
+    /// Defaults to cutting along the antimeridian of the clip frame.
+    fn preclip(&self) -> Preclip {
+        Preclip::Antimeridian
+    }
+
+    /// Convert geographic coordinates to the clip frame
+    ///
+    /// Clipping happens in this frame: the antimeridian cut runs along
+    /// ±180° and clip circles are centered on (0, 0). The default wraps
+    /// longitudes into [-180, 180].
+    fn to_clip_frame(&self, lon: f64, lat: f64) -> (f64, f64) {
+        (wrap_longitude(lon), lat)
+    }
+
+    /// Convert clip frame coordinates back to geographic coordinates
+    fn invert_clip_frame(&self, lon: f64, lat: f64) -> (f64, f64) {
+        (lon, lat)
+    }
+
+    /// Project a point given in the clip frame
+    ///
+    /// Projections that rotate override this to skip the round trip back
+    /// to geographic coordinates, which could move points on the cut to
+    /// the other side of the map.
+    fn project_clip_frame(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let (lon, lat) = self.invert_clip_frame(lon, lat);
+        self.project(lon, lat)
+    }
 }
 
 /// Builder trait for projections
@@ -55,13 +100,19 @@ pub trait ProjectionBuilder: Sized {
     /// Set the translation (screen offset)
     fn translate(self, x: f64, y: f64) -> Self;
 
-    /// Set the rotation
+    /// Set the rotation of the sphere before projecting
+    ///
+    /// Yaw λ, pitch φ and roll γ in degrees, as in d3's
+    /// `projection.rotate([λ, φ, γ])`. See [`GeoRotation`].
     fn rotate(self, lambda: f64, phi: f64, gamma: f64) -> Self;
 
     /// Set the clip angle (for azimuthal projections)
     fn clip_angle(self, angle: f64) -> Self;
 
     /// Set the precision for adaptive resampling
+    ///
+    /// The maximum distance in pixels between a projected great circle arc
+    /// and the line drawn for it. Defaults to √0.5; 0 disables resampling.
     fn precision(self, precision: f64) -> Self;
 }
 
@@ -97,6 +148,10 @@ pub struct MercatorProjection {
     translate_y: f64,
     /// Maximum latitude (clips at ~85.05°)
     max_lat: f64,
+    /// Rotation applied before projecting
+    rotation: GeoRotation,
+    /// Resampling precision in pixels
+    precision: f64,
 }
 
 impl Default for MercatorProjection {
@@ -115,6 +170,8 @@ impl MercatorProjection {
             translate_x: 0.0,
             translate_y: 0.0,
             max_lat: 85.05113, // atan(sinh(π)) in degrees
+            rotation: GeoRotation::default(),
+            precision: DEFAULT_PRECISION,
         }
     }
 
@@ -123,6 +180,26 @@ impl MercatorProjection {
         self.max_lat = lat.abs().min(89.99);
         self
     }
+
+    /// Project a rotated point, with longitude relative to the center
+    fn project_raw(&self, lon: f64, lat: f64) -> (f64, f64) {
+        // Clamp latitude to avoid infinity
+        let lat = lat.clamp(-self.max_lat, self.max_lat);
+
+        // Convert to radians
+        let lambda = lon.to_radians();
+        let phi = lat.to_radians();
+
+        // Mercator projection formula
+        let x = lambda;
+        let y = (PI / 4.0 + phi / 2.0).tan().ln();
+
+        // Apply scale and translate
+        (
+            x * self.scale + self.translate_x,
+            -y * self.scale + self.translate_y, // Y is inverted
+        )
+    }
 }
 
 impl ProjectionBuilder for MercatorProjection {
@@ -143,8 +220,8 @@ impl ProjectionBuilder for MercatorProjection {
         self
     }
 
-    fn rotate(self, _lambda: f64, _phi: f64, _gamma: f64) -> Self {
-        // Mercator doesn't support rotation in the traditional sense
+    fn rotate(mut self, lambda: f64, phi: f64, gamma: f64) -> Self {
+        self.rotation = GeoRotation::new(lambda, phi, gamma);
         self
     }
 
@@ -152,29 +229,16 @@ impl ProjectionBuilder for MercatorProjection {
         self
     }
 
-    fn precision(self, _precision: f64) -> Self {
+    fn precision(mut self, precision: f64) -> Self {
+        self.precision = precision.max(0.0);
         self
     }
 }
 
 impl Projection for MercatorProjection {
     fn project(&self, lon: f64, lat: f64) -> (f64, f64) {
-        // Clamp latitude to avoid infinity
-        let lat = lat.clamp(-self.max_lat, self.max_lat);
-
-        // Convert to radians
-        let lambda = (lon - self.center_lon).to_radians();
-        let phi = lat.to_radians();
-
-        // Mercator projection formula
-        let x = lambda;
-        let y = (PI / 4.0 + phi / 2.0).tan().ln();
-
-        // Apply scale and translate
-        (
-            x * self.scale + self.translate_x,
-            -y * self.scale + self.translate_y, // Y is inverted
-        )
+        let (lon, lat) = self.rotation.rotate(lon, lat);
+        self.project_raw(wrap_longitude(lon - self.center_lon), lat)
     }
 
     fn invert(&self, x: f64, y: f64) -> (f64, f64) {
@@ -186,12 +250,30 @@ impl Projection for MercatorProjection {
         let lon = px.to_degrees() + self.center_lon;
         let lat = (2.0 * py.exp().atan() - PI / 2.0).to_degrees();
 
-        (lon, lat)
+        self.rotation.invert(lon, lat)
     }
 
     fn projection_type(&self) -> &'static str {
         "mercator"
     }
+
+    fn resample_precision(&self) -> f64 {
+        self.precision
+    }
+
+    fn to_clip_frame(&self, lon: f64, lat: f64) -> (f64, f64) {
+        // Cut along the antimeridian of the rotated center meridian
+        let (lon, lat) = self.rotation.rotate(lon, lat);
+        (wrap_longitude(lon - self.center_lon), lat)
+    }
+
+    fn invert_clip_frame(&self, lon: f64, lat: f64) -> (f64, f64) {
+        self.rotation.invert(lon + self.center_lon, lat)
+    }
+
+    fn project_clip_frame(&self, lon: f64, lat: f64) -> (f64, f64) {
+        self.project_raw(lon, lat)
+    }
 }
 
 /// Equirectangular projection (plate carrée)
@@ -225,6 +307,10 @@ pub struct EquirectangularProjection {
     translate_x: f64,
     /// Translation Y
     translate_y: f64,
+    /// Rotation applied before projecting
+    rotation: GeoRotation,
+    /// Resampling precision in pixels
+    precision: f64,
 }
 
 impl Default for EquirectangularProjection {
@@ -242,8 +328,21 @@ impl EquirectangularProjection {
             center_lat: 0.0,
             translate_x: 0.0,
             translate_y: 0.0,
+            rotation: GeoRotation::default(),
+            precision: DEFAULT_PRECISION,
         }
     }
+
+    /// Project a rotated point, with longitude relative to the center
+    fn project_raw(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let x = lon.to_radians();
+        let y = (lat - self.center_lat).to_radians();
+
+        (
+            x * self.scale + self.translate_x,
+            -y * self.scale + self.translate_y,
+        )
+    }
 }
 
 impl ProjectionBuilder for EquirectangularProjection {
@@ -264,7 +363,8 @@ impl ProjectionBuilder for EquirectangularProjection {
         self
     }
 
-    fn rotate(self, _lambda: f64, _phi: f64, _gamma: f64) -> Self {
+    fn rotate(mut self, lambda: f64, phi: f64, gamma: f64) -> Self {
+        self.rotation = GeoRotation::new(lambda, phi, gamma);
         self
     }
 
@@ -272,20 +372,16 @@ impl ProjectionBuilder for EquirectangularProjection {
         self
     }
 
-    fn precision(self, _precision: f64) -> Self {
+    fn precision(mut self, precision: f64) -> Self {
+        self.precision = precision.max(0.0);
         self
     }
 }
 
 impl Projection for EquirectangularProjection {
     fn project(&self, lon: f64, lat: f64) -> (f64, f64) {
-        let x = (lon - self.center_lon).to_radians();
-        let y = (lat - self.center_lat).to_radians();
-
-        (
-            x * self.scale + self.translate_x,
-            -y * self.scale + self.translate_y,
-        )
+        let (lon, lat) = self.rotation.rotate(lon, lat);
+        self.project_raw(wrap_longitude(lon - self.center_lon), lat)
     }
 
     fn invert(&self, x: f64, y: f64) -> (f64, f64) {
@@ -295,12 +391,30 @@ impl Projection for EquirectangularProjection {
         let lon = px.to_degrees() + self.center_lon;
         let lat = py.to_degrees() + self.center_lat;
 
-        (lon, lat)
+        self.rotation.invert(lon, lat)
     }
 
     fn projection_type(&self) -> &'static str {
         "equirectangular"
     }
+
+    fn resample_precision(&self) -> f64 {
+        self.precision
+    }
+
+    fn to_clip_frame(&self, lon: f64, lat: f64) -> (f64, f64) {
+        // Cut along the antimeridian of the rotated center meridian
+        let (lon, lat) = self.rotation.rotate(lon, lat);
+        (wrap_longitude(lon - self.center_lon), lat)
+    }
+
+    fn invert_clip_frame(&self, lon: f64, lat: f64) -> (f64, f64) {
+        self.rotation.invert(lon + self.center_lon, lat)
+    }
+
+    fn project_clip_frame(&self, lon: f64, lat: f64) -> (f64, f64) {
+        self.project_raw(lon, lat)
+    }
 }
 
 /// Orthographic projection (azimuthal)
@@ -330,14 +444,12 @@ pub struct OrthographicProjection {
     translate_x: f64,
     /// Translation Y
     translate_y: f64,
-    /// Rotation: lambda (longitude)
-    rotate_lambda: f64,
-    /// Rotation: phi (latitude)
-    rotate_phi: f64,
-    /// Rotation: gamma (roll)
-    rotate_gamma: f64,
+    /// Rotation applied before projecting
+    rotation: GeoRotation,
     /// Clip angle in degrees (default 90°)
     clip_angle: f64,
+    /// Resampling precision in pixels
+    precision: f64,
 }
 
 impl Default for OrthographicProjection {
@@ -353,83 +465,25 @@ impl OrthographicProjection {
             scale: 1.0,
             translate_x: 0.0,
             translate_y: 0.0,
-            rotate_lambda: 0.0,
-            rotate_phi: 0.0,
-            rotate_gamma: 0.0,
+            rotation: GeoRotation::default(),
             clip_angle: 90.0,
+            precision: DEFAULT_PRECISION,
         }
     }
 
-    /// Apply rotation to convert to rotated coordinates
-    ///
-    /// D3's rotation convention (from d3-geo/src/rotation.js):
-    /// - λ (rotate_lambda): Add to longitude
-    /// - φ (rotate_phi): Tilt the pole
-    /// - γ (rotate_gamma): Roll around the viewing axis
-    fn rotate_point(&self, lon: f64, lat: f64) -> (f64, f64) {
-        // Step 1: Apply λ rotation (shift longitude)
-        let lambda = lon.to_radians() + self.rotate_lambda.to_radians();
+    /// Project a rotated point
+    fn project_raw(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let lambda = lon.to_radians();
         let phi = lat.to_radians();
 
-        // Step 2: Apply φ and γ rotation (D3's rotationPhiGamma formula)
-        let delta_phi = self.rotate_phi.to_radians();
-        let delta_gamma = self.rotate_gamma.to_radians();
-
-        let cos_delta_phi = delta_phi.cos();
-        let sin_delta_phi = delta_phi.sin();
-        let cos_delta_gamma = delta_gamma.cos();
-        let sin_delta_gamma = delta_gamma.sin();
-
-        let cos_phi = phi.cos();
-        let x = lambda.cos() * cos_phi;
-        let y = lambda.sin() * cos_phi;
-        let z = phi.sin();
-
-        // D3's formula for combined φ and γ rotation
-        let k = z * cos_delta_phi + x * sin_delta_phi;
-
-        let new_lambda = (y * cos_delta_gamma - k * sin_delta_gamma)
-            .atan2(x * cos_delta_phi - z * sin_delta_phi);
-        let new_phi = (k * cos_delta_gamma + y * sin_delta_gamma)
-            .clamp(-1.0, 1.0)
-            .asin();
-
-        (new_lambda, new_phi)
-    }
-
-    /// Apply inverse rotation to convert from rotated coordinates back to geographic
-    ///
-    /// The inverse applies the same D3 formula with negated angles,
-    /// then subtracts the λ rotation.
-    fn inverse_rotate_point(&self, lambda: f64, phi: f64) -> (f64, f64) {
-        // Apply inverse φ,γ rotation using the same D3 formula with negated angles
-        let delta_phi = -self.rotate_phi.to_radians();
-        let delta_gamma = -self.rotate_gamma.to_radians();
-
-        let cos_delta_phi = delta_phi.cos();
-        let sin_delta_phi = delta_phi.sin();
-        let cos_delta_gamma = delta_gamma.cos();
-        let sin_delta_gamma = delta_gamma.sin();
-
-        let cos_phi = phi.cos();
-        let x = lambda.cos() * cos_phi;
-        let y = lambda.sin() * cos_phi;
-        let z = phi.sin();
-
-        // D3's formula with negated angles
-        let k = z * cos_delta_phi + x * sin_delta_phi;
-
-        let new_lambda = (y * cos_delta_gamma - k * sin_delta_gamma)
-            .atan2(x * cos_delta_phi - z * sin_delta_phi);
-        let new_phi = (k * cos_delta_gamma + y * sin_delta_gamma)
-            .clamp(-1.0, 1.0)
-            .asin();
-
-        // Apply inverse λ rotation
-        let lon = new_lambda.to_degrees() - self.rotate_lambda;
-        let lat = new_phi.to_degrees();
+        // Orthographic projection
+        let x = phi.cos() * lambda.sin();
+        let y = phi.sin();
 
-        (lon, lat)
+        (
+            x * self.scale + self.translate_x,
+            -y * self.scale + self.translate_y,
+        )
     }
 }
 
@@ -451,9 +505,7 @@ impl ProjectionBuilder for OrthographicProjection {
     }
 
     fn rotate(mut self, lambda: f64, phi: f64, gamma: f64) -> Self {
-        self.rotate_lambda = lambda;
-        self.rotate_phi = phi;
-        self.rotate_gamma = gamma;
+        self.rotation = GeoRotation::new(lambda, phi, gamma);
         self
     }
 
@@ -462,23 +514,16 @@ impl ProjectionBuilder for OrthographicProjection {
         self
     }
 
-    fn precision(self, _precision: f64) -> Self {
+    fn precision(mut self, precision: f64) -> Self {
+        self.precision = precision.max(0.0);
         self
     }
 }
 
 impl Projection for OrthographicProjection {
     fn project(&self, lon: f64, lat: f64) -> (f64, f64) {
-        let (lambda, phi) = self.rotate_point(lon, lat);
-
-        // Orthographic projection
-        let x = phi.cos() * lambda.sin();
-        let y = phi.sin();
-
-        (
-            x * self.scale + self.translate_x,
-            -y * self.scale + self.translate_y,
-        )
+        let (lon, lat) = self.rotation.rotate(lon, lat);
+        self.project_raw(lon, lat)
     }
 
     fn invert(&self, x: f64, y: f64) -> (f64, f64) {
@@ -505,7 +550,7 @@ impl Projection for OrthographicProjection {
         let lambda = (px * sin_c).atan2(rho * cos_c);
 
         // Apply inverse rotation to get geographic coordinates
-        self.inverse_rotate_point(lambda, phi)
+        self.rotation.invert(lambda.to_degrees(), phi.to_degrees())
     }
 
     fn projection_type(&self) -> &'static str {
@@ -513,9 +558,29 @@ impl Projection for OrthographicProjection {
     }
 
     fn is_visible(&self, lon: f64, lat: f64) -> bool {
-        let (lambda, phi) = self.rotate_point(lon, lat);
-        let cos_c = phi.cos() * lambda.cos();
-        cos_c >= self.clip_angle.to_radians().cos()
+        let (lon, lat) = self.rotation.rotate(lon, lat);
+        let cos_c = lat.to_radians().cos() * lon.to_radians().cos();
+        cos_c > self.clip_angle.to_radians().cos()
+    }
+
+    fn preclip(&self) -> Preclip {
+        Preclip::Circle(self.clip_angle)
+    }
+
+    fn resample_precision(&self) -> f64 {
+        self.precision
+    }
+
+    fn to_clip_frame(&self, lon: f64, lat: f64) -> (f64, f64) {
+        self.rotation.rotate(lon, lat)
+    }
+
+    fn invert_clip_frame(&self, lon: f64, lat: f64) -> (f64, f64) {
+        self.rotation.invert(lon, lat)
+    }
+
+    fn project_clip_frame(&self, lon: f64, lat: f64) -> (f64, f64) {
+        self.project_raw(lon, lat)
     }
 }
 
@@ -552,6 +617,10 @@ pub struct AlbersProjection {
     parallel1: f64,
     /// Second standard parallel
     parallel2: f64,
+    /// Rotation applied before projecting
+    rotation: GeoRotation,
+    /// Resampling precision in pixels
+    precision: f64,
     // Precomputed values
     n: f64,
     c: f64,
@@ -585,6 +654,8 @@ impl AlbersProjection {
             center_lat: 0.0,
             parallel1,
             parallel2,
+            rotation: GeoRotation::default(),
+            precision: DEFAULT_PRECISION,
             n: 0.0,
             c: 0.0,
             rho0: 0.0,
@@ -614,6 +685,23 @@ impl AlbersProjection {
         self.c = cos_phi1 * cos_phi1 + 2.0 * self.n * sin_phi1;
         self.rho0 = (self.c - 2.0 * self.n * phi0.sin()).sqrt() / self.n;
     }
+
+    /// Project a rotated point, with longitude relative to the center
+    fn project_raw(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let lambda = lon.to_radians();
+        let phi = lat.to_radians();
+
+        let rho = (self.c - 2.0 * self.n * phi.sin()).sqrt() / self.n;
+        let theta = self.n * lambda;
+
+        let x = rho * theta.sin();
+        let y = self.rho0 - rho * theta.cos();
+
+        (
+            x * self.scale + self.translate_x,
+            y * self.scale + self.translate_y,
+        )
+    }
 }
 
 impl ProjectionBuilder for AlbersProjection {
@@ -635,7 +723,8 @@ impl ProjectionBuilder for AlbersProjection {
         self
     }
 
-    fn rotate(self, _lambda: f64, _phi: f64, _gamma: f64) -> Self {
+    fn rotate(mut self, lambda: f64, phi: f64, gamma: f64) -> Self {
+        self.rotation = GeoRotation::new(lambda, phi, gamma);
         self
     }
 
@@ -643,26 +732,16 @@ impl ProjectionBuilder for AlbersProjection {
         self
     }
 
-    fn precision(self, _precision: f64) -> Self {
+    fn precision(mut self, precision: f64) -> Self {
+        self.precision = precision.max(0.0);
         self
     }
 }
 
 impl Projection for AlbersProjection {
     fn project(&self, lon: f64, lat: f64) -> (f64, f64) {
-        let lambda = (lon - self.center_lon).to_radians();
-        let phi = lat.to_radians();
-
-        let rho = (self.c - 2.0 * self.n * phi.sin()).sqrt() / self.n;
-        let theta = self.n * lambda;
-
-        let x = rho * theta.sin();
-        let y = self.rho0 - rho * theta.cos();
-
-        (
-            x * self.scale + self.translate_x,
-            y * self.scale + self.translate_y,
-        )
+        let (lon, lat) = self.rotation.rotate(lon, lat);
+        self.project_raw(wrap_longitude(lon - self.center_lon), lat)
     }
 
     fn invert(&self, x: f64, y: f64) -> (f64, f64) {
@@ -680,12 +759,30 @@ impl Projection for AlbersProjection {
             .asin()
             .to_degrees();
 
-        (lon, lat)
+        self.rotation.invert(lon, lat)
     }
 
     fn projection_type(&self) -> &'static str {
         "albers"
     }
+
+    fn resample_precision(&self) -> f64 {
+        self.precision
+    }
+
+    fn to_clip_frame(&self, lon: f64, lat: f64) -> (f64, f64) {
+        // Cut along the antimeridian of the rotated center meridian
+        let (lon, lat) = self.rotation.rotate(lon, lat);
+        (wrap_longitude(lon - self.center_lon), lat)
+    }
+
+    fn invert_clip_frame(&self, lon: f64, lat: f64) -> (f64, f64) {
+        self.rotation.invert(lon + self.center_lon, lat)
+    }
+
+    fn project_clip_frame(&self, lon: f64, lat: f64) -> (f64, f64) {
+        self.project_raw(lon, lat)
+    }
 }
 
 /// Calculate the bounds of a geometry when projected
@@ -863,7 +960,10 @@ impl MercatorProjection {
     /// ```
     pub fn fit_extent(self, coordinates: &[[f64; 2]], extent: [[f64; 2]; 2]) -> Self {
         // Create a base projection to compute bounds
-        let base = MercatorProjection::new().center(self.center_lon, self.center_lat);
+        let [lambda, phi, gamma] = self.rotation.angles();
+        let base = MercatorProjection::new()
+            .center(self.center_lon, self.center_lat)
+            .rotate(lambda, phi, gamma);
         let (scale, tx, ty) = compute_fit_extent(&base, coordinates, extent);
         Self {
             scale,
@@ -882,7 +982,10 @@ impl MercatorProjection {
 impl EquirectangularProjection {
     /// Fit the projection to display given coordinates within an extent.
     pub fn fit_extent(self, coordinates: &[[f64; 2]], extent: [[f64; 2]; 2]) -> Self {
-        let base = EquirectangularProjection::new().center(self.center_lon, self.center_lat);
+        let [lambda, phi, gamma] = self.rotation.angles();
+        let base = EquirectangularProjection::new()
+            .center(self.center_lon, self.center_lat)
+            .rotate(lambda, phi, gamma);
         let (scale, tx, ty) = compute_fit_extent(&base, coordinates, extent);
         Self {
             scale,
@@ -901,8 +1004,9 @@ impl EquirectangularProjection {
 impl OrthographicProjection {
     /// Fit the projection to display given coordinates within an extent.
     pub fn fit_extent(self, coordinates: &[[f64; 2]], extent: [[f64; 2]; 2]) -> Self {
+        let [lambda, phi, gamma] = self.rotation.angles();
         let base = OrthographicProjection::new()
-            .rotate(self.rotate_lambda, self.rotate_phi, self.rotate_gamma)
+            .rotate(lambda, phi, gamma)
             .clip_angle(self.clip_angle);
         let (scale, tx, ty) = compute_fit_extent(&base, coordinates, extent);
         Self {
@@ -923,9 +1027,11 @@ impl AlbersProjection {
     /// Fit the projection to display given coordinates within an extent.
     pub fn fit_extent(mut self, coordinates: &[[f64; 2]], extent: [[f64; 2]; 2]) -> Self {
         // For Albers, we need to recalculate with the new scale
+        let [lambda, phi, gamma] = self.rotation.angles();
         let base = AlbersProjection::new()
             .parallels(self.parallel1, self.parallel2)
-            .center(self.center_lon, self.center_lat);
+            .center(self.center_lon, self.center_lat)
+            .rotate(lambda, phi, gamma);
         let (scale, tx, ty) = compute_fit_extent(&base, coordinates, extent);
         self.scale = scale;
         self.translate_x = tx;
@@ -1034,6 +1140,10 @@ mod tests {
 
         // Back of globe should not be visible
         assert!(!proj.is_visible(180.0, 0.0));
+
+        // The limb itself is clipped, for points as for lines
+        assert!(!proj.is_visible(90.0, 0.0));
+        assert!(!proj.is_visible(0.0, 90.0));
     }
 
     #[test]
@@ -1187,6 +1297,96 @@ mod tests {
         assert!((lat - original.1).abs() < 0.1);
     }
 
+    fn assert_roundtrip<P: Projection>(proj: &P, points: &[(f64, f64)]) {
+        for &(lon, lat) in points {
+            let (x, y) = proj.project(lon, lat);
+            let (ilon, ilat) = proj.invert(x, y);
+            assert!(
+                (ilon - lon).abs() < 1e-6 && (ilat - lat).abs() < 1e-6,
+                "{} roundtrip failed: ({}, {}) -> ({}, {})",
+                proj.projection_type(),
+                lon,
+                lat,
+                ilon,
+                ilat
+            );
+        }
+    }
+
+    #[test]
+    fn test_rotation_roundtrip_all_projections() {
+        let rotate = (-150.0, 20.0, 30.0);
+        let points = [(150.0, -20.0), (170.0, 0.0), (135.0, 10.0), (160.0, -35.0)];
+
+        let mercator = MercatorProjection::new()
+            .scale(100.0)
+            .translate(400.0, 300.0)
+            .rotate(rotate.0, rotate.1, rotate.2);
+        assert_roundtrip(&mercator, &points);
+
+        let equirect = EquirectangularProjection::new()
+            .scale(100.0)
+            .translate(400.0, 300.0)
+            .rotate(rotate.0, rotate.1, rotate.2);
+        assert_roundtrip(&equirect, &points);
+
+        let ortho = OrthographicProjection::new()
+            .scale(100.0)
+            .translate(400.0, 300.0)
+            .rotate(rotate.0, rotate.1, rotate.2);
+        assert_roundtrip(&ortho, &points);
+
+        let albers = AlbersProjection::new()
+            .scale(100.0)
+            .translate(400.0, 300.0)
+            .rotate(rotate.0, rotate.1, rotate.2);
+        assert_roundtrip(&albers, &points);
+    }
+
+    #[test]
+    fn test_mercator_pacific_centered() {
+        let proj = MercatorProjection::new()
+            .scale(100.0)
+            .translate(400.0, 300.0)
+            .rotate(-150.0, 0.0, 0.0);
+
+        // 150°E sits at the center of the map
+        let (x, y) = proj.project(150.0, 0.0);
+        assert!((x - 400.0).abs() < 1e-9);
+        assert!((y - 300.0).abs() < 1e-9);
+
+        // The date line is near the middle, Greenwich near the right edge
+        let (x, _) = proj.project(180.0, 0.0);
+        assert!((x - (400.0 + 30f64.to_radians() * 100.0)).abs() < 1e-9);
+        let (x, _) = proj.project(0.0, 0.0);
+        assert!((x - (400.0 - 150f64.to_radians() * 100.0)).abs() < 1e-9);
+
+        // The cut moves to 30°W
+        let (lon, _) = proj.to_clip_frame(-30.0, 0.0);
+        assert!((lon.abs() - 180.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_transverse_mercator() {
+        let proj = MercatorProjection::new()
+            .scale(100.0)
+            .translate(400.0, 300.0)
+            .rotate(0.0, 0.0, 90.0);
+
+        // The prime meridian becomes a horizontal line through the center
+        for lat in [-60.0, -30.0, 0.0, 30.0, 60.0] {
+            let (_, y) = proj.project(0.0, lat);
+            assert!((y - 300.0).abs() < 1e-9, "lat {} -> y {}", lat, y);
+        }
+        let (x0, _) = proj.project(0.0, -30.0);
+        let (x1, _) = proj.project(0.0, 30.0);
+        assert!((x1 - x0).abs() > 100.0);
+
+        // Distortion now grows away from the meridian instead of the equator
+        let (_, y) = proj.project(80.0, 0.0);
+        assert!((y - 300.0).abs() > 200.0);
+    }
+
     #[test]
     fn test_fit_extent_mercator() {
         // Simple bounding box
@@ -0,0 +1,176 @@
+//! Adaptive resampling
+//!
+//! Port of d3-geo's resampling. Geographic edges are great circle arcs, but
+//! a projected edge is drawn as a straight line between its projected ends.
+//! Long edges are recursively split at their spherical midpoint until the
+//! projected midpoint lies within `precision` pixels of the straight line.
+
+use super::geojson::Position;
+
+/// Maximum number of times an edge is halved
+const MAX_DEPTH: u32 = 16;
+
+/// Edges longer than 30° are always split (cos 30°)
+const COS_MIN_DISTANCE: f64 = 0.866_025_403_784_438_6;
+
+const EPSILON: f64 = 1e-6;
+
+/// A projected vertex with its position on the unit sphere
+#[derive(Clone, Copy)]
+struct Vertex {
+    x: f64,
+    y: f64,
+    lambda: f64,
+    a: f64,
+    b: f64,
+    c: f64,
+}
+
+impl Vertex {
+    fn new(lambda: f64, phi: f64, x: f64, y: f64) -> Self {
+        let cos_phi = phi.cos();
+        Self {
+            x,
+            y,
+            lambda,
+            a: cos_phi * lambda.cos(),
+            b: cos_phi * lambda.sin(),
+            c: phi.sin(),
+        }
+    }
+}
+
+/// Project a line, adding points along long edges
+///
+/// `points` are in degrees and `project` maps them (in degrees) to screen
+/// coordinates. A `closed` ring also resamples the edge from its last point
+/// back to the first, without repeating the first point in the output.
+pub(crate) fn resample<F>(
+    points: &[Position],
+    closed: bool,
+    precision: f64,
+    project: F,
+) -> Vec<(f64, f64)>
+where
+    F: Fn(f64, f64) -> (f64, f64),
+{
+    if precision <= 0.0 {
+        return points.iter().map(|p| project(p[0], p[1])).collect();
+    }
+
+    let delta2 = precision * precision;
+    let closing = if closed { points.first() } else { None };
+    let mut out = Vec::with_capacity(points.len());
+    let mut prev: Option<Vertex> = None;
+
+    for p in points.iter().chain(closing) {
+        let (x, y) = project(p[0], p[1]);
+        let vertex = Vertex::new(p[0].to_radians(), p[1].to_radians(), x, y);
+        if let Some(prev) = prev {
+            subdivide(&prev, &vertex, MAX_DEPTH, delta2, &project, &mut out);
+        }
+        out.push((x, y));
+        prev = Some(vertex);
+    }
+
+    if closing.is_some() {
+        out.pop();
+    }
+    out
+}
+
+/// Emit the points needed between two vertices (exclusive)
+fn subdivide<F>(
+    v0: &Vertex,
+    v1: &Vertex,
+    depth: u32,
+    delta2: f64,
+    project: &F,
+    out: &mut Vec<(f64, f64)>,
+) where
+    F: Fn(f64, f64) -> (f64, f64),
+{
+    let dx = v1.x - v0.x;
+    let dy = v1.y - v0.y;
+    let d2 = dx * dx + dy * dy;
+    if d2 <= 4.0 * delta2 || depth == 0 {
+        return;
+    }
+
+    // Spherical midpoint
+    let (a, b, c) = (v0.a + v1.a, v0.b + v1.b, v0.c + v1.c);
+    let m = (a * a + b * b + c * c).sqrt();
+    let c = c / m;
+    let phi2 = c.clamp(-1.0, 1.0).asin();
+    let lambda2 = if (c.abs() - 1.0).abs() < EPSILON || (v0.lambda - v1.lambda).abs() < EPSILON {
+        (v0.lambda + v1.lambda) / 2.0
+    } else {
+        b.atan2(a)
+    };
+    let (x2, y2) = project(lambda2.to_degrees(), phi2.to_degrees());
+    let dx2 = x2 - v0.x;
+    let dy2 = y2 - v0.y;
+    let dz = dy * dx2 - dx * dy2;
+
+    // Split if the midpoint is off the line, bunched toward one end, or
+    // the edge is long on the sphere
+    if dz * dz / d2 > delta2
+        || ((dx * dx2 + dy * dy2) / d2 - 0.5).abs() > 0.3
+        || v0.a * v1.a + v0.b * v1.b + v0.c * v1.c < COS_MIN_DISTANCE
+    {
+        let mid = Vertex {
+            x: x2,
+            y: y2,
+            lambda: lambda2,
+            a: a / m,
+            b: b / m,
+            c,
+        };
+        subdivide(v0, &mid, depth - 1, delta2, project, out);
+        out.push((x2, y2));
+        subdivide(&mid, v1, depth - 1, delta2, project, out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Plate carrée at 100px per radian
+    fn plate_carree(lon: f64, lat: f64) -> (f64, f64) {
+        (lon.to_radians() * 100.0, -lat.to_radians() * 100.0)
+    }
+
+    #[test]
+    fn test_resample_disabled() {
+        let points = [[0.0, 0.0], [90.0, 45.0]];
+        let out = resample(&points, false, 0.0, plate_carree);
+        assert_eq!(out.len(), 2);
+    }
+
+    #[test]
+    fn test_resample_great_circle() {
+        // The great circle between two points at 45°N bulges poleward
+        let points = [[-60.0, 45.0], [60.0, 45.0]];
+        let out = resample(&points, false, 0.5, plate_carree);
+        assert!(out.len() > 10);
+        assert_eq!(out[0], plate_carree(-60.0, 45.0));
+        assert_eq!(*out.last().unwrap(), plate_carree(60.0, 45.0));
+        let top = out.iter().map(|p| p.1).fold(f64::MAX, f64::min);
+        assert!(top < plate_carree(0.0, 54.0).1);
+
+        // Straight lines stay straight: the equator needs no extra points
+        // beyond the 30° limit
+        let out = resample(&[[0.0, 0.0], [20.0, 0.0]], false, 0.5, plate_carree);
+        assert_eq!(out.len(), 2);
+    }
+
+    #[test]
+    fn test_resample_closed() {
+        let ring = [[0.0, 60.0], [60.0, 60.0], [60.0, 70.0], [0.0, 70.0]];
+        let out = resample(&ring, true, 0.5, plate_carree);
+        assert!(out.len() > ring.len());
+        assert_eq!(out[0], plate_carree(0.0, 60.0));
+        assert_ne!(*out.last().unwrap(), out[0]);
+    }
+}
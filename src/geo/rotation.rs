@@ -0,0 +1,210 @@
+//! Spherical rotation
+//!
+//! Rotates the sphere before projection, like d3's `d3.geoRotation()`.
+//! Every projection applies its rotation with [`GeoRotation`], so any of
+//! them can be re-centered or turned sideways:
+//!
+//! - λ (yaw) turns the globe around the polar axis, moving the central
+//!   meridian (and the antimeridian cut with it)
+//! - φ (pitch) tilts the poles toward or away from the viewer
+//! - γ (roll) spins around the view axis; γ = 90° turns a Mercator
+//!   projection into a transverse Mercator
+//!
+//! # Example
+//!
+//! ```
+//! use makepad_d3::geo::GeoRotation;
+//!
+//! // Center the map on the Pacific (150°E)
+//! let rotation = GeoRotation::new(-150.0, 0.0, 0.0);
+//! let (lon, lat) = rotation.rotate(150.0, 10.0);
+//! assert!(lon.abs() < 1e-9 && (lat - 10.0).abs() < 1e-9);
+//!
+//! let (lon, lat) = rotation.invert(lon, lat);
+//! assert!((lon - 150.0).abs() < 1e-9 && (lat - 10.0).abs() < 1e-9);
+//! ```
+
+use std::f64::consts::{PI, TAU};
+
+/// A rotation of the sphere by yaw λ, pitch φ and roll γ, in degrees
+#[derive(Clone, Copy, Debug)]
+pub struct GeoRotation {
+    /// Rotation angles in degrees
+    angles: [f64; 3],
+    /// λ in radians
+    delta_lambda: f64,
+    /// Sine and cosine of φ
+    sin_phi: f64,
+    cos_phi: f64,
+    /// Sine and cosine of γ
+    sin_gamma: f64,
+    cos_gamma: f64,
+}
+
+impl GeoRotation {
+    /// Create a rotation from yaw λ, pitch φ and roll γ in degrees
+    ///
+    /// A point at longitude -λ moves to the central meridian; φ and γ are
+    /// then applied around the new center.
+    pub fn new(lambda: f64, phi: f64, gamma: f64) -> Self {
+        let (sin_phi, cos_phi) = phi.to_radians().sin_cos();
+        let (sin_gamma, cos_gamma) = gamma.to_radians().sin_cos();
+        Self {
+            angles: [lambda, phi, gamma],
+            delta_lambda: lambda.to_radians(),
+            sin_phi,
+            cos_phi,
+            sin_gamma,
+            cos_gamma,
+        }
+    }
+
+    /// Get the rotation angles [λ, φ, γ] in degrees
+    pub fn angles(&self) -> [f64; 3] {
+        self.angles
+    }
+
+    /// Check whether the rotation leaves points in place
+    pub fn is_identity(&self) -> bool {
+        self.angles == [0.0; 3]
+    }
+
+    /// Rotate a point (degrees)
+    ///
+    /// Longitudes outside [-180, 180] are wrapped; ±180 itself is kept.
+    pub fn rotate(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let lambda = wrap(lon.to_radians() + self.delta_lambda);
+        let phi = lat.to_radians();
+        if !self.tilted() {
+            return (lambda.to_degrees(), lat);
+        }
+
+        let cos_phi = phi.cos();
+        let x = lambda.cos() * cos_phi;
+        let y = lambda.sin() * cos_phi;
+        let z = phi.sin();
+        let k = z * self.cos_phi + x * self.sin_phi;
+
+        let lambda =
+            (y * self.cos_gamma - k * self.sin_gamma).atan2(x * self.cos_phi - z * self.sin_phi);
+        let phi = (k * self.cos_gamma + y * self.sin_gamma)
+            .clamp(-1.0, 1.0)
+            .asin();
+        (lambda.to_degrees(), phi.to_degrees())
+    }
+
+    /// Undo the rotation of a point (degrees)
+    pub fn invert(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let (mut lambda, mut phi) = (lon.to_radians(), lat.to_radians());
+        if self.tilted() {
+            let cos_phi = phi.cos();
+            let x = lambda.cos() * cos_phi;
+            let y = lambda.sin() * cos_phi;
+            let z = phi.sin();
+            let k = z * self.cos_gamma - y * self.sin_gamma;
+
+            lambda = (y * self.cos_gamma + z * self.sin_gamma)
+                .atan2(x * self.cos_phi + k * self.sin_phi);
+            phi = (k * self.cos_phi - x * self.sin_phi)
+                .clamp(-1.0, 1.0)
+                .asin();
+        }
+        (
+            wrap(lambda - self.delta_lambda).to_degrees(),
+            phi.to_degrees(),
+        )
+    }
+
+    fn tilted(&self) -> bool {
+        self.angles[1] != 0.0 || self.angles[2] != 0.0
+    }
+}
+
+impl Default for GeoRotation {
+    fn default() -> Self {
+        Self::new(0.0, 0.0, 0.0)
+    }
+}
+
+impl PartialEq for GeoRotation {
+    /// Rotations are equal when their angles are; the rest is derived
+    fn eq(&self, other: &Self) -> bool {
+        self.angles == other.angles
+    }
+}
+
+/// Wrap a longitude in radians into [-π, π], keeping ±π
+fn wrap(lambda: f64) -> f64 {
+    if lambda.abs() > PI {
+        lambda - (lambda / TAU).round() * TAU
+    } else {
+        lambda
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: (f64, f64), b: (f64, f64)) {
+        assert!(
+            (a.0 - b.0).abs() < 1e-9 && (a.1 - b.1).abs() < 1e-9,
+            "{:?} != {:?}",
+            a,
+            b
+        );
+    }
+
+    #[test]
+    fn test_identity() {
+        let rotation = GeoRotation::default();
+        assert!(rotation.is_identity());
+        assert_eq!(rotation.rotate(180.0, 10.0), (180.0, 10.0));
+        assert_eq!(rotation.rotate(-180.0, 10.0), (-180.0, 10.0));
+        assert_close(rotation.rotate(190.0, 10.0), (-170.0, 10.0));
+    }
+
+    #[test]
+    fn test_default_matches_new() {
+        let rotation = GeoRotation::default();
+        assert_eq!(rotation, GeoRotation::new(0.0, 0.0, 0.0));
+        assert_eq!(rotation.cos_phi, 1.0);
+        assert_eq!(rotation.cos_gamma, 1.0);
+        assert_ne!(rotation, GeoRotation::new(0.0, 10.0, 0.0));
+    }
+
+    #[test]
+    fn test_rotate_known_points() {
+        // Yaw shifts longitudes
+        assert_close(
+            GeoRotation::new(90.0, 0.0, 0.0).rotate(100.0, 5.0),
+            (-170.0, 5.0),
+        );
+
+        // Pitch brings the north pole to the center
+        assert_close(
+            GeoRotation::new(0.0, -90.0, 0.0).rotate(0.0, 90.0),
+            (0.0, 0.0),
+        );
+
+        // Roll turns meridian 0 into the equator
+        let (_, lat) = GeoRotation::new(0.0, 0.0, 90.0).rotate(0.0, 30.0);
+        assert!(lat.abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let rotations = [
+            GeoRotation::new(-150.0, 0.0, 0.0),
+            GeoRotation::new(30.0, -45.0, 0.0),
+            GeoRotation::new(0.0, 20.0, 90.0),
+            GeoRotation::new(100.0, 35.0, -60.0),
+        ];
+        for rotation in rotations {
+            for &(lon, lat) in &[(0.0, 0.0), (-122.4, 37.8), (151.2, -33.9), (179.0, 80.0)] {
+                let (x, y) = rotation.rotate(lon, lat);
+                assert_close(rotation.invert(x, y), (lon, lat));
+            }
+        }
+    }
+}
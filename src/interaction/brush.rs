@@ -2,6 +2,7 @@
 //!
 //! Provides rectangular selection for filtering data in visualizations.
 
+use crate::shape::{polygon_clip_rect, Point};
 use serde::{Deserialize, Serialize};
 
 /// Type of brush selection
@@ -125,6 +126,11 @@ impl BrushSelection {
         y >= y0 && y <= y1
     }
 
+    /// Clip a polygon (e.g. a Voronoi cell or contour) to the selection
+    pub fn clip_polygon(&self, polygon: &[Point]) -> Vec<Point> {
+        polygon_clip_rect(polygon, self.x0, self.y0, self.x1, self.y1)
+    }
+
     /// Check if selection is empty (zero area)
     pub fn is_empty(&self) -> bool {
         self.width() < 1e-10 || self.height() < 1e-10
@@ -819,4 +825,16 @@ mod tests {
         assert_eq!(BrushBehavior::y().brush_type(), BrushType::Y);
         assert_eq!(BrushBehavior::xy().brush_type(), BrushType::XY);
     }
+
+    #[test]
+    fn test_brush_clip_polygon() {
+        let selection = BrushSelection::new(10.0, 10.0, 0.0, 0.0);
+        let triangle = [
+            Point::new(0.0, 0.0),
+            Point::new(0.0, 20.0),
+            Point::new(20.0, 0.0),
+        ];
+        let clipped = selection.clip_polygon(&triangle);
+        assert!((crate::shape::polygon_area(&clipped) - 100.0).abs() < 1e-9);
+    }
 }
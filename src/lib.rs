@@ -118,12 +118,12 @@ pub mod prelude {
         LegendOrientation, LegendPosition, ReferenceLine, ReferenceLineSet, TooltipConfig,
         TooltipWidget,
     };
-    pub use crate::data::{ChartData, Color, DataPoint, Dataset, PointStyle};
+    pub use crate::data::{ChartData, Color, ColorAssignment, DataPoint, Dataset, PointStyle};
     pub use crate::error::{D3Error, D3Result};
     pub use crate::geo::{
         AlbersProjection, BoundingBox, EquirectangularProjection, Feature, FeatureCollection,
-        GeoJson, GeoPath, GeoPathSegment, Geometry, GeometryType, MercatorProjection,
-        OrthographicProjection, Position, Projection, ProjectionBuilder, Properties,
+        GeoJson, GeoPath, GeoPathSegment, GeoRotation, Geometry, GeometryType, MercatorProjection,
+        OrthographicProjection, Position, Preclip, Projection, ProjectionBuilder, Properties,
     };
     pub use crate::interaction::{
         BrushBehavior, BrushSelection, BrushType, TooltipContent, ZoomBehavior, ZoomTransform,
@@ -165,15 +165,15 @@ pub mod prelude {
     };
     pub use crate::scale::{
         format_number, nice_bounds, nice_step, CategoryScale, ContinuousScale, DiscreteScale,
-        LinearScale, LogScale, PowScale, Scale, ScaleExt, SymlogScale, Tick, TickOptions,
-        TimeInterval, TimeScale, TimeTick,
+        LinearScale, LogScale, PolarScale, PowScale, RadialScale, Scale, ScaleExt, SymlogScale,
+        Tick, TickOptions, TimeInterval, TimeScale, TimeTick,
     };
     pub use crate::shape::{
         ArcDatum, ArcGenerator, AreaGenerator, LineGenerator, Path, PathSegment, PieLayout,
-        PieSlice, PieSort, Point, StackGenerator, StackOffset, StackOrder, StackPoint,
-        StackedSeries,
+        PieSlice, PieSort, Point, StackGenerator, StackMissing, StackOffset, StackOrder,
+        StackPoint, StackedSeries,
     };
 }
 
-// Re-export Color from data module at crate root for convenience
+// Re-export Color (an alias for color::Rgba) at crate root for convenience
 pub use data::Color;
@@ -1,9 +1,15 @@
 //! Colormap definitions for 3D visualization shaders
 //!
-//! This module provides colormap implementations that can be used both
-//! in Rust code and as shader functions.
+//! This module provides colormaps that can be used both in Rust code and
+//! as shader functions. Every colormap is a scheme from the
+//! [`color_schemes`](crate::color::color_schemes) registry: the built-in
+//! variants are the schemes the GPU shaders approximate, and any other
+//! scheme can be used through [`Colormap::Scheme`] or
+//! [`Colormap::from_name`]. On the CPU a name always samples the same
+//! registry colors.
 
 use super::types::Vec3;
+use crate::color::{color_scheme, ColorScheme, Rgba};
 
 /// Colormap types available for visualization
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
@@ -23,10 +29,67 @@ pub enum Colormap {
     Turbo,
     /// Grayscale - simple black to white
     Grayscale,
+    /// Any other scheme from the color scheme registry
+    ///
+    /// Sampled on the CPU. The GPU colormap shaders have no lookup for
+    /// registry schemes, so the draw primitives bake their colors with
+    /// [`cpu_rgba`](Colormap::cpu_rgba).
+    Scheme(&'static ColorScheme),
 }
 
 impl Colormap {
+    /// Look up a colormap by name
+    ///
+    /// Names are resolved through the [`color_scheme`] registry, e.g.
+    /// `"viridis"`, `"coolwarm"`, `"RdYlBu"` or `"interpolateBlues"`;
+    /// `"grayscale"` is accepted for `"gray"`. Schemes with a GPU shader
+    /// map to their built-in variant, so `"turbo"` and
+    /// `"interpolateTurbo"` are the same colormap. Returns None for
+    /// unknown names.
+    pub fn from_name(name: &str) -> Option<Colormap> {
+        let name = if name.eq_ignore_ascii_case("grayscale") {
+            "gray"
+        } else {
+            name
+        };
+        let scheme = color_scheme(name)?;
+        let colormap = match scheme.name() {
+            "viridis" => Colormap::Viridis,
+            "plasma" => Colormap::Plasma,
+            "inferno" => Colormap::Inferno,
+            "magma" => Colormap::Magma,
+            "coolwarm" => Colormap::CoolWarm,
+            "turbo" => Colormap::Turbo,
+            "gray" => Colormap::Grayscale,
+            _ => Colormap::Scheme(scheme),
+        };
+        Some(colormap)
+    }
+
+    /// Get the registry scheme this colormap samples
+    pub fn scheme(&self) -> &'static ColorScheme {
+        let name = match self {
+            Colormap::Viridis => "viridis",
+            Colormap::Plasma => "plasma",
+            Colormap::Inferno => "inferno",
+            Colormap::Magma => "magma",
+            Colormap::CoolWarm => "coolwarm",
+            Colormap::Turbo => "turbo",
+            Colormap::Grayscale => "gray",
+            Colormap::Scheme(scheme) => return scheme,
+        };
+        color_scheme(name).expect("built-in colormaps are registered")
+    }
+
+    /// Check whether the GPU colormap shaders can sample this colormap
+    pub fn has_shader(&self) -> bool {
+        !matches!(self, Colormap::Scheme(_))
+    }
+
     /// Get the colormap type as a shader-compatible float
+    ///
+    /// Registry schemes have no shader and map to viridis; see
+    /// [`has_shader`](Colormap::has_shader).
     pub fn to_shader_value(&self) -> f32 {
         match self {
             Colormap::Viridis => 0.0,
@@ -36,22 +99,14 @@ impl Colormap {
             Colormap::CoolWarm => 4.0,
             Colormap::Turbo => 5.0,
             Colormap::Grayscale => 6.0,
+            Colormap::Scheme(_) => 0.0,
         }
     }
 
     /// Sample the colormap at a normalized value t in [0, 1]
     pub fn sample(&self, t: f32) -> Vec3 {
-        let t = t.clamp(0.0, 1.0);
-
-        match self {
-            Colormap::Viridis => viridis(t),
-            Colormap::Plasma => plasma(t),
-            Colormap::Inferno => inferno(t),
-            Colormap::Magma => magma(t),
-            Colormap::CoolWarm => cool_warm(t),
-            Colormap::Turbo => turbo(t),
-            Colormap::Grayscale => Vec3::splat(t),
-        }
+        let c = self.scheme().interpolate(t.clamp(0.0, 1.0) as f64);
+        Vec3::new(c.r, c.g, c.b)
     }
 
     /// Get the colormap as RGBA with full opacity
@@ -59,118 +114,27 @@ impl Colormap {
         let rgb = self.sample(t);
         [rgb.x, rgb.y, rgb.z, 1.0]
     }
-}
-
-/// Viridis colormap - perceptually uniform, colorblind-friendly
-fn viridis(t: f32) -> Vec3 {
-    // 7-point interpolation
-    let colors = [
-        Vec3::new(0.267, 0.004, 0.329),
-        Vec3::new(0.282, 0.140, 0.458),
-        Vec3::new(0.254, 0.265, 0.530),
-        Vec3::new(0.163, 0.471, 0.558),
-        Vec3::new(0.134, 0.658, 0.517),
-        Vec3::new(0.477, 0.821, 0.318),
-        Vec3::new(0.993, 0.906, 0.144),
-    ];
-    interpolate_colormap(&colors, t)
-}
-
-/// Plasma colormap - high contrast purple to yellow
-fn plasma(t: f32) -> Vec3 {
-    let colors = [
-        Vec3::new(0.050, 0.030, 0.528),
-        Vec3::new(0.295, 0.012, 0.615),
-        Vec3::new(0.494, 0.012, 0.658),
-        Vec3::new(0.665, 0.138, 0.568),
-        Vec3::new(0.798, 0.280, 0.470),
-        Vec3::new(0.899, 0.434, 0.358),
-        Vec3::new(0.973, 0.580, 0.254),
-        Vec3::new(0.940, 0.975, 0.131),
-    ];
-    interpolate_colormap(&colors, t)
-}
-
-/// Inferno colormap - dark to bright through orange
-fn inferno(t: f32) -> Vec3 {
-    let colors = [
-        Vec3::new(0.001, 0.000, 0.014),
-        Vec3::new(0.120, 0.047, 0.290),
-        Vec3::new(0.329, 0.059, 0.406),
-        Vec3::new(0.533, 0.134, 0.416),
-        Vec3::new(0.735, 0.216, 0.330),
-        Vec3::new(0.891, 0.348, 0.194),
-        Vec3::new(0.976, 0.559, 0.040),
-        Vec3::new(0.988, 0.998, 0.645),
-    ];
-    interpolate_colormap(&colors, t)
-}
-
-/// Magma colormap - dark purple to yellow
-fn magma(t: f32) -> Vec3 {
-    let colors = [
-        Vec3::new(0.001, 0.000, 0.014),
-        Vec3::new(0.111, 0.066, 0.267),
-        Vec3::new(0.280, 0.090, 0.410),
-        Vec3::new(0.469, 0.131, 0.507),
-        Vec3::new(0.662, 0.186, 0.515),
-        Vec3::new(0.857, 0.323, 0.468),
-        Vec3::new(0.984, 0.557, 0.399),
-        Vec3::new(0.988, 0.991, 0.749),
-    ];
-    interpolate_colormap(&colors, t)
-}
 
-/// Cool-Warm diverging colormap - blue to red through white
-fn cool_warm(t: f32) -> Vec3 {
-    if t < 0.5 {
-        // Blue to white
-        let s = t * 2.0;
-        Vec3::new(0.230, 0.299, 0.754).lerp(&Vec3::new(0.865, 0.865, 0.865), s)
-    } else {
-        // White to red
-        let s = (t - 0.5) * 2.0;
-        Vec3::new(0.865, 0.865, 0.865).lerp(&Vec3::new(0.706, 0.016, 0.150), s)
+    /// Sample the colormap on the CPU when the GPU shaders can't
+    ///
+    /// Returns None for colormaps with a shader. Draw primitives upload
+    /// the returned color directly, so registry schemes don't come out
+    /// as viridis.
+    pub fn cpu_rgba(&self, t: f32) -> Option<[f32; 4]> {
+        (!self.has_shader()).then(|| self.sample_rgba(t))
     }
-}
-
-/// Turbo colormap - improved rainbow
-fn turbo(t: f32) -> Vec3 {
-    // Polynomial approximation of Google's Turbo colormap
-    let r = 0.13572138
-        + t * (4.61539260
-            + t * (-42.66032258 + t * (132.13108234 + t * (-152.94239396 + t * 59.28637943))));
-    let g = 0.09140261
-        + t * (2.19418839
-            + t * (4.84296658 + t * (-14.18503333 + t * (4.27729857 + t * 2.82956604))));
-    let b = 0.10667330
-        + t * (12.64194608
-            + t * (-60.58204836 + t * (110.36276771 + t * (-89.90310912 + t * 27.34824973))));
-
-    Vec3::new(r.clamp(0.0, 1.0), g.clamp(0.0, 1.0), b.clamp(0.0, 1.0))
-}
 
-/// Interpolate through a colormap defined by key colors
-fn interpolate_colormap(colors: &[Vec3], t: f32) -> Vec3 {
-    let n = colors.len();
-    if n == 0 {
-        return Vec3::ZERO;
-    }
-    if n == 1 {
-        return colors[0];
+    /// Sample the colormap as an opaque [`Rgba`]
+    pub fn sample_color(&self, t: f32) -> Rgba {
+        let rgb = self.sample(t);
+        Rgba::rgb(rgb.x, rgb.y, rgb.z)
     }
-
-    let t = t.clamp(0.0, 1.0);
-    let scaled = t * (n - 1) as f32;
-    let i = (scaled as usize).min(n - 2);
-    let f = scaled - i as f32;
-
-    colors[i].lerp(&colors[i + 1], f)
 }
 
 /// Shader code for colormaps (to be included in live_design!)
 ///
-/// This generates the GLSL-like shader code for colormap functions.
+/// This generates the GLSL-like shader code for colormap functions. They
+/// approximate the registry schemes with a few stops each.
 pub const COLORMAP_SHADER_CODE: &str = r#"
     // Colormap types:
     // 0 = Viridis, 1 = Plasma, 2 = Inferno, 3 = Magma
@@ -314,6 +278,56 @@ mod tests {
     fn test_rgba() {
         let rgba = Colormap::Viridis.sample_rgba(0.5);
         assert_eq!(rgba[3], 1.0); // Full opacity
+        assert_eq!(Colormap::Viridis.sample_color(0.5).to_array(), rgba);
+    }
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(Colormap::from_name("plasma"), Some(Colormap::Plasma));
+        assert_eq!(Colormap::from_name("cool_warm"), Some(Colormap::CoolWarm));
+        assert_eq!(Colormap::from_name("grayscale"), Some(Colormap::Grayscale));
+        assert_eq!(Colormap::from_name("nope"), None);
+
+        // One name, one set of colors: aliases resolve to the same scheme
+        for (a, b) in [
+            ("turbo", "interpolateTurbo"),
+            ("viridis", "interpolateViridis"),
+            ("coolwarm", "cool-warm"),
+            ("gray", "grayscale"),
+        ] {
+            let (a, b) = (
+                Colormap::from_name(a).unwrap(),
+                Colormap::from_name(b).unwrap(),
+            );
+            assert_eq!(a, b);
+            assert_eq!(a.sample_color(0.25), a.scheme().interpolate(0.25));
+        }
+
+        let blues = Colormap::from_name("interpolateBlues").unwrap();
+        assert!(!blues.has_shader());
+        assert_eq!(blues.to_shader_value(), 0.0);
+
+        // Blues runs from near white to dark blue
+        let light = blues.sample(0.0);
+        let dark = blues.sample(1.0);
+        assert!(light.x > 0.95 && light.z > 0.95);
+        assert!(dark.x < 0.05 && dark.z > 0.4);
+        assert_eq!(blues.sample(2.0), dark);
+    }
+
+    #[test]
+    fn test_scheme_cpu_fallback() {
+        assert_eq!(Colormap::Turbo.cpu_rgba(0.5), None);
+
+        // Registry schemes are baked on the CPU instead of drawing as viridis
+        for name in ["RdBu", "cividis", "Greens"] {
+            let scheme = Colormap::from_name(name).unwrap();
+            for t in [0.0, 1.0] {
+                let baked = scheme.cpu_rgba(t).unwrap();
+                assert_eq!(baked, scheme.sample_rgba(t));
+                assert_ne!(baked, Colormap::Viridis.sample_rgba(t), "{} at {}", name, t);
+            }
+        }
     }
 
     #[test]
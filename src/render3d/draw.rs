@@ -14,6 +14,8 @@
 
 use makepad_widgets::*;
 
+use super::colormap::Colormap;
+
 script_mod! {
     use mod.pod.*
     use mod.math.*
@@ -26,9 +28,10 @@ script_mod! {
         ..mod.draw.DrawQuad
 
         pixel: fn(){
-            // Sample colormap based on data value
+            // Sample colormap based on data value, or use the color
+            // baked on the CPU for colormaps without a shader
             let t = clamp(self.data_value, 0.0, 1.0);
-            let base_color = self.apply_colormap(t);
+            let base_color = mix(self.color.rgb, self.apply_colormap(t), self.use_colormap);
 
             // Normal vector (interpolated across surface)
             let normal = normalize(vec3(
@@ -276,9 +279,8 @@ script_mod! {
         ..mod.draw.DrawQuad
 
         pixel: fn(){
-            // Base color from colormap
-            let t = clamp(self.data_value, 0.0, 1.0);
-            let base_color = self.apply_colormap(t);
+            // Base color, sampled from the colormap on the CPU
+            let base_color = self.color.rgb;
 
             // Different shading per face: 0 = top (brightest),
             // 1 = front (medium), 2 = right side (darkest)
@@ -290,22 +292,6 @@ script_mod! {
             }
             return vec4(base_color * 0.7, 1.0);
         }
-
-        apply_colormap: fn(t){
-            // Simplified viridis for bars
-            let c0 = vec3(0.267, 0.004, 0.329);
-            let c1 = vec3(0.254, 0.265, 0.530);
-            let c2 = vec3(0.134, 0.658, 0.517);
-            let c3 = vec3(0.993, 0.906, 0.144);
-
-            let t3 = t * 3.0;
-            let i = floor(t3);
-            let f = fract(t3);
-
-            if i < 1.0 { return mix(c0, c1, f); }
-            if i < 2.0 { return mix(c1, c2, f); }
-            return mix(c2, c3, f);
-        }
     }
 
     // Grid plane for 3D scene reference
@@ -416,6 +402,10 @@ script_mod! {
 #[derive(Script, ScriptHook)]
 #[repr(C)]
 pub struct DrawSurface3D {
+    /// Colormap used to bake `color` when it has no shader
+    #[rust]
+    colormap: Colormap,
+
     /// Base quad shader state
     #[deref]
     pub draw_super: DrawQuad,
@@ -438,6 +428,14 @@ pub struct DrawSurface3D {
     #[live]
     pub colormap_type: f32,
 
+    /// Surface color sampled on the CPU, used when `use_colormap` is 0
+    #[live]
+    pub color: Vec4f,
+
+    /// Use the shader colormap instead of `color`
+    #[live(1.0)]
+    pub use_colormap: f32,
+
     /// Ambient light strength
     #[live(0.3)]
     pub ambient: f32,
@@ -472,11 +470,20 @@ impl DrawSurface3D {
     /// Set the data value for colormap lookup
     pub fn set_data_value(&mut self, value: f32) {
         self.data_value = value.clamp(0.0, 1.0);
+        if let Some([x, y, z, w]) = self.colormap.cpu_rgba(self.data_value) {
+            self.color = Vec4f { x, y, z, w };
+        }
     }
 
-    /// Set the colormap type
-    pub fn set_colormap(&mut self, colormap: super::colormap::Colormap) {
+    /// Set the colormap
+    ///
+    /// Colormaps without a shader, such as registry schemes, are sampled
+    /// on the CPU for each data value.
+    pub fn set_colormap(&mut self, colormap: Colormap) {
+        self.colormap = colormap;
         self.colormap_type = colormap.to_shader_value();
+        self.use_colormap = if colormap.has_shader() { 1.0 } else { 0.0 };
+        self.set_data_value(self.data_value);
     }
 
     /// Set lighting parameters
@@ -601,7 +608,14 @@ impl DrawPoint3D {
     }
 
     /// Set point color from data value using colormap
-    pub fn set_data_color(&mut self, value: f32, colormap: super::colormap::Colormap) {
+    ///
+    /// Colormaps without a shader are sampled here and set as a direct color.
+    pub fn set_data_color(&mut self, value: f32, colormap: Colormap) {
+        if !colormap.has_shader() {
+            let [x, y, z, w] = colormap.sample_rgba(value);
+            self.set_color(Vec4f { x, y, z, w });
+            return;
+        }
         self.data_value = value;
         self.colormap_type = colormap.to_shader_value();
         self.use_colormap = 1.0;
@@ -612,6 +626,10 @@ impl DrawPoint3D {
 #[derive(Script, ScriptHook)]
 #[repr(C)]
 pub struct DrawBar3D {
+    /// Colormap sampled for each bar
+    #[rust]
+    colormap: Colormap,
+
     /// Base quad shader state
     #[deref]
     pub draw_super: DrawQuad,
@@ -627,12 +645,18 @@ pub struct DrawBar3D {
     /// Colormap type
     #[live]
     pub colormap_type: f32,
+
+    /// Bar color sampled from the colormap
+    #[live]
+    pub color: Vec4f,
 }
 
 impl DrawBar3D {
     /// Draw a bar face
     pub fn draw_face(&mut self, cx: &mut Cx2d, rect: Rect, face: BarFace, value: f32) {
         self.data_value = value;
+        let [x, y, z, w] = self.colormap.sample_rgba(value);
+        self.color = Vec4f { x, y, z, w };
         self.face_type = match face {
             BarFace::Top => 0.0,
             BarFace::Front => 1.0,
@@ -642,7 +666,11 @@ impl DrawBar3D {
     }
 
     /// Set colormap
-    pub fn set_colormap(&mut self, colormap: super::colormap::Colormap) {
+    ///
+    /// Bar colors are always sampled on the CPU, so every colormap,
+    /// including registry schemes, is honoured.
+    pub fn set_colormap(&mut self, colormap: Colormap) {
+        self.colormap = colormap;
         self.colormap_type = colormap.to_shader_value();
     }
 }
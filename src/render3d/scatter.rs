@@ -22,6 +22,7 @@
 use super::camera::{Camera3D, CameraController, CameraEvent};
 use super::colormap::Colormap;
 use super::types::Vec3;
+use crate::shape::{SymbolScale, SymbolType};
 
 /// A single point in 3D scatter plot
 #[derive(Clone, Debug)]
@@ -40,6 +41,8 @@ pub struct ScatterPoint3D {
     pub color: Option<[f32; 4]>,
     /// Optional label
     pub label: Option<String>,
+    /// Optional marker symbol (overrides the symbol scale)
+    pub symbol: Option<SymbolType>,
 }
 
 impl ScatterPoint3D {
@@ -53,6 +56,7 @@ impl ScatterPoint3D {
             size: None,
             color: None,
             label: None,
+            symbol: None,
         }
     }
 
@@ -79,6 +83,12 @@ impl ScatterPoint3D {
         self.label = Some(label.into());
         self
     }
+
+    /// Set marker symbol
+    pub fn with_symbol(mut self, symbol: SymbolType) -> Self {
+        self.symbol = Some(symbol);
+        self
+    }
 }
 
 /// Projected point data for rendering
@@ -94,6 +104,8 @@ pub struct ProjectedPoint {
     pub size: f64,
     /// Color (RGBA)
     pub color: [f32; 4],
+    /// Marker symbol
+    pub symbol: SymbolType,
     /// Original point index
     pub index: usize,
 }
@@ -119,6 +131,9 @@ pub struct Scatter3D {
     /// Opacity
     pub opacity: f32,
 
+    /// Symbol scale keyed by point label
+    pub symbol_scale: Option<SymbolScale>,
+
     /// Data bounds (computed from points)
     bounds_min: [f64; 3],
     bounds_max: [f64; 3],
@@ -148,6 +163,7 @@ impl Scatter3D {
             point_size: 8.0,
             perspective_scaling: true,
             opacity: 1.0,
+            symbol_scale: None,
             bounds_min: [0.0, 0.0, 0.0],
             bounds_max: [1.0, 1.0, 1.0],
             needs_bounds_update: true,
@@ -187,6 +203,14 @@ impl Scatter3D {
         self.colormap = colormap;
     }
 
+    /// Assign marker symbols by point label
+    ///
+    /// Share the scale with 2D scatter series and legends so the same
+    /// category is drawn with the same symbol everywhere.
+    pub fn set_symbol_scale(&mut self, scale: SymbolScale) {
+        self.symbol_scale = Some(scale);
+    }
+
     /// Set base point size
     pub fn set_point_size(&mut self, size: f64) {
         self.point_size = size;
@@ -314,12 +338,21 @@ impl Scatter3D {
                 [rgb.x, rgb.y, rgb.z, self.opacity]
             };
 
+            let symbol = point
+                .symbol
+                .or_else(|| {
+                    let scale = self.symbol_scale.as_ref()?;
+                    scale.symbol_for(point.label.as_deref()?)
+                })
+                .unwrap_or_default();
+
             projected.push(ProjectedPoint {
                 screen_x,
                 screen_y,
                 depth: clip.z as f64,
                 size,
                 color,
+                symbol,
                 index: idx,
             });
         }
@@ -372,6 +405,27 @@ mod tests {
         assert_eq!(p.label, Some("test".to_string()));
     }
 
+    #[test]
+    fn test_scatter3d_symbols() {
+        let mut scatter = Scatter3D::new();
+        scatter.set_symbol_scale(SymbolScale::fill().with_domain(["a", "b"]));
+        scatter.set_points(vec![
+            ScatterPoint3D::new(0.0, 0.0, 0.0).with_label("b"),
+            ScatterPoint3D::new(1.0, 1.0, 1.0)
+                .with_label("b")
+                .with_symbol(SymbolType::Star),
+            ScatterPoint3D::new(0.5, 0.5, 0.5),
+        ]);
+
+        let mut projected = scatter.get_projected_points(800.0, 600.0);
+        projected.sort_by_key(|p| p.index);
+        let symbols: Vec<SymbolType> = projected.iter().map(|p| p.symbol).collect();
+        assert_eq!(
+            symbols,
+            vec![SymbolType::Cross, SymbolType::Star, SymbolType::Circle]
+        );
+    }
+
     #[test]
     fn test_scatter3d_new() {
         let scatter = Scatter3D::new();
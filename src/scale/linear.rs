@@ -1,7 +1,8 @@
 //! Linear scale implementation
 
 use super::traits::{ContinuousScale, Scale, ScaleExt, Tick, TickOptions};
-use super::utils::{format_number, nice_bounds, nice_step};
+use super::transition::ScaleInterpolate;
+use super::utils::{format_number, lerp, nice_bounds, nice_step};
 
 /// Linear scale for continuous numeric data
 ///
@@ -224,6 +225,21 @@ impl ContinuousScale for LinearScale {
 
 impl ScaleExt for LinearScale {}
 
+impl ScaleInterpolate for LinearScale {
+    fn interpolate(&self, to: &Self, t: f64) -> Self {
+        Self {
+            domain_min: lerp(self.domain_min, to.domain_min, t),
+            domain_max: lerp(self.domain_max, to.domain_max, t),
+            range_start: lerp(self.range_start, to.range_start, t),
+            range_end: lerp(self.range_end, to.range_end, t),
+            clamp: to.clamp,
+            // The interpolated domain is already the blend of two (possibly
+            // niced) domains; re-nicing it would make the animation jump.
+            nice: false,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
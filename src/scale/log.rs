@@ -1,7 +1,8 @@
 //! Logarithmic scale implementation
 
 use super::traits::{ContinuousScale, Scale, ScaleExt, Tick, TickOptions};
-use super::utils::format_number;
+use super::transition::{lerp_geometric, ScaleInterpolate};
+use super::utils::{format_number, lerp};
 
 /// Logarithmic scale for exponential data
 ///
@@ -235,6 +236,19 @@ impl ContinuousScale for LogScale {
 
 impl ScaleExt for LogScale {}
 
+impl ScaleInterpolate for LogScale {
+    fn interpolate(&self, to: &Self, t: f64) -> Self {
+        Self {
+            domain_min: lerp_geometric(self.domain_min, to.domain_min, t),
+            domain_max: lerp_geometric(self.domain_max, to.domain_max, t),
+            range_start: lerp(self.range_start, to.range_start, t),
+            range_end: lerp(self.range_end, to.range_end, t),
+            base: to.base,
+            clamp: to.clamp,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -15,6 +15,14 @@
 //! - [`LogScale`]: Logarithmic interpolation for exponential data
 //! - [`PowScale`]: Power/polynomial interpolation
 //! - [`SymlogScale`]: Symmetric log for data crossing zero
+//! - [`RadialScale`]: Area-proportional radii for polar charts
+//! - [`PolarScale`]: Angular band + radial scale pair producing screen points
+//!
+//! [`TimeLocale`] formats and parses d3-time-format specifiers with localized
+//! names; the axis formatters re-export it.
+//!
+//! [`ScaleTransition`] and [`BandTransition`] interpolate between two scale
+//! states for animated domain/range changes.
 //!
 //! # Example
 //! ```
@@ -35,11 +43,14 @@ mod point;
 mod pow;
 mod quantile;
 mod quantize;
+mod radial;
 mod sequential;
 mod symlog;
 mod threshold;
 mod time;
+mod time_locale;
 mod traits;
+mod transition;
 mod utils;
 
 pub use band::BandScale;
@@ -50,9 +61,12 @@ pub use point::PointScale;
 pub use pow::PowScale;
 pub use quantile::QuantileScale;
 pub use quantize::QuantizeScale;
+pub use radial::{PolarScale, RadialScale};
 pub use sequential::{interpolators, SequentialScale};
 pub use symlog::SymlogScale;
 pub use threshold::ThresholdScale;
 pub use time::{TimeInterval, TimeScale, TimeTick};
+pub use time_locale::{time_format, time_parse, TimeFormatter, TimeLocale, TimeParser};
 pub use traits::{ContinuousScale, DiscreteScale, Scale, ScaleExt, Tick, TickOptions};
+pub use transition::{BandTransition, ScaleInterpolate, ScaleTransition};
 pub use utils::{format_number, nice_bounds, nice_step};
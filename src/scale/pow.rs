@@ -1,7 +1,8 @@
 //! Power scale implementation
 
 use super::traits::{ContinuousScale, Scale, ScaleExt, Tick, TickOptions};
-use super::utils::{format_number, nice_bounds, nice_step};
+use super::transition::ScaleInterpolate;
+use super::utils::{format_number, lerp, nice_bounds, nice_step};
 
 /// Power scale for polynomial interpolation
 ///
@@ -231,6 +232,19 @@ impl ContinuousScale for PowScale {
 
 impl ScaleExt for PowScale {}
 
+impl ScaleInterpolate for PowScale {
+    fn interpolate(&self, to: &Self, t: f64) -> Self {
+        Self {
+            domain_min: lerp(self.domain_min, to.domain_min, t),
+            domain_max: lerp(self.domain_max, to.domain_max, t),
+            range_start: lerp(self.range_start, to.range_start, t),
+            range_end: lerp(self.range_end, to.range_end, t),
+            exponent: lerp(self.exponent, to.exponent, t),
+            clamp: to.clamp,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
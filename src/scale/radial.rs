@@ -0,0 +1,549 @@
+//! Radial scale implementation
+//!
+//! Radial scales map a linear domain to a radius so that the *area* of the
+//! encoded mark (a circle, a radial bar, a sunburst ring) grows linearly
+//! with the value. Also provides [`PolarScale`], which pairs an angular
+//! [`BandScale`] with a [`RadialScale`] for radar and radial bar charts.
+
+use super::band::BandScale;
+use super::traits::{ContinuousScale, DiscreteScale, Scale, ScaleExt, Tick, TickOptions};
+use super::transition::ScaleInterpolate;
+use super::utils::{format_number, lerp, nice_bounds, nice_step};
+use crate::shape::Point;
+use std::f64::consts::TAU;
+
+/// Radial scale for area-proportional radii
+///
+/// A variant of the linear scale where the range is internally squared, so
+/// that the returned radius is the square root of the linearly interpolated
+/// area. Negative values are handled symmetrically.
+///
+/// # D3.js Equivalent
+/// This is equivalent to `d3.scaleRadial()` in D3.js.
+///
+/// # Example
+/// ```
+/// use makepad_d3::scale::{Scale, RadialScale, ScaleExt};
+///
+/// let scale = RadialScale::new()
+///     .with_domain(0.0, 100.0)
+///     .with_range(0.0, 10.0);
+///
+/// // Half the value → half the area → radius 10 / sqrt(2)
+/// assert!((scale.scale(50.0) - 10.0 / 2f64.sqrt()).abs() < 1e-9);
+/// assert!((scale.invert(scale.scale(50.0)) - 50.0).abs() < 1e-9);
+/// ```
+#[derive(Clone, Debug)]
+pub struct RadialScale {
+    domain_min: f64,
+    domain_max: f64,
+    range_start: f64,
+    range_end: f64,
+    clamp: bool,
+    round: bool,
+}
+
+impl RadialScale {
+    /// Create a new radial scale with default domain [0, 1] and range [0, 1]
+    pub fn new() -> Self {
+        Self {
+            domain_min: 0.0,
+            domain_max: 1.0,
+            range_start: 0.0,
+            range_end: 1.0,
+            clamp: false,
+            round: false,
+        }
+    }
+
+    /// Enable clamping
+    pub fn with_clamp(mut self, clamp: bool) -> Self {
+        self.clamp = clamp;
+        self
+    }
+
+    /// Enable or disable rounding output radii to integers
+    pub fn with_round(mut self, round: bool) -> Self {
+        self.round = round;
+        self
+    }
+
+    /// Extend domain to "nice" round values if `nice` is true (for chaining)
+    pub fn with_nice(mut self, nice: bool) -> Self {
+        if nice {
+            self.nice();
+        }
+        self
+    }
+
+    /// Check whether output radii are rounded
+    pub fn is_rounded(&self) -> bool {
+        self.round
+    }
+
+    /// Signed square of a radius
+    fn square(x: f64) -> f64 {
+        x.signum() * x * x
+    }
+
+    /// Signed square root of an area
+    fn sqrt(x: f64) -> f64 {
+        x.signum() * x.abs().sqrt()
+    }
+}
+
+impl Default for RadialScale {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Scale for RadialScale {
+    fn scale_type(&self) -> &'static str {
+        "radial"
+    }
+
+    fn set_domain(&mut self, min: f64, max: f64) {
+        self.domain_min = min;
+        self.domain_max = max;
+    }
+
+    fn set_range(&mut self, start: f64, end: f64) {
+        self.range_start = start;
+        self.range_end = end;
+    }
+
+    fn domain(&self) -> (f64, f64) {
+        (self.domain_min, self.domain_max)
+    }
+
+    fn range(&self) -> (f64, f64) {
+        (self.range_start, self.range_end)
+    }
+
+    fn scale(&self, value: f64) -> f64 {
+        let value = if self.clamp {
+            value.clamp(
+                self.domain_min.min(self.domain_max),
+                self.domain_min.max(self.domain_max),
+            )
+        } else {
+            value
+        };
+
+        let t = self.normalize(value);
+        let area_start = Self::square(self.range_start);
+        let area_end = Self::square(self.range_end);
+        let radius = Self::sqrt(area_start + t * (area_end - area_start));
+
+        if self.round {
+            radius.round()
+        } else {
+            radius
+        }
+    }
+
+    fn invert(&self, radius: f64) -> f64 {
+        let area_start = Self::square(self.range_start);
+        let area_end = Self::square(self.range_end);
+        let area_span = area_end - area_start;
+        if area_span.abs() < f64::EPSILON {
+            return self.domain_min;
+        }
+
+        let t = (Self::square(radius) - area_start) / area_span;
+        self.domain_min + t * (self.domain_max - self.domain_min)
+    }
+
+    fn ticks(&self, options: &TickOptions) -> Vec<Tick> {
+        let span = self.domain_max - self.domain_min;
+
+        let step = options
+            .step_size
+            .unwrap_or_else(|| nice_step(span.abs(), options.count));
+
+        if step <= 0.0 {
+            return vec![];
+        }
+
+        let lo = self.domain_min.min(self.domain_max);
+        let hi = self.domain_min.max(self.domain_max);
+        let start = (lo / step).ceil() * step;
+
+        let mut ticks = Vec::new();
+        let epsilon = step * 0.0001;
+
+        let mut value = start;
+        while value <= hi + epsilon && ticks.len() < options.max_count {
+            let pos = self.scale(value);
+            ticks.push(Tick::new(value, format_number(value)).with_position(pos));
+            value += step;
+        }
+
+        ticks
+    }
+
+    fn copy_from(&mut self, other: &Self) {
+        self.domain_min = other.domain_min;
+        self.domain_max = other.domain_max;
+        self.range_start = other.range_start;
+        self.range_end = other.range_end;
+        self.clamp = other.clamp;
+        self.round = other.round;
+    }
+
+    fn clone_box(&self) -> Box<dyn Scale> {
+        Box::new(self.clone())
+    }
+}
+
+impl ContinuousScale for RadialScale {
+    fn nice(&mut self) {
+        let (nice_min, nice_max) = nice_bounds(self.domain_min, self.domain_max);
+        self.domain_min = nice_min;
+        self.domain_max = nice_max;
+    }
+
+    fn is_clamped(&self) -> bool {
+        self.clamp
+    }
+
+    fn set_clamp(&mut self, clamp: bool) {
+        self.clamp = clamp;
+    }
+}
+
+impl ScaleExt for RadialScale {}
+
+impl ScaleInterpolate for RadialScale {
+    fn interpolate(&self, to: &Self, t: f64) -> Self {
+        Self {
+            domain_min: lerp(self.domain_min, to.domain_min, t),
+            domain_max: lerp(self.domain_max, to.domain_max, t),
+            range_start: lerp(self.range_start, to.range_start, t),
+            range_end: lerp(self.range_end, to.range_end, t),
+            clamp: to.clamp,
+            round: to.round,
+        }
+    }
+}
+
+/// Polar coordinate helper for radar and radial bar charts
+///
+/// Combines an angular [`BandScale`] (categories around the circle) with a
+/// [`RadialScale`] (values outward from the center). Angles follow the D3
+/// convention used by `lineRadial` and `arc`: 0 is at 12 o'clock and angles
+/// increase clockwise in screen coordinates (y down).
+///
+/// # Example
+/// ```
+/// use makepad_d3::scale::{PolarScale, RadialScale, Scale, ScaleExt};
+/// use makepad_d3::shape::Point;
+///
+/// let polar = PolarScale::new()
+///     .with_categories(vec!["N", "E", "S", "W"])
+///     .with_radial(RadialScale::new().with_domain(0.0, 100.0).with_range(0.0, 50.0))
+///     .with_center(Point::new(100.0, 100.0));
+///
+/// // Category angles start at 12 o'clock (no band alignment offset)
+/// let p = polar.point_at(0, 100.0);
+/// assert!((p.x - 100.0).abs() < 1e-9);
+/// assert!((p.y - 50.0).abs() < 1e-9);
+/// ```
+#[derive(Clone, Debug)]
+pub struct PolarScale {
+    angle: BandScale,
+    radius: RadialScale,
+    center: Point,
+    /// Whether points sit at the band start (radar vertices) or center
+    align_center: bool,
+}
+
+impl PolarScale {
+    /// Create a polar scale with an empty angular domain over a full turn
+    pub fn new() -> Self {
+        Self {
+            angle: BandScale::new().with_range(0.0, TAU),
+            radius: RadialScale::new(),
+            center: Point::zero(),
+            align_center: false,
+        }
+    }
+
+    /// Set the angular categories (spread evenly over a full turn)
+    pub fn with_categories<S: Into<String>>(mut self, values: impl IntoIterator<Item = S>) -> Self {
+        self.angle = self.angle.with_domain(values);
+        self
+    }
+
+    /// Replace the angular band scale
+    ///
+    /// The band scale's range is interpreted in radians.
+    pub fn with_angle(mut self, angle: BandScale) -> Self {
+        self.angle = angle;
+        self
+    }
+
+    /// Replace the radial scale
+    pub fn with_radial(mut self, radius: RadialScale) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    /// Set the polar origin in screen coordinates
+    pub fn with_center(mut self, center: Point) -> Self {
+        self.center = center;
+        self
+    }
+
+    /// Place points at the center of each angular band instead of its start
+    ///
+    /// Radar charts want vertices at the band start (the default); radial
+    /// bar charts want the bar axis at the band center.
+    pub fn with_align_center(mut self, align_center: bool) -> Self {
+        self.align_center = align_center;
+        self
+    }
+
+    /// Get the angular band scale
+    pub fn angle_scale(&self) -> &BandScale {
+        &self.angle
+    }
+
+    /// Get the radial scale
+    pub fn radial_scale(&self) -> &RadialScale {
+        &self.radius
+    }
+
+    /// Get the polar origin
+    pub fn center(&self) -> Point {
+        self.center
+    }
+
+    /// Angle in radians for a category index
+    pub fn angle_at(&self, index: usize) -> f64 {
+        if self.align_center {
+            self.angle.center(index)
+        } else {
+            self.angle.scale_index(index)
+        }
+    }
+
+    /// Angle in radians for a category by name
+    pub fn angle_of(&self, category: &str) -> Option<f64> {
+        self.angle.index_of(category).map(|i| self.angle_at(i))
+    }
+
+    /// Angular width of each band in radians
+    pub fn bandwidth(&self) -> f64 {
+        self.angle.bandwidth()
+    }
+
+    /// Convert an angle (radians) and radius to a screen point
+    pub fn to_point(&self, angle: f64, radius: f64) -> Point {
+        Point::new(
+            self.center.x + radius * angle.sin(),
+            self.center.y - radius * angle.cos(),
+        )
+    }
+
+    /// Screen point for a category index and domain value
+    pub fn point_at(&self, index: usize, value: f64) -> Point {
+        self.to_point(self.angle_at(index), self.radius.scale(value))
+    }
+
+    /// Screen point for a category name and domain value
+    ///
+    /// Returns `None` if the category is not in the angular domain.
+    pub fn point(&self, category: &str, value: f64) -> Option<Point> {
+        self.angle
+            .index_of(category)
+            .map(|i| self.point_at(i, value))
+    }
+
+    /// Screen points for a series of values, one per category in order
+    pub fn points(&self, values: &[f64]) -> Vec<Point> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, &v)| self.point_at(i, v))
+            .collect()
+    }
+
+    /// Invert a screen point to (category index, domain value)
+    ///
+    /// Returns `None` if the angular domain is empty.
+    pub fn invert(&self, point: Point) -> Option<(usize, f64)> {
+        let dx = point.x - self.center.x;
+        let dy = point.y - self.center.y;
+        let mut angle = dx.atan2(-dy);
+        if angle < 0.0 {
+            angle += TAU;
+        }
+        if !self.align_center {
+            // Vertices sit at band starts: snap to the nearest one
+            angle += self.angle.step() / 2.0;
+            if angle >= TAU {
+                angle -= TAU;
+            }
+        }
+        let index = self.angle.invert_index(angle)?;
+        let radius = (dx * dx + dy * dy).sqrt();
+        Some((index, self.radius.invert(radius)))
+    }
+}
+
+impl Default for PolarScale {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_radial_scale_new() {
+        let scale = RadialScale::new();
+        assert_eq!(scale.domain(), (0.0, 1.0));
+        assert_eq!(scale.range(), (0.0, 1.0));
+        assert_eq!(scale.scale_type(), "radial");
+    }
+
+    #[test]
+    fn test_radial_scale_area_linear() {
+        let scale = RadialScale::new()
+            .with_domain(0.0, 100.0)
+            .with_range(0.0, 10.0);
+
+        assert!((scale.scale(0.0) - 0.0).abs() < 1e-9);
+        assert!((scale.scale(25.0) - 5.0).abs() < 1e-9);
+        assert!((scale.scale(100.0) - 10.0).abs() < 1e-9);
+
+        // Area (r²) is proportional to value
+        let a1 = scale.scale(20.0).powi(2);
+        let a2 = scale.scale(40.0).powi(2);
+        assert!((a2 / a1 - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_radial_scale_inner_radius() {
+        let scale = RadialScale::new()
+            .with_domain(0.0, 1.0)
+            .with_range(30.0, 40.0);
+
+        assert!((scale.scale(0.0) - 30.0).abs() < 1e-9);
+        assert!((scale.scale(1.0) - 40.0).abs() < 1e-9);
+        // Midpoint area between 900 and 1600 is 1250
+        assert!((scale.scale(0.5) - 1250f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_radial_scale_invert_roundtrip() {
+        let scale = RadialScale::new()
+            .with_domain(0.0, 100.0)
+            .with_range(10.0, 200.0);
+
+        for &value in &[0.0, 12.5, 50.0, 99.0, 100.0] {
+            let r = scale.scale(value);
+            assert!((scale.invert(r) - value).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_radial_scale_negative_domain() {
+        let scale = RadialScale::new()
+            .with_domain(-1.0, 1.0)
+            .with_range(-10.0, 10.0);
+
+        assert!((scale.scale(0.0)).abs() < 1e-9);
+        assert!((scale.scale(-0.5) + scale.scale(0.5)).abs() < 1e-9);
+        assert!((scale.invert(scale.scale(-0.5)) + 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_radial_scale_clamp_and_round() {
+        let scale = RadialScale::new()
+            .with_domain(0.0, 100.0)
+            .with_range(0.0, 10.0)
+            .with_clamp(true)
+            .with_round(true);
+
+        assert_eq!(scale.scale(-50.0), 0.0);
+        assert_eq!(scale.scale(500.0), 10.0);
+        assert_eq!(scale.scale(50.0), 7.0);
+    }
+
+    #[test]
+    fn test_radial_scale_ticks() {
+        let scale = RadialScale::new()
+            .with_domain(0.0, 100.0)
+            .with_range(0.0, 10.0);
+
+        let ticks = scale.ticks(&TickOptions::new().with_count(5));
+        assert_eq!(ticks.first().unwrap().value, 0.0);
+        assert_eq!(ticks.last().unwrap().value, 100.0);
+        assert!((ticks[1].position - scale.scale(ticks[1].value)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_radial_scale_nice() {
+        let scale = RadialScale::new().with_domain(3.2, 97.8).with_nice(true);
+        assert_eq!(scale.domain(), (0.0, 100.0));
+
+        let scale = RadialScale::new().with_domain(3.2, 97.8).with_nice(false);
+        assert_eq!(scale.domain(), (3.2, 97.8));
+    }
+
+    #[test]
+    fn test_polar_scale_points() {
+        let polar = PolarScale::new()
+            .with_categories(vec!["N", "E", "S", "W"])
+            .with_radial(
+                RadialScale::new()
+                    .with_domain(0.0, 1.0)
+                    .with_range(0.0, 10.0),
+            );
+
+        let n = polar.point("N", 1.0).unwrap();
+        let e = polar.point("E", 1.0).unwrap();
+        let s = polar.point("S", 1.0).unwrap();
+        assert!(n.x.abs() < 1e-9 && (n.y + 10.0).abs() < 1e-9);
+        assert!((e.x - 10.0).abs() < 1e-9 && e.y.abs() < 1e-9);
+        assert!(s.x.abs() < 1e-9 && (s.y - 10.0).abs() < 1e-9);
+        assert!(polar.point("X", 1.0).is_none());
+        assert_eq!(polar.points(&[1.0, 1.0, 1.0, 1.0]).len(), 4);
+    }
+
+    #[test]
+    fn test_polar_scale_align_center() {
+        let polar = PolarScale::new()
+            .with_categories(vec!["A", "B"])
+            .with_align_center(true);
+
+        assert!((polar.angle_of("A").unwrap() - TAU / 4.0).abs() < 1e-9);
+        assert!((polar.bandwidth() - TAU / 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_polar_scale_invert() {
+        let polar = PolarScale::new()
+            .with_categories(vec!["N", "E", "S", "W"])
+            .with_radial(
+                RadialScale::new()
+                    .with_domain(0.0, 100.0)
+                    .with_range(0.0, 50.0),
+            )
+            .with_center(Point::new(100.0, 100.0));
+
+        let p = polar.point_at(2, 36.0);
+        let (index, value) = polar.invert(p).unwrap();
+        assert_eq!(index, 2);
+        assert!((value - 36.0).abs() < 1e-9);
+
+        // Slightly counter-clockwise of N still snaps to N
+        let (index, _) = polar.invert(Point::new(99.0, 60.0)).unwrap();
+        assert_eq!(index, 0);
+    }
+}
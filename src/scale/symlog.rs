@@ -1,7 +1,8 @@
 //! Symmetric logarithmic scale implementation
 
 use super::traits::{ContinuousScale, Scale, ScaleExt, Tick, TickOptions};
-use super::utils::{format_number, nice_step};
+use super::transition::ScaleInterpolate;
+use super::utils::{format_number, lerp, nice_step};
 
 /// Symmetric logarithmic scale for data that crosses zero
 ///
@@ -245,6 +246,19 @@ impl ContinuousScale for SymlogScale {
 
 impl ScaleExt for SymlogScale {}
 
+impl ScaleInterpolate for SymlogScale {
+    fn interpolate(&self, to: &Self, t: f64) -> Self {
+        Self {
+            domain_min: lerp(self.domain_min, to.domain_min, t),
+            domain_max: lerp(self.domain_max, to.domain_max, t),
+            range_start: lerp(self.range_start, to.range_start, t),
+            range_end: lerp(self.range_end, to.range_end, t),
+            constant: lerp(self.constant, to.constant, t),
+            clamp: to.clamp,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,6 +1,9 @@
 //! Time scale implementation
 
+use super::time_locale::TimeLocale;
 use super::traits::{ContinuousScale, Scale, Tick, TickOptions};
+use super::transition::ScaleInterpolate;
+use super::utils::lerp;
 use chrono::{DateTime, Datelike, Duration, Timelike, Utc};
 
 /// Time interval for tick generation
@@ -142,6 +145,7 @@ pub struct TimeScale {
     range_end: f64,
     clamp: bool,
     format: Option<String>,
+    locale: Option<TimeLocale>,
 }
 
 impl TimeScale {
@@ -155,6 +159,7 @@ impl TimeScale {
             range_end: 100.0,
             clamp: false,
             format: None,
+            locale: None,
         }
     }
 
@@ -191,6 +196,14 @@ impl TimeScale {
         self
     }
 
+    /// Format tick labels with a locale's month, day and period names
+    ///
+    /// Tick formats are then interpreted as d3-time-format specifiers.
+    pub fn with_locale(mut self, locale: TimeLocale) -> Self {
+        self.locale = Some(locale);
+        self
+    }
+
     /// Get domain start
     pub fn domain_start(&self) -> DateTime<Utc> {
         self.domain_start
@@ -274,7 +287,10 @@ impl TimeScale {
         while current <= domain_max && ticks.len() < options.max_count {
             if current >= domain_min {
                 let pos = self.scale_time(current);
-                let label = current.format(format).to_string();
+                let label = match &self.locale {
+                    Some(locale) => locale.format(format, current),
+                    None => current.format(format).to_string(),
+                };
                 ticks.push(TimeTick {
                     time: current,
                     value: current.timestamp_millis() as f64,
@@ -455,6 +471,7 @@ impl Scale for TimeScale {
         self.range_end = other.range_end;
         self.clamp = other.clamp;
         self.format = other.format.clone();
+        self.locale = other.locale.clone();
     }
 
     fn clone_box(&self) -> Box<dyn Scale> {
@@ -479,6 +496,32 @@ impl ContinuousScale for TimeScale {
     }
 }
 
+impl ScaleInterpolate for TimeScale {
+    fn interpolate(&self, to: &Self, t: f64) -> Self {
+        let start_ms = lerp(
+            self.domain_start.timestamp_millis() as f64,
+            to.domain_start.timestamp_millis() as f64,
+            t,
+        );
+        let end_ms = lerp(
+            self.domain_end.timestamp_millis() as f64,
+            to.domain_end.timestamp_millis() as f64,
+            t,
+        );
+        Self {
+            domain_start: DateTime::from_timestamp_millis(start_ms.round() as i64)
+                .unwrap_or(to.domain_start),
+            domain_end: DateTime::from_timestamp_millis(end_ms.round() as i64)
+                .unwrap_or(to.domain_end),
+            range_start: lerp(self.range_start, to.range_start, t),
+            range_end: lerp(self.range_end, to.range_end, t),
+            clamp: to.clamp,
+            format: to.format.clone(),
+            locale: to.locale.clone(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -628,4 +671,23 @@ mod tests {
         assert!((TimeInterval::Minute(1).duration_ms() - 60000.0).abs() < 0.1);
         assert!((TimeInterval::Hour(1).duration_ms() - 3600000.0).abs() < 0.1);
     }
+
+    #[test]
+    fn test_locale_ticks_from_parsed_domain() {
+        use crate::axis::time_parse;
+
+        let parser = time_parse("%d.%m.%Y");
+        let (start, end) = parser
+            .parse_extent(["15.03.2024", "01.01.2024", "30.06.2024"])
+            .unwrap();
+        let scale = TimeScale::new()
+            .with_time_domain(start, end)
+            .with_range(0.0, 600.0)
+            .with_locale(TimeLocale::fr_fr())
+            .with_format("%B");
+
+        let ticks = scale.time_ticks(&TickOptions::default());
+        assert_eq!(ticks.first().unwrap().label, "janvier");
+        assert!(ticks.iter().any(|t| t.label == "février"));
+    }
 }
@@ -0,0 +1,966 @@
+//! Localized d3-time-format formatting and parsing
+//!
+//! [`TimeLocale`] holds month and day names, AM/PM markers and the locale's
+//! date/time orders. It formats and parses the d3-time-format directives
+//! (`%Y-%m-%d`, `%B %e`, `%I:%M %p`, ...) so that the spec strings used for
+//! axis labels can also parse CSV date columns. All times are UTC.
+//!
+//! Supported directives: `%a %A %b %B %c %d %e %f %g %G %H %I %j %L %m %M
+//! %p %q %Q %s %S %u %U %V %w %W %x %X %y %Y %Z %%`, with the `0`, `_` and
+//! `-` padding modifiers. chrono's `%3f`/`%6f`/`%9f` fractional seconds are
+//! also accepted so existing [`TimeFormat`](crate::axis::TimeFormat) strings work
+//! unchanged. `%g` and `%G` are format-only.
+//!
+//! # Example
+//! ```
+//! use makepad_d3::scale::{time_parse, TimeLocale};
+//! use chrono::{TimeZone, Utc};
+//!
+//! let dt = Utc.with_ymd_and_hms(2024, 3, 5, 14, 30, 0).unwrap();
+//! let de = TimeLocale::de_de();
+//! assert_eq!(de.format("%A, %e. %B %Y", dt), "Dienstag,  5. März 2024");
+//!
+//! let parser = time_parse("%Y-%m-%d");
+//! let parsed = parser.parse("2024-03-05").unwrap();
+//! assert_eq!(parsed, Utc.with_ymd_and_hms(2024, 3, 5, 0, 0, 0).unwrap());
+//! ```
+
+use crate::error::{D3Error, D3Result};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Timelike, Utc, Weekday};
+
+/// Locale definition for time formatting and parsing
+///
+/// Mirrors d3's `timeFormatLocale` definition object.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimeLocale {
+    /// Date and time format used by `%c`
+    pub date_time: String,
+    /// Date format used by `%x`
+    pub date: String,
+    /// Time format used by `%X`
+    pub time: String,
+    /// AM and PM markers used by `%p`
+    pub periods: [String; 2],
+    /// Full weekday names, starting on Sunday (`%A`)
+    pub days: [String; 7],
+    /// Abbreviated weekday names, starting on Sunday (`%a`)
+    pub short_days: [String; 7],
+    /// Full month names (`%B`)
+    pub months: [String; 12],
+    /// Abbreviated month names (`%b`)
+    pub short_months: [String; 12],
+}
+
+impl Default for TimeLocale {
+    fn default() -> Self {
+        Self::en_us()
+    }
+}
+
+/// Convert a fixed-size array of string slices to owned strings
+fn owned<const N: usize>(names: [&str; N]) -> [String; N] {
+    names.map(str::to_string)
+}
+
+impl TimeLocale {
+    /// Build a locale from its formats and name tables
+    #[allow(clippy::too_many_arguments)]
+    fn define(
+        date_time: &str,
+        date: &str,
+        time: &str,
+        periods: [&str; 2],
+        days: [&str; 7],
+        short_days: [&str; 7],
+        months: [&str; 12],
+        short_months: [&str; 12],
+    ) -> Self {
+        Self {
+            date_time: date_time.to_string(),
+            date: date.to_string(),
+            time: time.to_string(),
+            periods: owned(periods),
+            days: owned(days),
+            short_days: owned(short_days),
+            months: owned(months),
+            short_months: owned(short_months),
+        }
+    }
+
+    /// English (United States)
+    pub fn en_us() -> Self {
+        Self::define(
+            "%x, %X",
+            "%-m/%-d/%Y",
+            "%-I:%M:%S %p",
+            ["AM", "PM"],
+            [
+                "Sunday",
+                "Monday",
+                "Tuesday",
+                "Wednesday",
+                "Thursday",
+                "Friday",
+                "Saturday",
+            ],
+            ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"],
+            [
+                "January",
+                "February",
+                "March",
+                "April",
+                "May",
+                "June",
+                "July",
+                "August",
+                "September",
+                "October",
+                "November",
+                "December",
+            ],
+            [
+                "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+            ],
+        )
+    }
+
+    /// English (United Kingdom)
+    pub fn en_gb() -> Self {
+        let mut locale = Self::en_us();
+        locale.date_time = "%a %e %b %X %Y".to_string();
+        locale.date = "%d/%m/%Y".to_string();
+        locale.time = "%H:%M:%S".to_string();
+        locale
+    }
+
+    /// German (Germany)
+    pub fn de_de() -> Self {
+        Self::define(
+            "%A, der %e. %B %Y, %X",
+            "%d.%m.%Y",
+            "%H:%M:%S",
+            ["AM", "PM"],
+            [
+                "Sonntag",
+                "Montag",
+                "Dienstag",
+                "Mittwoch",
+                "Donnerstag",
+                "Freitag",
+                "Samstag",
+            ],
+            ["So", "Mo", "Di", "Mi", "Do", "Fr", "Sa"],
+            [
+                "Januar",
+                "Februar",
+                "März",
+                "April",
+                "Mai",
+                "Juni",
+                "Juli",
+                "August",
+                "September",
+                "Oktober",
+                "November",
+                "Dezember",
+            ],
+            [
+                "Jan", "Feb", "Mrz", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez",
+            ],
+        )
+    }
+
+    /// French (France)
+    pub fn fr_fr() -> Self {
+        Self::define(
+            "%A %e %B %Y à %X",
+            "%d/%m/%Y",
+            "%H:%M:%S",
+            ["AM", "PM"],
+            [
+                "dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi",
+            ],
+            ["dim.", "lun.", "mar.", "mer.", "jeu.", "ven.", "sam."],
+            [
+                "janvier",
+                "février",
+                "mars",
+                "avril",
+                "mai",
+                "juin",
+                "juillet",
+                "août",
+                "septembre",
+                "octobre",
+                "novembre",
+                "décembre",
+            ],
+            [
+                "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.",
+                "nov.", "déc.",
+            ],
+        )
+    }
+
+    /// Spanish (Spain)
+    pub fn es_es() -> Self {
+        Self::define(
+            "%A, %e de %B de %Y, %X",
+            "%d/%m/%Y",
+            "%H:%M:%S",
+            ["AM", "PM"],
+            [
+                "domingo",
+                "lunes",
+                "martes",
+                "miércoles",
+                "jueves",
+                "viernes",
+                "sábado",
+            ],
+            ["dom", "lun", "mar", "mié", "jue", "vie", "sáb"],
+            [
+                "enero",
+                "febrero",
+                "marzo",
+                "abril",
+                "mayo",
+                "junio",
+                "julio",
+                "agosto",
+                "septiembre",
+                "octubre",
+                "noviembre",
+                "diciembre",
+            ],
+            [
+                "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sep", "oct", "nov", "dic",
+            ],
+        )
+    }
+
+    /// Japanese (Japan)
+    pub fn ja_jp() -> Self {
+        let months = [
+            "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+        ];
+        Self::define(
+            "%x %a %X",
+            "%Y/%m/%d",
+            "%H:%M:%S",
+            ["午前", "午後"],
+            [
+                "日曜日",
+                "月曜日",
+                "火曜日",
+                "水曜日",
+                "木曜日",
+                "金曜日",
+                "土曜日",
+            ],
+            ["日", "月", "火", "水", "木", "金", "土"],
+            months,
+            months,
+        )
+    }
+
+    /// Chinese (China)
+    pub fn zh_cn() -> Self {
+        let months = [
+            "一月",
+            "二月",
+            "三月",
+            "四月",
+            "五月",
+            "六月",
+            "七月",
+            "八月",
+            "九月",
+            "十月",
+            "十一月",
+            "十二月",
+        ];
+        Self::define(
+            "%x %A %X",
+            "%Y年%-m月%-d日",
+            "%H:%M:%S",
+            ["上午", "下午"],
+            [
+                "星期日",
+                "星期一",
+                "星期二",
+                "星期三",
+                "星期四",
+                "星期五",
+                "星期六",
+            ],
+            ["周日", "周一", "周二", "周三", "周四", "周五", "周六"],
+            months,
+            months,
+        )
+    }
+
+    /// Look up a built-in locale by BCP 47 tag (e.g. `"fr-FR"`, `"ja"`)
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.replace('_', "-").to_ascii_lowercase();
+        let locale = match name.as_str() {
+            "en" | "en-us" => Self::en_us(),
+            "en-gb" => Self::en_gb(),
+            "de" | "de-de" => Self::de_de(),
+            "fr" | "fr-fr" => Self::fr_fr(),
+            "es" | "es-es" => Self::es_es(),
+            "ja" | "ja-jp" => Self::ja_jp(),
+            "zh" | "zh-cn" => Self::zh_cn(),
+            _ => return None,
+        };
+        Some(locale)
+    }
+
+    /// Create a reusable formatter for a specifier
+    pub fn formatter(&self, spec: impl Into<String>) -> TimeFormatter {
+        TimeFormatter {
+            spec: spec.into(),
+            locale: self.clone(),
+        }
+    }
+
+    /// Create a reusable parser for a specifier
+    pub fn parser(&self, spec: impl Into<String>) -> TimeParser {
+        TimeParser {
+            spec: spec.into(),
+            locale: self.clone(),
+        }
+    }
+
+    /// Format a datetime with a d3-time-format specifier
+    pub fn format(&self, spec: &str, dt: DateTime<Utc>) -> String {
+        let mut out = String::new();
+        let mut chars = spec.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                out.push(c);
+                continue;
+            }
+
+            let mut pad = None;
+            let mut width = None;
+            match chars.peek() {
+                Some(&p @ ('0' | '_' | '-')) => {
+                    pad = Some(p);
+                    chars.next();
+                }
+                Some(&d @ ('3' | '6' | '9')) => {
+                    width = d.to_digit(10);
+                    chars.next();
+                }
+                _ => {}
+            }
+
+            let Some(directive) = chars.next() else {
+                out.push('%');
+                break;
+            };
+            self.format_directive(&mut out, directive, pad, width, dt);
+        }
+
+        out
+    }
+
+    /// Append one formatted directive to `out`
+    fn format_directive(
+        &self,
+        out: &mut String,
+        directive: char,
+        pad: Option<char>,
+        width: Option<u32>,
+        dt: DateTime<Utc>,
+    ) {
+        let num = |out: &mut String, value: i64, digits: usize, default_pad: char| {
+            let pad = pad.unwrap_or(default_pad);
+            let sign = if value < 0 { "-" } else { "" };
+            let s = value.abs().to_string();
+            out.push_str(sign);
+            if pad != '-' && s.len() < digits {
+                let fill = if pad == '_' { ' ' } else { '0' };
+                out.push_str(&fill.to_string().repeat(digits - s.len()));
+            }
+            out.push_str(&s);
+        };
+
+        let weekday = dt.weekday().num_days_from_sunday() as usize;
+        let yday = dt.ordinal0() as i64;
+
+        match directive {
+            'a' => out.push_str(&self.short_days[weekday]),
+            'A' => out.push_str(&self.days[weekday]),
+            'b' | 'h' => out.push_str(&self.short_months[dt.month0() as usize]),
+            'B' => out.push_str(&self.months[dt.month0() as usize]),
+            'c' => out.push_str(&self.format(&self.date_time, dt)),
+            'd' => num(out, dt.day() as i64, 2, '0'),
+            'e' => num(out, dt.day() as i64, 2, '_'),
+            'f' => match width {
+                Some(digits) => {
+                    let nanos = dt.timestamp_subsec_nanos() as i64;
+                    let value = nanos / 10_i64.pow(9 - digits);
+                    num(out, value, digits as usize, '0');
+                }
+                None => num(out, dt.timestamp_subsec_micros() as i64, 6, '0'),
+            },
+            'g' => num(out, dt.iso_week().year().rem_euclid(100) as i64, 2, '0'),
+            'G' => num(out, dt.iso_week().year() as i64, 4, '0'),
+            'H' => num(out, dt.hour() as i64, 2, '0'),
+            'I' => num(out, dt.hour12().1 as i64, 2, '0'),
+            'j' => num(out, yday + 1, 3, '0'),
+            'L' => num(out, dt.timestamp_subsec_millis() as i64, 3, '0'),
+            'm' => num(out, dt.month() as i64, 2, '0'),
+            'M' => num(out, dt.minute() as i64, 2, '0'),
+            'p' => out.push_str(&self.periods[usize::from(dt.hour() >= 12)]),
+            'q' => num(out, (dt.month0() / 3 + 1) as i64, 1, '0'),
+            'Q' => out.push_str(&dt.timestamp_millis().to_string()),
+            's' => out.push_str(&dt.timestamp().to_string()),
+            'S' => num(out, dt.second() as i64, 2, '0'),
+            'u' => num(out, dt.weekday().number_from_monday() as i64, 1, '0'),
+            'U' => num(out, (yday + 7 - weekday as i64) / 7, 2, '0'),
+            'V' => num(out, dt.iso_week().week() as i64, 2, '0'),
+            'w' => num(out, weekday as i64, 1, '0'),
+            'W' => num(out, (yday + 7 - (weekday as i64 + 6) % 7) / 7, 2, '0'),
+            'x' => out.push_str(&self.format(&self.date, dt)),
+            'X' => out.push_str(&self.format(&self.time, dt)),
+            'y' => num(out, dt.year().rem_euclid(100) as i64, 2, '0'),
+            'Y' => num(out, dt.year() as i64, 4, '0'),
+            'Z' => out.push_str("+0000"),
+            '%' => out.push('%'),
+            other => {
+                out.push('%');
+                out.push(other);
+            }
+        }
+    }
+
+    /// Parse a string with a d3-time-format specifier
+    ///
+    /// The whole input must match. Fields that are not present default to
+    /// January 1, 1900 at midnight, as in d3.
+    pub fn parse(&self, spec: &str, input: &str) -> D3Result<DateTime<Utc>> {
+        let mut fields = ParsedFields::default();
+        let consumed = self.parse_into(&mut fields, spec, input).ok_or_else(|| {
+            D3Error::parse_error(format!("{:?} does not match {:?}", input, spec))
+        })?;
+        if consumed != input.len() {
+            return Err(D3Error::parse_error(format!(
+                "trailing input {:?} after {:?}",
+                &input[consumed..],
+                spec
+            )));
+        }
+        fields.resolve().ok_or_else(|| {
+            D3Error::parse_error(format!("{:?} is not a valid date for {:?}", input, spec))
+        })
+    }
+
+    /// Parse `input` against `spec`, returning the number of bytes consumed
+    fn parse_into(&self, d: &mut ParsedFields, spec: &str, input: &str) -> Option<usize> {
+        let mut j = 0;
+        let mut chars = spec.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                let next = input[j..].chars().next()?;
+                if next != c {
+                    return None;
+                }
+                j += c.len_utf8();
+                continue;
+            }
+
+            let mut width = None;
+            match chars.peek() {
+                Some('0' | '_' | '-') => {
+                    chars.next();
+                }
+                Some(&d @ ('3' | '6' | '9')) => {
+                    width = d.to_digit(10);
+                    chars.next();
+                }
+                _ => {}
+            }
+
+            let directive = chars.next()?;
+            let rest = &input[j..];
+            j += match directive {
+                'a' => parse_name(rest, &self.short_days, &mut d.weekday)?,
+                'A' => parse_name(rest, &self.days, &mut d.weekday)?,
+                'b' | 'h' => parse_name(rest, &self.short_months, &mut d.month)?,
+                'B' => parse_name(rest, &self.months, &mut d.month)?,
+                'c' => self.parse_into(d, &self.date_time.clone(), rest)?,
+                'd' | 'e' => parse_int(rest, 2, &mut d.day)?,
+                'f' => {
+                    let digits = width.unwrap_or(6);
+                    let mut value = None;
+                    let n = parse_int(rest, digits as usize, &mut value)?;
+                    d.millis = value.map(|v| v * 1000 / 10_i64.pow(digits));
+                    n
+                }
+                'H' | 'I' => parse_int(rest, 2, &mut d.hour)?,
+                'j' => parse_int(rest, 3, &mut d.day_of_year)?,
+                'L' => parse_int(rest, 3, &mut d.millis)?,
+                'm' => {
+                    let mut value = None;
+                    let n = parse_int(rest, 2, &mut value)?;
+                    d.month = value.map(|m| m - 1);
+                    n
+                }
+                'M' => parse_int(rest, 2, &mut d.minute)?,
+                'p' => {
+                    let mut value = None;
+                    let n = parse_name(rest, &self.periods, &mut value)?;
+                    d.pm = value.map(|p| p == 1);
+                    n
+                }
+                'q' => {
+                    let mut value = None;
+                    let n = parse_int(rest, 1, &mut value)?;
+                    d.quarter = value;
+                    n
+                }
+                'Q' => parse_signed(rest, &mut d.epoch_millis)?,
+                's' => {
+                    let mut value = None;
+                    let n = parse_signed(rest, &mut value)?;
+                    d.epoch_millis = Some(value?.checked_mul(1000)?);
+                    d.epoch_seconds = true;
+                    n
+                }
+                'S' => parse_int(rest, 2, &mut d.second)?,
+                'u' => {
+                    let mut value = None;
+                    let n = parse_int(rest, 1, &mut value)?;
+                    d.weekday = value.map(|u| u % 7);
+                    n
+                }
+                'U' => parse_int(rest, 2, &mut d.week_sunday)?,
+                'V' => parse_int(rest, 2, &mut d.week_iso)?,
+                'w' => parse_int(rest, 1, &mut d.weekday)?,
+                'W' => parse_int(rest, 2, &mut d.week_monday)?,
+                'x' => self.parse_into(d, &self.date.clone(), rest)?,
+                'X' => self.parse_into(d, &self.time.clone(), rest)?,
+                'y' => {
+                    let mut value = None;
+                    let n = parse_int(rest, 2, &mut value)?;
+                    d.year = value.map(|y| y + if y > 68 { 1900 } else { 2000 });
+                    n
+                }
+                'Y' => parse_signed_width(rest, 4, &mut d.year)?,
+                'Z' => parse_zone(rest, &mut d.offset_minutes)?,
+                '%' => {
+                    if !rest.starts_with('%') {
+                        return None;
+                    }
+                    1
+                }
+                _ => return None,
+            };
+        }
+
+        Some(j)
+    }
+}
+
+/// Date fields collected while parsing
+#[derive(Default)]
+struct ParsedFields {
+    year: Option<i64>,
+    month: Option<i64>,
+    day: Option<i64>,
+    day_of_year: Option<i64>,
+    quarter: Option<i64>,
+    weekday: Option<i64>,
+    week_sunday: Option<i64>,
+    week_monday: Option<i64>,
+    week_iso: Option<i64>,
+    hour: Option<i64>,
+    minute: Option<i64>,
+    second: Option<i64>,
+    millis: Option<i64>,
+    pm: Option<bool>,
+    offset_minutes: Option<i64>,
+    epoch_millis: Option<i64>,
+    epoch_seconds: bool,
+}
+
+impl ParsedFields {
+    /// Combine the parsed fields into a UTC datetime
+    fn resolve(self) -> Option<DateTime<Utc>> {
+        if let Some(ms) = self.epoch_millis {
+            let extra = if self.epoch_seconds {
+                self.millis.unwrap_or(0)
+            } else {
+                0
+            };
+            return Utc.timestamp_millis_opt(ms.checked_add(extra)?).single();
+        }
+
+        let year = self.year.unwrap_or(1900) as i32;
+        let date = if let Some(week) = self.week_iso {
+            if !(1..=53).contains(&week) {
+                return None;
+            }
+            let weekday = self.weekday.unwrap_or(1);
+            let monday = NaiveDate::from_isoywd_opt(year, 1, Weekday::Mon)?;
+            monday + Duration::days((week - 1) * 7 + (weekday + 6) % 7)
+        } else if self.week_monday.is_some() || self.week_sunday.is_some() {
+            let jan1 = NaiveDate::from_ymd_opt(year, 1, 1)?;
+            let day = jan1.weekday().num_days_from_sunday() as i64;
+            let offset = if let Some(week) = self.week_monday {
+                let weekday = self.weekday.unwrap_or(1);
+                (weekday + 6) % 7 + week * 7 - (day + 5) % 7
+            } else {
+                let weekday = self.weekday.unwrap_or(0);
+                weekday + self.week_sunday.unwrap_or(0) * 7 - (day + 6) % 7
+            };
+            jan1 + Duration::days(offset - 1)
+        } else if let Some(yday) = self.day_of_year {
+            NaiveDate::from_yo_opt(year, yday as u32)?
+        } else {
+            let month = self
+                .month
+                .or_else(|| self.quarter.map(|q| (q - 1) * 3))
+                .unwrap_or(0);
+            NaiveDate::from_ymd_opt(year, (month + 1) as u32, self.day.unwrap_or(1) as u32)?
+        };
+
+        let mut hour = self.hour.unwrap_or(0);
+        if let Some(pm) = self.pm {
+            hour = hour % 12 + if pm { 12 } else { 0 };
+        }
+        let naive = date.and_hms_milli_opt(
+            hour as u32,
+            self.minute.unwrap_or(0) as u32,
+            self.second.unwrap_or(0) as u32,
+            self.millis.unwrap_or(0) as u32,
+        )?;
+        let utc = Utc.from_utc_datetime(&naive);
+        Some(utc - Duration::minutes(self.offset_minutes.unwrap_or(0)))
+    }
+}
+
+/// Parse an unsigned integer of at most `max_digits` digits
+///
+/// Leading spaces are skipped, so space-padded `%e` values parse. Digit
+/// runs that overflow an i64 don't match.
+fn parse_int(input: &str, max_digits: usize, out: &mut Option<i64>) -> Option<usize> {
+    let spaces = input.len() - input.trim_start_matches(' ').len();
+    let digits = input[spaces..]
+        .bytes()
+        .take(max_digits.saturating_sub(spaces))
+        .take_while(u8::is_ascii_digit)
+        .count();
+    if digits == 0 {
+        return None;
+    }
+    *out = Some(input[spaces..spaces + digits].parse().ok()?);
+    Some(spaces + digits)
+}
+
+/// Parse an optionally signed integer of at most `max_digits` digits
+fn parse_signed_width(input: &str, max_digits: usize, out: &mut Option<i64>) -> Option<usize> {
+    let negative = input.starts_with('-');
+    let start = usize::from(negative || input.starts_with('+'));
+    let n = parse_int(&input[start..], max_digits, out)?;
+    if negative {
+        *out = out.map(|v| -v);
+    }
+    Some(start + n)
+}
+
+/// Parse an optionally signed integer of any length
+fn parse_signed(input: &str, out: &mut Option<i64>) -> Option<usize> {
+    parse_signed_width(input, usize::MAX, out)
+}
+
+/// Parse one of `names` (case-insensitive, longest match first)
+fn parse_name(input: &str, names: &[String], out: &mut Option<i64>) -> Option<usize> {
+    let lower = input.to_lowercase();
+    let (index, name) = names
+        .iter()
+        .enumerate()
+        .filter(|(_, name)| !name.is_empty() && lower.starts_with(&name.to_lowercase()))
+        .max_by_key(|(_, name)| name.len())?;
+    *out = Some(index as i64);
+    // Lowercasing can change byte lengths; map back by character count.
+    let chars = name.chars().count();
+    Some(
+        input
+            .char_indices()
+            .nth(chars)
+            .map_or(input.len(), |(i, _)| i),
+    )
+}
+
+/// Parse a zone offset: `Z`, `+hh`, `+hhmm` or `+hh:mm`
+fn parse_zone(input: &str, out: &mut Option<i64>) -> Option<usize> {
+    if input.starts_with('Z') {
+        *out = Some(0);
+        return Some(1);
+    }
+    let sign = match input.chars().next()? {
+        '+' => 1,
+        '-' => -1,
+        _ => return None,
+    };
+    let bytes = input.as_bytes();
+    let digits = |at: usize| -> Option<i64> {
+        let pair = input.get(at..at + 2)?;
+        if pair.bytes().all(|b| b.is_ascii_digit()) {
+            pair.parse().ok()
+        } else {
+            None
+        }
+    };
+    let hours = digits(1)?;
+    let (minutes, len) = match bytes.get(3) {
+        Some(b':') => (digits(4)?, 6),
+        Some(b) if b.is_ascii_digit() => (digits(3)?, 5),
+        _ => (0, 3),
+    };
+    *out = Some(sign * (hours * 60 + minutes));
+    Some(len)
+}
+
+/// A reusable time formatter: a specifier bound to a [`TimeLocale`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimeFormatter {
+    spec: String,
+    locale: TimeLocale,
+}
+
+impl TimeFormatter {
+    /// Get the specifier
+    pub fn spec(&self) -> &str {
+        &self.spec
+    }
+
+    /// Get the locale
+    pub fn locale(&self) -> &TimeLocale {
+        &self.locale
+    }
+
+    /// Format a datetime
+    pub fn format(&self, dt: DateTime<Utc>) -> String {
+        self.locale.format(&self.spec, dt)
+    }
+}
+
+/// A reusable time parser: a specifier bound to a [`TimeLocale`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimeParser {
+    spec: String,
+    locale: TimeLocale,
+}
+
+impl TimeParser {
+    /// Get the specifier
+    pub fn spec(&self) -> &str {
+        &self.spec
+    }
+
+    /// Parse a string to a UTC datetime
+    pub fn parse(&self, input: &str) -> D3Result<DateTime<Utc>> {
+        self.locale.parse(&self.spec, input)
+    }
+
+    /// Parse a string to milliseconds since the Unix epoch
+    pub fn parse_ms(&self, input: &str) -> D3Result<f64> {
+        self.parse(input).map(|dt| dt.timestamp_millis() as f64)
+    }
+
+    /// Parse a column of values and return its (min, max) extent
+    ///
+    /// Suitable for [`TimeScale::with_time_domain`](crate::scale::TimeScale::with_time_domain).
+    /// Fails on the first unparseable value or if the column is empty.
+    pub fn parse_extent<'a>(
+        &self,
+        values: impl IntoIterator<Item = &'a str>,
+    ) -> D3Result<(DateTime<Utc>, DateTime<Utc>)> {
+        let mut extent: Option<(DateTime<Utc>, DateTime<Utc>)> = None;
+        for value in values {
+            let dt = self.parse(value)?;
+            extent = Some(match extent {
+                Some((lo, hi)) => (lo.min(dt), hi.max(dt)),
+                None => (dt, dt),
+            });
+        }
+        extent.ok_or_else(|| D3Error::invalid_data("no values to parse"))
+    }
+}
+
+/// Create an en-US time formatter (d3's `timeFormat`)
+pub fn time_format(spec: impl Into<String>) -> TimeFormatter {
+    TimeLocale::en_us().formatter(spec)
+}
+
+/// Create an en-US time parser (d3's `timeParse`)
+pub fn time_parse(spec: impl Into<String>) -> TimeParser {
+    TimeLocale::en_us().parser(spec)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dt(y: i32, m: u32, d: u32, h: u32, min: u32, s: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, s).unwrap()
+    }
+
+    #[test]
+    fn test_format_basic_directives() {
+        let t = dt(2024, 1, 5, 14, 3, 9) + Duration::milliseconds(42);
+        let f = time_format("%Y-%m-%d %H:%M:%S.%L");
+        assert_eq!(f.format(t), "2024-01-05 14:03:09.042");
+        assert_eq!(time_format("%-m/%-d/%y").format(t), "1/5/24");
+        assert_eq!(time_format("%e|%_m|%I %p").format(t), " 5| 1|02 PM");
+        assert_eq!(
+            time_format("%a %A %b %B").format(t),
+            "Fri Friday Jan January"
+        );
+        assert_eq!(time_format("%j %q %u %w %%").format(t), "005 1 5 5 %");
+        assert_eq!(time_format("%H:%M:%S.%3f").format(t), "14:03:09.042");
+    }
+
+    #[test]
+    fn test_format_week_numbers() {
+        // 2024-01-01 is a Monday
+        let t = dt(2024, 1, 7, 0, 0, 0);
+        assert_eq!(time_format("%U %W %V %G").format(t), "01 01 01 2024");
+        let t = dt(2021, 1, 1, 0, 0, 0);
+        assert_eq!(time_format("%V %G %g").format(t), "53 2020 20");
+    }
+
+    #[test]
+    fn test_format_locale_composites() {
+        let t = dt(2024, 3, 5, 14, 30, 0);
+        assert_eq!(time_format("%c").format(t), "3/5/2024, 2:30:00 PM");
+        assert_eq!(TimeLocale::de_de().format("%x", t), "05.03.2024");
+        assert_eq!(TimeLocale::fr_fr().format("%A %e %B", t), "mardi  5 mars");
+        assert_eq!(TimeLocale::es_es().format("%b %Y", t), "mar 2024");
+        assert_eq!(TimeLocale::ja_jp().format("%x %p", t), "2024/03/05 午後");
+        assert_eq!(
+            TimeLocale::zh_cn().format("%x %A", t),
+            "2024年3月5日 星期二"
+        );
+    }
+
+    #[test]
+    fn test_parse_basic() {
+        let p = time_parse("%Y-%m-%dT%H:%M:%S.%LZ");
+        assert_eq!(
+            p.parse("2024-03-05T14:30:15.250Z").unwrap(),
+            dt(2024, 3, 5, 14, 30, 15) + Duration::milliseconds(250)
+        );
+        assert_eq!(
+            time_parse("%m/%d/%Y").parse("03/05/2024").unwrap(),
+            dt(2024, 3, 5, 0, 0, 0)
+        );
+        assert_eq!(
+            time_parse("%Y").parse("2024").unwrap(),
+            dt(2024, 1, 1, 0, 0, 0)
+        );
+        assert_eq!(
+            time_parse("%H:%M").parse("09:15").unwrap(),
+            dt(1900, 1, 1, 9, 15, 0)
+        );
+    }
+
+    #[test]
+    fn test_parse_names_and_periods() {
+        assert_eq!(
+            time_parse("%B %e, %Y %I:%M %p")
+                .parse("march  5, 2024 02:30 pm")
+                .unwrap(),
+            dt(2024, 3, 5, 14, 30, 0)
+        );
+        assert_eq!(
+            TimeLocale::de_de()
+                .parse("%e. %B %Y", "5. März 2024")
+                .unwrap(),
+            dt(2024, 3, 5, 0, 0, 0)
+        );
+        assert_eq!(
+            TimeLocale::fr_fr()
+                .parse("%d %b %Y", "05 févr. 2024")
+                .unwrap(),
+            dt(2024, 2, 5, 0, 0, 0)
+        );
+        assert_eq!(
+            TimeLocale::zh_cn().parse("%x", "2024年3月5日").unwrap(),
+            dt(2024, 3, 5, 0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_parse_epoch_zone_and_weeks() {
+        assert_eq!(
+            time_parse("%s").parse("1709649000").unwrap(),
+            dt(2024, 3, 5, 14, 30, 0)
+        );
+        assert_eq!(
+            time_parse("%Q").parse("1709649000000").unwrap(),
+            dt(2024, 3, 5, 14, 30, 0)
+        );
+        assert_eq!(
+            time_parse("%Y-%m-%d %H:%M %Z")
+                .parse("2024-03-05 16:30 +02:00")
+                .unwrap(),
+            dt(2024, 3, 5, 14, 30, 0)
+        );
+        assert_eq!(
+            time_parse("%Y-W%V-%u").parse("2021-W01-1").unwrap(),
+            dt(2021, 1, 4, 0, 0, 0)
+        );
+        assert_eq!(
+            time_parse("%Y %W %w").parse("2024 01 1").unwrap(),
+            dt(2024, 1, 1, 0, 0, 0)
+        );
+        assert_eq!(time_parse("%y").parse("69").unwrap().year(), 1969);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(time_parse("%Y-%m-%d").parse("2024/03/05").is_err());
+        assert!(time_parse("%Y-%m-%d").parse("2024-02-30").is_err());
+        assert!(time_parse("%Y").parse("2024x").is_err());
+        assert!(time_parse("%Y").parse("").is_err());
+
+        // Out-of-range epochs fail instead of overflowing or defaulting
+        let en = TimeLocale::en_us();
+        assert!(en.parse("%s", "9223372036854776").is_err());
+        assert!(en.parse("%s", "99999999999999999999").is_err());
+        assert!(en.parse("%Q", "99999999999999999999").is_err());
+        assert!(en.parse("%s.%L", "9223372036854775.999").is_err());
+    }
+
+    #[test]
+    fn test_round_trip_and_extent() {
+        let spec = "%d %B %Y %H:%M";
+        let locale = TimeLocale::es_es();
+        let t = dt(2023, 11, 20, 8, 45, 0);
+        let s = locale.format(spec, t);
+        assert_eq!(locale.parse(spec, &s).unwrap(), t);
+
+        let parser = time_parse("%Y-%m-%d");
+        let (lo, hi) = parser
+            .parse_extent(["2024-03-05", "2023-01-01", "2024-12-31"])
+            .unwrap();
+        assert_eq!(lo, dt(2023, 1, 1, 0, 0, 0));
+        assert_eq!(hi, dt(2024, 12, 31, 0, 0, 0));
+        assert!(parser.parse_extent(Vec::<&str>::new()).is_err());
+    }
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(TimeLocale::from_name("de_DE"), Some(TimeLocale::de_de()));
+        assert_eq!(TimeLocale::from_name("ja"), Some(TimeLocale::ja_jp()));
+        assert!(TimeLocale::from_name("tlh").is_none());
+    }
+}
@@ -0,0 +1,376 @@
+//! Scale transitions
+//!
+//! Interpolate between two states of the same scale so that axes and marks
+//! animate smoothly when a domain or range changes, instead of jumping.
+//!
+//! - [`ScaleInterpolate`]: implemented by continuous scales (linear, log,
+//!   pow, symlog, radial, time); produces an intermediate scale at `t`.
+//! - [`ScaleTransition`]: holds a start and end scale plus an easing
+//!   function; sample it once per frame.
+//! - [`BandTransition`]: moves each category from its old band to its new
+//!   band, for reordering bar charts; categories entering or leaving the
+//!   domain report an opacity for fading.
+//!
+//! # Example
+//! ```
+//! use makepad_d3::axis::Axis;
+//! use makepad_d3::scale::{LinearScale, Scale, ScaleExt, ScaleTransition};
+//!
+//! let from = LinearScale::new().with_domain(0.0, 100.0).with_range(0.0, 500.0);
+//! let to = LinearScale::new().with_domain(0.0, 200.0).with_range(0.0, 500.0);
+//! let transition = ScaleTransition::new(from, to);
+//!
+//! let mid = transition.at(0.5);
+//! assert_eq!(mid.domain(), (0.0, 150.0));
+//!
+//! // Re-layout the axis at each animation step
+//! let mut axis = Axis::new();
+//! axis.set_scale(&mid);
+//! let layout = axis.compute_layout(300.0);
+//! assert!(!layout.ticks.is_empty());
+//! ```
+
+use super::band::BandScale;
+use super::traits::{DiscreteScale, Scale, Tick};
+use super::utils::lerp;
+
+/// Interpolation between two states of the same scale type
+///
+/// `interpolate(to, 0.0)` reproduces `self` and `interpolate(to, 1.0)`
+/// reproduces `to`. Continuous parameters (domain, range, exponent) are
+/// interpolated; discrete flags (clamp, base, format) are taken from `to`.
+pub trait ScaleInterpolate: Sized {
+    /// Return the scale state at `t` (0-1) between `self` and `to`
+    fn interpolate(&self, to: &Self, t: f64) -> Self;
+}
+
+/// Interpolate a domain bound geometrically when both ends share a sign
+///
+/// Used by log-like scales so that a zoom from `[1, 10]` to `[1, 1000]`
+/// moves at a constant rate in log space.
+pub(crate) fn lerp_geometric(a: f64, b: f64, t: f64) -> f64 {
+    if a * b > 0.0 {
+        a.signum() * (a.abs().ln() + (b.abs().ln() - a.abs().ln()) * t).exp()
+    } else {
+        lerp(a, b, t)
+    }
+}
+
+/// Animated transition between two scales
+///
+/// # Example
+/// ```
+/// use makepad_d3::scale::{LinearScale, Scale, ScaleExt, ScaleTransition};
+///
+/// let transition = ScaleTransition::new(
+///     LinearScale::new().with_domain(0.0, 10.0),
+///     LinearScale::new().with_domain(10.0, 20.0),
+/// )
+/// .with_easing(|t| t * t);
+///
+/// assert_eq!(transition.at(0.5).domain(), (2.5, 12.5));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ScaleTransition<S> {
+    from: S,
+    to: S,
+    easing: fn(f64) -> f64,
+}
+
+impl<S: ScaleInterpolate + Clone> ScaleTransition<S> {
+    /// Create a transition with linear easing
+    pub fn new(from: S, to: S) -> Self {
+        Self {
+            from,
+            to,
+            easing: |t| t,
+        }
+    }
+
+    /// Set the easing function applied to `t` before interpolation
+    pub fn with_easing(mut self, easing: fn(f64) -> f64) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Get the start scale
+    pub fn from(&self) -> &S {
+        &self.from
+    }
+
+    /// Get the end scale
+    pub fn to(&self) -> &S {
+        &self.to
+    }
+
+    /// Sample the transition at `t` (clamped to 0-1)
+    pub fn at(&self, t: f64) -> S {
+        let t = t.clamp(0.0, 1.0);
+        if t <= 0.0 {
+            return self.from.clone();
+        }
+        if t >= 1.0 {
+            return self.to.clone();
+        }
+        self.from.interpolate(&self.to, (self.easing)(t))
+    }
+
+    /// Retarget a running transition
+    ///
+    /// The scale currently shown at `t` becomes the new start, so an
+    /// interrupted animation continues without a jump.
+    pub fn retarget(&mut self, t: f64, to: S) {
+        self.from = self.at(t);
+        self.to = to;
+    }
+}
+
+/// Transition between two band scales, e.g. when bars are re-sorted
+///
+/// Each category moves from its band in `from` to its band in `to`.
+/// Categories only present in `to` are entering (they sit at their new
+/// band and fade in); categories only present in `from` are exiting (they
+/// stay at their old band and fade out).
+///
+/// # Example
+/// ```
+/// use makepad_d3::scale::{BandScale, BandTransition};
+///
+/// let from = BandScale::new().with_domain(vec!["A", "B"]).with_range(0.0, 200.0);
+/// let to = BandScale::new().with_domain(vec!["B", "A"]).with_range(0.0, 200.0);
+/// let transition = BandTransition::new(from, to);
+///
+/// // Halfway through the swap both bars meet in the middle
+/// assert_eq!(transition.position("A", 0.5), Some(50.0));
+/// assert_eq!(transition.position("B", 0.5), Some(50.0));
+/// ```
+#[derive(Clone, Debug)]
+pub struct BandTransition {
+    from: BandScale,
+    to: BandScale,
+}
+
+impl BandTransition {
+    /// Create a band transition
+    pub fn new(from: BandScale, to: BandScale) -> Self {
+        Self { from, to }
+    }
+
+    /// Get the start scale
+    pub fn from(&self) -> &BandScale {
+        &self.from
+    }
+
+    /// Get the end scale
+    pub fn to(&self) -> &BandScale {
+        &self.to
+    }
+
+    /// All categories, in target order followed by exiting categories
+    pub fn categories(&self) -> Vec<&str> {
+        let mut out: Vec<&str> = self.to.domain_values().iter().map(String::as_str).collect();
+        for value in self.from.domain_values() {
+            if self.to.index_of(value).is_none() {
+                out.push(value);
+            }
+        }
+        out
+    }
+
+    /// Band start position of a category at `t`
+    ///
+    /// Returns `None` if the category is in neither scale.
+    pub fn position(&self, category: &str, t: f64) -> Option<f64> {
+        let t = t.clamp(0.0, 1.0);
+        match (
+            self.from.scale_category(category),
+            self.to.scale_category(category),
+        ) {
+            (Some(a), Some(b)) => Some(lerp(a, b, t)),
+            (None, Some(b)) => Some(b),
+            (Some(a), None) => Some(a),
+            (None, None) => None,
+        }
+    }
+
+    /// Band center position of a category at `t`
+    pub fn center(&self, category: &str, t: f64) -> Option<f64> {
+        self.position(category, t)
+            .map(|p| p + self.bandwidth(t) / 2.0)
+    }
+
+    /// Bandwidth at `t`
+    pub fn bandwidth(&self, t: f64) -> f64 {
+        lerp(
+            self.from.bandwidth(),
+            self.to.bandwidth(),
+            t.clamp(0.0, 1.0),
+        )
+    }
+
+    /// Range at `t`
+    pub fn range(&self, t: f64) -> (f64, f64) {
+        let t = t.clamp(0.0, 1.0);
+        let (a0, a1) = Scale::range(&self.from);
+        let (b0, b1) = Scale::range(&self.to);
+        (lerp(a0, b0, t), lerp(a1, b1, t))
+    }
+
+    /// Opacity of a category at `t` (fade for entering/exiting categories)
+    pub fn opacity(&self, category: &str, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        match (
+            self.from.index_of(category).is_some(),
+            self.to.index_of(category).is_some(),
+        ) {
+            (true, true) => 1.0,
+            (false, true) => t,
+            (true, false) => 1.0 - t,
+            (false, false) => 0.0,
+        }
+    }
+
+    /// Axis ticks at `t`, positioned at band centers
+    ///
+    /// Tick values are indices into [`categories`](Self::categories).
+    /// Exiting categories are shown while `t < 0.5` and entering ones from
+    /// `t = 0.5` on, so labels never double up.
+    pub fn ticks(&self, t: f64) -> Vec<Tick> {
+        let t = t.clamp(0.0, 1.0);
+        self.categories()
+            .into_iter()
+            .enumerate()
+            .filter(|(_, c)| {
+                match (
+                    self.from.index_of(c).is_some(),
+                    self.to.index_of(c).is_some(),
+                ) {
+                    (true, true) => true,
+                    (true, false) => t < 0.5,
+                    (false, true) => t >= 0.5,
+                    (false, false) => false,
+                }
+            })
+            .filter_map(|(i, c)| {
+                self.center(c, t)
+                    .map(|pos| Tick::new(i as f64, c).with_position(pos))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scale::{LinearScale, LogScale, ScaleExt, TimeScale};
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn test_transition_endpoints() {
+        let from = LinearScale::new()
+            .with_domain(0.0, 100.0)
+            .with_range(0.0, 500.0);
+        let to = LinearScale::new()
+            .with_domain(50.0, 60.0)
+            .with_range(100.0, 200.0);
+        let transition = ScaleTransition::new(from, to);
+
+        assert_eq!(transition.at(0.0).domain(), (0.0, 100.0));
+        assert_eq!(transition.at(1.0).domain(), (50.0, 60.0));
+        assert_eq!(transition.at(2.0).range(), (100.0, 200.0));
+        assert_eq!(transition.at(0.5).range(), (50.0, 350.0));
+    }
+
+    #[test]
+    fn test_transition_retarget() {
+        let mut transition = ScaleTransition::new(
+            LinearScale::new().with_domain(0.0, 10.0),
+            LinearScale::new().with_domain(0.0, 20.0),
+        );
+        transition.retarget(0.5, LinearScale::new().with_domain(0.0, 100.0));
+
+        assert_eq!(transition.from().domain(), (0.0, 15.0));
+        assert_eq!(transition.at(1.0).domain(), (0.0, 100.0));
+    }
+
+    #[test]
+    fn test_log_transition_geometric() {
+        let transition = ScaleTransition::new(
+            LogScale::new().with_domain(1.0, 10.0),
+            LogScale::new().with_domain(1.0, 1000.0),
+        );
+
+        let (_, max) = transition.at(0.5).domain();
+        assert!((max - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_time_transition() {
+        let a = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let b = Utc.with_ymd_and_hms(2024, 1, 3, 0, 0, 0).unwrap();
+        let c = Utc.with_ymd_and_hms(2024, 1, 5, 0, 0, 0).unwrap();
+
+        let from = TimeScale::new().with_time_domain(a, b);
+        let to = TimeScale::new().with_time_domain(b, c);
+        let mid = from.interpolate(&to, 0.5);
+
+        assert_eq!(
+            mid.domain_start(),
+            Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            mid.domain_end(),
+            Utc.with_ymd_and_hms(2024, 1, 4, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_band_transition_reorder() {
+        let from = BandScale::new()
+            .with_domain(vec!["A", "B", "C"])
+            .with_range(0.0, 300.0);
+        let to = BandScale::new()
+            .with_domain(vec!["C", "B", "A"])
+            .with_range(0.0, 300.0);
+        let transition = BandTransition::new(from, to);
+
+        assert_eq!(transition.position("A", 0.0), Some(0.0));
+        assert_eq!(transition.position("A", 1.0), Some(200.0));
+        assert_eq!(transition.position("A", 0.25), Some(50.0));
+        assert_eq!(transition.position("B", 0.5), Some(100.0));
+        assert_eq!(transition.position("Z", 0.5), None);
+        assert_eq!(transition.bandwidth(0.5), 100.0);
+    }
+
+    #[test]
+    fn test_band_transition_enter_exit() {
+        let from = BandScale::new()
+            .with_domain(vec!["A", "B"])
+            .with_range(0.0, 200.0);
+        let to = BandScale::new()
+            .with_domain(vec!["B", "C"])
+            .with_range(0.0, 200.0);
+        let transition = BandTransition::new(from, to);
+
+        assert_eq!(transition.categories(), vec!["B", "C", "A"]);
+        assert_eq!(transition.opacity("A", 0.25), 0.75);
+        assert_eq!(transition.opacity("C", 0.25), 0.25);
+        assert_eq!(transition.opacity("B", 0.25), 1.0);
+
+        let early: Vec<String> = transition
+            .ticks(0.25)
+            .into_iter()
+            .map(|t| t.label)
+            .collect();
+        assert_eq!(early, vec!["B", "A"]);
+
+        let late = transition.ticks(0.75);
+        assert_eq!(late.len(), 2);
+        assert_eq!(late[1].label, "C");
+        assert_eq!(late[1].position, 150.0);
+
+        // At the halfway point "A" has left and "C" has arrived
+        let half: Vec<String> = transition.ticks(0.5).into_iter().map(|t| t.label).collect();
+        assert_eq!(half, vec!["B", "C"]);
+    }
+}
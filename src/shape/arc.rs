@@ -4,11 +4,18 @@
 //! donut charts, and gauge visualizations.
 
 use super::path::{PathSegment, Point};
-use std::f64::consts::{PI, TAU};
+use std::f64::consts::{FRAC_PI_2, PI, TAU};
+
+/// Threshold below which radii and angles are treated as zero (as in d3)
+const EPSILON: f64 = 1e-12;
 
 /// Arc generator for radial visualizations
 ///
 /// Generates arc paths defined by inner/outer radius and start/end angles.
+/// Padding and corner rounding follow d3's `arc()`: the pad angle becomes a
+/// parallel gap of width `pad_radius * pad_angle` between neighbouring
+/// arcs, and the corner radius is clamped so corners never overlap on thin
+/// slices.
 ///
 /// # Example
 /// ```
@@ -19,7 +26,8 @@ use std::f64::consts::{PI, TAU};
 ///     .inner_radius(50.0)
 ///     .outer_radius(100.0)
 ///     .start_angle(0.0)
-///     .end_angle(PI / 2.0);
+///     .end_angle(PI / 2.0)
+///     .corner_radius(8.0);
 ///
 /// let path = arc.generate();
 /// ```
@@ -37,6 +45,8 @@ pub struct ArcGenerator {
     corner_radius: f64,
     /// Padding angle between adjacent arcs
     pad_angle: f64,
+    /// Radius at which the pad angle is measured (None = √(r0² + r1²))
+    pad_radius: Option<f64>,
 }
 
 impl Default for ArcGenerator {
@@ -55,6 +65,7 @@ impl ArcGenerator {
             end_angle: TAU,
             corner_radius: 0.0,
             pad_angle: 0.0,
+            pad_radius: None,
         }
     }
 
@@ -83,6 +94,9 @@ impl ArcGenerator {
     }
 
     /// Set the corner radius
+    ///
+    /// Clamped to half the ring thickness, and further reduced on slices
+    /// too thin to fit both corners.
     pub fn corner_radius(mut self, radius: f64) -> Self {
         self.corner_radius = radius.max(0.0);
         self
@@ -94,6 +108,15 @@ impl ArcGenerator {
         self
     }
 
+    /// Set the pad radius
+    ///
+    /// The gap between adjacent arcs has the constant width
+    /// `pad_radius * pad_angle`. Defaults to `√(inner² + outer²)`.
+    pub fn pad_radius(mut self, radius: f64) -> Self {
+        self.pad_radius = Some(radius.max(0.0));
+        self
+    }
+
     /// Get the inner radius
     pub fn get_inner_radius(&self) -> f64 {
         self.inner_radius
@@ -104,6 +127,11 @@ impl ArcGenerator {
         self.outer_radius
     }
 
+    /// Get the pad radius, if set explicitly
+    pub fn get_pad_radius(&self) -> Option<f64> {
+        self.pad_radius
+    }
+
     /// Calculate the centroid of the arc
     ///
     /// Returns the center point of the arc, useful for label placement.
@@ -124,36 +152,26 @@ impl ArcGenerator {
     pub fn generate(&self) -> Vec<PathSegment> {
         let mut path = Vec::new();
 
-        let inner = self.inner_radius;
-        let outer = self.outer_radius;
-        let mut start = self.start_angle;
-        let mut end = self.end_angle;
-
-        // Apply padding
-        if self.pad_angle > 0.0 {
-            let pad = self.pad_angle / 2.0;
-            start += pad;
-            end -= pad;
-        }
+        let (inner, outer) = if self.outer_radius < self.inner_radius {
+            (self.outer_radius, self.inner_radius)
+        } else {
+            (self.inner_radius, self.outer_radius)
+        };
 
-        // Ensure end > start
-        let sweep = end - start;
+        let sweep = self.end_angle - self.start_angle;
         if sweep.abs() < 1e-10 {
             return path;
         }
 
-        // Check if it's a full circle
-        let is_full = sweep.abs() >= TAU - 1e-10;
-
-        if is_full {
+        if outer <= EPSILON {
+            // A point
+            path.push(PathSegment::MoveTo(Point::zero()));
+            path.push(PathSegment::ClosePath);
+        } else if sweep.abs() > TAU - EPSILON {
             // Full circle - need two arcs
             self.generate_full_circle(&mut path, inner, outer);
-        } else if inner < 1e-10 {
-            // Pie slice (no inner radius)
-            self.generate_pie_slice(&mut path, outer, start, end);
         } else {
-            // Donut slice
-            self.generate_donut_slice(&mut path, inner, outer, start, end);
+            self.generate_sector(&mut path, inner, outer);
         }
 
         path
@@ -209,71 +227,222 @@ impl ArcGenerator {
         path.push(PathSegment::ClosePath);
     }
 
-    /// Generate a pie slice (no inner radius)
-    fn generate_pie_slice(&self, path: &mut Vec<PathSegment>, outer: f64, start: f64, end: f64) {
-        // Start at center
-        path.push(PathSegment::MoveTo(Point::zero()));
+    /// Generate a circular or annular sector, with padding and corners
+    ///
+    /// A port of the sector branch of d3-shape's `arc()`.
+    fn generate_sector(&self, path: &mut Vec<PathSegment>, r0: f64, r1: f64) {
+        let a0 = self.start_angle - FRAC_PI_2;
+        let a1 = self.end_angle - FRAC_PI_2;
+        let da = (a1 - a0).abs();
+        let cw = a1 > a0;
+
+        let (mut a01, mut a11, mut a00, mut a10) = (a0, a1, a0, a1);
+        let (mut da0, mut da1) = (da, da);
+        let ap = self.pad_angle / 2.0;
+        let rp = if ap > EPSILON {
+            self.pad_radius
+                .unwrap_or_else(|| (r0 * r0 + r1 * r1).sqrt())
+        } else {
+            0.0
+        };
+        let rc = ((r1 - r0).abs() / 2.0).min(self.corner_radius);
+        let (mut rc0, mut rc1) = (rc, rc);
+
+        // Apply padding: a parallel gap of width rp * pad_angle
+        if rp > EPSILON {
+            let sign = if cw { 1.0 } else { -1.0 };
+            let mut p0 = (rp / r0 * ap.sin()).clamp(-1.0, 1.0).asin();
+            let mut p1 = (rp / r1 * ap.sin()).clamp(-1.0, 1.0).asin();
+            da0 -= p0 * 2.0;
+            if da0 > EPSILON {
+                p0 *= sign;
+                a00 += p0;
+                a10 -= p0;
+            } else {
+                da0 = 0.0;
+                a00 = (a0 + a1) / 2.0;
+                a10 = a00;
+            }
+            da1 -= p1 * 2.0;
+            if da1 > EPSILON {
+                p1 *= sign;
+                a01 += p1;
+                a11 -= p1;
+            } else {
+                da1 = 0.0;
+                a01 = (a0 + a1) / 2.0;
+                a11 = a01;
+            }
+        }
 
-        // Line to outer arc start
-        let outer_start = self.point_at(start, outer);
-        path.push(PathSegment::LineTo(outer_start));
+        let p01 = polar(r1, a01);
+        let p10 = polar(r0, a10);
+        let p11 = polar(r1, a11);
+        let p00 = polar(r0, a00);
+
+        // Restrict the corner radius to what the sector angle can hold; if
+        // the edges don't intersect the arc is too small for corners at all
+        if rc > EPSILON && da < PI {
+            match intersect(p01, p00, p11, p10) {
+                Some(oc) => {
+                    let a = p01.sub(&oc);
+                    let b = p11.sub(&oc);
+                    let cos = (a.x * b.x + a.y * b.y)
+                        / (a.distance(&Point::zero()) * b.distance(&Point::zero()));
+                    let kc = 1.0 / (cos.clamp(-1.0, 1.0).acos() / 2.0).sin();
+                    let lc = oc.distance(&Point::zero());
+                    rc0 = rc.min((r0 - lc) / (kc - 1.0));
+                    rc1 = rc.min((r1 - lc) / (kc + 1.0));
+                }
+                None => {
+                    rc0 = 0.0;
+                    rc1 = 0.0;
+                }
+            }
+        }
 
-        // Arc along outer edge
-        let adjusted_start = start - PI / 2.0;
-        let adjusted_end = end - PI / 2.0;
+        // Outer ring
+        if da1 <= EPSILON {
+            // Collapsed to a line
+            path.push(PathSegment::MoveTo(p01));
+        } else if rc1 > EPSILON {
+            let t0 = corner_tangents(p00, p01, r1, rc1, cw);
+            let t1 = corner_tangents(p11, p10, r1, rc1, cw);
+            path.push(PathSegment::MoveTo(t0.center.add(&t0.p01)));
+            if rc1 < rc {
+                // The two corners have merged
+                push_arc(path, t0.center, rc1, t0.p01, t1.p01, !cw);
+            } else {
+                push_arc(path, t0.center, rc1, t0.p01, t0.p11, !cw);
+                push_arc(
+                    path,
+                    Point::zero(),
+                    r1,
+                    t0.center.add(&t0.p11),
+                    t1.center.add(&t1.p11),
+                    !cw,
+                );
+                push_arc(path, t1.center, rc1, t1.p11, t1.p01, !cw);
+            }
+        } else {
+            path.push(PathSegment::MoveTo(p01));
+            path.push(PathSegment::ArcTo {
+                center: Point::zero(),
+                radius: r1,
+                start_angle: a01,
+                end_angle: a11,
+                counterclockwise: !cw,
+            });
+        }
 
-        path.push(PathSegment::ArcTo {
-            center: Point::zero(),
-            radius: outer,
-            start_angle: adjusted_start,
-            end_angle: adjusted_end,
-            counterclockwise: false,
-        });
+        // Inner ring (or the center point of a pie slice)
+        if r0 <= EPSILON || da0 <= EPSILON {
+            path.push(PathSegment::LineTo(p10));
+        } else if rc0 > EPSILON {
+            let t0 = corner_tangents(p10, p11, r0, -rc0, cw);
+            let t1 = corner_tangents(p01, p00, r0, -rc0, cw);
+            path.push(PathSegment::LineTo(t0.center.add(&t0.p01)));
+            if rc0 < rc {
+                push_arc(path, t0.center, rc0, t0.p01, t1.p01, !cw);
+            } else {
+                push_arc(path, t0.center, rc0, t0.p01, t0.p11, !cw);
+                push_arc(
+                    path,
+                    Point::zero(),
+                    r0,
+                    t0.center.add(&t0.p11),
+                    t1.center.add(&t1.p11),
+                    cw,
+                );
+                push_arc(path, t1.center, rc0, t1.p11, t1.p01, !cw);
+            }
+        } else {
+            path.push(PathSegment::ArcTo {
+                center: Point::zero(),
+                radius: r0,
+                start_angle: a10,
+                end_angle: a00,
+                counterclockwise: cw,
+            });
+        }
 
-        // Close back to center
         path.push(PathSegment::ClosePath);
     }
+}
 
-    /// Generate a donut slice
-    fn generate_donut_slice(
-        &self,
-        path: &mut Vec<PathSegment>,
-        inner: f64,
-        outer: f64,
-        start: f64,
-        end: f64,
-    ) {
-        // Start at outer arc start
-        let outer_start = self.point_at(start, outer);
-        path.push(PathSegment::MoveTo(outer_start));
-
-        // Arc along outer edge
-        let adjusted_start = start - PI / 2.0;
-        let adjusted_end = end - PI / 2.0;
+/// Point at `radius` along the (already offset) canvas angle
+fn polar(radius: f64, angle: f64) -> Point {
+    Point::new(radius * angle.cos(), radius * angle.sin())
+}
 
-        path.push(PathSegment::ArcTo {
-            center: Point::zero(),
-            radius: outer,
-            start_angle: adjusted_start,
-            end_angle: adjusted_end,
-            counterclockwise: false,
-        });
+/// Arc around `center` between the directions of two offset vectors
+fn push_arc(
+    path: &mut Vec<PathSegment>,
+    center: Point,
+    radius: f64,
+    from: Point,
+    to: Point,
+    counterclockwise: bool,
+) {
+    path.push(PathSegment::ArcTo {
+        center,
+        radius,
+        start_angle: from.y.atan2(from.x),
+        end_angle: to.y.atan2(to.x),
+        counterclockwise,
+    });
+}
 
-        // Line to inner arc end
-        let inner_end = self.point_at(end, inner);
-        path.push(PathSegment::LineTo(inner_end));
+/// Intersection of the lines p0–p1 and p2–p3
+fn intersect(p0: Point, p1: Point, p2: Point, p3: Point) -> Option<Point> {
+    let d10 = p1.sub(&p0);
+    let d32 = p3.sub(&p2);
+    let t = d32.y * d10.x - d32.x * d10.y;
+    if t * t < EPSILON {
+        return None;
+    }
+    let t = (d32.x * (p0.y - p2.y) - d32.y * (p0.x - p2.x)) / t;
+    Some(p0.add(&d10.scale(t)))
+}
 
-        // Arc along inner edge (reverse direction)
-        path.push(PathSegment::ArcTo {
-            center: Point::zero(),
-            radius: inner,
-            start_angle: adjusted_end,
-            end_angle: adjusted_start,
-            counterclockwise: true,
-        });
+/// A rounded corner: circle center plus offsets to its two tangent points
+struct CornerTangents {
+    /// Center of the corner circle
+    center: Point,
+    /// Offset from the center to the tangent point on the radial edge
+    p01: Point,
+    /// Offset from the center to the tangent point on the ring
+    p11: Point,
+}
 
-        // Close back to start
-        path.push(PathSegment::ClosePath);
+/// Corner circle of radius `rc` tangent to the edge p0–p1 and the ring `r1`
+fn corner_tangents(p0: Point, p1: Point, r1: f64, rc: f64, cw: bool) -> CornerTangents {
+    let d01 = p0.sub(&p1);
+    let lo = if cw { rc } else { -rc } / (d01.x * d01.x + d01.y * d01.y).sqrt();
+    let offset = Point::new(lo * d01.y, -lo * d01.x);
+    let q11 = p0.add(&offset);
+    let q10 = p1.add(&offset);
+    let mid = q11.lerp(&q10, 0.5);
+    let dx = q10.x - q11.x;
+    let dy = q10.y - q11.y;
+    let d2 = dx * dx + dy * dy;
+    let r = r1 - rc;
+    let cross = q11.x * q10.y - q10.x * q11.y;
+    let d = if dy < 0.0 { -1.0 } else { 1.0 } * (r * r * d2 - cross * cross).max(0.0).sqrt();
+    let c0 = Point::new((cross * dy - dx * d) / d2, (-cross * dx - dy * d) / d2);
+    let c1 = Point::new((cross * dy + dx * d) / d2, (-cross * dx + dy * d) / d2);
+
+    // Pick the closer of the two intersection points
+    let center = if c0.distance(&mid) > c1.distance(&mid) {
+        c1
+    } else {
+        c0
+    };
+
+    CornerTangents {
+        center,
+        p01: offset.scale(-1.0),
+        p11: center.scale(r1 / r - 1.0),
     }
 }
 
@@ -292,6 +461,8 @@ pub struct ArcDatum {
     pub corner_radius: f64,
     /// Pad angle
     pub pad_angle: f64,
+    /// Pad radius (None = √(inner² + outer²))
+    pub pad_radius: Option<f64>,
 }
 
 impl ArcDatum {
@@ -304,18 +475,23 @@ impl ArcDatum {
             end_angle,
             corner_radius: 0.0,
             pad_angle: 0.0,
+            pad_radius: None,
         }
     }
 
     /// Convert to an ArcGenerator
     pub fn to_generator(&self) -> ArcGenerator {
-        ArcGenerator::new()
+        let arc = ArcGenerator::new()
             .inner_radius(self.inner_radius)
             .outer_radius(self.outer_radius)
             .start_angle(self.start_angle)
             .end_angle(self.end_angle)
             .corner_radius(self.corner_radius)
-            .pad_angle(self.pad_angle)
+            .pad_angle(self.pad_angle);
+        match self.pad_radius {
+            Some(radius) => arc.pad_radius(radius),
+            None => arc,
+        }
     }
 
     /// Generate path segments
@@ -327,6 +503,73 @@ impl ArcDatum {
     pub fn centroid(&self) -> Point {
         self.to_generator().centroid()
     }
+
+    /// Get the angle halfway between the start and end angles
+    pub fn mid_angle(&self) -> f64 {
+        (self.start_angle + self.end_angle) / 2.0
+    }
+
+    /// Check whether a point, relative to the arc's center, lies on the arc
+    ///
+    /// Tests the radius against the inner and outer radii and the angle
+    /// (0 at 12 o'clock, clockwise) against the arc's sweep, excluding the
+    /// pad gap on either side. Rounded corners are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// use makepad_d3::shape::ArcDatum;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut arc = ArcDatum::new(0.0, PI / 2.0);
+    /// arc.inner_radius = 50.0;
+    ///
+    /// assert!(arc.contains(50.0, -50.0)); // 1:30 o'clock, radius ≈ 71
+    /// assert!(!arc.contains(10.0, -10.0)); // inside the hole
+    /// assert!(!arc.contains(-50.0, 50.0)); // opposite side
+    /// ```
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        let (r0, r1) = if self.outer_radius < self.inner_radius {
+            (self.outer_radius, self.inner_radius)
+        } else {
+            (self.inner_radius, self.outer_radius)
+        };
+        let r = x.hypot(y);
+        if r < r0 || r > r1 {
+            return false;
+        }
+
+        let (a0, a1) = if self.end_angle < self.start_angle {
+            (self.end_angle, self.start_angle)
+        } else {
+            (self.start_angle, self.end_angle)
+        };
+        let sweep = a1 - a0;
+        if sweep >= TAU - EPSILON {
+            return true;
+        }
+        let offset = (x.atan2(-y) - a0).rem_euclid(TAU);
+        if offset > sweep {
+            return false;
+        }
+
+        let ap = self.pad_angle / 2.0;
+        if ap <= EPSILON {
+            return true;
+        }
+        // Perpendicular distance to each radial edge must exceed half the gap
+        let rp = self
+            .pad_radius
+            .unwrap_or_else(|| (r0 * r0 + r1 * r1).sqrt());
+        let half_gap = rp * ap.sin();
+        let edge_distance = |delta: f64| {
+            if delta < FRAC_PI_2 {
+                r * delta.sin()
+            } else {
+                r
+            }
+        };
+        edge_distance(offset) >= half_gap && edge_distance(sweep - offset) >= half_gap
+    }
 }
 
 #[cfg(test)]
@@ -399,4 +642,112 @@ mod tests {
         let path = datum.generate();
         assert!(!path.is_empty());
     }
+
+    fn has_arc_with_radius(path: &[PathSegment], r: f64) -> bool {
+        path.iter().any(|s| match s {
+            PathSegment::ArcTo { radius, .. } => (radius - r).abs() < 1e-9,
+            _ => false,
+        })
+    }
+
+    #[test]
+    fn test_arc_corner_radius() {
+        let arc = ArcGenerator::new()
+            .inner_radius(50.0)
+            .outer_radius(100.0)
+            .end_angle(PI / 2.0)
+            .corner_radius(10.0);
+        let path = arc.generate();
+
+        // Four corners, the outer and the inner ring
+        let arcs = path
+            .iter()
+            .filter(|s| matches!(s, PathSegment::ArcTo { .. }))
+            .count();
+        assert_eq!(arcs, 6);
+        assert!(has_arc_with_radius(&path, 10.0));
+        assert_eq!(path.last(), Some(&PathSegment::ClosePath));
+
+        // Clamped to half the ring thickness
+        let thick = arc.clone().corner_radius(100.0).generate();
+        assert!(has_arc_with_radius(&thick, 25.0));
+        for segment in &thick {
+            if let PathSegment::ArcTo { center, radius, .. } = segment {
+                assert!(center.distance(&Point::zero()) < 1e-9 || *radius <= 25.0 + 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_arc_corner_radius_thin_slice() {
+        let path = ArcGenerator::new()
+            .inner_radius(50.0)
+            .outer_radius(100.0)
+            .end_angle(0.1)
+            .corner_radius(20.0)
+            .generate();
+
+        // Corners shrink to fit the slice; merged corners leave no ring arc
+        for segment in &path {
+            if let PathSegment::ArcTo { radius, center, .. } = segment {
+                if center.distance(&Point::zero()) > 1e-9 {
+                    assert!(*radius < 20.0);
+                }
+            }
+        }
+        let end = path
+            .iter()
+            .find_map(|s| match s {
+                PathSegment::MoveTo(p) => Some(*p),
+                _ => None,
+            })
+            .unwrap();
+        assert!(end.distance(&Point::zero()) <= 100.0 + 1e-9);
+    }
+
+    #[test]
+    fn test_arc_pad_radius() {
+        // With a pad radius the gap has constant width rp * pad_angle
+        let arc = ArcGenerator::new()
+            .inner_radius(50.0)
+            .outer_radius(100.0)
+            .end_angle(PI / 2.0)
+            .pad_angle(0.02)
+            .pad_radius(100.0);
+        let path = arc.generate();
+
+        // The outer ring starts 1 unit (rp * sin(pad/2)) right of the y axis
+        match path[0] {
+            PathSegment::MoveTo(p) => assert!((p.x - 100.0 * 0.01f64.sin()).abs() < 1e-9),
+            ref other => panic!("expected move, got {:?}", other),
+        }
+        match path[2] {
+            PathSegment::ArcTo { start_angle, .. } => {
+                let inner = Point::new(50.0 * start_angle.cos(), 50.0 * start_angle.sin());
+                assert!((inner.y - -(100.0 * 0.01f64.sin())).abs() < 1e-9);
+            }
+            ref other => panic!("expected arc, got {:?}", other),
+        }
+        assert_eq!(arc.get_pad_radius(), Some(100.0));
+    }
+
+    #[test]
+    fn test_arc_datum_contains() {
+        let mut datum = ArcDatum::new(PI / 2.0, PI);
+        datum.inner_radius = 20.0;
+        assert!(datum.contains(50.0, 10.0));
+        assert!(!datum.contains(50.0, -10.0));
+        assert!(!datum.contains(5.0, 5.0));
+        assert!(!datum.contains(150.0, 10.0));
+
+        // Angles beyond a full turn wrap around
+        let wrapped = ArcDatum::new(TAU - 0.5, TAU + 0.5);
+        assert!(wrapped.contains(0.0, -50.0));
+
+        // Points in the pad gap are excluded
+        datum.pad_angle = 0.2;
+        datum.pad_radius = Some(50.0);
+        assert!(!datum.contains(60.0, 2.0));
+        assert!(datum.contains(60.0, 10.0));
+    }
 }
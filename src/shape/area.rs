@@ -6,11 +6,18 @@
 use super::curve::{Curve, LinearCurve};
 use super::path::{PathSegment, Point};
 use crate::data::DataPoint;
+use crate::error::{D3Error, D3Result};
+use std::ops::Range;
+
+type Accessor = Box<dyn Fn(&DataPoint, usize) -> f64 + Send + Sync>;
+type Predicate = Box<dyn Fn(&DataPoint, usize) -> bool + Send + Sync>;
 
 /// Area generator for filled regions
 ///
-/// Generates a closed path representing the area between a baseline (y0)
-/// and a top line (y1).
+/// Generates a closed path representing the area between a baseline
+/// (`x0`, `y0`) and a topline (`x1`, `y1`). For vertical areas `x1` defaults
+/// to `x0`; setting `x0`/`x1` with a shared `y` gives horizontal areas such
+/// as violins or population pyramids.
 ///
 /// # Example
 /// ```
@@ -27,14 +34,16 @@ use crate::data::DataPoint;
 /// let path = area.generate(&data);
 /// ```
 pub struct AreaGenerator {
-    /// Function to extract x coordinate
-    x_fn: Box<dyn Fn(&DataPoint, usize) -> f64 + Send + Sync>,
+    /// Function to extract baseline x coordinate (x0)
+    x0_fn: Accessor,
+    /// Function to extract topline x coordinate (x1), defaults to x0
+    x1_fn: Option<Accessor>,
     /// Function to extract baseline y coordinate (y0)
-    y0_fn: Box<dyn Fn(&DataPoint, usize) -> f64 + Send + Sync>,
+    y0_fn: Accessor,
     /// Function to extract top y coordinate (y1)
-    y1_fn: Box<dyn Fn(&DataPoint, usize) -> f64 + Send + Sync>,
+    y1_fn: Accessor,
     /// Function to determine if a point is defined
-    defined_fn: Box<dyn Fn(&DataPoint, usize) -> bool + Send + Sync>,
+    defined_fn: Predicate,
     /// Curve interpolation
     curve: Box<dyn Curve>,
 }
@@ -51,7 +60,8 @@ impl AreaGenerator {
     /// Default baseline is y=0, top is the data point's y value.
     pub fn new() -> Self {
         Self {
-            x_fn: Box::new(|d, i| d.x_or(i)),
+            x0_fn: Box::new(|d, i| d.x_or(i)),
+            x1_fn: None,
             y0_fn: Box::new(|_, _| 0.0),
             y1_fn: Box::new(|d, _| d.y),
             defined_fn: Box::new(|d, _| d.y.is_finite()),
@@ -59,12 +69,31 @@ impl AreaGenerator {
         }
     }
 
-    /// Set the x accessor function
+    /// Set the x accessor function for both the baseline and the topline
     pub fn x<F>(mut self, f: F) -> Self
     where
         F: Fn(&DataPoint, usize) -> f64 + Send + Sync + 'static,
     {
-        self.x_fn = Box::new(f);
+        self.x0_fn = Box::new(f);
+        self.x1_fn = None;
+        self
+    }
+
+    /// Set the baseline x accessor function (x0)
+    pub fn x0<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&DataPoint, usize) -> f64 + Send + Sync + 'static,
+    {
+        self.x0_fn = Box::new(f);
+        self
+    }
+
+    /// Set the topline x accessor function (x1)
+    pub fn x1<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&DataPoint, usize) -> f64 + Send + Sync + 'static,
+    {
+        self.x1_fn = Some(Box::new(f));
         self
     }
 
@@ -86,7 +115,9 @@ impl AreaGenerator {
         self
     }
 
-    /// Set both y0 and y1 to the same value (for range areas like error bands)
+    /// Set both y0 and y1 to the same value
+    ///
+    /// Used for horizontal areas, where the extent comes from `x0`/`x1`.
     pub fn y<F>(self, f: F) -> Self
     where
         F: Fn(&DataPoint, usize) -> f64 + Send + Sync + Clone + 'static,
@@ -96,6 +127,9 @@ impl AreaGenerator {
     }
 
     /// Set the defined predicate
+    ///
+    /// Each run of defined points produces its own closed subpath; gaps are
+    /// never bridged.
     pub fn defined<F>(mut self, f: F) -> Self
     where
         F: Fn(&DataPoint, usize) -> bool + Send + Sync + 'static,
@@ -111,46 +145,34 @@ impl AreaGenerator {
     }
 
     /// Generate path segments from data points
+    ///
+    /// Returns one closed subpath per run of defined points.
     pub fn generate(&self, data: &[DataPoint]) -> Vec<PathSegment> {
-        // Collect defined points
-        let mut top_points: Vec<Point> = Vec::new();
-        let mut bottom_points: Vec<Point> = Vec::new();
-
-        for (i, d) in data.iter().enumerate() {
-            if (self.defined_fn)(d, i) {
-                let x = (self.x_fn)(d, i);
-                let y0 = (self.y0_fn)(d, i);
-                let y1 = (self.y1_fn)(d, i);
-
-                top_points.push(Point::new(x, y1));
-                bottom_points.push(Point::new(x, y0));
+        let mut path = Vec::new();
+
+        for run in defined_runs(data, &self.defined_fn) {
+            let mut top_points = Vec::with_capacity(run.len());
+            let mut bottom_points = Vec::with_capacity(run.len());
+            for i in run {
+                let d = &data[i];
+                let x0 = (self.x0_fn)(d, i);
+                let x1 = self.x1_fn.as_ref().map_or(x0, |f| f(d, i));
+                top_points.push(Point::new(x1, (self.y1_fn)(d, i)));
+                bottom_points.push(Point::new(x0, (self.y0_fn)(d, i)));
             }
-        }
-
-        if top_points.is_empty() {
-            return vec![];
-        }
-
-        // Generate top curve
-        let mut path = self.curve.generate(&top_points);
 
-        // Reverse bottom points and generate bottom curve
-        bottom_points.reverse();
-        let bottom_path = self.curve.generate(&bottom_points);
+            // Topline forwards, then the baseline backwards
+            path.extend(self.curve.generate(&top_points));
 
-        // Connect to bottom curve (skip the MoveTo)
-        if bottom_path.len() > 1 {
-            // Add line to first bottom point
+            bottom_points.reverse();
+            let bottom_path = self.curve.generate(&bottom_points);
             if let Some(PathSegment::MoveTo(p)) = bottom_path.first() {
                 path.push(PathSegment::LineTo(*p));
             }
-            // Add rest of bottom curve
             path.extend(bottom_path.into_iter().skip(1));
+            path.push(PathSegment::ClosePath);
         }
 
-        // Close the path
-        path.push(PathSegment::ClosePath);
-
         path
     }
 
@@ -160,6 +182,69 @@ impl AreaGenerator {
     pub fn generate_range(&self, data: &[DataPoint]) -> Vec<PathSegment> {
         self.generate(data)
     }
+
+    /// Generate the baseline edge `(x0, y0)` as an open line
+    ///
+    /// Same as [`line_y0`](Self::line_y0).
+    pub fn line_x0(&self, data: &[DataPoint]) -> Vec<PathSegment> {
+        self.edge(data, &self.x0_fn, &self.y0_fn)
+    }
+
+    /// Generate the `(x1, y0)` edge as an open line
+    ///
+    /// For horizontal areas this is the right-hand outline.
+    pub fn line_x1(&self, data: &[DataPoint]) -> Vec<PathSegment> {
+        let x1 = self.x1_fn.as_ref().unwrap_or(&self.x0_fn);
+        self.edge(data, x1, &self.y0_fn)
+    }
+
+    /// Generate the baseline edge `(x0, y0)` as an open line
+    pub fn line_y0(&self, data: &[DataPoint]) -> Vec<PathSegment> {
+        self.edge(data, &self.x0_fn, &self.y0_fn)
+    }
+
+    /// Generate the `(x0, y1)` edge as an open line
+    ///
+    /// For vertical areas this is the topline, useful for stroking the
+    /// outline separately from the fill.
+    pub fn line_y1(&self, data: &[DataPoint]) -> Vec<PathSegment> {
+        self.edge(data, &self.x0_fn, &self.y1_fn)
+    }
+
+    /// Open line through one edge, split at undefined points
+    fn edge(&self, data: &[DataPoint], x: &Accessor, y: &Accessor) -> Vec<PathSegment> {
+        let mut path = Vec::new();
+        for run in defined_runs(data, &self.defined_fn) {
+            let points: Vec<Point> = run
+                .map(|i| Point::new(x(&data[i], i), y(&data[i], i)))
+                .collect();
+            path.extend(self.curve.generate(&points));
+        }
+        path
+    }
+}
+
+/// Index ranges of consecutive defined points
+pub(super) fn defined_runs(
+    data: &[DataPoint],
+    defined: &dyn Fn(&DataPoint, usize) -> bool,
+) -> Vec<Range<usize>> {
+    let mut runs = Vec::new();
+    let mut start = None;
+    for (i, d) in data.iter().enumerate() {
+        match (defined(d, i), start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                runs.push(s..i);
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        runs.push(s..data.len());
+    }
+    runs
 }
 
 /// Convenience functions for creating area generators with specific curves
@@ -186,6 +271,70 @@ impl AreaGenerator {
         use super::curve::MonotoneCurve;
         Self::new().curve(MonotoneCurve::new())
     }
+
+    /// Create an area generator with step-before interpolation
+    pub fn step_before() -> Self {
+        use super::curve::StepCurve;
+        Self::new().curve(StepCurve::before())
+    }
+
+    /// Create an area generator with step-after interpolation
+    pub fn step_after() -> Self {
+        use super::curve::StepCurve;
+        Self::new().curve(StepCurve::after())
+    }
+
+    /// Create an area generator with basis interpolation
+    pub fn basis() -> Self {
+        use super::curve::BasisCurve;
+        Self::new().curve(BasisCurve::new())
+    }
+
+    /// Create an area generator with cardinal interpolation
+    pub fn cardinal(tension: f64) -> Self {
+        use super::curve::CardinalCurve;
+        Self::new().curve(CardinalCurve::new(tension))
+    }
+
+    /// Create an area generator with natural cubic spline
+    pub fn natural() -> Self {
+        use super::curve::NaturalCurve;
+        Self::new().curve(NaturalCurve::new())
+    }
+
+    /// Create an area generator with monotone interpolation along y
+    pub fn monotone_y() -> Self {
+        use super::curve::MonotoneYCurve;
+        Self::new().curve(MonotoneYCurve::new())
+    }
+
+    /// Create an area generator with horizontal-tangent bump interpolation
+    pub fn bump_x() -> Self {
+        use super::curve::BumpCurve;
+        Self::new().curve(BumpCurve::x())
+    }
+
+    /// Create an area generator with vertical-tangent bump interpolation
+    pub fn bump_y() -> Self {
+        use super::curve::BumpCurve;
+        Self::new().curve(BumpCurve::y())
+    }
+
+    /// Create an area generator with a curve selected by its d3 name
+    ///
+    /// See [`curve::from_name`](super::curve::from_name) for accepted names.
+    /// Closed curves are rejected, since they would close the top line
+    /// before the baseline is drawn.
+    pub fn named(curve: &str) -> D3Result<Self> {
+        let curve = super::curve::from_name(curve)?;
+        if curve.is_closed() {
+            return Err(D3Error::config_error(format!(
+                "closed curve {:?} cannot outline an area",
+                curve.curve_type()
+            )));
+        }
+        Ok(Self::new().curve(curve))
+    }
 }
 
 #[cfg(test)]
@@ -260,5 +409,77 @@ mod tests {
         let _ = AreaGenerator::step().generate(&data);
         let _ = AreaGenerator::catmull_rom().generate(&data);
         let _ = AreaGenerator::monotone().generate(&data);
+        let _ = AreaGenerator::step_before().generate(&data);
+        let _ = AreaGenerator::step_after().generate(&data);
+        let _ = AreaGenerator::basis().generate(&data);
+        let _ = AreaGenerator::cardinal(0.5).generate(&data);
+        let _ = AreaGenerator::natural().generate(&data);
+        let _ = AreaGenerator::monotone_y().generate(&data);
+        let _ = AreaGenerator::bump_x().generate(&data);
+        let _ = AreaGenerator::bump_y().generate(&data);
+        assert!(AreaGenerator::named("bumpX").is_ok());
+        assert!(AreaGenerator::named("basisClosed").is_err());
+        assert!(AreaGenerator::named("cardinalClosed(0.5)").is_err());
+    }
+
+    #[test]
+    fn test_area_generator_horizontal() {
+        // Violin-style: extent along x, position along y
+        let data = vec![
+            DataPoint::from((2.0, 0.0)),
+            DataPoint::from((5.0, 10.0)),
+            DataPoint::from((3.0, 20.0)),
+        ];
+        let area = AreaGenerator::new()
+            .x0(|d, i| -d.x_or(i))
+            .x1(|d, i| d.x_or(i))
+            .y(|d, _| d.y);
+        let path = area.generate(&data);
+
+        assert_eq!(path[0], PathSegment::move_to(2.0, 0.0));
+        assert_eq!(path[2], PathSegment::line_to(3.0, 20.0));
+        assert_eq!(path[3], PathSegment::line_to(-3.0, 20.0));
+        assert_eq!(path[5], PathSegment::line_to(-2.0, 0.0));
+        assert_eq!(path[6], PathSegment::ClosePath);
+
+        assert_eq!(area.line_x1(&data)[1], PathSegment::line_to(5.0, 10.0));
+        assert_eq!(area.line_x0(&data)[1], PathSegment::line_to(-5.0, 10.0));
+    }
+
+    #[test]
+    fn test_area_generator_gaps_split_subpaths() {
+        let mut data = sample_data();
+        data.insert(2, DataPoint::new(75.0, f64::NAN));
+        let path = AreaGenerator::new().generate(&data);
+
+        let moves = path
+            .iter()
+            .filter(|s| matches!(s, PathSegment::MoveTo(_)))
+            .count();
+        let closes = path
+            .iter()
+            .filter(|s| matches!(s, PathSegment::ClosePath))
+            .count();
+        assert_eq!((moves, closes), (2, 2));
+
+        // The second subpath starts at the first point after the gap
+        let second = path
+            .iter()
+            .position(|s| *s == PathSegment::move_to(100.0, 120.0));
+        assert_eq!(second, Some(5));
+    }
+
+    #[test]
+    fn test_area_generator_edges() {
+        let data = sample_data();
+        let area = AreaGenerator::new().y0(|_, _| 10.0);
+
+        let top = area.line_y1(&data);
+        assert_eq!(top.len(), 4);
+        assert_eq!(top[3], PathSegment::line_to(150.0, 180.0));
+
+        let bottom = area.line_y0(&data);
+        assert_eq!(bottom[0], PathSegment::move_to(0.0, 10.0));
+        assert_eq!(bottom, area.line_x0(&data));
     }
 }
@@ -103,6 +103,138 @@ impl Curve for BasisCurve {
     }
 }
 
+/// B-spline segment for the knot window `p0, p1, p2`
+///
+/// Ends at the spline point near `p1`, starting from the previous one.
+fn basis_segment(p0: Point, p1: Point, p2: Point) -> PathSegment {
+    PathSegment::CurveTo {
+        cp1: Point::new((2.0 * p0.x + p1.x) / 3.0, (2.0 * p0.y + p1.y) / 3.0),
+        cp2: Point::new((p0.x + 2.0 * p1.x) / 3.0, (p0.y + 2.0 * p1.y) / 3.0),
+        end: basis_point(p0, p1, p2),
+    }
+}
+
+/// Point on the uniform cubic B-spline at the knot `p1`
+fn basis_point(p0: Point, p1: Point, p2: Point) -> Point {
+    Point::new(
+        (p0.x + 4.0 * p1.x + p2.x) / 6.0,
+        (p0.y + 4.0 * p1.y + p2.y) / 6.0,
+    )
+}
+
+/// Closed B-spline curve
+///
+/// Treats the points as a cyclic control polygon and produces a smooth
+/// closed loop. Like [`BasisCurve`], the loop does not pass through the
+/// points.
+///
+/// # Example
+/// ```
+/// use makepad_d3::shape::curve::{Curve, BasisClosedCurve};
+/// use makepad_d3::shape::{PathSegment, Point};
+///
+/// let points = vec![
+///     Point::new(0.0, 0.0),
+///     Point::new(100.0, 0.0),
+///     Point::new(100.0, 100.0),
+///     Point::new(0.0, 100.0),
+/// ];
+/// let path = BasisClosedCurve::new().generate(&points);
+/// assert_eq!(path.len(), 6); // MoveTo + 4 curves + ClosePath
+/// assert_eq!(path[5], PathSegment::ClosePath);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BasisClosedCurve;
+
+impl BasisClosedCurve {
+    /// Create a new closed basis curve
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Curve for BasisClosedCurve {
+    fn generate(&self, points: &[Point]) -> Vec<PathSegment> {
+        let n = points.len();
+        match n {
+            0 => return vec![],
+            1 => return vec![PathSegment::MoveTo(points[0]), PathSegment::ClosePath],
+            2 => {
+                let (a, b) = (points[0], points[1]);
+                return vec![
+                    PathSegment::MoveTo(a.lerp(&b, 2.0 / 3.0)),
+                    PathSegment::LineTo(a.lerp(&b, 1.0 / 3.0)),
+                    PathSegment::ClosePath,
+                ];
+            }
+            _ => {}
+        }
+
+        let at = |i: usize| points[i % n];
+        let mut path = Vec::with_capacity(n + 2);
+        path.push(PathSegment::MoveTo(basis_point(at(0), at(1), at(2))));
+        for i in 1..=n {
+            path.push(basis_segment(at(i), at(i + 1), at(i + 2)));
+        }
+        path.push(PathSegment::ClosePath);
+        path
+    }
+
+    fn curve_type(&self) -> &'static str {
+        "basis-closed"
+    }
+}
+
+/// Open B-spline curve
+///
+/// Like [`BasisCurve`], but without the straight lead-in and lead-out to the
+/// first and last points: the curve starts and ends on the spline itself.
+///
+/// # Example
+/// ```
+/// use makepad_d3::shape::curve::{Curve, BasisOpenCurve};
+/// use makepad_d3::shape::Point;
+///
+/// let points = vec![
+///     Point::new(0.0, 0.0),
+///     Point::new(60.0, 60.0),
+///     Point::new(120.0, 0.0),
+///     Point::new(180.0, 60.0),
+/// ];
+/// let path = BasisOpenCurve::new().generate(&points);
+/// assert_eq!(path.len(), 2); // MoveTo + 1 curve
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BasisOpenCurve;
+
+impl BasisOpenCurve {
+    /// Create a new open basis curve
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Curve for BasisOpenCurve {
+    fn generate(&self, points: &[Point]) -> Vec<PathSegment> {
+        if points.len() < 3 {
+            return vec![];
+        }
+
+        let mut path = Vec::with_capacity(points.len() - 1);
+        path.push(PathSegment::MoveTo(basis_point(
+            points[0], points[1], points[2],
+        )));
+        for window in points.windows(3).skip(1) {
+            path.push(basis_segment(window[0], window[1], window[2]));
+        }
+        path
+    }
+
+    fn curve_type(&self) -> &'static str {
+        "basis-open"
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -256,4 +388,44 @@ mod tests {
         let curve = BasisCurve::new();
         assert_eq!(curve.curve_type(), "basis");
     }
+
+    #[test]
+    fn test_basis_closed_is_continuous() {
+        let points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(90.0, 10.0),
+            Point::new(100.0, 100.0),
+            Point::new(10.0, 80.0),
+            Point::new(-20.0, 40.0),
+        ];
+        let path = BasisClosedCurve::new().generate(&points);
+        let start = path[0].end_point().unwrap();
+        let last = path[path.len() - 2].end_point().unwrap();
+        assert!(approx_eq(start.x, last.x) && approx_eq(start.y, last.y));
+        assert_eq!(path.len(), points.len() + 2);
+    }
+
+    #[test]
+    fn test_basis_open_matches_d3() {
+        let points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(60.0, 60.0),
+            Point::new(120.0, 0.0),
+            Point::new(180.0, 60.0),
+            Point::new(240.0, 0.0),
+        ];
+        let path = BasisOpenCurve::new().generate(&points);
+        assert_eq!(path.len(), 3);
+        // d3: M60,40C80,20,100,20,120,20C140,20,160,40,180,40
+        assert_eq!(path[0], PathSegment::MoveTo(Point::new(60.0, 40.0)));
+        match &path[2] {
+            PathSegment::CurveTo { cp1, cp2, end } => {
+                assert!(approx_eq(cp1.x, 140.0) && approx_eq(cp1.y, 20.0));
+                assert!(approx_eq(cp2.x, 160.0) && approx_eq(cp2.y, 40.0));
+                assert!(approx_eq(end.x, 180.0) && approx_eq(end.y, 40.0));
+            }
+            _ => panic!("Expected CurveTo"),
+        }
+        assert!(BasisOpenCurve::new().generate(&points[..2]).is_empty());
+    }
 }
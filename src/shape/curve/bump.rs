@@ -0,0 +1,113 @@
+//! Bump curve interpolation
+
+use super::{Curve, PathSegment, Point};
+
+/// Axis along which a bump curve's tangents run
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BumpAxis {
+    /// Horizontal tangents at every point (bumpX)
+    #[default]
+    X,
+    /// Vertical tangents at every point (bumpY)
+    Y,
+}
+
+/// Bump curve
+///
+/// Connects consecutive points with cubic Béziers whose tangents are
+/// horizontal ([`BumpAxis::X`]) or vertical ([`BumpAxis::Y`]) at each point.
+/// Used for bump charts and for node-link edges.
+///
+/// # Example
+/// ```
+/// use makepad_d3::shape::curve::{Curve, BumpCurve};
+/// use makepad_d3::shape::{PathSegment, Point};
+///
+/// let curve = BumpCurve::x();
+/// let points = vec![Point::new(0.0, 0.0), Point::new(100.0, 50.0)];
+/// let path = curve.generate(&points);
+/// assert_eq!(path[1], PathSegment::curve_to(50.0, 0.0, 50.0, 50.0, 100.0, 50.0));
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BumpCurve {
+    /// Tangent axis
+    pub axis: BumpAxis,
+}
+
+impl BumpCurve {
+    /// Create a new bump curve along the given axis
+    pub fn new(axis: BumpAxis) -> Self {
+        Self { axis }
+    }
+
+    /// Create a bump curve with horizontal tangents
+    pub fn x() -> Self {
+        Self::new(BumpAxis::X)
+    }
+
+    /// Create a bump curve with vertical tangents
+    pub fn y() -> Self {
+        Self::new(BumpAxis::Y)
+    }
+}
+
+impl Curve for BumpCurve {
+    fn generate(&self, points: &[Point]) -> Vec<PathSegment> {
+        if points.is_empty() {
+            return vec![];
+        }
+
+        let mut path = Vec::with_capacity(points.len());
+        path.push(PathSegment::MoveTo(points[0]));
+
+        for pair in points.windows(2) {
+            let (p0, p1) = (pair[0], pair[1]);
+            let (cp1, cp2) = match self.axis {
+                BumpAxis::X => {
+                    let mx = (p0.x + p1.x) / 2.0;
+                    (Point::new(mx, p0.y), Point::new(mx, p1.y))
+                }
+                BumpAxis::Y => {
+                    let my = (p0.y + p1.y) / 2.0;
+                    (Point::new(p0.x, my), Point::new(p1.x, my))
+                }
+            };
+            path.push(PathSegment::CurveTo { cp1, cp2, end: p1 });
+        }
+
+        path
+    }
+
+    fn curve_type(&self) -> &'static str {
+        match self.axis {
+            BumpAxis::X => "bump-x",
+            BumpAxis::Y => "bump-y",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bump_y() {
+        let points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(40.0, 100.0),
+            Point::new(0.0, 200.0),
+        ];
+        let path = BumpCurve::y().generate(&points);
+        assert_eq!(path.len(), 3);
+        assert_eq!(
+            path[2],
+            PathSegment::curve_to(40.0, 150.0, 0.0, 150.0, 0.0, 200.0)
+        );
+    }
+
+    #[test]
+    fn test_bump_single_point() {
+        let path = BumpCurve::x().generate(&[Point::new(1.0, 1.0)]);
+        assert_eq!(path, vec![PathSegment::MoveTo(Point::new(1.0, 1.0))]);
+    }
+}
@@ -0,0 +1,123 @@
+//! Bundle curve interpolation for hierarchical edge bundling
+
+use super::{BasisCurve, Curve, PathSegment, Point};
+
+/// Bundle curve
+///
+/// A [`BasisCurve`] whose control points are first pulled towards the
+/// straight line between the first and last point. The `beta` parameter
+/// sets the bundle strength:
+///
+/// - Beta 0: Straight line from first to last point
+/// - Beta 1: Plain B-spline through the control points
+///
+/// Typically used with paths through a hierarchy (leaf → ancestors → leaf)
+/// to draw bundled edges.
+///
+/// # Example
+/// ```
+/// use makepad_d3::shape::curve::{Curve, BundleCurve};
+/// use makepad_d3::shape::Point;
+///
+/// let curve = BundleCurve::new(0.85);
+/// let points = vec![
+///     Point::new(0.0, 0.0),
+///     Point::new(50.0, 100.0),
+///     Point::new(100.0, 0.0),
+/// ];
+/// let path = curve.generate(&points);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct BundleCurve {
+    /// Bundle strength (0 to 1)
+    pub beta: f64,
+}
+
+impl Default for BundleCurve {
+    fn default() -> Self {
+        Self { beta: 0.85 }
+    }
+}
+
+impl BundleCurve {
+    /// Create a new bundle curve with given beta
+    pub fn new(beta: f64) -> Self {
+        Self {
+            beta: beta.clamp(0.0, 1.0),
+        }
+    }
+}
+
+impl Curve for BundleCurve {
+    fn generate(&self, points: &[Point]) -> Vec<PathSegment> {
+        let n = points.len();
+        if n < 3 || self.beta == 1.0 {
+            return BasisCurve.generate(points);
+        }
+
+        let first = points[0];
+        let delta = points[n - 1].sub(&first);
+        let straightened: Vec<Point> = points
+            .iter()
+            .enumerate()
+            .map(|(i, p)| {
+                let t = i as f64 / (n - 1) as f64;
+                let on_line = first.add(&delta.scale(t));
+                on_line.lerp(p, self.beta)
+            })
+            .collect();
+
+        BasisCurve.generate(&straightened)
+    }
+
+    fn curve_type(&self) -> &'static str {
+        "bundle"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Vec<Point> {
+        vec![
+            Point::new(0.0, 0.0),
+            Point::new(50.0, 100.0),
+            Point::new(100.0, 0.0),
+        ]
+    }
+
+    #[test]
+    fn test_bundle_beta_zero_is_straight() {
+        let path = BundleCurve::new(0.0).generate(&sample());
+        for segment in &path {
+            if let Some(p) = segment.end_point() {
+                assert!(p.y.abs() < 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_bundle_beta_one_is_basis() {
+        let points = sample();
+        assert_eq!(
+            BundleCurve::new(1.0).generate(&points),
+            BasisCurve.generate(&points)
+        );
+    }
+
+    #[test]
+    fn test_bundle_pulls_towards_chord() {
+        let points = sample();
+        let peak = |path: &[PathSegment]| {
+            path.iter()
+                .filter_map(|s| s.end_point())
+                .map(|p| p.y)
+                .fold(0.0, f64::max)
+        };
+        let loose = peak(&BundleCurve::new(0.5).generate(&points));
+        let tight = peak(&BasisCurve.generate(&points));
+        assert!(loose > 0.0 && loose < tight);
+        assert!((loose - tight * 0.5).abs() < 1e-10);
+    }
+}
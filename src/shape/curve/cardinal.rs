@@ -1,6 +1,6 @@
 //! Cardinal spline curve interpolation
 
-use super::{Curve, PathSegment, Point};
+use super::{closed_segments, open_segments, Curve, PathSegment, Point};
 
 /// Cardinal spline curve
 ///
@@ -99,6 +99,108 @@ impl Curve for CardinalCurve {
     }
 }
 
+/// Cardinal segment from `p1` to `p2` with neighbours `p0` and `p3`
+fn cardinal_segment(p0: Point, p1: Point, p2: Point, p3: Point, k: f64) -> PathSegment {
+    PathSegment::CurveTo {
+        cp1: Point::new(p1.x + k * (p2.x - p0.x), p1.y + k * (p2.y - p0.y)),
+        cp2: Point::new(p2.x - k * (p3.x - p1.x), p2.y - k * (p3.y - p1.y)),
+        end: p2,
+    }
+}
+
+/// Closed cardinal spline curve
+///
+/// Like [`CardinalCurve`], but treats the points as a loop: the curve
+/// returns smoothly from the last point to the first.
+///
+/// # Example
+/// ```
+/// use makepad_d3::shape::curve::{Curve, CardinalClosedCurve};
+/// use makepad_d3::shape::{PathSegment, Point};
+///
+/// let points = vec![
+///     Point::new(0.0, 0.0),
+///     Point::new(100.0, 0.0),
+///     Point::new(50.0, 80.0),
+/// ];
+/// let path = CardinalClosedCurve::new(0.5).generate(&points);
+/// assert_eq!(path.len(), 5); // MoveTo + 3 curves + ClosePath
+/// assert_eq!(path[4], PathSegment::ClosePath);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CardinalClosedCurve {
+    /// Tension parameter (0 to 1)
+    pub tension: f64,
+}
+
+impl CardinalClosedCurve {
+    /// Create a new closed cardinal curve with given tension
+    pub fn new(tension: f64) -> Self {
+        Self {
+            tension: tension.clamp(0.0, 1.0),
+        }
+    }
+}
+
+impl Curve for CardinalClosedCurve {
+    fn generate(&self, points: &[Point]) -> Vec<PathSegment> {
+        closed_segments(points, |p0, p1, p2, p3| {
+            cardinal_segment(p0, p1, p2, p3, (1.0 - self.tension) / 6.0)
+        })
+    }
+
+    fn curve_type(&self) -> &'static str {
+        "cardinal-closed"
+    }
+}
+
+/// Open cardinal spline curve
+///
+/// Like [`CardinalCurve`], but the first and last points only shape the
+/// tangents: the curve runs from the second point to the second-to-last.
+///
+/// # Example
+/// ```
+/// use makepad_d3::shape::curve::{Curve, CardinalOpenCurve};
+/// use makepad_d3::shape::{PathSegment, Point};
+///
+/// let points = vec![
+///     Point::new(0.0, 0.0),
+///     Point::new(50.0, 100.0),
+///     Point::new(100.0, 50.0),
+///     Point::new(150.0, 100.0),
+/// ];
+/// let path = CardinalOpenCurve::new(0.0).generate(&points);
+/// assert_eq!(path[0], PathSegment::MoveTo(points[1]));
+/// assert_eq!(path.len(), 2);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CardinalOpenCurve {
+    /// Tension parameter (0 to 1)
+    pub tension: f64,
+}
+
+impl CardinalOpenCurve {
+    /// Create a new open cardinal curve with given tension
+    pub fn new(tension: f64) -> Self {
+        Self {
+            tension: tension.clamp(0.0, 1.0),
+        }
+    }
+}
+
+impl Curve for CardinalOpenCurve {
+    fn generate(&self, points: &[Point]) -> Vec<PathSegment> {
+        open_segments(points, |p0, p1, p2, p3| {
+            cardinal_segment(p0, p1, p2, p3, (1.0 - self.tension) / 6.0)
+        })
+    }
+
+    fn curve_type(&self) -> &'static str {
+        "cardinal-open"
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -145,4 +247,40 @@ mod tests {
         let path = curve.generate(&points);
         assert_eq!(path.len(), 2); // Falls back to linear
     }
+
+    #[test]
+    fn test_cardinal_closed_wraps_tangents() {
+        let points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(100.0, 0.0),
+            Point::new(100.0, 100.0),
+            Point::new(0.0, 100.0),
+        ];
+        let path = CardinalClosedCurve::new(0.0).generate(&points);
+        assert_eq!(path.len(), 6);
+        // Segment back to the start uses p2 and p1 as neighbours
+        match &path[4] {
+            PathSegment::CurveTo { cp1, cp2, end } => {
+                let k = 100.0 / 6.0;
+                assert_eq!(*end, points[0]);
+                assert!(cp1.distance(&Point::new(-k, 100.0 - k)) < 1e-10);
+                assert!(cp2.distance(&Point::new(-k, k)) < 1e-10);
+            }
+            _ => panic!("Expected CurveTo"),
+        }
+    }
+
+    #[test]
+    fn test_cardinal_open_skips_end_points() {
+        let points: Vec<Point> = (0..5)
+            .map(|i| Point::new(i as f64 * 10.0, (i % 2) as f64 * 10.0))
+            .collect();
+        let path = CardinalOpenCurve::new(0.5).generate(&points);
+        assert_eq!(path.len(), 3);
+        assert_eq!(path.last().unwrap().end_point(), Some(points[3]));
+        assert_eq!(
+            CardinalOpenCurve::new(0.5).generate(&points[..3]),
+            vec![PathSegment::MoveTo(points[1])]
+        );
+    }
 }
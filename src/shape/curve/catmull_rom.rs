@@ -1,6 +1,6 @@
 //! Catmull-Rom spline curve interpolation
 
-use super::{Curve, PathSegment, Point};
+use super::{closed_segments, open_segments, Curve, PathSegment, Point};
 
 /// Catmull-Rom spline curve
 ///
@@ -211,6 +211,123 @@ impl Curve for CatmullRomCurve {
     }
 }
 
+/// Closed Catmull-Rom spline curve
+///
+/// Like [`CatmullRomCurve`], but treats the points as a loop that passes
+/// through every point and returns smoothly to the first.
+///
+/// # Example
+/// ```
+/// use makepad_d3::shape::curve::{Curve, CatmullRomClosedCurve};
+/// use makepad_d3::shape::{PathSegment, Point};
+///
+/// let points = vec![
+///     Point::new(0.0, 0.0),
+///     Point::new(100.0, 0.0),
+///     Point::new(50.0, 80.0),
+/// ];
+/// let path = CatmullRomClosedCurve::centripetal().generate(&points);
+/// assert_eq!(path.len(), 5); // MoveTo + 3 curves + ClosePath
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct CatmullRomClosedCurve {
+    /// Alpha parameter (0.0 = uniform, 0.5 = centripetal, 1.0 = chordal)
+    pub alpha: f64,
+}
+
+impl Default for CatmullRomClosedCurve {
+    fn default() -> Self {
+        Self::centripetal()
+    }
+}
+
+impl CatmullRomClosedCurve {
+    /// Create a new closed Catmull-Rom curve with given alpha
+    pub fn new(alpha: f64) -> Self {
+        Self {
+            alpha: alpha.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Create a centripetal closed Catmull-Rom curve (alpha = 0.5)
+    pub fn centripetal() -> Self {
+        Self::new(0.5)
+    }
+}
+
+impl Curve for CatmullRomClosedCurve {
+    fn generate(&self, points: &[Point]) -> Vec<PathSegment> {
+        let spline = CatmullRomCurve::new(self.alpha);
+        closed_segments(points, |p0, p1, p2, p3| {
+            let (cp1, cp2) = spline.to_bezier(p0, p1, p2, p3);
+            PathSegment::CurveTo { cp1, cp2, end: p2 }
+        })
+    }
+
+    fn curve_type(&self) -> &'static str {
+        "catmull-rom-closed"
+    }
+}
+
+/// Open Catmull-Rom spline curve
+///
+/// Like [`CatmullRomCurve`], but the first and last points only shape the
+/// tangents: the curve runs from the second point to the second-to-last.
+///
+/// # Example
+/// ```
+/// use makepad_d3::shape::curve::{Curve, CatmullRomOpenCurve};
+/// use makepad_d3::shape::{PathSegment, Point};
+///
+/// let points = vec![
+///     Point::new(0.0, 0.0),
+///     Point::new(50.0, 100.0),
+///     Point::new(100.0, 50.0),
+///     Point::new(150.0, 100.0),
+/// ];
+/// let path = CatmullRomOpenCurve::centripetal().generate(&points);
+/// assert_eq!(path[0], PathSegment::MoveTo(points[1]));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct CatmullRomOpenCurve {
+    /// Alpha parameter (0.0 = uniform, 0.5 = centripetal, 1.0 = chordal)
+    pub alpha: f64,
+}
+
+impl Default for CatmullRomOpenCurve {
+    fn default() -> Self {
+        Self::centripetal()
+    }
+}
+
+impl CatmullRomOpenCurve {
+    /// Create a new open Catmull-Rom curve with given alpha
+    pub fn new(alpha: f64) -> Self {
+        Self {
+            alpha: alpha.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Create a centripetal open Catmull-Rom curve (alpha = 0.5)
+    pub fn centripetal() -> Self {
+        Self::new(0.5)
+    }
+}
+
+impl Curve for CatmullRomOpenCurve {
+    fn generate(&self, points: &[Point]) -> Vec<PathSegment> {
+        let spline = CatmullRomCurve::new(self.alpha);
+        open_segments(points, |p0, p1, p2, p3| {
+            let (cp1, cp2) = spline.to_bezier(p0, p1, p2, p3);
+            PathSegment::CurveTo { cp1, cp2, end: p2 }
+        })
+    }
+
+    fn curve_type(&self) -> &'static str {
+        "catmull-rom-open"
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -262,4 +379,46 @@ mod tests {
         let path = curve.generate(&points);
         assert_eq!(path.len(), 2); // Falls back to linear
     }
+
+    #[test]
+    fn test_catmull_rom_closed_passes_through_points() {
+        let points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(100.0, 10.0),
+            Point::new(120.0, 90.0),
+            Point::new(10.0, 100.0),
+        ];
+        let path = CatmullRomClosedCurve::centripetal().generate(&points);
+        let ends: Vec<Point> = path[1..5].iter().filter_map(|s| s.end_point()).collect();
+        assert_eq!(ends, vec![points[1], points[2], points[3], points[0]]);
+        assert_eq!(path[5], PathSegment::ClosePath);
+    }
+
+    #[test]
+    fn test_catmull_rom_open_uniform_matches_cardinal() {
+        use crate::shape::curve::CardinalOpenCurve;
+
+        let points: Vec<Point> = (0..6)
+            .map(|i| Point::new(i as f64 * 20.0, ((i * 7) % 5) as f64 * 15.0))
+            .collect();
+        let catmull = CatmullRomOpenCurve::new(0.0).generate(&points);
+        let cardinal = CardinalOpenCurve::new(0.0).generate(&points);
+        assert_eq!(catmull.len(), cardinal.len());
+        for (a, b) in catmull.iter().zip(&cardinal) {
+            match (a, b) {
+                (
+                    PathSegment::CurveTo { cp1, cp2, end },
+                    PathSegment::CurveTo {
+                        cp1: c1,
+                        cp2: c2,
+                        end: e,
+                    },
+                ) => {
+                    assert!(cp1.distance(c1) < 1e-9 && cp2.distance(c2) < 1e-9);
+                    assert_eq!(end, e);
+                }
+                _ => assert_eq!(a, b),
+            }
+        }
+    }
 }
@@ -51,6 +51,51 @@ impl Curve for LinearCurve {
     }
 }
 
+/// Closed linear interpolation - a polygon through all points
+///
+/// Like [`LinearCurve`], but joins the last point back to the first. Useful
+/// for radar charts.
+///
+/// # Example
+/// ```
+/// use makepad_d3::shape::curve::{Curve, LinearClosedCurve};
+/// use makepad_d3::shape::{PathSegment, Point};
+///
+/// let points = vec![
+///     Point::new(0.0, 0.0),
+///     Point::new(50.0, 100.0),
+///     Point::new(100.0, 0.0),
+/// ];
+/// let path = LinearClosedCurve.generate(&points);
+/// assert_eq!(path.len(), 4); // MoveTo + 2 LineTo + ClosePath
+/// assert_eq!(path[3], PathSegment::ClosePath);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LinearClosedCurve;
+
+impl LinearClosedCurve {
+    /// Create a new closed linear curve
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Curve for LinearClosedCurve {
+    fn generate(&self, points: &[Point]) -> Vec<PathSegment> {
+        if points.is_empty() {
+            return vec![];
+        }
+
+        let mut path = LinearCurve.generate(points);
+        path.push(PathSegment::ClosePath);
+        path
+    }
+
+    fn curve_type(&self) -> &'static str {
+        "linear-closed"
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -84,4 +129,17 @@ mod tests {
         let path = curve.generate(&points);
         assert_eq!(path.len(), 5);
     }
+
+    #[test]
+    fn test_linear_closed_single_point() {
+        let path = LinearClosedCurve.generate(&[Point::new(1.0, 2.0)]);
+        assert_eq!(
+            path,
+            vec![
+                PathSegment::MoveTo(Point::new(1.0, 2.0)),
+                PathSegment::ClosePath
+            ]
+        );
+        assert!(LinearClosedCurve.generate(&[]).is_empty());
+    }
 }
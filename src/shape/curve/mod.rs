@@ -6,14 +6,27 @@
 //! # Available Curves
 //!
 //! - [`LinearCurve`]: Straight line segments between points
+//! - [`LinearClosedCurve`]: Closed polygon through all points
 //! - [`StepCurve`]: Step function with configurable position
 //! - [`BasisCurve`]: B-spline interpolation (smooth, doesn't pass through points)
+//! - [`BasisClosedCurve`], [`BasisOpenCurve`]: Closed and open B-splines
+//! - [`BundleCurve`]: Straightened B-spline for hierarchical edge bundling
 //! - [`CardinalCurve`]: Cardinal spline with tension parameter
+//! - [`CardinalClosedCurve`], [`CardinalOpenCurve`]: Closed and open cardinal
+//!   splines
 //! - [`CatmullRomCurve`]: Catmull-Rom spline (passes through all points)
+//! - [`CatmullRomClosedCurve`], [`CatmullRomOpenCurve`]: Closed and open
+//!   Catmull-Rom splines
 //! - [`MonotoneCurve`]: Monotone cubic interpolation (preserves monotonicity)
+//! - [`MonotoneYCurve`]: Monotone cubic interpolation along the y axis
 //! - [`NaturalCurve`]: Natural cubic spline (C2 continuous)
+//! - [`BumpCurve`]: Horizontal or vertical tangent Béziers (bumpX/bumpY)
+//!
+//! Curves can also be selected by their d3 name with [`from_name`].
 
 mod basis;
+mod bump;
+mod bundle;
 mod cardinal;
 mod catmull_rom;
 mod linear;
@@ -21,15 +34,18 @@ mod monotone;
 mod natural;
 mod step;
 
-pub use basis::BasisCurve;
-pub use cardinal::CardinalCurve;
-pub use catmull_rom::CatmullRomCurve;
-pub use linear::LinearCurve;
-pub use monotone::MonotoneCurve;
+pub use basis::{BasisClosedCurve, BasisCurve, BasisOpenCurve};
+pub use bump::{BumpAxis, BumpCurve};
+pub use bundle::BundleCurve;
+pub use cardinal::{CardinalClosedCurve, CardinalCurve, CardinalOpenCurve};
+pub use catmull_rom::{CatmullRomClosedCurve, CatmullRomCurve, CatmullRomOpenCurve};
+pub use linear::{LinearClosedCurve, LinearCurve};
+pub use monotone::{MonotoneCurve, MonotoneYCurve};
 pub use natural::NaturalCurve;
 pub use step::{StepCurve, StepPosition};
 
 use super::path::{PathSegment, Point};
+use crate::error::{D3Error, D3Result};
 
 /// Trait for curve interpolation algorithms
 pub trait Curve: Send + Sync {
@@ -38,6 +54,21 @@ pub trait Curve: Send + Sync {
 
     /// Get the curve type name
     fn curve_type(&self) -> &'static str;
+
+    /// Check whether the curve closes back to its first point
+    fn is_closed(&self) -> bool {
+        self.curve_type().ends_with("-closed")
+    }
+}
+
+impl Curve for Box<dyn Curve> {
+    fn generate(&self, points: &[Point]) -> Vec<PathSegment> {
+        (**self).generate(points)
+    }
+
+    fn curve_type(&self) -> &'static str {
+        (**self).curve_type()
+    }
 }
 
 /// Box a curve for dynamic dispatch
@@ -45,6 +76,121 @@ pub fn box_curve(curve: impl Curve + 'static) -> Box<dyn Curve> {
     Box::new(curve)
 }
 
+/// Look up a curve by its d3 name
+///
+/// Names are matched case-insensitively, with or without d3's `curve`
+/// prefix, in camelCase, kebab-case or snake_case (`"curveCatmullRomOpen"`,
+/// `"catmull-rom-open"` and `"catmull_rom_open"` are equivalent). The
+/// parameter of `bundle` (beta), `cardinal*` (tension) and `catmullRom*`
+/// (alpha) may be given in parentheses, e.g. `"bundle(0.5)"`.
+///
+/// # Example
+/// ```
+/// use makepad_d3::shape::curve::{self, Curve};
+///
+/// assert_eq!(curve::from_name("curveBasisClosed").unwrap().curve_type(), "basis-closed");
+/// assert_eq!(curve::from_name("bump-y").unwrap().curve_type(), "bump-y");
+/// assert_eq!(curve::from_name("cardinal(0.5)").unwrap().curve_type(), "cardinal");
+/// assert!(curve::from_name("wiggly").is_err());
+/// ```
+pub fn from_name(name: &str) -> D3Result<Box<dyn Curve>> {
+    let trimmed = name.trim();
+    let (base, param) = match trimmed.find('(') {
+        Some(open) if trimmed.ends_with(')') => {
+            let arg = trimmed[open + 1..trimmed.len() - 1].trim();
+            let value: f64 = arg
+                .parse()
+                .map_err(|_| D3Error::parse_error(format!("invalid curve parameter: {}", arg)))?;
+            (&trimmed[..open], Some(value))
+        }
+        _ => (trimmed, None),
+    };
+
+    let mut key: String = base
+        .chars()
+        .filter(|c| !matches!(c, '-' | '_' | ' '))
+        .collect::<String>()
+        .to_ascii_lowercase();
+    if key.starts_with("curve") && key.len() > 5 {
+        key.drain(..5);
+    }
+
+    let curve: Box<dyn Curve> = match key.as_str() {
+        "linear" => Box::new(LinearCurve),
+        "linearclosed" => Box::new(LinearClosedCurve),
+        "step" => Box::new(StepCurve::middle()),
+        "stepbefore" => Box::new(StepCurve::before()),
+        "stepafter" => Box::new(StepCurve::after()),
+        "basis" => Box::new(BasisCurve),
+        "basisclosed" => Box::new(BasisClosedCurve),
+        "basisopen" => Box::new(BasisOpenCurve),
+        "bundle" => Box::new(BundleCurve::new(param.unwrap_or(0.85))),
+        "cardinal" => Box::new(CardinalCurve::new(param.unwrap_or(0.0))),
+        "cardinalclosed" => Box::new(CardinalClosedCurve::new(param.unwrap_or(0.0))),
+        "cardinalopen" => Box::new(CardinalOpenCurve::new(param.unwrap_or(0.0))),
+        "catmullrom" => Box::new(CatmullRomCurve::new(param.unwrap_or(0.5))),
+        "catmullromclosed" => Box::new(CatmullRomClosedCurve::new(param.unwrap_or(0.5))),
+        "catmullromopen" => Box::new(CatmullRomOpenCurve::new(param.unwrap_or(0.5))),
+        "monotone" | "monotonex" => Box::new(MonotoneCurve),
+        "monotoney" => Box::new(MonotoneYCurve),
+        "natural" => Box::new(NaturalCurve::new()),
+        "bumpx" => Box::new(BumpCurve::x()),
+        "bumpy" => Box::new(BumpCurve::y()),
+        _ => return Err(D3Error::parse_error(format!("unknown curve: {}", name))),
+    };
+    Ok(curve)
+}
+
+/// Build a closed loop of segments over a cyclic point sequence
+///
+/// `segment(p0, p1, p2, p3)` produces the segment from `p1` to `p2`.
+fn closed_segments(
+    points: &[Point],
+    segment: impl Fn(Point, Point, Point, Point) -> PathSegment,
+) -> Vec<PathSegment> {
+    let n = points.len();
+    match n {
+        0 => return vec![],
+        1 => return vec![PathSegment::MoveTo(points[0]), PathSegment::ClosePath],
+        2 => {
+            return vec![
+                PathSegment::MoveTo(points[0]),
+                PathSegment::LineTo(points[1]),
+                PathSegment::ClosePath,
+            ]
+        }
+        _ => {}
+    }
+
+    let at = |i: usize| points[i % n];
+    let mut path = Vec::with_capacity(n + 2);
+    path.push(PathSegment::MoveTo(points[0]));
+    for i in 0..n {
+        path.push(segment(at(i + n - 1), at(i), at(i + 1), at(i + 2)));
+    }
+    path.push(PathSegment::ClosePath);
+    path
+}
+
+/// Build segments between the inner points of a sequence
+///
+/// The first and last points only serve as tangent neighbours.
+fn open_segments(
+    points: &[Point],
+    segment: impl Fn(Point, Point, Point, Point) -> PathSegment,
+) -> Vec<PathSegment> {
+    if points.len() < 3 {
+        return vec![];
+    }
+
+    let mut path = Vec::with_capacity(points.len() - 2);
+    path.push(PathSegment::MoveTo(points[1]));
+    for window in points.windows(4) {
+        path.push(segment(window[0], window[1], window[2], window[3]));
+    }
+    path
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -82,6 +228,37 @@ mod tests {
         assert!(path.is_empty());
     }
 
+    #[test]
+    fn test_from_name_variants() {
+        let cases = [
+            ("linear", "linear"),
+            ("curveLinearClosed", "linear-closed"),
+            ("step-after", "step-after"),
+            ("basis_open", "basis-open"),
+            ("bundle(0.5)", "bundle"),
+            ("CatmullRomClosed", "catmull-rom-closed"),
+            ("monotoneX", "monotone"),
+            ("monotoneY", "monotone-y"),
+            ("bumpX", "bump-x"),
+        ];
+        for (name, expected) in cases {
+            assert_eq!(from_name(name).unwrap().curve_type(), expected, "{}", name);
+        }
+
+        assert!(from_name("bundle(abc)").is_err());
+        assert!(from_name("curve").is_err());
+    }
+
+    #[test]
+    fn test_boxed_curve_in_generator() {
+        use crate::data::DataPoint;
+        use crate::shape::LineGenerator;
+
+        let data: Vec<DataPoint> = (0..4).map(|i| DataPoint::from((i as f64, 1.0))).collect();
+        let line = LineGenerator::new().curve(from_name("linearClosed").unwrap());
+        assert_eq!(line.generate(&data).last(), Some(&PathSegment::ClosePath));
+    }
+
     #[test]
     fn test_single_point() {
         let curve = LinearCurve;
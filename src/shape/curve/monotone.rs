@@ -129,6 +129,63 @@ impl Curve for MonotoneCurve {
     }
 }
 
+/// Monotone cubic interpolation along the y axis
+///
+/// Like [`MonotoneCurve`], but assumes the points are monotonic in y and
+/// preserves monotonicity in x. Useful for vertical line charts where y is
+/// the independent variable.
+///
+/// # Example
+/// ```
+/// use makepad_d3::shape::curve::{Curve, MonotoneYCurve};
+/// use makepad_d3::shape::Point;
+///
+/// let curve = MonotoneYCurve::new();
+/// let points = vec![
+///     Point::new(0.0, 0.0),
+///     Point::new(100.0, 50.0),
+///     Point::new(200.0, 100.0),
+///     Point::new(180.0, 150.0),
+/// ];
+/// let path = curve.generate(&points);
+/// assert_eq!(path.len(), 4); // MoveTo + 3 curves
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MonotoneYCurve;
+
+impl MonotoneYCurve {
+    /// Create a new y-monotone curve
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Curve for MonotoneYCurve {
+    fn generate(&self, points: &[Point]) -> Vec<PathSegment> {
+        let flip = |p: Point| Point::new(p.y, p.x);
+        let flipped: Vec<Point> = points.iter().copied().map(flip).collect();
+
+        MonotoneCurve
+            .generate(&flipped)
+            .into_iter()
+            .map(|segment| match segment {
+                PathSegment::MoveTo(p) => PathSegment::MoveTo(flip(p)),
+                PathSegment::LineTo(p) => PathSegment::LineTo(flip(p)),
+                PathSegment::CurveTo { cp1, cp2, end } => PathSegment::CurveTo {
+                    cp1: flip(cp1),
+                    cp2: flip(cp2),
+                    end: flip(end),
+                },
+                other => other,
+            })
+            .collect()
+    }
+
+    fn curve_type(&self) -> &'static str {
+        "monotone-y"
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -192,4 +249,25 @@ mod tests {
         let path = curve.generate(&points);
         assert!(!path.is_empty());
     }
+
+    #[test]
+    fn test_monotone_y_preserves_x_monotonicity() {
+        let points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(50.0, 50.0),
+            Point::new(100.0, 100.0),
+            Point::new(150.0, 150.0),
+        ];
+
+        let path = MonotoneYCurve::new().generate(&points);
+        assert_eq!(path[0], PathSegment::MoveTo(points[0]));
+        for segment in &path[1..] {
+            if let PathSegment::CurveTo { cp1, cp2, end } = segment {
+                assert!(cp1.x >= 0.0 && cp1.x <= 150.0);
+                assert!(cp2.x >= 0.0 && cp2.x <= 150.0);
+                assert!(end.x >= 0.0 && end.x <= 150.0);
+            }
+        }
+        assert_eq!(path.last().unwrap().end_point(), Some(points[3]));
+    }
 }
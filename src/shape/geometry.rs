@@ -0,0 +1,819 @@
+//! Geometric queries on paths
+//!
+//! Tight bounding boxes, arc length, point and tangent at a distance along
+//! the path, fill hit testing and distance to the stroke. Quadratic and cubic
+//! Béziers and circular arcs are handled analytically where possible;
+//! winding numbers use a fine flattening of curved segments.
+//!
+//! # Example
+//! ```
+//! use makepad_d3::shape::{FillRule, Path, Point};
+//!
+//! let mut path = Path::new();
+//! path.move_to(0.0, 0.0)
+//!     .line_to(100.0, 0.0)
+//!     .line_to(100.0, 100.0)
+//!     .line_to(0.0, 100.0)
+//!     .close();
+//!
+//! assert_eq!(path.length(), 400.0);
+//! assert_eq!(path.point_at_length(150.0), Some(Point::new(100.0, 50.0)));
+//! assert!(path.contains(Point::new(50.0, 50.0), FillRule::NonZero));
+//! assert_eq!(path.distance_to(Point::new(50.0, 40.0)), 40.0);
+//! ```
+
+use super::path::{Path, PathSegment, Point};
+use std::f64::consts::{FRAC_PI_2, TAU};
+
+/// Flattening tolerance used for winding-number hit tests
+const HIT_TOLERANCE: f64 = 1e-3;
+
+/// Rule deciding which regions of a self-overlapping path are inside
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FillRule {
+    /// Inside where the winding number is non-zero (SVG/canvas default)
+    #[default]
+    NonZero,
+    /// Inside where a ray crosses the outline an odd number of times
+    EvenOdd,
+}
+
+/// A drawable piece of a path with an explicit start point
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Piece {
+    Line(Point, Point),
+    Quad(Point, Point, Point),
+    Cubic(Point, Point, Point, Point),
+    /// Circular arc from `start` angle sweeping `sweep` radians
+    /// (positive = increasing angle, clockwise on screen)
+    Arc {
+        center: Point,
+        radius: f64,
+        start: f64,
+        sweep: f64,
+    },
+}
+
+/// A continuous run of pieces started by a move-to
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct Subpath {
+    pub start: Point,
+    pub pieces: Vec<Piece>,
+    pub closed: bool,
+}
+
+impl Subpath {
+    /// Pieces including the closing line back to the start, if closed
+    pub fn closed_pieces(&self) -> impl Iterator<Item = Piece> + '_ {
+        let closing = self.pieces.last().and_then(|last| {
+            let end = last.end();
+            (self.closed && end.distance(&self.start) > 0.0).then_some(Piece::Line(end, self.start))
+        });
+        self.pieces.iter().copied().chain(closing)
+    }
+
+    /// Pieces implicitly closed for filling
+    pub fn fill_pieces(&self) -> impl Iterator<Item = Piece> + '_ {
+        let closing = self.pieces.last().and_then(|last| {
+            let end = last.end();
+            (end.distance(&self.start) > 0.0).then_some(Piece::Line(end, self.start))
+        });
+        self.pieces.iter().copied().chain(closing)
+    }
+}
+
+/// Normalize a canvas-style arc to a signed sweep (as d3-path does)
+pub(crate) fn arc_sweep(start_angle: f64, end_angle: f64, counterclockwise: bool) -> f64 {
+    let mut da = if counterclockwise {
+        start_angle - end_angle
+    } else {
+        end_angle - start_angle
+    };
+    if da < 0.0 {
+        da = da % TAU + TAU;
+    }
+    if da > TAU - 1e-6 {
+        da = TAU;
+    }
+    if counterclockwise {
+        -da
+    } else {
+        da
+    }
+}
+
+impl Path {
+    /// Split the path into subpaths of explicit pieces
+    ///
+    /// Arcs follow canvas semantics: a line connects the current point to
+    /// the arc start.
+    pub(crate) fn subpaths(&self) -> Vec<Subpath> {
+        let mut subpaths: Vec<Subpath> = Vec::new();
+        let mut current: Option<Point> = None;
+
+        let open = |subpaths: &mut Vec<Subpath>, p: Point| {
+            subpaths.push(Subpath {
+                start: p,
+                ..Default::default()
+            });
+        };
+
+        for segment in &self.segments {
+            match *segment {
+                PathSegment::MoveTo(p) => {
+                    open(&mut subpaths, p);
+                    current = Some(p);
+                }
+                PathSegment::ClosePath => {
+                    if let Some(sub) = subpaths.last_mut() {
+                        sub.closed = true;
+                        let start = sub.start;
+                        current = Some(start);
+                        // Drawing after a close starts a new subpath at the same point
+                        open(&mut subpaths, start);
+                    }
+                }
+                ref segment => {
+                    let from = match current {
+                        Some(p) => p,
+                        None => {
+                            let p = match *segment {
+                                PathSegment::ArcTo {
+                                    center,
+                                    radius,
+                                    start_angle,
+                                    ..
+                                } => Point::new(
+                                    center.x + radius * start_angle.cos(),
+                                    center.y + radius * start_angle.sin(),
+                                ),
+                                _ => segment.end_point().unwrap_or_default(),
+                            };
+                            open(&mut subpaths, p);
+                            p
+                        }
+                    };
+                    let sub = subpaths.last_mut().expect("subpath opened above");
+                    let end = match *segment {
+                        PathSegment::LineTo(p) => {
+                            sub.pieces.push(Piece::Line(from, p));
+                            p
+                        }
+                        PathSegment::QuadTo { cp, end } => {
+                            sub.pieces.push(Piece::Quad(from, cp, end));
+                            end
+                        }
+                        PathSegment::CurveTo { cp1, cp2, end } => {
+                            sub.pieces.push(Piece::Cubic(from, cp1, cp2, end));
+                            end
+                        }
+                        PathSegment::ArcTo {
+                            center,
+                            radius,
+                            start_angle,
+                            end_angle,
+                            counterclockwise,
+                        } => {
+                            let radius = radius.abs();
+                            let arc = Piece::Arc {
+                                center,
+                                radius,
+                                start: start_angle,
+                                sweep: arc_sweep(start_angle, end_angle, counterclockwise),
+                            };
+                            let arc_start = arc.start();
+                            if from.distance(&arc_start) > 1e-9 {
+                                sub.pieces.push(Piece::Line(from, arc_start));
+                            }
+                            if radius > 0.0 {
+                                sub.pieces.push(arc);
+                            }
+                            arc.end()
+                        }
+                        PathSegment::MoveTo(_) | PathSegment::ClosePath => unreachable!(),
+                    };
+                    current = Some(end);
+                }
+            }
+        }
+
+        subpaths.retain(|sub| !sub.pieces.is_empty());
+        subpaths
+    }
+
+    /// Tight axis-aligned bounding box as `(min, max)` corners
+    ///
+    /// Curve extrema are found analytically, so control points that lie
+    /// outside the curve do not inflate the box. Returns `None` for an
+    /// empty path.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        let mut min = Point::new(f64::INFINITY, f64::INFINITY);
+        let mut max = Point::new(f64::NEG_INFINITY, f64::NEG_INFINITY);
+        let mut include = |p: Point| {
+            min = Point::new(min.x.min(p.x), min.y.min(p.y));
+            max = Point::new(max.x.max(p.x), max.y.max(p.y));
+        };
+
+        for sub in self.subpaths() {
+            include(sub.start);
+            for piece in &sub.pieces {
+                piece.extrema(&mut include);
+            }
+        }
+
+        min.x.is_finite().then_some((min, max))
+    }
+
+    /// Total arc length of the path (move-to jumps are not counted)
+    pub fn length(&self) -> f64 {
+        self.subpaths()
+            .iter()
+            .flat_map(|sub| sub.closed_pieces())
+            .map(|piece| piece.length())
+            .sum()
+    }
+
+    /// Point at a distance along the path
+    ///
+    /// The distance is clamped to `[0, length]`. Returns `None` for a path
+    /// without drawn segments.
+    pub fn point_at_length(&self, distance: f64) -> Option<Point> {
+        self.locate(distance).map(|(piece, t)| piece.point(t))
+    }
+
+    /// Unit tangent direction at a distance along the path
+    ///
+    /// Use `tangent.y.atan2(tangent.x)` for the rotation of text placed
+    /// along the path.
+    pub fn tangent_at_length(&self, distance: f64) -> Option<Point> {
+        self.locate(distance).map(|(piece, t)| piece.tangent(t))
+    }
+
+    /// Find the piece and parameter at a distance along the path
+    fn locate(&self, distance: f64) -> Option<(Piece, f64)> {
+        let pieces: Vec<Piece> = self
+            .subpaths()
+            .iter()
+            .flat_map(|sub| sub.closed_pieces().collect::<Vec<_>>())
+            .collect();
+        let last = *pieces.last()?;
+
+        let mut remaining = distance.max(0.0);
+        for piece in pieces {
+            let len = piece.length();
+            if remaining <= len {
+                return Some((piece, piece.t_at_length(remaining, len)));
+            }
+            remaining -= len;
+        }
+        Some((last, 1.0))
+    }
+
+    /// Test whether a point lies inside the filled path
+    ///
+    /// Open subpaths are implicitly closed, as when filling.
+    pub fn contains(&self, point: Point, rule: FillRule) -> bool {
+        let mut winding = 0;
+        let mut polyline = Vec::new();
+        for sub in self.subpaths() {
+            polyline.clear();
+            polyline.push(sub.start);
+            for piece in sub.fill_pieces() {
+                piece.flatten_into(HIT_TOLERANCE, &mut polyline);
+            }
+            winding += winding_number(&polyline, point);
+        }
+        match rule {
+            FillRule::NonZero => winding != 0,
+            FillRule::EvenOdd => winding % 2 != 0,
+        }
+    }
+
+    /// Shortest distance from a point to the stroked outline
+    ///
+    /// Compare against half the stroke width for stroke hit testing.
+    /// Returns infinity for a path without drawn segments.
+    pub fn distance_to(&self, point: Point) -> f64 {
+        self.subpaths()
+            .iter()
+            .flat_map(|sub| sub.closed_pieces().collect::<Vec<_>>())
+            .map(|piece| piece.distance_to(point))
+            .fold(f64::INFINITY, f64::min)
+    }
+}
+
+/// Winding number of a closed polyline around a point
+fn winding_number(polyline: &[Point], p: Point) -> i32 {
+    let mut winding = 0;
+    let n = polyline.len();
+    for i in 0..n {
+        let a = polyline[i];
+        let b = polyline[(i + 1) % n];
+        let cross = (b.x - a.x) * (p.y - a.y) - (p.x - a.x) * (b.y - a.y);
+        if a.y <= p.y {
+            if b.y > p.y && cross > 0.0 {
+                winding += 1;
+            }
+        } else if b.y <= p.y && cross < 0.0 {
+            winding -= 1;
+        }
+    }
+    winding
+}
+
+/// 8-point Gauss–Legendre abscissae and weights on [-1, 1]
+const GAUSS: [(f64, f64); 8] = [
+    (-0.960_289_856_497_536_2, 0.101_228_536_290_376_26),
+    (-0.796_666_477_413_626_7, 0.222_381_034_453_374_47),
+    (-0.525_532_409_916_329, 0.313_706_645_877_887_3),
+    (-0.183_434_642_495_649_8, 0.362_683_783_378_362),
+    (0.183_434_642_495_649_8, 0.362_683_783_378_362),
+    (0.525_532_409_916_329, 0.313_706_645_877_887_3),
+    (0.796_666_477_413_626_7, 0.222_381_034_453_374_47),
+    (0.960_289_856_497_536_2, 0.101_228_536_290_376_26),
+];
+
+impl Piece {
+    pub fn start(&self) -> Point {
+        match *self {
+            Piece::Line(a, _) | Piece::Quad(a, _, _) | Piece::Cubic(a, _, _, _) => a,
+            Piece::Arc { .. } => self.point(0.0),
+        }
+    }
+
+    pub fn end(&self) -> Point {
+        match *self {
+            Piece::Line(_, b) | Piece::Quad(_, _, b) | Piece::Cubic(_, _, _, b) => b,
+            Piece::Arc { .. } => self.point(1.0),
+        }
+    }
+
+    /// Point at parameter `t` in [0, 1]
+    pub fn point(&self, t: f64) -> Point {
+        let mt = 1.0 - t;
+        match *self {
+            Piece::Line(a, b) => a.lerp(&b, t),
+            Piece::Quad(a, c, b) => Point::new(
+                mt * mt * a.x + 2.0 * mt * t * c.x + t * t * b.x,
+                mt * mt * a.y + 2.0 * mt * t * c.y + t * t * b.y,
+            ),
+            Piece::Cubic(a, c1, c2, b) => {
+                let (w0, w1, w2, w3) =
+                    (mt * mt * mt, 3.0 * mt * mt * t, 3.0 * mt * t * t, t * t * t);
+                Point::new(
+                    w0 * a.x + w1 * c1.x + w2 * c2.x + w3 * b.x,
+                    w0 * a.y + w1 * c1.y + w2 * c2.y + w3 * b.y,
+                )
+            }
+            Piece::Arc {
+                center,
+                radius,
+                start,
+                sweep,
+            } => {
+                let angle = start + sweep * t;
+                Point::new(
+                    center.x + radius * angle.cos(),
+                    center.y + radius * angle.sin(),
+                )
+            }
+        }
+    }
+
+    /// First derivative with respect to `t`
+    pub fn derivative(&self, t: f64) -> Point {
+        let mt = 1.0 - t;
+        match *self {
+            Piece::Line(a, b) => b.sub(&a),
+            Piece::Quad(a, c, b) => c.sub(&a).scale(2.0 * mt).add(&b.sub(&c).scale(2.0 * t)),
+            Piece::Cubic(a, c1, c2, b) => c1
+                .sub(&a)
+                .scale(3.0 * mt * mt)
+                .add(&c2.sub(&c1).scale(6.0 * mt * t))
+                .add(&b.sub(&c2).scale(3.0 * t * t)),
+            Piece::Arc {
+                radius,
+                start,
+                sweep,
+                ..
+            } => {
+                let angle = start + sweep * t;
+                Point::new(-angle.sin(), angle.cos()).scale(radius * sweep)
+            }
+        }
+    }
+
+    /// Unit tangent at `t`, falling back to the chord for degenerate ends
+    pub fn tangent(&self, t: f64) -> Point {
+        let mut d = self.derivative(t);
+        if d.x.hypot(d.y) < 1e-12 {
+            // Coincident control points: look slightly inward
+            let (t0, t1) = if t < 0.5 {
+                (t, t + 1e-3)
+            } else {
+                (t - 1e-3, t)
+            };
+            d = self.point(t1).sub(&self.point(t0));
+        }
+        if d.x.hypot(d.y) < 1e-12 {
+            d = self.end().sub(&self.start());
+        }
+        let len = d.x.hypot(d.y);
+        if len > 0.0 {
+            d.scale(1.0 / len)
+        } else {
+            Point::new(1.0, 0.0)
+        }
+    }
+
+    /// Report the endpoints and interior axis extrema
+    fn extrema(&self, include: &mut impl FnMut(Point)) {
+        include(self.start());
+        include(self.end());
+        match *self {
+            Piece::Line(..) => {}
+            Piece::Quad(a, c, b) => {
+                for (p0, p1, p2) in [(a.x, c.x, b.x), (a.y, c.y, b.y)] {
+                    let denom = p0 - 2.0 * p1 + p2;
+                    if denom.abs() > 1e-12 {
+                        let t = (p0 - p1) / denom;
+                        if t > 0.0 && t < 1.0 {
+                            include(self.point(t));
+                        }
+                    }
+                }
+            }
+            Piece::Cubic(a, c1, c2, b) => {
+                for (p0, p1, p2, p3) in [(a.x, c1.x, c2.x, b.x), (a.y, c1.y, c2.y, b.y)] {
+                    // Derivative coefficients: at² + bt + c
+                    let qa = 3.0 * (-p0 + 3.0 * p1 - 3.0 * p2 + p3);
+                    let qb = 6.0 * (p0 - 2.0 * p1 + p2);
+                    let qc = 3.0 * (p1 - p0);
+                    for t in quadratic_roots(qa, qb, qc) {
+                        if t > 0.0 && t < 1.0 {
+                            include(self.point(t));
+                        }
+                    }
+                }
+            }
+            Piece::Arc { start, sweep, .. } => {
+                let (lo, hi) = if sweep >= 0.0 {
+                    (start, start + sweep)
+                } else {
+                    (start + sweep, start)
+                };
+                let mut k = (lo / FRAC_PI_2).ceil();
+                while k * FRAC_PI_2 <= hi {
+                    include(self.point((k * FRAC_PI_2 - start) / sweep));
+                    k += 1.0;
+                }
+            }
+        }
+    }
+
+    /// Arc length of the whole piece
+    pub fn length(&self) -> f64 {
+        match *self {
+            Piece::Line(a, b) => a.distance(&b),
+            Piece::Arc { radius, sweep, .. } => radius * sweep.abs(),
+            _ => self.length_between(0.0, 1.0, 0),
+        }
+    }
+
+    /// Arc length over [t0, t1] by adaptive Gauss–Legendre quadrature
+    fn length_between(&self, t0: f64, t1: f64, depth: u32) -> f64 {
+        let gauss = |a: f64, b: f64| {
+            let half = (b - a) / 2.0;
+            let mid = (a + b) / 2.0;
+            GAUSS
+                .iter()
+                .map(|&(x, w)| {
+                    let d = self.derivative(mid + half * x);
+                    w * d.x.hypot(d.y)
+                })
+                .sum::<f64>()
+                * half
+        };
+        let whole = gauss(t0, t1);
+        let mid = (t0 + t1) / 2.0;
+        let split = gauss(t0, mid) + gauss(mid, t1);
+        if depth >= 12 || (whole - split).abs() <= 1e-9 * split.max(1.0) {
+            split
+        } else {
+            self.length_between(t0, mid, depth + 1) + self.length_between(mid, t1, depth + 1)
+        }
+    }
+
+    /// Parameter at which the arc length from the start equals `s`
+    pub fn t_at_length(&self, s: f64, total: f64) -> f64 {
+        if total <= 0.0 {
+            return 0.0;
+        }
+        match self {
+            Piece::Line(..) | Piece::Arc { .. } => (s / total).clamp(0.0, 1.0),
+            _ => {
+                // Newton's method safeguarded by bisection
+                let (mut lo, mut hi) = (0.0, 1.0);
+                let mut t = s / total;
+                for _ in 0..50 {
+                    let err = self.length_between(0.0, t, 0) - s;
+                    if err.abs() < 1e-9 {
+                        break;
+                    }
+                    if err > 0.0 {
+                        hi = t;
+                    } else {
+                        lo = t;
+                    }
+                    let d = self.derivative(t);
+                    let speed = d.x.hypot(d.y);
+                    let next = t - err / speed;
+                    t = if speed > 1e-12 && next > lo && next < hi {
+                        next
+                    } else {
+                        (lo + hi) / 2.0
+                    };
+                }
+                t
+            }
+        }
+    }
+
+    /// Shortest distance from `p` to this piece
+    pub fn distance_to(&self, p: Point) -> f64 {
+        match *self {
+            Piece::Line(a, b) => {
+                let ab = b.sub(&a);
+                let len2 = ab.x * ab.x + ab.y * ab.y;
+                let t = if len2 > 0.0 {
+                    (((p.x - a.x) * ab.x + (p.y - a.y) * ab.y) / len2).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+                a.lerp(&b, t).distance(&p)
+            }
+            Piece::Arc {
+                center,
+                radius,
+                start,
+                sweep,
+            } => {
+                let angle = (p.y - center.y).atan2(p.x - center.x);
+                // Offset of the point's angle from the arc start, in sweep direction
+                let mut offset = (angle - start) * sweep.signum();
+                offset = offset.rem_euclid(TAU);
+                if offset <= sweep.abs() {
+                    (p.distance(&center) - radius).abs()
+                } else {
+                    p.distance(&self.start()).min(p.distance(&self.end()))
+                }
+            }
+            _ => {
+                // Coarse sampling, then golden-section refinement
+                const SAMPLES: usize = 32;
+                let dist = |t: f64| self.point(t).distance(&p);
+                let best = (0..=SAMPLES)
+                    .map(|i| i as f64 / SAMPLES as f64)
+                    .min_by(|&a, &b| dist(a).total_cmp(&dist(b)))
+                    .unwrap_or(0.0);
+                let step = 1.0 / SAMPLES as f64;
+                let (mut lo, mut hi) = ((best - step).max(0.0), (best + step).min(1.0));
+                let ratio = (5.0_f64.sqrt() - 1.0) / 2.0;
+                for _ in 0..60 {
+                    let m1 = hi - ratio * (hi - lo);
+                    let m2 = lo + ratio * (hi - lo);
+                    if dist(m1) < dist(m2) {
+                        hi = m2;
+                    } else {
+                        lo = m1;
+                    }
+                }
+                dist((lo + hi) / 2.0).min(dist(best))
+            }
+        }
+    }
+
+    /// Append points approximating this piece (excluding its start)
+    ///
+    /// Subdivision counts follow Wang's formula so the polyline stays within
+    /// `tolerance` of the curve.
+    pub fn flatten_into(&self, tolerance: f64, out: &mut Vec<Point>) {
+        let tolerance = tolerance.max(1e-9);
+        let wang = |degree: f64, second: &[Point]| -> usize {
+            let m = second.iter().map(|d| d.x.hypot(d.y)).fold(0.0, f64::max);
+            ((degree * (degree - 1.0) / 8.0 * m / tolerance)
+                .sqrt()
+                .ceil() as usize)
+                .clamp(1, 1000)
+        };
+        let count = match *self {
+            Piece::Line(_, b) => {
+                out.push(b);
+                return;
+            }
+            Piece::Quad(a, c, b) => wang(2.0, &[a.sub(&c.scale(2.0)).add(&b)]),
+            Piece::Cubic(a, c1, c2, b) => wang(
+                3.0,
+                &[
+                    a.sub(&c1.scale(2.0)).add(&c2),
+                    c1.sub(&c2.scale(2.0)).add(&b),
+                ],
+            ),
+            Piece::Arc { radius, sweep, .. } => {
+                if radius <= tolerance {
+                    1
+                } else {
+                    let step = 2.0 * (1.0 - tolerance / radius).acos();
+                    ((sweep.abs() / step).ceil() as usize).clamp(1, 1000)
+                }
+            }
+        };
+        for i in 1..count {
+            out.push(self.point(i as f64 / count as f64));
+        }
+        out.push(self.end());
+    }
+}
+
+/// Real roots of at² + bt + c
+fn quadratic_roots(a: f64, b: f64, c: f64) -> Vec<f64> {
+    if a.abs() < 1e-12 {
+        if b.abs() < 1e-12 {
+            return Vec::new();
+        }
+        return vec![-c / b];
+    }
+    let disc = b * b - 4.0 * a * c;
+    if disc < 0.0 {
+        return Vec::new();
+    }
+    let sq = disc.sqrt();
+    vec![(-b + sq) / (2.0 * a), (-b - sq) / (2.0 * a)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    fn close(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() < eps
+    }
+
+    fn square(x: f64, y: f64, size: f64, clockwise: bool) -> Vec<PathSegment> {
+        let mut corners = [(x, y), (x + size, y), (x + size, y + size), (x, y + size)];
+        if !clockwise {
+            corners.reverse();
+        }
+        let mut segments = vec![PathSegment::move_to(corners[0].0, corners[0].1)];
+        segments.extend(
+            corners[1..]
+                .iter()
+                .map(|&(x, y)| PathSegment::line_to(x, y)),
+        );
+        segments.push(PathSegment::ClosePath);
+        segments
+    }
+
+    #[test]
+    fn test_bounds_cubic_is_tight() {
+        let mut path = Path::new();
+        path.move_to(0.0, 0.0)
+            .curve_to(0.0, 100.0, 100.0, 100.0, 100.0, 0.0);
+        let (min, max) = path.bounds().unwrap();
+        assert_eq!(min, Point::new(0.0, 0.0));
+        assert!(close(max.x, 100.0, 1e-9));
+        // Control points reach y = 100, the curve only 75
+        assert!(close(max.y, 75.0, 1e-9));
+
+        let mut quad = Path::new();
+        quad.move_to(0.0, 0.0).quad_to(50.0, -100.0, 100.0, 0.0);
+        let (min, _) = quad.bounds().unwrap();
+        assert!(close(min.y, -50.0, 1e-9));
+
+        assert!(Path::new().bounds().is_none());
+    }
+
+    #[test]
+    fn test_bounds_arc() {
+        // Quarter arc from 12 o'clock to 3 o'clock (screen coordinates)
+        let path = Path::from_iter([PathSegment::arc_to(0.0, 0.0, 10.0, -FRAC_PI_2, 0.0, false)]);
+        let (min, max) = path.bounds().unwrap();
+        assert!(close(min.x, 0.0, 1e-9) && close(min.y, -10.0, 1e-9));
+        assert!(close(max.x, 10.0, 1e-9) && close(max.y, 0.0, 1e-9));
+
+        // Passing through angle 0 counterclockwise from the bottom
+        let path = Path::from_iter([PathSegment::arc_to(0.0, 0.0, 10.0, 1.0, -1.0, true)]);
+        let (_, max) = path.bounds().unwrap();
+        assert!(close(max.x, 10.0, 1e-9));
+    }
+
+    #[test]
+    fn test_length() {
+        let circle = Path::from_iter([PathSegment::arc_to(0.0, 0.0, 10.0, 0.0, TAU, false)]);
+        assert!(close(circle.length(), TAU * 10.0, 1e-9));
+
+        // A cubic with collinear, evenly spaced controls is a straight line
+        let mut line = Path::new();
+        line.move_to(0.0, 0.0)
+            .curve_to(10.0, 0.0, 20.0, 0.0, 30.0, 0.0);
+        assert!(close(line.length(), 30.0, 1e-9));
+
+        // Quarter circle approximated by a cubic (length ≈ 15.710167, slightly
+        // more than the true arc)
+        let k = 0.552_284_749_8 * 10.0;
+        let mut quarter = Path::new();
+        quarter
+            .move_to(10.0, 0.0)
+            .curve_to(10.0, k, k, 10.0, 0.0, 10.0);
+        assert!(close(quarter.length(), 15.710167, 1e-6));
+
+        // Separate subpaths do not include the jump between them
+        let mut two = Path::new();
+        two.move_to(0.0, 0.0)
+            .line_to(10.0, 0.0)
+            .move_to(100.0, 0.0)
+            .line_to(100.0, 5.0);
+        assert_eq!(two.length(), 15.0);
+    }
+
+    #[test]
+    fn test_point_and_tangent_at_length() {
+        let path = Path::from_iter(square(0.0, 0.0, 10.0, true));
+        assert_eq!(path.point_at_length(15.0), Some(Point::new(10.0, 5.0)));
+        assert_eq!(path.tangent_at_length(15.0), Some(Point::new(0.0, 1.0)));
+        // Closing edge is included; beyond the end clamps
+        assert_eq!(path.point_at_length(35.0), Some(Point::new(0.0, 5.0)));
+        assert_eq!(path.point_at_length(100.0), Some(Point::new(0.0, 0.0)));
+
+        let mut curve = Path::new();
+        curve
+            .move_to(0.0, 0.0)
+            .curve_to(10.0, 0.0, 20.0, 0.0, 30.0, 0.0);
+        let p = curve.point_at_length(12.0).unwrap();
+        assert!(close(p.x, 12.0, 1e-6) && close(p.y, 0.0, 1e-9));
+
+        let arc = Path::from_iter([PathSegment::arc_to(0.0, 0.0, 10.0, 0.0, PI, false)]);
+        let p = arc.point_at_length(TAU * 10.0 / 4.0).unwrap();
+        assert!(close(p.x, 0.0, 1e-9) && close(p.y, 10.0, 1e-9));
+        let t = arc.tangent_at_length(TAU * 10.0 / 4.0).unwrap();
+        assert!(close(t.x, -1.0, 1e-9) && close(t.y, 0.0, 1e-9));
+
+        assert!(Path::new().point_at_length(1.0).is_none());
+    }
+
+    #[test]
+    fn test_contains_fill_rules() {
+        // Two overlapping squares drawn in the same direction
+        let mut path = Path::from_iter(square(0.0, 0.0, 20.0, true));
+        path.extend(square(10.0, 10.0, 20.0, true));
+        let overlap = Point::new(15.0, 15.0);
+        assert!(path.contains(overlap, FillRule::NonZero));
+        assert!(!path.contains(overlap, FillRule::EvenOdd));
+        assert!(path.contains(Point::new(5.0, 5.0), FillRule::EvenOdd));
+        assert!(!path.contains(Point::new(25.0, 5.0), FillRule::NonZero));
+
+        // A hole drawn in the opposite direction is outside for both rules
+        let mut donut = Path::from_iter(square(0.0, 0.0, 30.0, true));
+        donut.extend(square(10.0, 10.0, 10.0, false));
+        assert!(!donut.contains(overlap, FillRule::NonZero));
+        assert!(!donut.contains(overlap, FillRule::EvenOdd));
+    }
+
+    #[test]
+    fn test_contains_curves_and_open_paths() {
+        let circle = Path::from_iter([PathSegment::arc_to(0.0, 0.0, 10.0, 0.0, TAU, false)]);
+        assert!(circle.contains(Point::new(7.0, 7.0), FillRule::NonZero));
+        assert!(!circle.contains(Point::new(7.2, 7.2), FillRule::NonZero));
+
+        // Open triangle is implicitly closed for filling
+        let mut open = Path::new();
+        open.move_to(0.0, 0.0).line_to(10.0, 0.0).line_to(0.0, 10.0);
+        assert!(open.contains(Point::new(2.0, 2.0), FillRule::NonZero));
+    }
+
+    #[test]
+    fn test_distance_to() {
+        let path = Path::from_iter(square(0.0, 0.0, 10.0, true));
+        assert_eq!(path.distance_to(Point::new(5.0, 3.0)), 3.0);
+        assert_eq!(path.distance_to(Point::new(13.0, 14.0)), 5.0);
+
+        let arc = Path::from_iter([PathSegment::arc_to(0.0, 0.0, 10.0, 0.0, FRAC_PI_2, false)]);
+        assert!(close(arc.distance_to(Point::new(0.0, 0.0)), 10.0, 1e-9));
+        assert!(close(
+            arc.distance_to(Point::new(14.0, 14.0)),
+            2.0_f64.sqrt() * 14.0 - 10.0,
+            1e-9
+        ));
+        // Outside the swept angle: nearest endpoint
+        assert!(close(arc.distance_to(Point::new(10.0, -5.0)), 5.0, 1e-9));
+
+        let mut curve = Path::new();
+        curve
+            .move_to(0.0, 0.0)
+            .curve_to(0.0, 100.0, 100.0, 100.0, 100.0, 0.0);
+        assert!(close(curve.distance_to(Point::new(50.0, 80.0)), 5.0, 1e-6));
+
+        assert_eq!(Path::new().distance_to(Point::zero()), f64::INFINITY);
+    }
+}
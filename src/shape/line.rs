@@ -6,6 +6,7 @@
 use super::curve::{Curve, LinearCurve};
 use super::path::{PathSegment, Point};
 use crate::data::DataPoint;
+use crate::error::D3Result;
 
 /// Line generator that converts data points to path segments
 ///
@@ -180,6 +181,79 @@ impl LineGenerator {
         use super::curve::NaturalCurve;
         Self::new().curve(NaturalCurve::new())
     }
+
+    /// Create a line generator that closes the line back to the first point
+    pub fn linear_closed() -> Self {
+        use super::curve::LinearClosedCurve;
+        Self::new().curve(LinearClosedCurve)
+    }
+
+    /// Create a line generator with closed basis interpolation
+    pub fn basis_closed() -> Self {
+        use super::curve::BasisClosedCurve;
+        Self::new().curve(BasisClosedCurve::new())
+    }
+
+    /// Create a line generator with open basis interpolation
+    pub fn basis_open() -> Self {
+        use super::curve::BasisOpenCurve;
+        Self::new().curve(BasisOpenCurve::new())
+    }
+
+    /// Create a line generator with bundle interpolation of strength `beta`
+    pub fn bundle(beta: f64) -> Self {
+        use super::curve::BundleCurve;
+        Self::new().curve(BundleCurve::new(beta))
+    }
+
+    /// Create a line generator with closed cardinal interpolation
+    pub fn cardinal_closed(tension: f64) -> Self {
+        use super::curve::CardinalClosedCurve;
+        Self::new().curve(CardinalClosedCurve::new(tension))
+    }
+
+    /// Create a line generator with open cardinal interpolation
+    pub fn cardinal_open(tension: f64) -> Self {
+        use super::curve::CardinalOpenCurve;
+        Self::new().curve(CardinalOpenCurve::new(tension))
+    }
+
+    /// Create a line generator with closed Catmull-Rom interpolation
+    pub fn catmull_rom_closed() -> Self {
+        use super::curve::CatmullRomClosedCurve;
+        Self::new().curve(CatmullRomClosedCurve::centripetal())
+    }
+
+    /// Create a line generator with open Catmull-Rom interpolation
+    pub fn catmull_rom_open() -> Self {
+        use super::curve::CatmullRomOpenCurve;
+        Self::new().curve(CatmullRomOpenCurve::centripetal())
+    }
+
+    /// Create a line generator with monotone interpolation along y
+    pub fn monotone_y() -> Self {
+        use super::curve::MonotoneYCurve;
+        Self::new().curve(MonotoneYCurve::new())
+    }
+
+    /// Create a line generator with horizontal-tangent bump interpolation
+    pub fn bump_x() -> Self {
+        use super::curve::BumpCurve;
+        Self::new().curve(BumpCurve::x())
+    }
+
+    /// Create a line generator with vertical-tangent bump interpolation
+    pub fn bump_y() -> Self {
+        use super::curve::BumpCurve;
+        Self::new().curve(BumpCurve::y())
+    }
+
+    /// Create a line generator with a curve selected by its d3 name
+    ///
+    /// See [`curve::from_name`](super::curve::from_name) for accepted names.
+    pub fn named(curve: &str) -> D3Result<Self> {
+        Ok(Self::new().curve(super::curve::from_name(curve)?))
+    }
 }
 
 #[cfg(test)]
@@ -245,6 +319,25 @@ mod tests {
         let _ = LineGenerator::catmull_rom().generate(&data);
         let _ = LineGenerator::monotone().generate(&data);
         let _ = LineGenerator::natural().generate(&data);
+        let _ = LineGenerator::linear_closed().generate(&data);
+        let _ = LineGenerator::basis_closed().generate(&data);
+        let _ = LineGenerator::basis_open().generate(&data);
+        let _ = LineGenerator::bundle(0.85).generate(&data);
+        let _ = LineGenerator::cardinal_closed(0.5).generate(&data);
+        let _ = LineGenerator::cardinal_open(0.5).generate(&data);
+        let _ = LineGenerator::catmull_rom_closed().generate(&data);
+        let _ = LineGenerator::catmull_rom_open().generate(&data);
+        let _ = LineGenerator::monotone_y().generate(&data);
+        let _ = LineGenerator::bump_x().generate(&data);
+        let _ = LineGenerator::bump_y().generate(&data);
+    }
+
+    #[test]
+    fn test_line_generator_named() {
+        let data = sample_data();
+        let named = LineGenerator::named("curveBumpX").unwrap().generate(&data);
+        assert_eq!(named, LineGenerator::bump_x().generate(&data));
+        assert!(LineGenerator::named("zigzag").is_err());
     }
 
     #[test]
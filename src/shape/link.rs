@@ -0,0 +1,257 @@
+//! Link generator for node-link diagrams
+//!
+//! Generates smooth cubic Bézier edges between a source and a target point,
+//! as in d3's `linkHorizontal`, `linkVertical` and `linkRadial`. Works with
+//! node positions from hierarchy layouts such as
+//! [`TreeLayout`](crate::layout::TreeLayout).
+
+use super::curve::Curve;
+use super::path::{PathSegment, Point};
+use super::radial::point_radial;
+use crate::layout::HierarchyNode;
+
+/// Direction in which link tangents leave and enter nodes
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LinkOrientation {
+    /// Tangents are horizontal (left-to-right trees)
+    #[default]
+    Horizontal,
+    /// Tangents are vertical (top-down trees)
+    Vertical,
+    /// Positions are `(angle, radius)` and tangents are radial
+    Radial,
+}
+
+/// Link generator for edges between nodes
+///
+/// By default a node's position is its layout `(x, y)`. Use
+/// [`position`](Self::position) to map layout coordinates to screen
+/// coordinates, e.g. to swap axes for a horizontal tree.
+///
+/// # Example
+/// ```
+/// use makepad_d3::shape::{LinkGenerator, PathSegment, Point};
+///
+/// let link = LinkGenerator::horizontal();
+/// let path = link.generate(Point::new(0.0, 0.0), Point::new(100.0, 50.0));
+///
+/// assert_eq!(path[0], PathSegment::move_to(0.0, 0.0));
+/// assert_eq!(path[1], PathSegment::curve_to(50.0, 0.0, 50.0, 50.0, 100.0, 50.0));
+/// ```
+pub struct LinkGenerator {
+    /// Tangent direction at the endpoints
+    orientation: LinkOrientation,
+    /// Maps a node's layout `(x, y)` to link coordinates
+    position_fn: Box<dyn Fn(f64, f64) -> Point + Send + Sync>,
+    /// Custom curve through source and target (d3's `link(curve)`)
+    curve: Option<Box<dyn Curve>>,
+}
+
+impl Default for LinkGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LinkGenerator {
+    /// Create a new horizontal link generator
+    pub fn new() -> Self {
+        Self {
+            orientation: LinkOrientation::Horizontal,
+            position_fn: Box::new(Point::new),
+            curve: None,
+        }
+    }
+
+    /// Links with horizontal tangents (d3's `linkHorizontal`)
+    pub fn horizontal() -> Self {
+        Self::new().orientation(LinkOrientation::Horizontal)
+    }
+
+    /// Links with vertical tangents (d3's `linkVertical`)
+    pub fn vertical() -> Self {
+        Self::new().orientation(LinkOrientation::Vertical)
+    }
+
+    /// Links between `(angle, radius)` positions (d3's `linkRadial`)
+    ///
+    /// Angles are in radians, 0 at 12 o'clock and increasing clockwise.
+    pub fn radial() -> Self {
+        Self::new().orientation(LinkOrientation::Radial)
+    }
+
+    /// Set the orientation
+    pub fn orientation(mut self, orientation: LinkOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Set how a node's layout `(x, y)` maps to link coordinates
+    ///
+    /// For radial links the returned point is interpreted as
+    /// `(angle, radius)`.
+    pub fn position<F>(mut self, f: F) -> Self
+    where
+        F: Fn(f64, f64) -> Point + Send + Sync + 'static,
+    {
+        self.position_fn = Box::new(f);
+        self
+    }
+
+    /// Draw links with a custom curve instead of the orientation's Bézier
+    ///
+    /// The curve receives the source and target points (d3's `link(curve)`).
+    /// Radial positions are converted to Cartesian first.
+    pub fn curve(mut self, curve: impl Curve + 'static) -> Self {
+        self.curve = Some(Box::new(curve));
+        self
+    }
+
+    /// Get the orientation
+    pub fn get_orientation(&self) -> LinkOrientation {
+        self.orientation
+    }
+
+    /// Generate a link between two points
+    pub fn generate(&self, source: Point, target: Point) -> Vec<PathSegment> {
+        if let Some(curve) = &self.curve {
+            let (s, t) = match self.orientation {
+                LinkOrientation::Radial => (
+                    point_radial(source.x, source.y),
+                    point_radial(target.x, target.y),
+                ),
+                _ => (source, target),
+            };
+            return curve.generate(&[s, t]);
+        }
+
+        let (start, cp1, cp2, end) = match self.orientation {
+            LinkOrientation::Horizontal => {
+                let mx = (source.x + target.x) / 2.0;
+                (
+                    source,
+                    Point::new(mx, source.y),
+                    Point::new(mx, target.y),
+                    target,
+                )
+            }
+            LinkOrientation::Vertical => {
+                let my = (source.y + target.y) / 2.0;
+                (
+                    source,
+                    Point::new(source.x, my),
+                    Point::new(target.x, my),
+                    target,
+                )
+            }
+            LinkOrientation::Radial => {
+                let mr = (source.y + target.y) / 2.0;
+                (
+                    point_radial(source.x, source.y),
+                    point_radial(source.x, mr),
+                    point_radial(target.x, mr),
+                    point_radial(target.x, target.y),
+                )
+            }
+        };
+
+        vec![
+            PathSegment::MoveTo(start),
+            PathSegment::CurveTo { cp1, cp2, end },
+        ]
+    }
+
+    /// Generate a link between two laid-out hierarchy nodes
+    pub fn generate_nodes<T>(
+        &self,
+        source: &HierarchyNode<T>,
+        target: &HierarchyNode<T>,
+    ) -> Vec<PathSegment> {
+        self.generate(
+            (self.position_fn)(source.x, source.y),
+            (self.position_fn)(target.x, target.y),
+        )
+    }
+
+    /// Generate links for every parent-child edge of a laid-out tree
+    ///
+    /// Links are returned in pre-order, one path per edge.
+    pub fn generate_tree<T>(&self, root: &HierarchyNode<T>) -> Vec<Vec<PathSegment>> {
+        let mut links = Vec::new();
+        self.collect_links(root, &mut links);
+        links
+    }
+
+    fn collect_links<T>(&self, node: &HierarchyNode<T>, links: &mut Vec<Vec<PathSegment>>) {
+        for child in &node.children {
+            links.push(self.generate_nodes(node, child));
+            self.collect_links(child, links);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::TreeLayout;
+    use crate::shape::curve::LinearCurve;
+    use std::f64::consts::PI;
+
+    fn end_of(path: &[PathSegment]) -> Point {
+        path.last().and_then(|s| s.end_point()).unwrap()
+    }
+
+    #[test]
+    fn test_link_vertical() {
+        let path =
+            LinkGenerator::vertical().generate(Point::new(0.0, 0.0), Point::new(40.0, 100.0));
+        assert_eq!(
+            path[1],
+            PathSegment::curve_to(0.0, 50.0, 40.0, 50.0, 40.0, 100.0)
+        );
+    }
+
+    #[test]
+    fn test_link_radial() {
+        let path =
+            LinkGenerator::radial().generate(Point::new(0.0, 0.0), Point::new(PI / 2.0, 100.0));
+        assert_eq!(path[0], PathSegment::move_to(0.0, 0.0));
+        match &path[1] {
+            PathSegment::CurveTo { cp2, end, .. } => {
+                assert!((cp2.x - 50.0).abs() < 1e-9 && cp2.y.abs() < 1e-9);
+                assert!((end.x - 100.0).abs() < 1e-9 && end.y.abs() < 1e-9);
+            }
+            other => panic!("expected curve, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_link_custom_curve() {
+        let path = LinkGenerator::new()
+            .curve(LinearCurve)
+            .generate(Point::new(0.0, 0.0), Point::new(10.0, 10.0));
+        assert_eq!(path[1], PathSegment::line_to(10.0, 10.0));
+    }
+
+    #[test]
+    fn test_link_tree_edges() {
+        let mut root = HierarchyNode::branch("root".to_string());
+        let mut a = HierarchyNode::branch("a".to_string());
+        a.add_child(HierarchyNode::leaf("a1".to_string(), 1.0));
+        root.add_child(a);
+        root.add_child(HierarchyNode::leaf("b".to_string(), 1.0));
+        root.sum();
+
+        let laid = TreeLayout::new().size(100.0, 200.0).layout(&root);
+        let links = LinkGenerator::horizontal()
+            .position(|x, y| Point::new(y, x))
+            .generate_tree(&laid);
+
+        assert_eq!(links.len(), 3);
+        // Edges come in pre-order: root→a, a→a1, root→b
+        let a1 = &laid.children[0].children[0];
+        assert_eq!(end_of(&links[1]), Point::new(a1.y, a1.x));
+        let b = &laid.children[1];
+        assert_eq!(end_of(&links[2]), Point::new(b.y, b.x));
+    }
+}
@@ -6,15 +6,30 @@
 //! # Modules
 //!
 //! - [`curve`]: Curve interpolation algorithms (linear, step, cardinal, etc.)
-//! - [`path`]: Path segment primitives
+//! - [`path`]: Path segment primitives, with SVG path data serialization
+//!   ([`Path::to_svg_d`]) and parsing ([`Path::from_svg_d`]), plus geometry
+//!   queries: [`Path::bounds`], [`Path::length`], [`Path::point_at_length`],
+//!   [`Path::contains`] and [`Path::distance_to`]; flattening
+//!   ([`Path::flatten`]), dashing ([`Path::dash`]) and stroke outlining
+//!   ([`Path::stroke`])
+//! - Polygon utilities on [`Point`] slices: [`polygon_area`],
+//!   [`polygon_centroid`], [`polygon_hull`], [`polygon_contains`],
+//!   [`polygon_length`], simplification ([`polygon_simplify_douglas_peucker`],
+//!   [`polygon_simplify_visvalingam`]) and clipping ([`polygon_clip_rect`])
 //!
 //! # Generators
 //!
 //! - [`LineGenerator`]: Generate line paths from data points
 //! - [`AreaGenerator`]: Generate filled area paths
+//! - [`LineRadialGenerator`], [`AreaRadialGenerator`]: Polar lines and areas
+//!   for radar charts and spiral timelines
+//! - [`LinkGenerator`]: Generate Bézier edges between tree nodes
 //! - [`ArcGenerator`]: Generate arc paths for pie/donut charts
 //! - [`PieLayout`]: Compute pie slice angles from values
+//! - [`PieLabelLayout`]: Place outside pie labels with leader lines
 //! - [`StackGenerator`]: Compute stacked layouts for bar/area charts
+//! - [`SymbolGenerator`]: Generate area-sized scatter/legend symbols, with
+//!   [`SymbolScale`] assigning symbols to categories
 //!
 //! # Example
 //!
@@ -38,13 +53,34 @@ pub mod path;
 
 mod arc;
 mod area;
+mod geometry;
 mod line;
+mod link;
 mod pie;
+mod pie_label;
+mod polygon;
+mod radial;
 mod stack;
+mod stroke;
+mod svg;
+mod symbol;
 
 pub use arc::{ArcDatum, ArcGenerator};
 pub use area::AreaGenerator;
+pub use geometry::FillRule;
 pub use line::LineGenerator;
+pub use link::{LinkGenerator, LinkOrientation};
 pub use path::{Path, PathSegment, Point};
 pub use pie::{PieLayout, PieSlice, PieSort};
-pub use stack::{StackGenerator, StackOffset, StackOrder, StackPoint, StackedSeries};
+pub use pie_label::{PieLabel, PieLabelAnchor, PieLabelLayout};
+pub use polygon::{
+    polygon_area, polygon_centroid, polygon_clip, polygon_clip_rect, polygon_contains,
+    polygon_hull, polygon_length, polygon_simplify_douglas_peucker, polygon_simplify_visvalingam,
+    simplify_douglas_peucker, simplify_visvalingam,
+};
+pub use radial::{point_radial, AreaRadialGenerator, LineRadialGenerator};
+pub use stack::{
+    StackBar, StackGenerator, StackMissing, StackOffset, StackOrder, StackPoint, StackedSeries,
+};
+pub use stroke::{LineCap, LineJoin, Polyline, StrokeStyle};
+pub use symbol::{SymbolGenerator, SymbolScale, SymbolType};
@@ -180,7 +180,7 @@ impl PathSegment {
 }
 
 /// A complete path consisting of multiple segments
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct Path {
     /// The segments making up this path
     pub segments: Vec<PathSegment>,
@@ -0,0 +1,309 @@
+//! Outside label placement for pie and donut charts
+//!
+//! Places one label per slice outside the ring, connected to its slice by a
+//! leader line. Labels on each side of the pie are stacked vertically so
+//! they never overlap, moving as little as possible (in the least-squares
+//! sense) from their ideal positions.
+
+use super::arc::ArcDatum;
+use super::path::{PathSegment, Point};
+use std::f64::consts::PI;
+
+/// Horizontal text anchor of a pie label
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PieLabelAnchor {
+    /// Text starts at the label position (right side of the pie)
+    Start,
+    /// Text ends at the label position (left side of the pie)
+    End,
+}
+
+/// A placed pie label with its leader line
+///
+/// Coordinates are relative to the pie's center.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PieLabel {
+    /// Index of the slice in the input
+    pub index: usize,
+    /// Where the leader line touches the slice (outer edge, mid-angle)
+    pub anchor: Point,
+    /// Where the leader line bends towards the label
+    pub elbow: Point,
+    /// Label position: the end of the leader line
+    pub position: Point,
+    /// Horizontal text anchor: [`PieLabelAnchor::Start`] on the right
+    /// side, [`PieLabelAnchor::End`] on the left
+    pub text_anchor: PieLabelAnchor,
+}
+
+impl PieLabel {
+    /// Get the leader line as path segments
+    pub fn leader_line(&self) -> Vec<PathSegment> {
+        vec![
+            PathSegment::MoveTo(self.anchor),
+            PathSegment::LineTo(self.elbow),
+            PathSegment::LineTo(self.position),
+        ]
+    }
+
+    /// Check whether the label sits on the left side of the pie
+    pub fn is_left(&self) -> bool {
+        self.text_anchor == PieLabelAnchor::End
+    }
+}
+
+/// Layout for outside pie labels with leader lines
+///
+/// Each label starts at the slice's mid-angle on a circle of
+/// [`radius`](Self::radius) just outside the ring. Labels on the same side
+/// are then pushed apart to at least [`spacing`](Self::spacing) and kept
+/// within the vertical [`extent`](Self::extent).
+///
+/// # Example
+/// ```
+/// use makepad_d3::shape::{ArcDatum, PieLabelLayout, PieLayout};
+///
+/// let slices = PieLayout::new().compute(&[50.0, 2.0, 2.0, 2.0, 44.0]);
+/// let arcs: Vec<ArcDatum> = slices
+///     .iter()
+///     .map(|s| ArcDatum::new(s.start_angle, s.end_angle))
+///     .collect();
+///
+/// let labels = PieLabelLayout::new().radius(115.0).spacing(14.0).layout(&arcs);
+/// assert_eq!(labels.len(), 5);
+///
+/// // The three thin slices get labels at least 14 apart
+/// let mut ys: Vec<f64> = labels[1..4].iter().map(|l| l.position.y).collect();
+/// ys.sort_by(|a, b| a.partial_cmp(b).unwrap());
+/// assert!(ys[1] - ys[0] >= 14.0 - 1e-9 && ys[2] - ys[1] >= 14.0 - 1e-9);
+/// ```
+#[derive(Clone, Debug)]
+pub struct PieLabelLayout {
+    /// Radius of the circle the leader lines bend on
+    radius: f64,
+    /// Length of the horizontal part of the leader line
+    line_length: f64,
+    /// Minimum vertical distance between labels on the same side
+    spacing: f64,
+    /// Vertical bounds for label positions (None = ±(radius + spacing))
+    extent: Option<(f64, f64)>,
+}
+
+impl Default for PieLabelLayout {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PieLabelLayout {
+    /// Create a new label layout with default settings
+    pub fn new() -> Self {
+        Self {
+            radius: 110.0,
+            line_length: 12.0,
+            spacing: 14.0,
+            extent: None,
+        }
+    }
+
+    /// Set the radius at which leader lines bend
+    ///
+    /// Should be a little larger than the pie's outer radius.
+    pub fn radius(mut self, radius: f64) -> Self {
+        self.radius = radius.max(0.0);
+        self
+    }
+
+    /// Set the length of the horizontal part of the leader line
+    pub fn line_length(mut self, length: f64) -> Self {
+        self.line_length = length.max(0.0);
+        self
+    }
+
+    /// Set the minimum vertical distance between labels (the line height)
+    pub fn spacing(mut self, spacing: f64) -> Self {
+        self.spacing = spacing.max(0.0);
+        self
+    }
+
+    /// Set the vertical bounds for label positions, relative to the center
+    pub fn extent(mut self, min_y: f64, max_y: f64) -> Self {
+        self.extent = Some((min_y.min(max_y), min_y.max(max_y)));
+        self
+    }
+
+    /// Place labels for the given arcs
+    ///
+    /// Labels are returned in input order. Empty slices still get a label;
+    /// filter them out beforehand if unwanted.
+    pub fn layout(&self, arcs: &[ArcDatum]) -> Vec<PieLabel> {
+        let (min_y, max_y) = self
+            .extent
+            .unwrap_or((-self.radius - self.spacing, self.radius + self.spacing));
+
+        let mut labels: Vec<PieLabel> = arcs
+            .iter()
+            .enumerate()
+            .map(|(index, arc)| {
+                let angle = arc.mid_angle();
+                let (sin, cos) = angle.sin_cos();
+                let outer = arc.outer_radius.max(arc.inner_radius);
+                let elbow = Point::new(self.radius * sin, -self.radius * cos);
+                let left = angle.rem_euclid(2.0 * PI) > PI;
+                PieLabel {
+                    index,
+                    anchor: Point::new(outer * sin, -outer * cos),
+                    elbow,
+                    position: elbow,
+                    text_anchor: if left {
+                        PieLabelAnchor::End
+                    } else {
+                        PieLabelAnchor::Start
+                    },
+                }
+            })
+            .collect();
+
+        for left in [false, true] {
+            let mut side: Vec<usize> = (0..labels.len())
+                .filter(|&i| labels[i].is_left() == left)
+                .collect();
+            side.sort_by(|&a, &b| {
+                labels[a]
+                    .elbow
+                    .y
+                    .partial_cmp(&labels[b].elbow.y)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+            let ideal: Vec<f64> = side.iter().map(|&i| labels[i].elbow.y).collect();
+            let placed = stack_positions(&ideal, self.spacing, min_y, max_y);
+
+            for (&i, y) in side.iter().zip(placed) {
+                let label = &mut labels[i];
+                let direction = if left { -1.0 } else { 1.0 };
+                label.elbow.y = y;
+                // Keep the elbow outside the ring when the label moved
+                let reach = (self.radius * self.radius - y * y).max(0.0).sqrt();
+                label.elbow.x = direction * label.elbow.x.abs().max(reach);
+                label.position = Point::new(label.elbow.x + direction * self.line_length, y);
+            }
+        }
+
+        labels
+    }
+}
+
+/// Push sorted positions apart to at least `spacing`, within `[min, max]`
+///
+/// Moves the positions as little as possible: labels that would overlap
+/// are merged into a cluster centered on their ideal positions, so a
+/// crowded group spreads both up and down. If the labels cannot fit at
+/// all they are spread evenly over the extent.
+fn stack_positions(ideal: &[f64], spacing: f64, min: f64, max: f64) -> Vec<f64> {
+    let n = ideal.len();
+    if n == 0 {
+        return vec![];
+    }
+    if spacing * (n - 1) as f64 > max - min {
+        let step = if n > 1 {
+            (max - min) / (n - 1) as f64
+        } else {
+            0.0
+        };
+        return (0..n).map(|i| min + step * i as f64).collect();
+    }
+
+    // With z[i] = y[i] - i * spacing the spacing constraint becomes z
+    // non-decreasing. Pool adjacent violators into (sum, count) clusters;
+    // each cluster sits at the mean of its members.
+    let mut clusters: Vec<(f64, usize)> = Vec::new();
+    for (i, y) in ideal.iter().enumerate() {
+        let (mut sum, mut count) = (y - spacing * i as f64, 1);
+        while let Some(&(prev_sum, prev_count)) = clusters.last() {
+            if prev_sum / prev_count as f64 <= sum / count as f64 {
+                break;
+            }
+            sum += prev_sum;
+            count += prev_count;
+            clusters.pop();
+        }
+        clusters.push((sum, count));
+    }
+
+    // Clamping the means keeps the first label above `min` and the last
+    // below `max` without breaking the order
+    let last = max - spacing * (n - 1) as f64;
+    let mut placed = Vec::with_capacity(n);
+    for &(sum, count) in &clusters {
+        let z = (sum / count as f64).clamp(min, last);
+        for _ in 0..count {
+            placed.push(z + spacing * placed.len() as f64);
+        }
+    }
+    placed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arcs(angles: &[(f64, f64)]) -> Vec<ArcDatum> {
+        angles.iter().map(|&(a, b)| ArcDatum::new(a, b)).collect()
+    }
+
+    #[test]
+    fn test_pie_label_sides() {
+        let labels = PieLabelLayout::new()
+            .radius(120.0)
+            .layout(&arcs(&[(0.0, PI), (PI, 2.0 * PI)]));
+
+        // Right half: label at 3 o'clock, anchored at its start
+        assert_eq!(labels[0].text_anchor, PieLabelAnchor::Start);
+        assert!((labels[0].anchor.x - 100.0).abs() < 1e-9);
+        assert!((labels[0].position.x - 132.0).abs() < 1e-9);
+        assert!(labels[0].position.y.abs() < 1e-9);
+
+        assert!(labels[1].is_left());
+        assert!((labels[1].position.x + 132.0).abs() < 1e-9);
+        assert_eq!(labels[1].leader_line().len(), 3);
+    }
+
+    #[test]
+    fn test_pie_label_stacking() {
+        // Three slivers near 3 o'clock would overlap without stacking
+        let a = PI / 2.0;
+        let labels = PieLabelLayout::new().spacing(20.0).layout(&arcs(&[
+            (a - 0.02, a - 0.01),
+            (a - 0.01, a),
+            (a, a + 0.01),
+        ]));
+
+        let ys: Vec<f64> = labels.iter().map(|l| l.position.y).collect();
+        assert!(ys[1] - ys[0] >= 20.0 - 1e-9);
+        assert!(ys[2] - ys[1] >= 20.0 - 1e-9);
+        // Stacking keeps the group near its ideal position
+        assert!(ys[1].abs() < 20.0);
+    }
+
+    #[test]
+    fn test_stack_positions_bounds() {
+        assert_eq!(
+            stack_positions(&[8.0, 9.0, 10.0], 5.0, -10.0, 10.0),
+            vec![0.0, 5.0, 10.0]
+        );
+        // A crowded group spreads both ways around its ideal position
+        assert_eq!(
+            stack_positions(&[0.0, 0.0, 0.0], 10.0, -100.0, 100.0),
+            vec![-10.0, 0.0, 10.0]
+        );
+        assert_eq!(
+            stack_positions(&[-50.0, 0.0, 1.0], 10.0, -100.0, 100.0),
+            vec![-50.0, -4.5, 5.5]
+        );
+        // Too many labels for the extent are spread evenly
+        assert_eq!(
+            stack_positions(&[0.0, 0.0, 0.0], 50.0, -10.0, 10.0),
+            vec![-10.0, 0.0, 10.0]
+        );
+    }
+}
@@ -0,0 +1,496 @@
+//! Polygon utilities
+//!
+//! Port of d3-polygon plus simplification and clipping. A polygon is a
+//! slice of [`Point`]s; the closing edge from the last point back to the
+//! first is implicit, so the first point should not be repeated.
+//!
+//! Orientation follows d3: with the origin in the top-left corner (y
+//! pointing down), counterclockwise polygons have a positive area.
+
+use super::path::Point;
+
+/// Signed area of a polygon
+///
+/// Positive if the vertices are counterclockwise on screen (y down),
+/// negative if clockwise.
+///
+/// # Example
+/// ```
+/// use makepad_d3::shape::{polygon_area, Point};
+///
+/// let square = [
+///     Point::new(0.0, 0.0),
+///     Point::new(0.0, 10.0),
+///     Point::new(10.0, 10.0),
+///     Point::new(10.0, 0.0),
+/// ];
+/// assert_eq!(polygon_area(&square), 100.0);
+/// ```
+pub fn polygon_area(polygon: &[Point]) -> f64 {
+    edges(polygon)
+        .map(|(a, b)| a.y * b.x - a.x * b.y)
+        .sum::<f64>()
+        / 2.0
+}
+
+/// Centroid (center of mass) of a polygon
+///
+/// Returns `None` for empty or zero-area polygons.
+pub fn polygon_centroid(polygon: &[Point]) -> Option<Point> {
+    let mut x = 0.0;
+    let mut y = 0.0;
+    let mut k = 0.0;
+    for (a, b) in edges(polygon) {
+        let c = a.x * b.y - b.x * a.y;
+        x += (a.x + b.x) * c;
+        y += (a.y + b.y) * c;
+        k += c * 3.0;
+    }
+    if k.abs() < f64::EPSILON {
+        return None;
+    }
+    Some(Point::new(x / k, y / k))
+}
+
+/// Convex hull of a set of points
+///
+/// Returns the hull vertices in counterclockwise order (positive
+/// [`polygon_area`]), or `None` if there are fewer than three points.
+/// Collinear points on the hull's edges are omitted.
+///
+/// # Example
+/// ```
+/// use makepad_d3::shape::{polygon_hull, Point};
+///
+/// let points = [
+///     Point::new(0.0, 0.0),
+///     Point::new(5.0, 5.0),
+///     Point::new(10.0, 0.0),
+///     Point::new(10.0, 10.0),
+///     Point::new(0.0, 10.0),
+/// ];
+/// let hull = polygon_hull(&points).unwrap();
+/// assert_eq!(hull.len(), 4);
+/// assert!(!hull.contains(&Point::new(5.0, 5.0)));
+/// ```
+pub fn polygon_hull(points: &[Point]) -> Option<Vec<Point>> {
+    if points.len() < 3 {
+        return None;
+    }
+
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| {
+        a.x.partial_cmp(&b.x)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(a.y.partial_cmp(&b.y).unwrap_or(std::cmp::Ordering::Equal))
+    });
+    sorted.dedup();
+
+    // Andrew's monotone chain
+    let mut hull: Vec<Point> = Vec::with_capacity(sorted.len() + 1);
+    let reversed: Vec<Point> = sorted.iter().rev().copied().collect();
+    for chain in [&sorted, &reversed] {
+        let start = hull.len();
+        for &p in chain {
+            while hull.len() >= start + 2
+                && cross(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0.0
+            {
+                hull.pop();
+            }
+            hull.push(p);
+        }
+        // The last point of each chain starts the next one
+        hull.pop();
+    }
+
+    if hull.len() < 3 {
+        return None;
+    }
+    if polygon_area(&hull) < 0.0 {
+        hull.reverse();
+    }
+    Some(hull)
+}
+
+/// Check whether a point lies inside a polygon (even-odd rule)
+pub fn polygon_contains(polygon: &[Point], point: Point) -> bool {
+    let mut inside = false;
+    for (a, b) in edges(polygon) {
+        if (b.y > point.y) != (a.y > point.y)
+            && point.x < (a.x - b.x) * (point.y - b.y) / (a.y - b.y) + b.x
+        {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+/// Perimeter of a polygon, including the closing edge
+pub fn polygon_length(polygon: &[Point]) -> f64 {
+    edges(polygon).map(|(a, b)| a.distance(&b)).sum()
+}
+
+/// Simplify an open polyline with the Douglas–Peucker algorithm
+///
+/// Removes vertices closer than `tolerance` to the line through their
+/// retained neighbours. The first and last points are always kept.
+///
+/// # Example
+/// ```
+/// use makepad_d3::shape::{simplify_douglas_peucker, Point};
+///
+/// let line = [
+///     Point::new(0.0, 0.0),
+///     Point::new(5.0, 0.1),
+///     Point::new(10.0, 0.0),
+///     Point::new(10.0, 10.0),
+/// ];
+/// let simple = simplify_douglas_peucker(&line, 0.5);
+/// assert_eq!(simple.len(), 3);
+/// ```
+pub fn simplify_douglas_peucker(points: &[Point], tolerance: f64) -> Vec<Point> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+
+    let mut stack = vec![(0, points.len() - 1)];
+    while let Some((first, last)) = stack.pop() {
+        let mut max_distance = 0.0;
+        let mut index = first;
+        for (i, &p) in points.iter().enumerate().take(last).skip(first + 1) {
+            let d = segment_distance(p, points[first], points[last]);
+            if d > max_distance {
+                max_distance = d;
+                index = i;
+            }
+        }
+        if max_distance > tolerance {
+            keep[index] = true;
+            stack.push((first, index));
+            stack.push((index, last));
+        }
+    }
+
+    points
+        .iter()
+        .zip(keep)
+        .filter_map(|(&p, k)| k.then_some(p))
+        .collect()
+}
+
+/// Simplify an open polyline with the Visvalingam–Whyatt algorithm
+///
+/// Repeatedly removes the vertex forming the smallest triangle with its
+/// neighbours, until every remaining triangle has at least `min_area`.
+/// The first and last points are always kept.
+pub fn simplify_visvalingam(points: &[Point], min_area: f64) -> Vec<Point> {
+    visvalingam(points, min_area, false)
+}
+
+/// Simplify a polygon with the Douglas–Peucker algorithm
+///
+/// The ring is split at the vertex farthest from the first one and both
+/// halves are simplified. At least three vertices are kept.
+pub fn polygon_simplify_douglas_peucker(polygon: &[Point], tolerance: f64) -> Vec<Point> {
+    if polygon.len() <= 3 {
+        return polygon.to_vec();
+    }
+
+    let first = polygon[0];
+    let split = (1..polygon.len())
+        .max_by(|&a, &b| {
+            first
+                .distance(&polygon[a])
+                .partial_cmp(&first.distance(&polygon[b]))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+        .unwrap_or(1);
+
+    let mut ring = simplify_douglas_peucker(&polygon[..=split], tolerance);
+    let mut back: Vec<Point> = polygon[split..].to_vec();
+    back.push(first);
+    let back = simplify_douglas_peucker(&back, tolerance);
+    ring.extend_from_slice(&back[1..back.len() - 1]);
+
+    if ring.len() < 3 {
+        // Keep the vertex farthest from the remaining chord
+        let extra = (1..polygon.len()).filter(|&i| i != split).max_by(|&a, &b| {
+            segment_distance(polygon[a], first, polygon[split])
+                .partial_cmp(&segment_distance(polygon[b], first, polygon[split]))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        return match extra {
+            Some(i) if i < split => vec![first, polygon[i], polygon[split]],
+            Some(i) => vec![first, polygon[split], polygon[i]],
+            None => polygon.to_vec(),
+        };
+    }
+    ring
+}
+
+/// Simplify a polygon with the Visvalingam–Whyatt algorithm
+///
+/// Like [`simplify_visvalingam`], treating the ring as closed. At least
+/// three vertices are kept.
+pub fn polygon_simplify_visvalingam(polygon: &[Point], min_area: f64) -> Vec<Point> {
+    visvalingam(polygon, min_area, true)
+}
+
+/// Clip a polygon to a rectangle (Sutherland–Hodgman)
+///
+/// Returns an empty vector if the polygon lies entirely outside.
+///
+/// # Example
+/// ```
+/// use makepad_d3::shape::{polygon_area, polygon_clip_rect, Point};
+///
+/// let triangle = [
+///     Point::new(0.0, 0.0),
+///     Point::new(20.0, 20.0),
+///     Point::new(20.0, 0.0),
+/// ];
+/// let clipped = polygon_clip_rect(&triangle, 0.0, 0.0, 10.0, 10.0);
+/// assert!((polygon_area(&clipped).abs() - 50.0).abs() < 1e-9);
+/// ```
+pub fn polygon_clip_rect(polygon: &[Point], x0: f64, y0: f64, x1: f64, y1: f64) -> Vec<Point> {
+    let (x0, x1) = (x0.min(x1), x0.max(x1));
+    let (y0, y1) = (y0.min(y1), y0.max(y1));
+    let clip = [
+        Point::new(x0, y0),
+        Point::new(x0, y1),
+        Point::new(x1, y1),
+        Point::new(x1, y0),
+    ];
+    polygon_clip(polygon, &clip)
+}
+
+/// Clip a polygon to a convex polygon (Sutherland–Hodgman)
+///
+/// The clip polygon may have either orientation. The result can contain
+/// degenerate edges along the clip boundary when the subject is concave.
+pub fn polygon_clip(subject: &[Point], clip: &[Point]) -> Vec<Point> {
+    if clip.len() < 3 {
+        return Vec::new();
+    }
+    // Points left of each edge are inside for positive-area clip polygons
+    let sign = if polygon_area(clip) >= 0.0 { 1.0 } else { -1.0 };
+
+    let mut output = subject.to_vec();
+    for (a, b) in edges(clip) {
+        if output.is_empty() {
+            break;
+        }
+        let input = std::mem::take(&mut output);
+        let inside = |p: Point| sign * cross(a, b, p) <= 0.0;
+        let mut prev = input[input.len() - 1];
+        for &p in &input {
+            match (inside(p), inside(prev)) {
+                (true, true) => output.push(p),
+                (true, false) => {
+                    output.push(line_intersection(prev, p, a, b));
+                    output.push(p);
+                }
+                (false, true) => output.push(line_intersection(prev, p, a, b)),
+                (false, false) => {}
+            }
+            prev = p;
+        }
+    }
+    output
+}
+
+/// Edges of a closed polygon as `(start, end)` pairs
+fn edges(polygon: &[Point]) -> impl Iterator<Item = (Point, Point)> + '_ {
+    let n = polygon.len();
+    (0..n).map(move |i| (polygon[(i + n - 1) % n], polygon[i]))
+}
+
+/// Cross product of `a → b` and `a → c`
+fn cross(a: Point, b: Point, c: Point) -> f64 {
+    (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)
+}
+
+/// Distance from `p` to the segment `a`–`b`
+fn segment_distance(p: Point, a: Point, b: Point) -> f64 {
+    let ab = b.sub(&a);
+    let len2 = ab.x * ab.x + ab.y * ab.y;
+    if len2 == 0.0 {
+        return p.distance(&a);
+    }
+    let t = (((p.x - a.x) * ab.x + (p.y - a.y) * ab.y) / len2).clamp(0.0, 1.0);
+    p.distance(&a.add(&ab.scale(t)))
+}
+
+/// Intersection of segment `p`–`q` with the infinite line `a`–`b`
+fn line_intersection(p: Point, q: Point, a: Point, b: Point) -> Point {
+    let dp = q.sub(&p);
+    let da = b.sub(&a);
+    let denom = dp.x * da.y - dp.y * da.x;
+    if denom == 0.0 {
+        return q;
+    }
+    let t = ((a.x - p.x) * da.y - (a.y - p.y) * da.x) / denom;
+    p.add(&dp.scale(t))
+}
+
+/// Area of the triangle formed by a vertex and its neighbours
+fn triangle_area(a: Point, b: Point, c: Point) -> f64 {
+    cross(a, b, c).abs() / 2.0
+}
+
+/// Visvalingam–Whyatt over a polyline or ring
+fn visvalingam(points: &[Point], min_area: f64, closed: bool) -> Vec<Point> {
+    let n = points.len();
+    let min_len = if closed { 3 } else { 2 };
+    if n <= min_len {
+        return points.to_vec();
+    }
+
+    let mut alive: Vec<usize> = (0..n).collect();
+    loop {
+        let m = alive.len();
+        if m <= min_len {
+            break;
+        }
+        let candidates = if closed { 0..m } else { 1..m - 1 };
+        let smallest = candidates
+            .map(|i| {
+                let prev = points[alive[(i + m - 1) % m]];
+                let next = points[alive[(i + 1) % m]];
+                (i, triangle_area(prev, points[alive[i]], next))
+            })
+            .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+        match smallest {
+            Some((i, area)) if area < min_area => {
+                alive.remove(i);
+            }
+            _ => break,
+        }
+    }
+
+    alive.into_iter().map(|i| points[i]).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square() -> Vec<Point> {
+        vec![
+            Point::new(0.0, 0.0),
+            Point::new(0.0, 10.0),
+            Point::new(10.0, 10.0),
+            Point::new(10.0, 0.0),
+        ]
+    }
+
+    #[test]
+    fn test_polygon_area_orientation() {
+        let mut polygon = square();
+        assert_eq!(polygon_area(&polygon), 100.0);
+        polygon.reverse();
+        assert_eq!(polygon_area(&polygon), -100.0);
+        assert_eq!(polygon_area(&[]), 0.0);
+    }
+
+    #[test]
+    fn test_polygon_centroid() {
+        assert_eq!(polygon_centroid(&square()), Some(Point::new(5.0, 5.0)));
+        let triangle = [
+            Point::new(0.0, 0.0),
+            Point::new(0.0, 3.0),
+            Point::new(3.0, 0.0),
+        ];
+        let c = polygon_centroid(&triangle).unwrap();
+        assert!((c.x - 1.0).abs() < 1e-12 && (c.y - 1.0).abs() < 1e-12);
+        let line = [Point::new(0.0, 0.0), Point::new(1.0, 1.0)];
+        assert_eq!(polygon_centroid(&line), None);
+    }
+
+    #[test]
+    fn test_polygon_hull() {
+        let mut points = square();
+        points.push(Point::new(5.0, 5.0));
+        points.push(Point::new(5.0, 0.0)); // collinear, on an edge
+        let hull = polygon_hull(&points).unwrap();
+        assert_eq!(hull.len(), 4);
+        assert!(polygon_area(&hull) > 0.0);
+
+        assert!(polygon_hull(&points[..2]).is_none());
+        let collinear = [
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(2.0, 2.0),
+        ];
+        assert!(polygon_hull(&collinear).is_none());
+    }
+
+    #[test]
+    fn test_polygon_contains_and_length() {
+        let polygon = square();
+        assert!(polygon_contains(&polygon, Point::new(5.0, 5.0)));
+        assert!(!polygon_contains(&polygon, Point::new(15.0, 5.0)));
+        assert_eq!(polygon_length(&polygon), 40.0);
+    }
+
+    #[test]
+    fn test_polygon_simplify() {
+        // A square with noise on every edge
+        let mut noisy = Vec::new();
+        for (a, b) in edges(&square()) {
+            for k in 0..5 {
+                let t = k as f64 / 5.0;
+                let jitter = if k % 2 == 1 { 0.05 } else { 0.0 };
+                noisy.push(a.lerp(&b, t).add(&Point::new(jitter, -jitter)));
+            }
+        }
+
+        let dp = polygon_simplify_douglas_peucker(&noisy, 0.2);
+        assert_eq!(dp.len(), 4);
+        assert!((polygon_area(&dp).abs() - 100.0).abs() < 1e-9);
+
+        let vw = polygon_simplify_visvalingam(&noisy, 1.0);
+        assert_eq!(vw.len(), 4);
+        assert!((polygon_area(&vw).abs() - 100.0).abs() < 1e-9);
+
+        // Nothing is dropped below three vertices
+        assert_eq!(polygon_simplify_visvalingam(&noisy, 1e9).len(), 3);
+        assert_eq!(polygon_simplify_douglas_peucker(&noisy, 1e9).len(), 3);
+    }
+
+    #[test]
+    fn test_simplify_visvalingam_polyline() {
+        let line = [
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.01),
+            Point::new(2.0, 0.0),
+            Point::new(3.0, 5.0),
+        ];
+        let simple = simplify_visvalingam(&line, 0.1);
+        assert_eq!(simple, vec![line[0], line[2], line[3]],);
+    }
+
+    #[test]
+    fn test_polygon_clip() {
+        // Fully inside is unchanged, fully outside is empty
+        let inner = polygon_clip_rect(&square(), -1.0, -1.0, 11.0, 11.0);
+        assert_eq!(polygon_area(&inner), 100.0);
+        assert!(polygon_clip_rect(&square(), 20.0, 20.0, 30.0, 30.0).is_empty());
+
+        // Overlapping quarter, with either clip orientation
+        let quarter = polygon_clip_rect(&square(), 5.0, 5.0, 20.0, 20.0);
+        assert!((polygon_area(&quarter) - 25.0).abs() < 1e-9);
+        let mut clip = vec![
+            Point::new(5.0, 5.0),
+            Point::new(5.0, 20.0),
+            Point::new(20.0, 20.0),
+            Point::new(20.0, 5.0),
+        ];
+        clip.reverse();
+        assert!((polygon_area(&polygon_clip(&square(), &clip)) - 25.0).abs() < 1e-9);
+    }
+}
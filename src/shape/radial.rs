@@ -0,0 +1,395 @@
+//! Radial line and area generators
+//!
+//! Polar counterparts of [`LineGenerator`](super::LineGenerator) and
+//! [`AreaGenerator`](super::AreaGenerator), for radar charts, spiral
+//! timelines and radial stacked areas.
+//!
+//! Angles are in radians with 0 at 12 o'clock, increasing clockwise, the
+//! same convention as [`ArcGenerator`](super::ArcGenerator). Points are
+//! projected to Cartesian coordinates relative to the origin and then
+//! interpolated with any [`Curve`].
+
+use super::area::defined_runs;
+use super::curve::{Curve, LinearCurve};
+use super::path::{PathSegment, Point};
+use crate::data::DataPoint;
+
+type Accessor = Box<dyn Fn(&DataPoint, usize) -> f64 + Send + Sync>;
+type Predicate = Box<dyn Fn(&DataPoint, usize) -> bool + Send + Sync>;
+
+/// Convert polar coordinates to a Cartesian point
+///
+/// Angle 0 points up (negative y) and angles increase clockwise.
+pub fn point_radial(angle: f64, radius: f64) -> Point {
+    Point::new(radius * angle.sin(), -radius * angle.cos())
+}
+
+/// Radial line generator
+///
+/// Like [`LineGenerator`](super::LineGenerator), but with angle and radius
+/// accessors in place of x and y.
+///
+/// # Example
+/// ```
+/// use makepad_d3::data::DataPoint;
+/// use makepad_d3::shape::{LineRadialGenerator, PathSegment};
+/// use std::f64::consts::PI;
+///
+/// let data = vec![
+///     DataPoint::from((0.0, 10.0)),
+///     DataPoint::from((PI / 2.0, 20.0)),
+/// ];
+///
+/// let line = LineRadialGenerator::new();
+/// let path = line.generate(&data);
+///
+/// match &path[1] {
+///     PathSegment::LineTo(p) => assert!((p.x - 20.0).abs() < 1e-9 && p.y.abs() < 1e-9),
+///     _ => unreachable!(),
+/// }
+/// ```
+pub struct LineRadialGenerator {
+    /// Function to extract the angle in radians
+    angle_fn: Accessor,
+    /// Function to extract the radius
+    radius_fn: Accessor,
+    /// Function to determine if a point is defined (included)
+    defined_fn: Predicate,
+    /// Curve interpolation
+    curve: Box<dyn Curve>,
+}
+
+impl Default for LineRadialGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LineRadialGenerator {
+    /// Create a new radial line generator
+    ///
+    /// By default the angle is the data point's x (or index) and the radius
+    /// is its y.
+    pub fn new() -> Self {
+        Self {
+            angle_fn: Box::new(|d, i| d.x_or(i)),
+            radius_fn: Box::new(|d, _| d.y),
+            defined_fn: Box::new(|d, _| d.y.is_finite()),
+            curve: Box::new(LinearCurve),
+        }
+    }
+
+    /// Set the angle accessor function
+    pub fn angle<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&DataPoint, usize) -> f64 + Send + Sync + 'static,
+    {
+        self.angle_fn = Box::new(f);
+        self
+    }
+
+    /// Set the radius accessor function
+    pub fn radius<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&DataPoint, usize) -> f64 + Send + Sync + 'static,
+    {
+        self.radius_fn = Box::new(f);
+        self
+    }
+
+    /// Set the defined predicate
+    ///
+    /// Points for which this function returns false will be excluded,
+    /// creating gaps in the line.
+    pub fn defined<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&DataPoint, usize) -> bool + Send + Sync + 'static,
+    {
+        self.defined_fn = Box::new(f);
+        self
+    }
+
+    /// Set the curve interpolation
+    pub fn curve(mut self, curve: impl Curve + 'static) -> Self {
+        self.curve = Box::new(curve);
+        self
+    }
+
+    /// Generate path segments from data points
+    pub fn generate(&self, data: &[DataPoint]) -> Vec<PathSegment> {
+        let mut path = Vec::new();
+        for run in defined_runs(data, &self.defined_fn) {
+            let points: Vec<Point> = run
+                .map(|i| point_radial((self.angle_fn)(&data[i], i), (self.radius_fn)(&data[i], i)))
+                .collect();
+            path.extend(self.curve.generate(&points));
+        }
+        path
+    }
+}
+
+/// Radial area generator
+///
+/// Like [`AreaGenerator`](super::AreaGenerator), but with angle and radius
+/// accessors. The area spans from the inner radius to the outer radius;
+/// start and end angles may differ for spiral or skewed bands.
+///
+/// # Example
+/// ```
+/// use makepad_d3::data::DataPoint;
+/// use makepad_d3::shape::{AreaRadialGenerator, PathSegment};
+///
+/// let data: Vec<DataPoint> = (0..8)
+///     .map(|i| DataPoint::from((i as f64, 50.0 + (i % 2) as f64 * 20.0)))
+///     .collect();
+///
+/// let area = AreaRadialGenerator::new()
+///     .angle(|_, i| i as f64 * std::f64::consts::TAU / 8.0)
+///     .inner_radius(|_, _| 20.0);
+/// let path = area.generate(&data);
+///
+/// assert_eq!(path.last(), Some(&PathSegment::ClosePath));
+/// ```
+pub struct AreaRadialGenerator {
+    /// Function to extract the angle of the outer edge
+    end_angle_fn: Accessor,
+    /// Function to extract the angle of the inner edge
+    start_angle_fn: Option<Accessor>,
+    /// Function to extract the inner radius
+    inner_radius_fn: Accessor,
+    /// Function to extract the outer radius
+    outer_radius_fn: Accessor,
+    /// Function to determine if a point is defined
+    defined_fn: Predicate,
+    /// Curve interpolation
+    curve: Box<dyn Curve>,
+}
+
+impl Default for AreaRadialGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AreaRadialGenerator {
+    /// Create a new radial area generator
+    ///
+    /// By default the angle is the data point's x (or index), the inner
+    /// radius is 0 and the outer radius is the data point's y.
+    pub fn new() -> Self {
+        Self {
+            end_angle_fn: Box::new(|d, i| d.x_or(i)),
+            start_angle_fn: None,
+            inner_radius_fn: Box::new(|_, _| 0.0),
+            outer_radius_fn: Box::new(|d, _| d.y),
+            defined_fn: Box::new(|d, _| d.y.is_finite()),
+            curve: Box::new(LinearCurve),
+        }
+    }
+
+    /// Set the angle accessor for both edges
+    pub fn angle<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&DataPoint, usize) -> f64 + Send + Sync + 'static,
+    {
+        self.end_angle_fn = Box::new(f);
+        self.start_angle_fn = None;
+        self
+    }
+
+    /// Set the angle accessor for the inner edge
+    pub fn start_angle<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&DataPoint, usize) -> f64 + Send + Sync + 'static,
+    {
+        self.start_angle_fn = Some(Box::new(f));
+        self
+    }
+
+    /// Set the angle accessor for the outer edge
+    pub fn end_angle<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&DataPoint, usize) -> f64 + Send + Sync + 'static,
+    {
+        self.end_angle_fn = Box::new(f);
+        self
+    }
+
+    /// Set the inner radius accessor function
+    pub fn inner_radius<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&DataPoint, usize) -> f64 + Send + Sync + 'static,
+    {
+        self.inner_radius_fn = Box::new(f);
+        self
+    }
+
+    /// Set the outer radius accessor function
+    pub fn outer_radius<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&DataPoint, usize) -> f64 + Send + Sync + 'static,
+    {
+        self.outer_radius_fn = Box::new(f);
+        self
+    }
+
+    /// Set the defined predicate
+    ///
+    /// Each run of defined points produces its own closed subpath.
+    pub fn defined<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&DataPoint, usize) -> bool + Send + Sync + 'static,
+    {
+        self.defined_fn = Box::new(f);
+        self
+    }
+
+    /// Set the curve interpolation
+    pub fn curve(mut self, curve: impl Curve + 'static) -> Self {
+        self.curve = Box::new(curve);
+        self
+    }
+
+    /// Generate path segments from data points
+    pub fn generate(&self, data: &[DataPoint]) -> Vec<PathSegment> {
+        let mut path = Vec::new();
+
+        for run in defined_runs(data, &self.defined_fn) {
+            let mut outer = Vec::new();
+            let mut inner = Vec::new();
+            for i in run {
+                let d = &data[i];
+                let end = (self.end_angle_fn)(d, i);
+                let start = self.start_angle_fn.as_ref().map_or(end, |f| f(d, i));
+                outer.push(point_radial(end, (self.outer_radius_fn)(d, i)));
+                inner.push(point_radial(start, (self.inner_radius_fn)(d, i)));
+            }
+
+            path.extend(self.curve.generate(&outer));
+
+            inner.reverse();
+            let inner_path = self.curve.generate(&inner);
+            if let Some(PathSegment::MoveTo(p)) = inner_path.first() {
+                path.push(PathSegment::LineTo(*p));
+            }
+            path.extend(inner_path.into_iter().skip(1));
+            path.push(PathSegment::ClosePath);
+        }
+
+        path
+    }
+
+    /// Generate only the outer edge as an open line
+    ///
+    /// Useful for stroking the top of a radial area.
+    pub fn generate_outer_line(&self, data: &[DataPoint]) -> Vec<PathSegment> {
+        let mut path = Vec::new();
+        for run in defined_runs(data, &self.defined_fn) {
+            let points: Vec<Point> = run
+                .map(|i| {
+                    let d = &data[i];
+                    point_radial((self.end_angle_fn)(d, i), (self.outer_radius_fn)(d, i))
+                })
+                .collect();
+            path.extend(self.curve.generate(&points));
+        }
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    fn assert_point(segment: &PathSegment, x: f64, y: f64) {
+        let p = segment.end_point().expect("segment has an end point");
+        assert!(
+            (p.x - x).abs() < 1e-9 && (p.y - y).abs() < 1e-9,
+            "expected ({}, {}), got {:?}",
+            x,
+            y,
+            p
+        );
+    }
+
+    #[test]
+    fn test_point_radial() {
+        let p = point_radial(0.0, 10.0);
+        assert!(p.x.abs() < 1e-12 && (p.y + 10.0).abs() < 1e-12);
+        let p = point_radial(PI, 10.0);
+        assert!(p.x.abs() < 1e-9 && (p.y - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_line_radial_gaps() {
+        let data = vec![
+            DataPoint::from((0.0, 10.0)),
+            DataPoint::from((PI / 2.0, 10.0)),
+            DataPoint::from((PI, f64::NAN)),
+            DataPoint::from((PI * 1.5, 10.0)),
+        ];
+        let path = LineRadialGenerator::new().generate(&data);
+
+        assert_eq!(path.len(), 3);
+        assert_point(&path[0], 0.0, -10.0);
+        assert_point(&path[1], 10.0, 0.0);
+        assert!(matches!(path[2], PathSegment::MoveTo(_)));
+        assert_point(&path[2], -10.0, 0.0);
+    }
+
+    #[test]
+    fn test_line_radial_reuses_curves() {
+        use crate::shape::curve::CardinalCurve;
+
+        let data: Vec<DataPoint> = (0..6)
+            .map(|i| DataPoint::from((i as f64, 5.0 + i as f64)))
+            .collect();
+        let path = LineRadialGenerator::new()
+            .angle(|_, i| i as f64 * PI / 3.0)
+            .curve(CardinalCurve::new(0.0))
+            .generate(&data);
+
+        assert!(path
+            .iter()
+            .any(|s| matches!(s, PathSegment::CurveTo { .. })));
+        assert_point(&path[0], 0.0, -5.0);
+    }
+
+    #[test]
+    fn test_area_radial_ring() {
+        let data: Vec<DataPoint> = (0..3).map(|i| DataPoint::from((i as f64, 20.0))).collect();
+        let path = AreaRadialGenerator::new()
+            .angle(|_, i| i as f64 * PI / 2.0)
+            .inner_radius(|_, _| 10.0)
+            .generate(&data);
+
+        // Outer: MoveTo + 2 LineTo; inner: LineTo + 2 LineTo; ClosePath
+        assert_eq!(path.len(), 7);
+        assert_point(&path[0], 0.0, -20.0);
+        assert_point(&path[2], 0.0, 20.0);
+        assert_point(&path[3], 0.0, 10.0);
+        assert_point(&path[5], 0.0, -10.0);
+        assert_eq!(path[6], PathSegment::ClosePath);
+    }
+
+    #[test]
+    fn test_area_radial_split_angles_and_gaps() {
+        let mut data: Vec<DataPoint> = (0..5).map(|i| DataPoint::from((i as f64, 20.0))).collect();
+        data[2].y = f64::NAN;
+
+        let path = AreaRadialGenerator::new()
+            .start_angle(|_, _| 0.0)
+            .end_angle(|_, i| i as f64 * 0.1)
+            .inner_radius(|_, _| 5.0)
+            .generate(&data);
+
+        let closes = path
+            .iter()
+            .filter(|s| matches!(s, PathSegment::ClosePath))
+            .count();
+        assert_eq!(closes, 2);
+        // The inner edge of the second run sits at angle 0
+        assert_point(&path[path.len() - 2], 0.0, -5.0);
+    }
+}
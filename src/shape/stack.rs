@@ -1,8 +1,16 @@
 //! Stack generator for stacked charts
 //!
 //! Computes stacked layouts for bar charts, area charts, and stream graphs.
+//! Series come from [`ChartData`] datasets, raw value vectors, or keyed
+//! records (d3's `stack().keys()`). Stacked series convert directly to
+//! [`AreaGenerator`](super::AreaGenerator) input via
+//! [`StackedSeries::to_data_points`] and to band-scale bars via
+//! [`StackedSeries::bars`].
 
-use crate::data::ChartData;
+use crate::data::{ChartData, DataPoint};
+use crate::scale::{BandScale, DiscreteScale, Scale};
+use std::cmp::Ordering;
+use std::collections::HashMap;
 
 /// Stack ordering method
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
@@ -14,7 +22,13 @@ pub enum StackOrder {
     Ascending,
     /// Sort by sum of values descending
     Descending,
-    /// Sort so smallest series are in the middle
+    /// Sort by the index of each series' peak value
+    Appearance,
+    /// Early-peaking series in the middle, later ones on the outside
+    ///
+    /// Series are taken in [`Appearance`](Self::Appearance) order and
+    /// alternately added above or below, whichever side has the smaller sum.
+    /// Recommended for streamgraphs with [`StackOffset::Wiggle`].
     InsideOut,
     /// Reverse the current order
     Reverse,
@@ -28,7 +42,7 @@ pub enum StackOffset {
     None,
     /// Normalize to fill [0, 1] range
     Expand,
-    /// Center around zero (diverging stacks)
+    /// Stack positive values above zero and negative values below zero
     Diverging,
     /// Center the baseline to minimize weighted wiggle
     Silhouette,
@@ -36,6 +50,20 @@ pub enum StackOffset {
     Wiggle,
 }
 
+/// How missing or non-finite values are stacked
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StackMissing {
+    /// Treat missing values as zero
+    #[default]
+    Zero,
+    /// Leave a gap: the point's `y1` is NaN and later series stack on the
+    /// previous baseline
+    Gap,
+    /// Linearly interpolate between the neighbouring values of the same
+    /// series; leading and trailing missing values are zero
+    Interpolate,
+}
+
 /// A single point in a stacked series
 #[derive(Clone, Debug)]
 pub struct StackPoint {
@@ -55,6 +83,28 @@ impl StackPoint {
     pub fn height(&self) -> f64 {
         self.y1 - self.y0
     }
+
+    /// Check whether both bounds are finite
+    ///
+    /// Points left as gaps by [`StackMissing::Gap`] are not defined.
+    pub fn is_defined(&self) -> bool {
+        self.y0.is_finite() && self.y1.is_finite()
+    }
+}
+
+/// A bar of a stacked series positioned on a band scale
+#[derive(Clone, Debug, PartialEq)]
+pub struct StackBar {
+    /// Index of the data point (band)
+    pub index: usize,
+    /// Left edge
+    pub x: f64,
+    /// Top edge
+    pub y: f64,
+    /// Width of the bar (the band width)
+    pub width: f64,
+    /// Height of the bar
+    pub height: f64,
 }
 
 /// A stacked series with its key and stacked points
@@ -77,6 +127,59 @@ impl StackedSeries {
             points: vec![StackPoint::new(0.0, 0.0); n_points],
         }
     }
+
+    /// Convert to data points for an [`AreaGenerator`](super::AreaGenerator)
+    ///
+    /// Each point has `x` = index, `y` = `y1` and `y_min` = `y0`. Gaps keep
+    /// a NaN `y`, so the area generator's default `defined` predicate splits
+    /// the area there.
+    ///
+    /// # Example
+    /// ```
+    /// use makepad_d3::shape::{AreaGenerator, StackGenerator};
+    ///
+    /// let values = vec![vec![1.0, 2.0], vec![3.0, 4.0]];
+    /// let stacked = StackGenerator::new().compute_from_values(&values, &[]);
+    ///
+    /// let area = AreaGenerator::new().y0(|d, _| d.y_min.unwrap_or(0.0));
+    /// let path = area.generate(&stacked[1].to_data_points());
+    /// assert!(!path.is_empty());
+    /// ```
+    pub fn to_data_points(&self) -> Vec<DataPoint> {
+        self.points
+            .iter()
+            .enumerate()
+            .map(|(i, p)| {
+                let mut point = DataPoint::new(i as f64, p.y1);
+                point.y_min = Some(p.y0);
+                point
+            })
+            .collect()
+    }
+
+    /// Position the defined points as bars on a band scale
+    ///
+    /// Point `i` is drawn in band `i` of `x`; `y` maps stacked values to
+    /// pixels. Works for both upward and inverted (screen) y ranges.
+    pub fn bars<S: Scale>(&self, x: &BandScale, y: &S) -> Vec<StackBar> {
+        let width = x.bandwidth();
+        self.points
+            .iter()
+            .enumerate()
+            .filter(|(i, p)| p.is_defined() && *i < x.len())
+            .map(|(i, p)| {
+                let a = y.scale(p.y0);
+                let b = y.scale(p.y1);
+                StackBar {
+                    index: i,
+                    x: x.scale_index(i),
+                    y: a.min(b),
+                    width,
+                    height: (a - b).abs(),
+                }
+            })
+            .collect()
+    }
 }
 
 /// Stack generator for creating stacked layouts
@@ -102,12 +205,34 @@ impl StackedSeries {
 ///
 /// assert_eq!(stacked.len(), 2); // Two series
 /// ```
+///
+/// Keyed records work like d3's `stack().keys()`:
+/// ```
+/// use std::collections::HashMap;
+/// use makepad_d3::shape::StackGenerator;
+///
+/// let records: Vec<HashMap<String, f64>> = vec![
+///     [("apples".to_string(), 3.0), ("pears".to_string(), 1.0)].into(),
+///     [("apples".to_string(), 2.0)].into(),
+/// ];
+///
+/// let stacked = StackGenerator::new()
+///     .keys(["apples", "pears"])
+///     .compute_maps(&records);
+///
+/// assert_eq!(stacked[1].key, "pears");
+/// assert_eq!(stacked[1].points[0].y1, 4.0);
+/// ```
 #[derive(Clone, Debug)]
 pub struct StackGenerator {
+    /// Series keys (empty = all series)
+    keys: Vec<String>,
     /// Ordering method
     order: StackOrder,
     /// Offset method
     offset: StackOffset,
+    /// Missing value policy
+    missing: StackMissing,
 }
 
 impl Default for StackGenerator {
@@ -120,11 +245,22 @@ impl StackGenerator {
     /// Create a new stack generator
     pub fn new() -> Self {
         Self {
+            keys: Vec::new(),
             order: StackOrder::None,
             offset: StackOffset::None,
+            missing: StackMissing::Zero,
         }
     }
 
+    /// Set the series keys
+    ///
+    /// Required for [`compute_records`](Self::compute_records). For
+    /// [`compute`](Self::compute) the keys select datasets by label.
+    pub fn keys<S: Into<String>>(mut self, keys: impl IntoIterator<Item = S>) -> Self {
+        self.keys = keys.into_iter().map(Into::into).collect();
+        self
+    }
+
     /// Set the stack order
     pub fn order(mut self, order: StackOrder) -> Self {
         self.order = order;
@@ -137,178 +273,207 @@ impl StackGenerator {
         self
     }
 
+    /// Set the missing value policy
+    pub fn missing(mut self, missing: StackMissing) -> Self {
+        self.missing = missing;
+        self
+    }
+
+    /// Get the series keys
+    pub fn get_keys(&self) -> &[String] {
+        &self.keys
+    }
+
     /// Compute stacked series from chart data
+    ///
+    /// Points beyond a dataset's length and non-finite values are missing.
     pub fn compute(&self, data: &ChartData) -> Vec<StackedSeries> {
-        let n_series = data.datasets.len();
-        if n_series == 0 {
-            return vec![];
-        }
+        let series: Vec<(String, Option<usize>)> = if self.keys.is_empty() {
+            data.datasets
+                .iter()
+                .enumerate()
+                .map(|(i, d)| (d.label.clone(), Some(i)))
+                .collect()
+        } else {
+            self.keys
+                .iter()
+                .map(|k| (k.clone(), data.datasets.iter().position(|d| &d.label == k)))
+                .collect()
+        };
 
-        let n_points = data.len();
-        if n_points == 0 {
-            return vec![];
-        }
+        let n_points = series
+            .iter()
+            .filter_map(|(_, i)| *i)
+            .map(|i| data.datasets[i].data.len())
+            .max()
+            .unwrap_or(0);
 
-        // Initialize result with series info
-        let mut result: Vec<StackedSeries> = data
-            .datasets
+        let matrix = series
             .iter()
-            .enumerate()
-            .map(|(i, d)| StackedSeries::new(d.label.clone(), i, n_points))
+            .map(|(_, index)| {
+                (0..n_points)
+                    .map(|j| {
+                        index
+                            .and_then(|i| data.datasets[i].data.get(j))
+                            .map_or(f64::NAN, |p| p.y)
+                    })
+                    .collect()
+            })
             .collect();
 
-        // Compute series order
-        let order = self.compute_order(data);
-
-        // Stack values
-        for i in 0..n_points {
-            let mut y0 = 0.0;
-            for &series_idx in &order {
-                let y = data.datasets[series_idx]
-                    .data
-                    .get(i)
-                    .map(|p| p.y)
-                    .unwrap_or(0.0);
-
-                result[series_idx].points[i] = StackPoint::new(y0, y0 + y);
-                y0 += y;
-            }
-        }
-
-        // Apply offset
-        self.apply_offset(&mut result, n_points);
-
-        result
+        self.stack(series.into_iter().map(|(k, _)| k).collect(), matrix)
     }
 
     /// Compute stacked series from raw values
     ///
     /// Each inner Vec is a series, containing values for each point.
     pub fn compute_from_values(&self, values: &[Vec<f64>], keys: &[String]) -> Vec<StackedSeries> {
-        let n_series = values.len();
-        if n_series == 0 {
-            return vec![];
-        }
+        let keys = (0..values.len())
+            .map(|i| {
+                keys.get(i)
+                    .cloned()
+                    .unwrap_or_else(|| format!("series_{}", i))
+            })
+            .collect();
+        self.stack(keys, values.to_vec())
+    }
 
-        let n_points = values[0].len();
-        if n_points == 0 {
+    /// Compute stacked series from keyed records
+    ///
+    /// There is one series per key (see [`keys`](Self::keys)) and one point
+    /// per record; `value` returns `None` for missing values.
+    pub fn compute_records<R, F>(&self, records: &[R], value: F) -> Vec<StackedSeries>
+    where
+        F: Fn(&R, &str) -> Option<f64>,
+    {
+        let matrix = self
+            .keys
+            .iter()
+            .map(|key| {
+                records
+                    .iter()
+                    .map(|r| value(r, key).unwrap_or(f64::NAN))
+                    .collect()
+            })
+            .collect();
+        self.stack(self.keys.clone(), matrix)
+    }
+
+    /// Compute stacked series from records stored as key/value maps
+    pub fn compute_maps(&self, records: &[HashMap<String, f64>]) -> Vec<StackedSeries> {
+        self.compute_records(records, |r, key| r.get(key).copied())
+    }
+
+    /// Stack a series-by-point value matrix where NaN marks missing values
+    #[allow(clippy::needless_range_loop)]
+    fn stack(&self, keys: Vec<String>, mut matrix: Vec<Vec<f64>>) -> Vec<StackedSeries> {
+        let n_points = matrix.iter().map(Vec::len).max().unwrap_or(0);
+        if matrix.is_empty() || n_points == 0 {
             return vec![];
         }
+        for values in &mut matrix {
+            values.resize(n_points, f64::NAN);
+            self.fill_missing(values);
+        }
 
-        // Initialize result
-        let mut result: Vec<StackedSeries> = values
-            .iter()
+        let mut result: Vec<StackedSeries> = keys
+            .into_iter()
             .enumerate()
-            .map(|(i, _)| {
-                let key = keys
-                    .get(i)
-                    .cloned()
-                    .unwrap_or_else(|| format!("series_{}", i));
-                StackedSeries::new(key, i, n_points)
-            })
+            .map(|(i, key)| StackedSeries::new(key, i, n_points))
             .collect();
 
-        // Compute order
-        let order = self.compute_order_from_values(values);
-
-        // Stack values
-        for i in 0..n_points {
-            let mut y0 = 0.0;
-            for &series_idx in &order {
-                let y = values[series_idx].get(i).copied().unwrap_or(0.0);
-                result[series_idx].points[i] = StackPoint::new(y0, y0 + y);
-                y0 += y;
+        let order = self.compute_order(&matrix);
+
+        for j in 0..n_points {
+            let mut positive = 0.0;
+            let mut negative = 0.0;
+            for &i in &order {
+                let y = matrix[i][j];
+                result[i].points[j] = if !y.is_finite() {
+                    StackPoint::new(positive, f64::NAN)
+                } else if self.offset == StackOffset::Diverging && y < 0.0 {
+                    negative += y;
+                    StackPoint::new(negative, negative - y)
+                } else {
+                    positive += y;
+                    StackPoint::new(positive - y, positive)
+                };
             }
         }
 
-        // Apply offset
         self.apply_offset(&mut result, n_points);
 
         result
     }
 
-    /// Compute the series order
-    fn compute_order(&self, data: &ChartData) -> Vec<usize> {
-        let n = data.datasets.len();
-        let mut indices: Vec<usize> = (0..n).collect();
-
-        match self.order {
-            StackOrder::None => {}
-            StackOrder::Ascending => {
-                let sums: Vec<f64> = data
-                    .datasets
-                    .iter()
-                    .map(|d| d.data.iter().map(|p| p.y).sum())
-                    .collect();
-                indices.sort_by(|&a, &b| sums[a].partial_cmp(&sums[b]).unwrap());
+    /// Apply the missing value policy to one series
+    fn fill_missing(&self, values: &mut [f64]) {
+        match self.missing {
+            StackMissing::Gap => {
+                for v in values.iter_mut().filter(|v| !v.is_finite()) {
+                    *v = f64::NAN;
+                }
             }
-            StackOrder::Descending => {
-                let sums: Vec<f64> = data
-                    .datasets
-                    .iter()
-                    .map(|d| d.data.iter().map(|p| p.y).sum())
-                    .collect();
-                indices.sort_by(|&a, &b| sums[b].partial_cmp(&sums[a]).unwrap());
+            StackMissing::Zero => {
+                for v in values.iter_mut().filter(|v| !v.is_finite()) {
+                    *v = 0.0;
+                }
             }
-            StackOrder::InsideOut => {
-                // Sort by sum, then interleave smallest to center
-                let sums: Vec<f64> = data
-                    .datasets
-                    .iter()
-                    .map(|d| d.data.iter().map(|p| p.y).sum())
+            StackMissing::Interpolate => {
+                let known: Vec<usize> = (0..values.len())
+                    .filter(|&i| values[i].is_finite())
                     .collect();
-                indices.sort_by(|&a, &b| sums[b].partial_cmp(&sums[a]).unwrap());
-
-                let mut new_order = Vec::with_capacity(n);
-                let mut top = true;
-                for idx in indices {
-                    if top {
-                        new_order.push(idx);
-                    } else {
-                        new_order.insert(0, idx);
+                for i in 0..values.len() {
+                    if values[i].is_finite() {
+                        continue;
                     }
-                    top = !top;
+                    let next = known.partition_point(|&k| k < i);
+                    values[i] = match (next.checked_sub(1).map(|k| known[k]), known.get(next)) {
+                        (Some(a), Some(&b)) => {
+                            let t = (i - a) as f64 / (b - a) as f64;
+                            values[a] + (values[b] - values[a]) * t
+                        }
+                        _ => 0.0,
+                    };
                 }
-                indices = new_order;
-            }
-            StackOrder::Reverse => {
-                indices.reverse();
             }
         }
-
-        indices
     }
 
-    /// Compute the series order from raw values
-    fn compute_order_from_values(&self, values: &[Vec<f64>]) -> Vec<usize> {
-        let n = values.len();
+    /// Compute the stacking order (bottom to top) of the series
+    fn compute_order(&self, matrix: &[Vec<f64>]) -> Vec<usize> {
+        let n = matrix.len();
         let mut indices: Vec<usize> = (0..n).collect();
+        let sums: Vec<f64> = matrix
+            .iter()
+            .map(|v| v.iter().filter(|y| y.is_finite()).sum())
+            .collect();
 
         match self.order {
             StackOrder::None => {}
             StackOrder::Ascending => {
-                let sums: Vec<f64> = values.iter().map(|v| v.iter().sum()).collect();
-                indices.sort_by(|&a, &b| sums[a].partial_cmp(&sums[b]).unwrap());
+                indices.sort_by(|&a, &b| cmp_f64(sums[a], sums[b]));
             }
             StackOrder::Descending => {
-                let sums: Vec<f64> = values.iter().map(|v| v.iter().sum()).collect();
-                indices.sort_by(|&a, &b| sums[b].partial_cmp(&sums[a]).unwrap());
+                indices.sort_by(|&a, &b| cmp_f64(sums[b], sums[a]));
             }
+            StackOrder::Appearance => indices = appearance(matrix),
             StackOrder::InsideOut => {
-                let sums: Vec<f64> = values.iter().map(|v| v.iter().sum()).collect();
-                indices.sort_by(|&a, &b| sums[b].partial_cmp(&sums[a]).unwrap());
-
-                let mut new_order = Vec::with_capacity(n);
-                let mut top = true;
-                for idx in indices {
-                    if top {
-                        new_order.push(idx);
+                let mut tops = Vec::new();
+                let mut bottoms = Vec::new();
+                let (mut top, mut bottom) = (0.0, 0.0);
+                for i in appearance(matrix) {
+                    if top < bottom {
+                        top += sums[i];
+                        tops.push(i);
                     } else {
-                        new_order.insert(0, idx);
+                        bottom += sums[i];
+                        bottoms.push(i);
                     }
-                    top = !top;
                 }
-                indices = new_order;
+                bottoms.reverse();
+                bottoms.extend(tops);
+                indices = bottoms;
             }
             StackOrder::Reverse => {
                 indices.reverse();
@@ -325,9 +490,8 @@ impl StackGenerator {
             StackOffset::Expand => {
                 self.apply_expand_offset(series, n_points);
             }
-            StackOffset::Diverging => {
-                self.apply_diverging_offset(series, n_points);
-            }
+            // Positive and negative values are stacked apart in `stack`
+            StackOffset::Diverging => {}
             StackOffset::Silhouette => {
                 self.apply_silhouette_offset(series, n_points);
             }
@@ -340,7 +504,7 @@ impl StackGenerator {
     /// Normalize to [0, 1] range
     fn apply_expand_offset(&self, series: &mut [StackedSeries], n_points: usize) {
         for i in 0..n_points {
-            let total: f64 = series.iter().map(|s| s.points[i].height()).sum();
+            let total = defined_total(series, i);
 
             if total > 0.0 {
                 for s in series.iter_mut() {
@@ -351,27 +515,10 @@ impl StackGenerator {
         }
     }
 
-    /// Center around zero
-    fn apply_diverging_offset(&self, series: &mut [StackedSeries], n_points: usize) {
-        for i in 0..n_points {
-            let total: f64 = series.iter().map(|s| s.points[i].height()).sum();
-
-            let offset = -total / 2.0;
-            for s in series.iter_mut() {
-                s.points[i].y0 += offset;
-                s.points[i].y1 += offset;
-            }
-        }
-    }
-
     /// Center baseline (silhouette)
     fn apply_silhouette_offset(&self, series: &mut [StackedSeries], n_points: usize) {
         for i in 0..n_points {
-            let total: f64 = series
-                .iter()
-                .map(|s| s.points[i].y1)
-                .max_by(|a, b| a.partial_cmp(b).unwrap())
-                .unwrap_or(0.0);
+            let total = defined_total(series, i);
 
             let offset = -total / 2.0;
             for s in series.iter_mut() {
@@ -395,13 +542,13 @@ impl StackGenerator {
             let mut total_weight = 0.0;
 
             for (j, s) in series.iter().enumerate() {
-                let height = s.points[i].height();
+                let height = defined_height(&s.points[i]);
                 let weight = (n - j) as f64;
                 sum += weight * height;
                 total_weight += weight;
             }
 
-            let total: f64 = series.iter().map(|s| s.points[i].height()).sum();
+            let total = defined_total(series, i);
             let offset = if total_weight > 0.0 && total > 0.0 {
                 -sum / (total_weight * 2.0)
             } else {
@@ -416,10 +563,50 @@ impl StackGenerator {
     }
 }
 
+/// Series indices sorted by the position of their peak value
+fn appearance(matrix: &[Vec<f64>]) -> Vec<usize> {
+    let peaks: Vec<usize> = matrix
+        .iter()
+        .map(|values| {
+            let mut peak = 0;
+            let mut max = f64::NEG_INFINITY;
+            for (i, &v) in values.iter().enumerate() {
+                if v > max {
+                    max = v;
+                    peak = i;
+                }
+            }
+            peak
+        })
+        .collect();
+    let mut indices: Vec<usize> = (0..matrix.len()).collect();
+    indices.sort_by_key(|&i| peaks[i]);
+    indices
+}
+
+/// Height of a stack point, zero for gaps
+fn defined_height(point: &StackPoint) -> f64 {
+    if point.is_defined() {
+        point.height()
+    } else {
+        0.0
+    }
+}
+
+/// Total stacked height at point `i`, ignoring gaps
+fn defined_total(series: &[StackedSeries], i: usize) -> f64 {
+    series.iter().map(|s| defined_height(&s.points[i])).sum()
+}
+
+fn cmp_f64(a: f64, b: f64) -> Ordering {
+    a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::data::Dataset;
+    use crate::scale::{LinearScale, ScaleExt};
 
     fn sample_data() -> ChartData {
         ChartData::new()
@@ -466,23 +653,18 @@ mod tests {
 
     #[test]
     fn test_stack_diverging() {
-        let data = sample_data();
+        let values = vec![vec![10.0, -5.0], vec![-4.0, 8.0], vec![6.0, -3.0]];
         let stack = StackGenerator::new().offset(StackOffset::Diverging);
-        let result = stack.compute(&data);
-
-        // Stacks should be centered around 0
-        for i in 0..4 {
-            let min_y0 = result
-                .iter()
-                .map(|s| s.points[i].y0)
-                .fold(f64::INFINITY, f64::min);
-            let max_y1 = result
-                .iter()
-                .map(|s| s.points[i].y1)
-                .fold(f64::NEG_INFINITY, f64::max);
-            let center = (min_y0 + max_y1) / 2.0;
-            assert!(center.abs() < 0.01);
-        }
+        let result = stack.compute_from_values(&values, &[]);
+
+        // Positives stack up from zero, negatives down from zero
+        let bounds = |s: usize, i: usize| (result[s].points[i].y0, result[s].points[i].y1);
+        assert_eq!(bounds(0, 0), (0.0, 10.0));
+        assert_eq!(bounds(1, 0), (-4.0, 0.0));
+        assert_eq!(bounds(2, 0), (10.0, 16.0));
+        assert_eq!(bounds(0, 1), (-5.0, 0.0));
+        assert_eq!(bounds(1, 1), (0.0, 8.0));
+        assert_eq!(bounds(2, 1), (-8.0, -5.0));
     }
 
     #[test]
@@ -517,4 +699,106 @@ mod tests {
         let result = stack.compute(&data);
         assert!(result.is_empty());
     }
+
+    #[test]
+    fn test_stack_keyed_records() {
+        let records: Vec<HashMap<String, f64>> = vec![
+            [("a".to_string(), 1.0), ("b".to_string(), 2.0)].into(),
+            [("b".to_string(), 3.0)].into(),
+            [("a".to_string(), 5.0), ("b".to_string(), 1.0)].into(),
+        ];
+        let stack = StackGenerator::new().keys(["b", "a"]);
+        let result = stack.compute_maps(&records);
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].key, "b");
+        assert_eq!(result[1].points[0].y0, 2.0);
+        assert_eq!(result[1].points[0].y1, 3.0);
+        // Missing "a" in the second record stacks as zero by default
+        assert_eq!(result[1].points[1].height(), 0.0);
+    }
+
+    #[test]
+    fn test_stack_keys_select_datasets() {
+        let result = StackGenerator::new()
+            .keys(["Series 3", "Series 1"])
+            .compute(&sample_data());
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].key, "Series 3");
+        assert_eq!(result[1].points[0].y0, 5.0);
+        assert_eq!(result[1].points[0].y1, 15.0);
+    }
+
+    #[test]
+    fn test_stack_missing_policies() {
+        let values = vec![vec![1.0; 3], vec![2.0, f64::NAN, 4.0], vec![1.0; 3]];
+        let top = |missing| {
+            let result = StackGenerator::new()
+                .missing(missing)
+                .compute_from_values(&values, &[]);
+            (result[1].points[1].clone(), result[2].points[1].clone())
+        };
+
+        let (gap, above) = top(StackMissing::Gap);
+        assert!(!gap.is_defined());
+        assert_eq!(gap.y0, 1.0);
+        assert_eq!((above.y0, above.y1), (1.0, 2.0));
+
+        let (zero, _) = top(StackMissing::Zero);
+        assert_eq!(zero.height(), 0.0);
+
+        let (lerp, above) = top(StackMissing::Interpolate);
+        assert_eq!(lerp.height(), 3.0);
+        assert_eq!(above.y1, 5.0);
+    }
+
+    #[test]
+    fn test_stack_order_appearance_and_inside_out() {
+        // Peaks at indices 2, 0, 3, 1
+        let values = vec![
+            vec![1.0, 2.0, 9.0, 1.0],
+            vec![9.0, 1.0, 1.0, 1.0],
+            vec![1.0, 1.0, 2.0, 9.0],
+            vec![1.0, 9.0, 1.0, 1.0],
+        ];
+        let stack = StackGenerator::new();
+        assert_eq!(
+            stack
+                .clone()
+                .order(StackOrder::Appearance)
+                .compute_order(&values),
+            vec![1, 3, 0, 2]
+        );
+        // Sums are 13, 12, 13, 12: 1 → bottom, 3 → top, 0 → bottom, 2 → top
+        assert_eq!(
+            stack.order(StackOrder::InsideOut).compute_order(&values),
+            vec![0, 1, 3, 2]
+        );
+    }
+
+    #[test]
+    fn test_stack_to_areas_and_bars() {
+        let values = vec![vec![10.0, 20.0], vec![5.0, f64::NAN]];
+        let result = StackGenerator::new()
+            .missing(StackMissing::Gap)
+            .compute_from_values(&values, &[]);
+
+        let points = result[1].to_data_points();
+        assert_eq!(points[0].y, 15.0);
+        assert_eq!(points[0].y_min, Some(10.0));
+        assert!(points[1].y.is_nan());
+
+        let x = BandScale::new()
+            .with_domain(["A", "B"])
+            .with_range(0.0, 100.0);
+        let y = LinearScale::new()
+            .with_domain(0.0, 20.0)
+            .with_range(200.0, 0.0);
+        let bars = result[1].bars(&x, &y);
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].index, 0);
+        assert!((bars[0].y - 50.0).abs() < 1e-9);
+        assert!((bars[0].height - 50.0).abs() < 1e-9);
+        assert!((bars[0].width - 50.0).abs() < 1e-9);
+    }
 }
@@ -0,0 +1,750 @@
+//! Path flattening, dashing and stroke outlining
+//!
+//! Turns any [`Path`] into geometry that every backend can draw the same
+//! way: polylines within a flattening tolerance, dashed sub-paths that follow
+//! the original curves, and stroke outlines as fill polygons.
+//!
+//! Stroke outlines are meant to be filled with
+//! [`FillRule::NonZero`](super::FillRule::NonZero): inner joins pass through
+//! the vertex and closed subpaths produce an outer and a reversed inner ring.
+//!
+//! # Example
+//! ```
+//! use makepad_d3::shape::{LineCap, LineJoin, Path, StrokeStyle};
+//!
+//! let mut path = Path::new();
+//! path.move_to(0.0, 0.0).line_to(100.0, 0.0).line_to(100.0, 50.0);
+//!
+//! let style = StrokeStyle::new(4.0)
+//!     .with_join(LineJoin::Round)
+//!     .with_cap(LineCap::Square)
+//!     .with_dash(vec![6.0, 4.0]);
+//! let polygons = path.stroke(&style, 0.25);
+//! assert_eq!(polygons.len(), 15);
+//! ```
+
+use super::geometry::Piece;
+use super::path::{Path, PathSegment, Point};
+use std::f64::consts::PI;
+
+/// A flattened subpath
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Polyline {
+    /// Vertices in drawing order
+    pub points: Vec<Point>,
+    /// Whether the last vertex connects back to the first
+    pub closed: bool,
+}
+
+/// Shape drawn at the corners of a stroke
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LineJoin {
+    /// Extend the outer edges to a point (falls back to bevel past the miter limit)
+    #[default]
+    Miter,
+    /// Round the corner with a circular arc
+    Round,
+    /// Cut the corner with a straight line
+    Bevel,
+}
+
+/// Shape drawn at the ends of open subpaths
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LineCap {
+    /// End exactly at the endpoint
+    #[default]
+    Butt,
+    /// Semicircle centered on the endpoint
+    Round,
+    /// Square extending half the stroke width past the endpoint
+    Square,
+}
+
+/// Stroke parameters for [`Path::stroke`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct StrokeStyle {
+    /// Stroke width
+    pub width: f64,
+    /// Corner style
+    pub join: LineJoin,
+    /// End style
+    pub cap: LineCap,
+    /// Maximum miter length as a multiple of the stroke width
+    pub miter_limit: f64,
+    /// Alternating dash and gap lengths (empty for a solid stroke)
+    pub dash: Vec<f64>,
+    /// Distance into the dash pattern at which the stroke starts
+    pub dash_offset: f64,
+}
+
+impl Default for StrokeStyle {
+    fn default() -> Self {
+        Self {
+            width: 1.0,
+            join: LineJoin::Miter,
+            cap: LineCap::Butt,
+            miter_limit: 4.0,
+            dash: Vec::new(),
+            dash_offset: 0.0,
+        }
+    }
+}
+
+impl StrokeStyle {
+    /// Create a solid stroke of the given width
+    pub fn new(width: f64) -> Self {
+        Self {
+            width: width.max(0.0),
+            ..Default::default()
+        }
+    }
+
+    /// Set the join style
+    pub fn with_join(mut self, join: LineJoin) -> Self {
+        self.join = join;
+        self
+    }
+
+    /// Set the cap style
+    pub fn with_cap(mut self, cap: LineCap) -> Self {
+        self.cap = cap;
+        self
+    }
+
+    /// Set the miter limit
+    pub fn with_miter_limit(mut self, limit: f64) -> Self {
+        self.miter_limit = limit.max(1.0);
+        self
+    }
+
+    /// Set the dash pattern (e.g. from `LineDash::dash_array`)
+    pub fn with_dash(mut self, dash: Vec<f64>) -> Self {
+        self.dash = dash;
+        self
+    }
+
+    /// Set the dash offset
+    pub fn with_dash_offset(mut self, offset: f64) -> Self {
+        self.dash_offset = offset;
+        self
+    }
+}
+
+impl Path {
+    /// Approximate the path with polylines
+    ///
+    /// Every point of the polylines lies within `tolerance` of the curve.
+    /// Closed subpaths do not repeat their first point.
+    pub fn flatten(&self, tolerance: f64) -> Vec<Polyline> {
+        self.subpaths()
+            .into_iter()
+            .map(|sub| {
+                let mut points = vec![sub.start];
+                for piece in &sub.pieces {
+                    piece.flatten_into(tolerance, &mut points);
+                }
+                if sub.closed && points.len() > 1 && points.last() == Some(&sub.start) {
+                    points.pop();
+                }
+                Polyline {
+                    points,
+                    closed: sub.closed,
+                }
+            })
+            .collect()
+    }
+
+    /// Split the path into dashes following a dash pattern
+    ///
+    /// `pattern` alternates dash and gap lengths and is repeated twice if it
+    /// has an odd number of entries, as in SVG. Dashes keep the original
+    /// curve types, and the pattern restarts at each subpath. Zero-length
+    /// dashes become degenerate segments, which [`Path::stroke`] draws as
+    /// dots with round or square caps. An empty or invalid pattern returns
+    /// the path unchanged.
+    pub fn dash(&self, pattern: &[f64], offset: f64) -> Path {
+        let mut pattern = pattern.to_vec();
+        if pattern.len() % 2 == 1 {
+            pattern.extend_from_within(..);
+        }
+        let total: f64 = pattern.iter().sum();
+        if pattern.is_empty() || pattern.iter().any(|&d| d < 0.0 || !d.is_finite()) || total <= 0.0
+        {
+            return self.clone();
+        }
+
+        // Pattern position after the offset
+        let mut phase = offset.rem_euclid(total);
+        let mut first = 0;
+        while phase > 0.0 && phase >= pattern[first] {
+            phase -= pattern[first];
+            first = (first + 1) % pattern.len();
+        }
+        let first_remaining = pattern[first] - phase;
+
+        let mut out = Path::new();
+        for sub in self.subpaths() {
+            let mut index = first;
+            let mut remaining = first_remaining;
+            let mut drawing = false;
+
+            for piece in sub.closed_pieces() {
+                let len = piece.length();
+                let mut pos = 0.0;
+                loop {
+                    let on = index % 2 == 0;
+                    if remaining <= 0.0 {
+                        if on && !drawing {
+                            // Zero-length dash: a dot
+                            let p = piece.point(piece.t_at_length(pos, len));
+                            out.push(PathSegment::MoveTo(p));
+                            out.push(PathSegment::LineTo(p));
+                        }
+                        index = (index + 1) % pattern.len();
+                        remaining = pattern[index];
+                        drawing = false;
+                        continue;
+                    }
+                    if pos >= len {
+                        break;
+                    }
+
+                    let step = remaining.min(len - pos);
+                    if on {
+                        let t0 = piece.t_at_length(pos, len);
+                        let t1 = piece.t_at_length(pos + step, len);
+                        let part = piece.section(t0, t1);
+                        if !drawing {
+                            out.push(PathSegment::MoveTo(part.start()));
+                            drawing = true;
+                        }
+                        out.push(part.to_segment());
+                    }
+                    pos += step;
+                    remaining -= step;
+                    if remaining <= 1e-12 {
+                        remaining = 0.0;
+                        if on {
+                            // The dash ended exactly here; don't redraw it as a dot
+                            index = (index + 1) % pattern.len();
+                            remaining = pattern[index];
+                            drawing = false;
+                        }
+                    }
+                }
+            }
+        }
+        out
+    }
+
+    /// Outline the stroke of the path as fill polygons
+    ///
+    /// Curves are flattened to `tolerance` first, and round joins and caps
+    /// use the same tolerance. Fill the result with the non-zero rule.
+    pub fn stroke(&self, style: &StrokeStyle, tolerance: f64) -> Vec<Vec<Point>> {
+        let half = style.width / 2.0;
+        if half <= 0.0 || !half.is_finite() {
+            return Vec::new();
+        }
+        let dashed;
+        let path = if style.dash.is_empty() {
+            self
+        } else {
+            dashed = self.dash(&style.dash, style.dash_offset);
+            &dashed
+        };
+
+        let outliner = Outliner {
+            half,
+            style,
+            tolerance: tolerance.max(1e-9),
+        };
+        let mut polygons = Vec::new();
+        for line in path.flatten(tolerance) {
+            let mut points: Vec<Point> = Vec::with_capacity(line.points.len());
+            for p in line.points {
+                if !points.last().is_some_and(|last| last.distance(&p) <= 1e-12) {
+                    points.push(p);
+                }
+            }
+            if line.closed
+                && points.len() > 1
+                && points[0].distance(&points[points.len() - 1]) <= 1e-12
+            {
+                points.pop();
+            }
+
+            match points.len() {
+                0 => {}
+                1 => polygons.extend(outliner.dot(points[0])),
+                2 if line.closed => {
+                    points.push(points[0]);
+                    polygons.push(outliner.open(&points));
+                }
+                _ if line.closed => {
+                    polygons.push(outliner.closed_side(&points));
+                    points.reverse();
+                    polygons.push(outliner.closed_side(&points));
+                }
+                _ => polygons.push(outliner.open(&points)),
+            }
+        }
+        polygons
+    }
+}
+
+impl Piece {
+    /// Split a Bézier at `t` with de Casteljau's algorithm
+    fn split(&self, t: f64) -> (Piece, Piece) {
+        match *self {
+            Piece::Quad(a, c, b) => {
+                let ac = a.lerp(&c, t);
+                let cb = c.lerp(&b, t);
+                let m = ac.lerp(&cb, t);
+                (Piece::Quad(a, ac, m), Piece::Quad(m, cb, b))
+            }
+            Piece::Cubic(a, c1, c2, b) => {
+                let p01 = a.lerp(&c1, t);
+                let p12 = c1.lerp(&c2, t);
+                let p23 = c2.lerp(&b, t);
+                let p012 = p01.lerp(&p12, t);
+                let p123 = p12.lerp(&p23, t);
+                let m = p012.lerp(&p123, t);
+                (Piece::Cubic(a, p01, p012, m), Piece::Cubic(m, p123, p23, b))
+            }
+            _ => (self.section(0.0, t), self.section(t, 1.0)),
+        }
+    }
+
+    /// The part of the piece between parameters `t0` and `t1`
+    fn section(&self, t0: f64, t1: f64) -> Piece {
+        match *self {
+            Piece::Line(..) => Piece::Line(self.point(t0), self.point(t1)),
+            Piece::Arc {
+                center,
+                radius,
+                start,
+                sweep,
+            } => Piece::Arc {
+                center,
+                radius,
+                start: start + sweep * t0,
+                sweep: sweep * (t1 - t0),
+            },
+            _ => {
+                let head = if t1 < 1.0 { self.split(t1).0 } else { *self };
+                if t0 <= 0.0 || t1 <= 0.0 {
+                    head
+                } else {
+                    head.split(t0 / t1).1
+                }
+            }
+        }
+    }
+
+    /// Convert back to a path segment (the start point is implied)
+    fn to_segment(self) -> PathSegment {
+        match self {
+            Piece::Line(_, b) => PathSegment::LineTo(b),
+            Piece::Quad(_, cp, end) => PathSegment::QuadTo { cp, end },
+            Piece::Cubic(_, cp1, cp2, end) => PathSegment::CurveTo { cp1, cp2, end },
+            Piece::Arc {
+                center,
+                radius,
+                start,
+                sweep,
+            } => PathSegment::ArcTo {
+                center,
+                radius,
+                start_angle: start,
+                end_angle: start + sweep,
+                counterclockwise: sweep < 0.0,
+            },
+        }
+    }
+}
+
+/// Left-hand normal of a unit direction
+fn normal(d: Point) -> Point {
+    Point::new(-d.y, d.x)
+}
+
+/// Unit direction from `a` to `b`
+fn direction(a: Point, b: Point) -> Point {
+    let d = b.sub(&a);
+    d.scale(1.0 / d.x.hypot(d.y))
+}
+
+/// Builds outline polygons for flattened polylines
+struct Outliner<'a> {
+    half: f64,
+    style: &'a StrokeStyle,
+    tolerance: f64,
+}
+
+impl Outliner<'_> {
+    /// Outline of an open polyline: left side, end cap, right side, start cap
+    fn open(&self, points: &[Point]) -> Vec<Point> {
+        let mut out = Vec::new();
+        self.side(points, &mut out);
+        let n = points.len();
+        self.cap(
+            points[n - 1],
+            direction(points[n - 2], points[n - 1]),
+            &mut out,
+        );
+        let reversed: Vec<Point> = points.iter().rev().copied().collect();
+        self.side(&reversed, &mut out);
+        self.cap(points[0], direction(points[1], points[0]), &mut out);
+        out
+    }
+
+    /// Left offset of an open polyline with joins at interior vertices
+    fn side(&self, points: &[Point], out: &mut Vec<Point>) {
+        let n = points.len();
+        let first = direction(points[0], points[1]);
+        out.push(points[0].add(&normal(first).scale(self.half)));
+        for i in 1..n - 1 {
+            let d0 = direction(points[i - 1], points[i]);
+            let d1 = direction(points[i], points[i + 1]);
+            self.join(points[i], d0, d1, out);
+        }
+        let last = direction(points[n - 2], points[n - 1]);
+        out.push(points[n - 1].add(&normal(last).scale(self.half)));
+    }
+
+    /// Left offset ring of a closed polyline
+    fn closed_side(&self, points: &[Point]) -> Vec<Point> {
+        let n = points.len();
+        let mut out = Vec::new();
+        for i in 0..n {
+            let prev = points[(i + n - 1) % n];
+            let next = points[(i + 1) % n];
+            self.join(
+                points[i],
+                direction(prev, points[i]),
+                direction(points[i], next),
+                &mut out,
+            );
+        }
+        out
+    }
+
+    /// Emit the left-side join at vertex `v` between directions `d0` and `d1`
+    fn join(&self, v: Point, d0: Point, d1: Point, out: &mut Vec<Point>) {
+        let h = self.half;
+        let (n0, n1) = (normal(d0), normal(d1));
+        let a = v.add(&n0.scale(h));
+        let b = v.add(&n1.scale(h));
+        let cross = d0.x * d1.y - d0.y * d1.x;
+        let dot = d0.x * d1.x + d0.y * d1.y;
+
+        if cross.abs() < 1e-12 && dot > 0.0 {
+            out.push(a);
+            return;
+        }
+        if cross > 0.0 {
+            // Inner side of the turn: pass through the vertex
+            out.extend([a, v, b]);
+            return;
+        }
+
+        out.push(a);
+        match self.style.join {
+            LineJoin::Bevel => {}
+            LineJoin::Miter => {
+                let m = n0.add(&n1);
+                let len = m.x.hypot(m.y);
+                if len > 1e-12 {
+                    let m = m.scale(1.0 / len);
+                    let cos_half = m.x * n0.x + m.y * n0.y;
+                    if cos_half > 1e-12 && 1.0 / cos_half <= self.style.miter_limit {
+                        out.push(v.add(&m.scale(h / cos_half)));
+                    }
+                }
+            }
+            LineJoin::Round => {
+                let sweep = if cross.abs() < 1e-12 {
+                    -PI
+                } else {
+                    cross.atan2(dot)
+                };
+                self.arc(v, n0.y.atan2(n0.x), sweep, out);
+            }
+        }
+        out.push(b);
+    }
+
+    /// Emit the intermediate points of a cap from the left to the right offset
+    fn cap(&self, p: Point, d: Point, out: &mut Vec<Point>) {
+        let h = self.half;
+        let n = normal(d);
+        match self.style.cap {
+            LineCap::Butt => {}
+            LineCap::Square => {
+                let ext = d.scale(h);
+                out.push(p.add(&n.scale(h)).add(&ext));
+                out.push(p.sub(&n.scale(h)).add(&ext));
+            }
+            LineCap::Round => self.arc(p, n.y.atan2(n.x), -PI, out),
+        }
+    }
+
+    /// Emit interior points of a circular arc of radius `half` around `c`
+    fn arc(&self, c: Point, start: f64, sweep: f64, out: &mut Vec<Point>) {
+        let steps = if self.half <= self.tolerance {
+            1
+        } else {
+            let step = 2.0 * (1.0 - self.tolerance / self.half).acos();
+            ((sweep.abs() / step).ceil() as usize).clamp(1, 1000)
+        };
+        for i in 1..steps {
+            let angle = start + sweep * i as f64 / steps as f64;
+            out.push(Point::new(
+                c.x + self.half * angle.cos(),
+                c.y + self.half * angle.sin(),
+            ));
+        }
+    }
+
+    /// Outline of a zero-length subpath
+    fn dot(&self, p: Point) -> Option<Vec<Point>> {
+        let h = self.half;
+        match self.style.cap {
+            LineCap::Butt => None,
+            LineCap::Square => Some(vec![
+                Point::new(p.x - h, p.y - h),
+                Point::new(p.x + h, p.y - h),
+                Point::new(p.x + h, p.y + h),
+                Point::new(p.x - h, p.y + h),
+            ]),
+            LineCap::Round => {
+                let mut out = vec![Point::new(p.x + h, p.y)];
+                self.arc(p, 0.0, 2.0 * PI, &mut out);
+                Some(out)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shape::FillRule;
+
+    fn area(polygon: &[Point]) -> f64 {
+        let n = polygon.len();
+        (0..n)
+            .map(|i| {
+                let (a, b) = (polygon[i], polygon[(i + 1) % n]);
+                a.x * b.y - b.x * a.y
+            })
+            .sum::<f64>()
+            / 2.0
+    }
+
+    fn fill(polygons: &[Vec<Point>]) -> Path {
+        let mut path = Path::new();
+        for polygon in polygons {
+            path.move_to(polygon[0].x, polygon[0].y);
+            for p in &polygon[1..] {
+                path.line_to(p.x, p.y);
+            }
+            path.close();
+        }
+        path
+    }
+
+    fn line() -> Path {
+        let mut path = Path::new();
+        path.move_to(0.0, 0.0).line_to(10.0, 0.0);
+        path
+    }
+
+    fn corner() -> Path {
+        let mut path = Path::new();
+        path.move_to(0.0, 0.0)
+            .line_to(10.0, 0.0)
+            .line_to(10.0, 10.0);
+        path
+    }
+
+    #[test]
+    fn test_flatten_tolerance() {
+        let circle = Path::from_iter([
+            PathSegment::move_to(10.0, 0.0),
+            PathSegment::arc_to(0.0, 0.0, 10.0, 0.0, 2.0 * PI, false),
+            PathSegment::ClosePath,
+        ]);
+        let lines = circle.flatten(0.1);
+        assert_eq!(lines.len(), 1);
+        let line = &lines[0];
+        assert!(line.closed);
+        assert_ne!(line.points.first(), line.points.last());
+        let n = line.points.len();
+        for i in 0..n {
+            let (a, b) = (line.points[i], line.points[(i + 1) % n]);
+            assert!((a.x.hypot(a.y) - 10.0).abs() < 1e-9);
+            // Chord midpoints stay within tolerance of the circle
+            let mid = a.lerp(&b, 0.5);
+            assert!(10.0 - mid.x.hypot(mid.y) <= 0.1 + 1e-9);
+        }
+
+        let mut curve = Path::new();
+        curve
+            .move_to(0.0, 0.0)
+            .curve_to(0.0, 100.0, 100.0, 100.0, 100.0, 0.0);
+        let coarse = curve.flatten(1.0)[0].points.len();
+        let fine = curve.flatten(0.01)[0].points.len();
+        assert!(fine > coarse);
+        assert!(!curve.flatten(1.0)[0].closed);
+    }
+
+    #[test]
+    fn test_dash_line() {
+        let mut path = Path::new();
+        path.move_to(0.0, 0.0).line_to(100.0, 0.0);
+        let dashed = path.dash(&[10.0, 5.0], 0.0);
+        let moves: Vec<Point> = dashed
+            .iter()
+            .filter_map(|s| match s {
+                PathSegment::MoveTo(p) => Some(*p),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(moves.len(), 7);
+        assert_eq!(moves[1], Point::new(15.0, 0.0));
+        assert!((dashed.length() - 70.0).abs() < 1e-9);
+
+        // Offset starts partway into the first dash
+        let offset = path.dash(&[10.0, 5.0], 5.0);
+        assert_eq!(offset.segments[1], PathSegment::line_to(5.0, 0.0));
+
+        // Odd patterns repeat: [5] == [5, 5]
+        assert_eq!(path.dash(&[5.0], 0.0), path.dash(&[5.0, 5.0], 0.0));
+        // Invalid patterns leave the path solid
+        assert_eq!(path.dash(&[], 0.0), path);
+        assert_eq!(path.dash(&[0.0, 0.0], 0.0), path);
+    }
+
+    #[test]
+    fn test_dash_along_curves() {
+        let mut curve = Path::new();
+        curve
+            .move_to(0.0, 0.0)
+            .curve_to(0.0, 100.0, 100.0, 100.0, 100.0, 0.0);
+        let dashed = curve.dash(&[20.0, 10.0], 0.0);
+        assert!(dashed
+            .iter()
+            .all(|s| matches!(s, PathSegment::MoveTo(_) | PathSegment::CurveTo { .. })));
+        let total = curve.length();
+        let full = (total / 30.0).floor();
+        let expected = full * 20.0 + (total - full * 30.0).min(20.0);
+        assert!((dashed.length() - expected).abs() < 1e-6);
+        // Dashes lie on the original curve
+        for segment in dashed.iter() {
+            if let Some(p) = segment.end_point() {
+                assert!(curve.distance_to(p) < 1e-6);
+            }
+        }
+
+        let arc = Path::from_iter([PathSegment::arc_to(0.0, 0.0, 10.0, 0.0, PI, false)]);
+        let dashed = arc.dash(&[PI, PI], 0.0);
+        assert!((dashed.length() - 5.0 * PI).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_stroke_caps() {
+        let butt = line().stroke(&StrokeStyle::new(2.0), 0.01);
+        assert_eq!(butt.len(), 1);
+        assert_eq!(
+            butt[0],
+            vec![
+                Point::new(0.0, 1.0),
+                Point::new(10.0, 1.0),
+                Point::new(10.0, -1.0),
+                Point::new(0.0, -1.0),
+            ]
+        );
+        let square = line().stroke(&StrokeStyle::new(2.0).with_cap(LineCap::Square), 0.01);
+        assert!((area(&square[0]).abs() - 24.0).abs() < 1e-9);
+        let round = line().stroke(&StrokeStyle::new(2.0).with_cap(LineCap::Round), 0.001);
+        assert!((area(&round[0]).abs() - (20.0 + PI)).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_stroke_joins() {
+        let outer = Point::new(10.9, -0.9);
+        let near = Point::new(10.5, -0.3);
+
+        let miter = fill(&corner().stroke(&StrokeStyle::new(2.0), 0.01));
+        assert!(miter.contains(outer, FillRule::NonZero));
+
+        let bevel = fill(&corner().stroke(&StrokeStyle::new(2.0).with_join(LineJoin::Bevel), 0.01));
+        assert!(!bevel.contains(outer, FillRule::NonZero));
+        assert!(bevel.contains(near, FillRule::NonZero));
+
+        let round = fill(&corner().stroke(&StrokeStyle::new(2.0).with_join(LineJoin::Round), 0.01));
+        assert!(round.contains(Point::new(10.6, -0.6), FillRule::NonZero));
+        assert!(!round.contains(Point::new(10.8, -0.8), FillRule::NonZero));
+
+        // The inner corner and the stroke body are covered
+        for stroke in [&miter, &bevel, &round] {
+            assert!(stroke.contains(Point::new(9.5, 0.5), FillRule::NonZero));
+            assert!(stroke.contains(Point::new(5.0, 0.9), FillRule::NonZero));
+            assert!(!stroke.contains(Point::new(5.0, 5.0), FillRule::NonZero));
+        }
+    }
+
+    #[test]
+    fn test_miter_limit() {
+        let mut sharp = Path::new();
+        sharp.move_to(0.0, 0.0).line_to(10.0, 0.0).line_to(0.0, 1.0);
+        let polygons = sharp.stroke(&StrokeStyle::new(2.0), 0.01);
+        let reach = polygons[0].iter().map(|p| p.x).fold(f64::MIN, f64::max);
+        // A full miter would reach about 20 units past the vertex
+        assert!(reach < 11.5);
+
+        let polygons = sharp.stroke(&StrokeStyle::new(2.0).with_miter_limit(100.0), 0.01);
+        let reach = polygons[0].iter().map(|p| p.x).fold(f64::MIN, f64::max);
+        assert!(reach > 25.0);
+    }
+
+    #[test]
+    fn test_stroke_closed_ring() {
+        let mut square = Path::new();
+        square
+            .move_to(0.0, 0.0)
+            .line_to(20.0, 0.0)
+            .line_to(20.0, 20.0)
+            .line_to(0.0, 20.0)
+            .close();
+        let polygons = square.stroke(&StrokeStyle::new(4.0), 0.01);
+        assert_eq!(polygons.len(), 2);
+        let ring = fill(&polygons);
+        assert!(ring.contains(Point::new(10.0, 1.0), FillRule::NonZero));
+        assert!(ring.contains(Point::new(-1.9, -1.9), FillRule::NonZero));
+        assert!(ring.contains(Point::new(19.0, 1.0), FillRule::NonZero));
+        assert!(!ring.contains(Point::new(10.0, 10.0), FillRule::NonZero));
+        assert!(!ring.contains(Point::new(22.1, 10.0), FillRule::NonZero));
+    }
+
+    #[test]
+    fn test_dotted_stroke() {
+        let mut path = Path::new();
+        path.move_to(0.0, 0.0).line_to(40.0, 0.0);
+        let style = StrokeStyle::new(2.0)
+            .with_cap(LineCap::Round)
+            .with_dash(vec![0.0, 10.0]);
+        let dots = path.stroke(&style, 0.01);
+        assert_eq!(dots.len(), 5);
+        for (i, dot) in dots.iter().enumerate() {
+            let cx = dot.iter().map(|p| p.x).sum::<f64>() / dot.len() as f64;
+            assert!((cx - 10.0 * i as f64).abs() < 1e-6);
+        }
+
+        // Butt caps draw nothing for zero-length dashes
+        let style = style.with_cap(LineCap::Butt);
+        assert!(path.stroke(&style, 0.01).is_empty());
+    }
+}
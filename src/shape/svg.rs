@@ -0,0 +1,703 @@
+//! SVG path data serialization and parsing
+//!
+//! Converts [`Path`] to and from the SVG `d` attribute syntax. Serialization
+//! follows d3-path's compact output (`M0,0L10,10Z`); arcs are written as
+//! SVG `A` commands, with a connecting line when the arc does not start at
+//! the current point, matching canvas `arc()` semantics.
+//!
+//! The parser accepts the full SVG path grammar: absolute and relative
+//! commands, `H`/`V`, smooth `S`/`T` curves, implicit command repetition and
+//! compact number syntax (`M.5.5`). Circular arcs become
+//! [`PathSegment::ArcTo`]; elliptical arcs are converted to cubic Béziers.
+//!
+//! # Example
+//! ```
+//! use makepad_d3::shape::Path;
+//!
+//! let path = Path::from_svg_d("M10 10 h80 v80 h-80 Z").unwrap();
+//! assert_eq!(path.to_svg_d(), "M10,10L90,10L90,90L10,90Z");
+//!
+//! let parsed: Path = path.to_svg_d().parse().unwrap();
+//! assert_eq!(parsed, path);
+//! ```
+
+use super::path::{Path, PathSegment, Point};
+use crate::error::{D3Error, D3Result};
+use std::f64::consts::{PI, TAU};
+use std::fmt::{self, Write};
+use std::str::FromStr;
+
+/// Distance below which two points are treated as coincident
+const EPSILON: f64 = 1e-6;
+
+impl Path {
+    /// Serialize to SVG path data with full precision
+    ///
+    /// Numbers use the shortest representation that parses back to the
+    /// same `f64`, so [`Path::from_svg_d`] reproduces paths that start with
+    /// a `MoveTo` and contain only `MoveTo`, `LineTo`, `QuadTo`, `CurveTo`
+    /// and `ClosePath` exactly.
+    ///
+    /// An [`ArcTo`](PathSegment::ArcTo) parses back as an arc with the same
+    /// center, radius, direction and end points, but may gain a leading
+    /// `MoveTo`/`LineTo` to its start point, and full circles come back as
+    /// two half arcs.
+    pub fn to_svg_d(&self) -> String {
+        self.write_svg_d(None)
+    }
+
+    /// Serialize to SVG path data, rounding numbers to `digits` decimals
+    ///
+    /// Trailing zeros are trimmed, so `10.50` is written as `10.5`.
+    pub fn to_svg_d_with_precision(&self, digits: usize) -> String {
+        self.write_svg_d(Some(digits))
+    }
+
+    /// Parse SVG path data (the `d` attribute)
+    pub fn from_svg_d(d: &str) -> D3Result<Self> {
+        Parser::new(d).parse()
+    }
+
+    fn write_svg_d(&self, digits: Option<usize>) -> String {
+        let mut out = String::new();
+        let num = |out: &mut String, values: &[f64]| {
+            for (i, &v) in values.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_number(out, v, digits);
+            }
+        };
+
+        let mut current: Option<Point> = None;
+        let mut start = Point::zero();
+
+        for segment in &self.segments {
+            match *segment {
+                PathSegment::MoveTo(p) => {
+                    out.push('M');
+                    num(&mut out, &[p.x, p.y]);
+                    current = Some(p);
+                    start = p;
+                }
+                PathSegment::LineTo(p) => {
+                    out.push('L');
+                    num(&mut out, &[p.x, p.y]);
+                    current = Some(p);
+                }
+                PathSegment::QuadTo { cp, end } => {
+                    out.push('Q');
+                    num(&mut out, &[cp.x, cp.y, end.x, end.y]);
+                    current = Some(end);
+                }
+                PathSegment::CurveTo { cp1, cp2, end } => {
+                    out.push('C');
+                    num(&mut out, &[cp1.x, cp1.y, cp2.x, cp2.y, end.x, end.y]);
+                    current = Some(end);
+                }
+                PathSegment::ArcTo {
+                    center,
+                    radius,
+                    start_angle,
+                    end_angle,
+                    counterclockwise,
+                } => {
+                    let r = radius.abs();
+                    let p0 = Point::new(
+                        center.x + r * start_angle.cos(),
+                        center.y + r * start_angle.sin(),
+                    );
+                    let p1 = Point::new(
+                        center.x + r * end_angle.cos(),
+                        center.y + r * end_angle.sin(),
+                    );
+
+                    match current {
+                        None => {
+                            out.push('M');
+                            num(&mut out, &[p0.x, p0.y]);
+                            start = p0;
+                        }
+                        Some(c) if c.distance(&p0) > EPSILON => {
+                            out.push('L');
+                            num(&mut out, &[p0.x, p0.y]);
+                        }
+                        _ => {}
+                    }
+                    current = Some(p0);
+                    if r == 0.0 {
+                        continue;
+                    }
+
+                    let sweep = if counterclockwise { 0.0 } else { 1.0 };
+                    let mut da = if counterclockwise {
+                        start_angle - end_angle
+                    } else {
+                        end_angle - start_angle
+                    };
+                    if da < 0.0 {
+                        da = da % TAU + TAU;
+                    }
+
+                    if da > TAU - EPSILON {
+                        // Full circle: two half arcs through the opposite point
+                        let opposite = Point::new(2.0 * center.x - p0.x, 2.0 * center.y - p0.y);
+                        out.push('A');
+                        num(&mut out, &[r, r, 0.0, 1.0, sweep, opposite.x, opposite.y]);
+                        out.push('A');
+                        num(&mut out, &[r, r, 0.0, 1.0, sweep, p0.x, p0.y]);
+                    } else if da > EPSILON {
+                        let large = if da >= PI { 1.0 } else { 0.0 };
+                        out.push('A');
+                        num(&mut out, &[r, r, 0.0, large, sweep, p1.x, p1.y]);
+                        current = Some(p1);
+                    }
+                }
+                PathSegment::ClosePath => {
+                    out.push('Z');
+                    current = Some(start);
+                }
+            }
+        }
+
+        out
+    }
+}
+
+impl fmt::Display for Path {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_svg_d())
+    }
+}
+
+impl FromStr for Path {
+    type Err = D3Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_svg_d(s)
+    }
+}
+
+/// Append a number, optionally rounded to `digits` decimals
+fn write_number(out: &mut String, v: f64, digits: Option<usize>) {
+    // Normalize negative zero
+    let v = if v == 0.0 { 0.0 } else { v };
+    match digits {
+        None => {
+            let _ = write!(out, "{}", v);
+        }
+        Some(digits) => {
+            let mut s = format!("{:.*}", digits, v);
+            if s.contains('.') {
+                let trimmed = s.trim_end_matches('0').trim_end_matches('.').len();
+                s.truncate(trimmed);
+            }
+            if s == "-0" {
+                s = "0".to_string();
+            }
+            out.push_str(&s);
+        }
+    }
+}
+
+/// Convert an SVG endpoint-parameterized arc to path segments
+///
+/// Implements the endpoint-to-center conversion from the SVG specification
+/// (appendix F.6.5), including out-of-range radius correction.
+fn arc_segments(
+    from: Point,
+    rx: f64,
+    ry: f64,
+    rotation: f64,
+    large_arc: bool,
+    sweep: bool,
+    to: Point,
+) -> Vec<PathSegment> {
+    if from.distance(&to) < f64::EPSILON {
+        return Vec::new();
+    }
+    let (mut rx, mut ry) = (rx.abs(), ry.abs());
+    if rx == 0.0 || ry == 0.0 {
+        return vec![PathSegment::LineTo(to)];
+    }
+
+    let (sin, cos) = rotation.to_radians().sin_cos();
+    let dx2 = (from.x - to.x) / 2.0;
+    let dy2 = (from.y - to.y) / 2.0;
+    let x1p = cos * dx2 + sin * dy2;
+    let y1p = -sin * dx2 + cos * dy2;
+
+    let lambda = (x1p * x1p) / (rx * rx) + (y1p * y1p) / (ry * ry);
+    if lambda > 1.0 {
+        rx *= lambda.sqrt();
+        ry *= lambda.sqrt();
+    }
+
+    let num = rx * rx * ry * ry - rx * rx * y1p * y1p - ry * ry * x1p * x1p;
+    let den = rx * rx * y1p * y1p + ry * ry * x1p * x1p;
+    let sign = if large_arc == sweep { -1.0 } else { 1.0 };
+    let coef = sign * (num / den).max(0.0).sqrt();
+    let cxp = coef * rx * y1p / ry;
+    let cyp = -coef * ry * x1p / rx;
+    let center = Point::new(
+        cos * cxp - sin * cyp + (from.x + to.x) / 2.0,
+        sin * cxp + cos * cyp + (from.y + to.y) / 2.0,
+    );
+
+    let (ux, uy) = ((x1p - cxp) / rx, (y1p - cyp) / ry);
+    let (vx, vy) = ((-x1p - cxp) / rx, (-y1p - cyp) / ry);
+    let theta = uy.atan2(ux);
+    let mut delta = (ux * vy - uy * vx).atan2(ux * vx + uy * vy);
+    if !sweep && delta > 0.0 {
+        delta -= TAU;
+    } else if sweep && delta < 0.0 {
+        delta += TAU;
+    }
+
+    if (rx - ry).abs() <= 1e-9 * rx.max(ry) {
+        let start_angle = theta + rotation.to_radians();
+        return vec![PathSegment::ArcTo {
+            center,
+            radius: rx,
+            start_angle,
+            end_angle: start_angle + delta,
+            counterclockwise: !sweep,
+        }];
+    }
+
+    // Elliptical arc: approximate with cubics of at most 90° each
+    let count = (delta.abs() / (PI / 2.0)).ceil().max(1.0) as usize;
+    let step = delta / count as f64;
+    let k = 4.0 / 3.0 * (step / 4.0).tan();
+    let map = |x: f64, y: f64| {
+        Point::new(
+            center.x + cos * rx * x - sin * ry * y,
+            center.y + sin * rx * x + cos * ry * y,
+        )
+    };
+
+    (0..count)
+        .map(|i| {
+            let a0 = theta + step * i as f64;
+            let a1 = a0 + step;
+            let (s0, c0) = a0.sin_cos();
+            let (s1, c1) = a1.sin_cos();
+            let end = if i + 1 == count { to } else { map(c1, s1) };
+            PathSegment::CurveTo {
+                cp1: map(c0 - k * s0, s0 + k * c0),
+                cp2: map(c1 + k * s1, s1 - k * c1),
+                end,
+            }
+        })
+        .collect()
+}
+
+/// Recursive-descent parser for SVG path data
+struct Parser<'a> {
+    src: &'a str,
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(src: &'a str) -> Self {
+        Self {
+            src,
+            bytes: src.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    fn error(&self, msg: &str) -> D3Error {
+        D3Error::parse_error(format!("SVG path: {} at offset {}", msg, self.pos))
+    }
+
+    fn skip_separators(&mut self) {
+        while self.pos < self.bytes.len()
+            && (self.bytes[self.pos].is_ascii_whitespace() || self.bytes[self.pos] == b',')
+        {
+            self.pos += 1;
+        }
+    }
+
+    fn number(&mut self) -> D3Result<f64> {
+        self.skip_separators();
+        let start = self.pos;
+        let digits = |p: &mut Self| {
+            let s = p.pos;
+            while p.bytes.get(p.pos).is_some_and(u8::is_ascii_digit) {
+                p.pos += 1;
+            }
+            p.pos - s
+        };
+
+        if matches!(self.bytes.get(self.pos), Some(b'+' | b'-')) {
+            self.pos += 1;
+        }
+        let mut mantissa = digits(self);
+        if self.bytes.get(self.pos) == Some(&b'.') {
+            self.pos += 1;
+            mantissa += digits(self);
+        }
+        if mantissa == 0 {
+            self.pos = start;
+            return Err(self.error("expected number"));
+        }
+        if matches!(self.bytes.get(self.pos), Some(b'e' | b'E')) {
+            let mark = self.pos;
+            self.pos += 1;
+            if matches!(self.bytes.get(self.pos), Some(b'+' | b'-')) {
+                self.pos += 1;
+            }
+            if digits(self) == 0 {
+                self.pos = mark;
+            }
+        }
+
+        self.src[start..self.pos]
+            .parse()
+            .map_err(|_| self.error("invalid number"))
+    }
+
+    fn flag(&mut self) -> D3Result<bool> {
+        self.skip_separators();
+        let flag = match self.bytes.get(self.pos) {
+            Some(b'0') => false,
+            Some(b'1') => true,
+            _ => return Err(self.error("expected arc flag")),
+        };
+        self.pos += 1;
+        Ok(flag)
+    }
+
+    fn point(&mut self, relative: bool, current: Point) -> D3Result<Point> {
+        let x = self.number()?;
+        let y = self.number()?;
+        Ok(if relative {
+            Point::new(current.x + x, current.y + y)
+        } else {
+            Point::new(x, y)
+        })
+    }
+
+    fn parse(mut self) -> D3Result<Path> {
+        let mut path = Path::new();
+        let mut current = Point::zero();
+        let mut start = Point::zero();
+        let mut last_cubic: Option<Point> = None;
+        let mut last_quad: Option<Point> = None;
+        let mut command: Option<u8> = None;
+
+        loop {
+            self.skip_separators();
+            let Some(&byte) = self.bytes.get(self.pos) else {
+                break;
+            };
+
+            let cmd = if byte.is_ascii_alphabetic() {
+                self.pos += 1;
+                byte
+            } else {
+                match command {
+                    // Extra coordinate pairs after a moveto are linetos
+                    Some(b'M') => b'L',
+                    Some(b'm') => b'l',
+                    Some(b'Z' | b'z') | None => return Err(self.error("expected command")),
+                    Some(c) => c,
+                }
+            };
+            if command.is_none() && !matches!(cmd, b'M' | b'm') {
+                return Err(self.error("path must start with a moveto"));
+            }
+            command = Some(cmd);
+
+            let relative = cmd.is_ascii_lowercase();
+            let mut cubic = None;
+            let mut quad = None;
+
+            match cmd.to_ascii_uppercase() {
+                b'M' => {
+                    current = self.point(relative, current)?;
+                    start = current;
+                    path.segments.push(PathSegment::MoveTo(current));
+                }
+                b'L' => {
+                    current = self.point(relative, current)?;
+                    path.segments.push(PathSegment::LineTo(current));
+                }
+                b'H' => {
+                    let x = self.number()?;
+                    current.x = if relative { current.x + x } else { x };
+                    path.segments.push(PathSegment::LineTo(current));
+                }
+                b'V' => {
+                    let y = self.number()?;
+                    current.y = if relative { current.y + y } else { y };
+                    path.segments.push(PathSegment::LineTo(current));
+                }
+                b'C' | b'S' => {
+                    let cp1 = if cmd.eq_ignore_ascii_case(&b'C') {
+                        self.point(relative, current)?
+                    } else {
+                        last_cubic.map_or(current, |cp| current.scale(2.0).sub(&cp))
+                    };
+                    let cp2 = self.point(relative, current)?;
+                    let end = self.point(relative, current)?;
+                    path.segments.push(PathSegment::CurveTo { cp1, cp2, end });
+                    cubic = Some(cp2);
+                    current = end;
+                }
+                b'Q' | b'T' => {
+                    let cp = if cmd.eq_ignore_ascii_case(&b'Q') {
+                        self.point(relative, current)?
+                    } else {
+                        last_quad.map_or(current, |cp| current.scale(2.0).sub(&cp))
+                    };
+                    let end = self.point(relative, current)?;
+                    path.segments.push(PathSegment::QuadTo { cp, end });
+                    quad = Some(cp);
+                    current = end;
+                }
+                b'A' => {
+                    let rx = self.number()?;
+                    let ry = self.number()?;
+                    let rotation = self.number()?;
+                    let large_arc = self.flag()?;
+                    let sweep = self.flag()?;
+                    let end = self.point(relative, current)?;
+                    path.segments.extend(arc_segments(
+                        current, rx, ry, rotation, large_arc, sweep, end,
+                    ));
+                    current = end;
+                }
+                b'Z' => {
+                    path.segments.push(PathSegment::ClosePath);
+                    current = start;
+                }
+                _ => {
+                    self.pos -= 1;
+                    return Err(self.error("unknown command"));
+                }
+            }
+
+            last_cubic = cubic;
+            last_quad = quad;
+        }
+
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_point(p: Point, x: f64, y: f64) {
+        assert!(
+            (p.x - x).abs() < 1e-9 && (p.y - y).abs() < 1e-9,
+            "{:?} != ({}, {})",
+            p,
+            x,
+            y
+        );
+    }
+
+    #[test]
+    fn test_to_svg_d() {
+        let mut path = Path::new();
+        path.move_to(0.0, 0.0)
+            .line_to(10.5, -20.0)
+            .quad_to(1.0, 2.0, 3.0, 4.0)
+            .curve_to(1.0, 2.0, 3.0, 4.0, 5.0, 6.0)
+            .close();
+        assert_eq!(path.to_svg_d(), "M0,0L10.5,-20Q1,2,3,4C1,2,3,4,5,6Z");
+        assert_eq!(path.to_string(), path.to_svg_d());
+    }
+
+    #[test]
+    fn test_precision() {
+        let mut path = Path::new();
+        path.move_to(1.0 / 3.0, 2.0 / 3.0).line_to(-0.0001, 10.6);
+        assert_eq!(path.to_svg_d_with_precision(2), "M0.33,0.67L0,10.6");
+        assert_eq!(path.to_svg_d_with_precision(0), "M0,1L0,11");
+        let full = Path::from_svg_d(&path.to_svg_d()).unwrap();
+        assert_eq!(full, path);
+    }
+
+    #[test]
+    fn test_arc_serialization() {
+        let mut path = Path::new();
+        path.move_to(0.0, 0.0);
+        path.push(PathSegment::arc_to(0.0, 0.0, 10.0, 0.0, PI / 2.0, false));
+        // Line to the arc start, then a quarter arc clockwise (y down)
+        assert_eq!(
+            path.to_svg_d_with_precision(6),
+            "M0,0L10,0A10,10,0,0,1,0,10"
+        );
+
+        let circle = Path::from_iter([PathSegment::arc_to(5.0, 5.0, 5.0, 0.0, TAU, true)]);
+        assert_eq!(
+            circle.to_svg_d_with_precision(6),
+            "M10,5A5,5,0,1,0,0,5A5,5,0,1,0,10,5"
+        );
+    }
+
+    #[test]
+    fn test_arc_round_trip() {
+        let arcs = [
+            PathSegment::arc_to(5.0, -3.0, 10.0, 0.25, 2.5, false),
+            PathSegment::arc_to(0.0, 0.0, 4.0, 3.0, 1.0, true),
+        ];
+        for arc in arcs {
+            let path = Path::from_iter([arc.clone()]);
+            let parsed = Path::from_svg_d(&path.to_svg_d()).unwrap();
+
+            // The arc's start point is written as an explicit move
+            assert_eq!(parsed.len(), 2);
+            assert!(matches!(parsed.segments[0], PathSegment::MoveTo(_)));
+            match (&arc, &parsed.segments[1]) {
+                (
+                    PathSegment::ArcTo {
+                        center,
+                        radius,
+                        start_angle,
+                        end_angle,
+                        counterclockwise,
+                    },
+                    PathSegment::ArcTo {
+                        center: c,
+                        radius: r,
+                        start_angle: a0,
+                        end_angle: a1,
+                        counterclockwise: ccw,
+                    },
+                ) => {
+                    assert_point(*c, center.x, center.y);
+                    assert!((r - radius).abs() < 1e-9);
+                    assert!((a0 - start_angle).abs() < 1e-9);
+                    assert!((a1 - end_angle).abs() < 1e-9);
+                    assert_eq!(ccw, counterclockwise);
+                }
+                (_, other) => panic!("expected ArcTo, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_relative_and_shorthand() {
+        let path = Path::from_svg_d("m10,10 l5-5 h10 v-2 H0 V0 z m1 1 2 2").unwrap();
+        let ends: Vec<Option<Point>> = path.iter().map(|s| s.end_point()).collect();
+        assert_eq!(
+            ends,
+            vec![
+                Some(Point::new(10.0, 10.0)),
+                Some(Point::new(15.0, 5.0)),
+                Some(Point::new(25.0, 5.0)),
+                Some(Point::new(25.0, 3.0)),
+                Some(Point::new(0.0, 3.0)),
+                Some(Point::new(0.0, 0.0)),
+                None,
+                Some(Point::new(11.0, 11.0)),
+                Some(Point::new(13.0, 13.0)),
+            ]
+        );
+        assert!(matches!(path.segments[8], PathSegment::LineTo(_)));
+    }
+
+    #[test]
+    fn test_parse_compact_numbers() {
+        let path = Path::from_svg_d("M.5.5L-1e1-2E0,+3.25 4").unwrap();
+        assert_eq!(
+            path.segments,
+            vec![
+                PathSegment::move_to(0.5, 0.5),
+                PathSegment::line_to(-10.0, -2.0),
+                PathSegment::line_to(3.25, 4.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_smooth_curves() {
+        let path = Path::from_svg_d("M0 0 C0 10 10 10 10 0 S20 -10 20 0 Q25 5 30 0 T40 0").unwrap();
+        assert_eq!(
+            path.segments[2],
+            PathSegment::curve_to(10.0, -10.0, 20.0, -10.0, 20.0, 0.0)
+        );
+        assert_eq!(
+            path.segments[4],
+            PathSegment::quad_to(35.0, -5.0, 40.0, 0.0)
+        );
+
+        // S without a preceding cubic uses the current point
+        let path = Path::from_svg_d("M0 0 L5 5 s5 5 10 0").unwrap();
+        assert_eq!(
+            path.segments[2],
+            PathSegment::curve_to(5.0, 5.0, 10.0, 10.0, 15.0, 5.0)
+        );
+    }
+
+    #[test]
+    fn test_parse_circular_arc() {
+        let path = Path::from_svg_d("M10 0 A10 10 0 0 1 0 10").unwrap();
+        match path.segments[1] {
+            PathSegment::ArcTo {
+                center,
+                radius,
+                start_angle,
+                end_angle,
+                counterclockwise,
+            } => {
+                assert_point(center, 0.0, 0.0);
+                assert!((radius - 10.0).abs() < 1e-9);
+                assert!(start_angle.abs() < 1e-9);
+                assert!((end_angle - PI / 2.0).abs() < 1e-9);
+                assert!(!counterclockwise);
+            }
+            ref other => panic!("expected ArcTo, got {:?}", other),
+        }
+        assert_point(path.segments[1].end_point().unwrap(), 0.0, 10.0);
+
+        // Round trip through serialization
+        let again = Path::from_svg_d(&path.to_svg_d()).unwrap();
+        assert_eq!(again.len(), 2);
+        assert_point(again.segments[1].end_point().unwrap(), 0.0, 10.0);
+    }
+
+    #[test]
+    fn test_parse_elliptical_arc_to_cubics() {
+        let path = Path::from_svg_d("M0 0 a20 10 0 1 0 40 0").unwrap();
+        // Half ellipse (180°) needs two cubic segments
+        assert_eq!(path.len(), 3);
+        assert!(path
+            .iter()
+            .skip(1)
+            .all(|s| matches!(s, PathSegment::CurveTo { .. })));
+        assert_point(path.segments[1].end_point().unwrap(), 20.0, 10.0);
+        assert_point(path.segments[2].end_point().unwrap(), 40.0, 0.0);
+
+        // Radii too small are scaled up; zero radii degrade to a line
+        let path = Path::from_svg_d("M0 0 A1 1 0 0 0 10 0 A0 5 0 0 0 20 0").unwrap();
+        assert!(
+            matches!(path.segments[1], PathSegment::ArcTo { radius, .. } if (radius - 5.0).abs() < 1e-9)
+        );
+        assert_eq!(path.segments[2], PathSegment::line_to(20.0, 0.0));
+    }
+
+    #[test]
+    fn test_parse_arc_compact_flags() {
+        let path = Path::from_svg_d("M0 0a5 5 0 1110 0").unwrap();
+        assert_eq!(path.len(), 2);
+        assert_point(path.segments[1].end_point().unwrap(), 10.0, 0.0);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Path::from_svg_d("L10 10").is_err());
+        assert!(Path::from_svg_d("M10").is_err());
+        assert!(Path::from_svg_d("M0 0 X5").is_err());
+        assert!(Path::from_svg_d("M0 0 Z 5").is_err());
+        assert!(Path::from_svg_d("M0 0 A5 5 0 2 0 1 1").is_err());
+        assert!(Path::from_svg_d("").unwrap().is_empty());
+    }
+}
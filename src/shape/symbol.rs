@@ -0,0 +1,593 @@
+//! Symbol generator for scatter plots and legends
+//!
+//! Port of d3-shape's symbols. Every symbol type is sized by *area* in
+//! square pixels rather than by radius, so a circle and a star of the same
+//! `size` carry the same visual weight.
+//!
+//! Two sets are provided, matching d3:
+//!
+//! - [`SymbolType::FILL`]: circle, cross, diamond, square, star, triangle, wye
+//! - [`SymbolType::STROKE`]: circle, plus, times, triangle2, asterisk,
+//!   square2, diamond2 — designed to be stroked rather than filled
+//!
+//! [`SymbolScale`] assigns symbols to categories so that every component
+//! drawing the same series (scatter marks, legends, 3D labels) agrees on
+//! the shape.
+
+use super::path::{PathSegment, Point};
+use serde::{Deserialize, Serialize};
+use std::f64::consts::{PI, TAU};
+
+const SQRT3: f64 = 1.732_050_807_568_877_2;
+
+/// Built-in symbol shapes
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SymbolType {
+    /// Circle
+    #[default]
+    Circle,
+    /// Greek cross with arms of equal length
+    Cross,
+    /// Rhombus, taller than wide
+    Diamond,
+    /// Axis-aligned square
+    Square,
+    /// Five-pointed star
+    Star,
+    /// Upward-pointing triangle
+    Triangle,
+    /// Y shape with three arms
+    Wye,
+    /// Six-armed asterisk (stroke only)
+    Asterisk,
+    /// Square rotated by 45° (stroke set)
+    Diamond2,
+    /// Plus sign (stroke only)
+    Plus,
+    /// Square sized for stroking
+    Square2,
+    /// Diagonal cross (stroke only)
+    Times,
+    /// Upward-pointing triangle sized for stroking
+    Triangle2,
+}
+
+impl SymbolType {
+    /// d3's `symbolsFill`: symbols designed to be filled
+    pub const FILL: [SymbolType; 7] = [
+        SymbolType::Circle,
+        SymbolType::Cross,
+        SymbolType::Diamond,
+        SymbolType::Square,
+        SymbolType::Star,
+        SymbolType::Triangle,
+        SymbolType::Wye,
+    ];
+
+    /// d3's `symbolsStroke`: symbols designed to be stroked
+    pub const STROKE: [SymbolType; 7] = [
+        SymbolType::Circle,
+        SymbolType::Plus,
+        SymbolType::Times,
+        SymbolType::Triangle2,
+        SymbolType::Asterisk,
+        SymbolType::Square2,
+        SymbolType::Diamond2,
+    ];
+
+    /// d3 name of the symbol (e.g. `"circle"`, `"triangle2"`)
+    pub fn name(&self) -> &'static str {
+        match self {
+            SymbolType::Circle => "circle",
+            SymbolType::Cross => "cross",
+            SymbolType::Diamond => "diamond",
+            SymbolType::Square => "square",
+            SymbolType::Star => "star",
+            SymbolType::Triangle => "triangle",
+            SymbolType::Wye => "wye",
+            SymbolType::Asterisk => "asterisk",
+            SymbolType::Diamond2 => "diamond2",
+            SymbolType::Plus => "plus",
+            SymbolType::Square2 => "square2",
+            SymbolType::Times => "times",
+            SymbolType::Triangle2 => "triangle2",
+        }
+    }
+
+    /// Look up a symbol by its d3 name (case-insensitive)
+    ///
+    /// Accepts both `"triangle2"` and d3's `"symbolTriangle2"` spelling.
+    pub fn from_name(name: &str) -> Option<SymbolType> {
+        let name = name.trim();
+        let name = if name.len() > 6 && name[..6].eq_ignore_ascii_case("symbol") {
+            &name[6..]
+        } else {
+            name
+        };
+        Self::FILL
+            .iter()
+            .chain(Self::STROKE.iter())
+            .copied()
+            .find(|symbol| symbol.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Whether the symbol only has strokes and no fillable interior
+    pub fn is_stroke_only(&self) -> bool {
+        matches!(
+            self,
+            SymbolType::Asterisk | SymbolType::Plus | SymbolType::Times
+        )
+    }
+
+    /// Generate path segments for this symbol centered at the origin
+    pub fn path(&self, size: f64) -> Vec<PathSegment> {
+        let size = size.max(0.0);
+        let points = match self {
+            SymbolType::Circle => {
+                let r = (size / PI).sqrt();
+                return vec![
+                    PathSegment::move_to(r, 0.0),
+                    PathSegment::arc_to(0.0, 0.0, r, 0.0, TAU, false),
+                    PathSegment::ClosePath,
+                ];
+            }
+            SymbolType::Cross => {
+                let r = (size / 5.0).sqrt() / 2.0;
+                vec![
+                    (-3.0 * r, -r),
+                    (-r, -r),
+                    (-r, -3.0 * r),
+                    (r, -3.0 * r),
+                    (r, -r),
+                    (3.0 * r, -r),
+                    (3.0 * r, r),
+                    (r, r),
+                    (r, 3.0 * r),
+                    (-r, 3.0 * r),
+                    (-r, r),
+                    (-3.0 * r, r),
+                ]
+            }
+            SymbolType::Diamond => {
+                let tan30 = (1.0_f64 / 3.0).sqrt();
+                let y = (size / (tan30 * 2.0)).sqrt();
+                let x = y * tan30;
+                vec![(0.0, -y), (x, 0.0), (0.0, y), (-x, 0.0)]
+            }
+            SymbolType::Square => {
+                let w = size.sqrt();
+                let x = -w / 2.0;
+                vec![(x, x), (x + w, x), (x + w, x + w), (x, x + w)]
+            }
+            SymbolType::Star => {
+                const KA: f64 = 0.890_813_091_529_285_2;
+                let kr = (PI / 10.0).sin() / (7.0 * PI / 10.0).sin();
+                let kx = (TAU / 10.0).sin() * kr;
+                let ky = -(TAU / 10.0).cos() * kr;
+                let r = (size * KA).sqrt();
+                let x = kx * r;
+                let y = ky * r;
+                let mut points = vec![(0.0, -r), (x, y)];
+                for i in 1..5 {
+                    let a = TAU * i as f64 / 5.0;
+                    let (s, c) = a.sin_cos();
+                    points.push((s * r, -c * r));
+                    points.push((c * x - s * y, s * x + c * y));
+                }
+                points
+            }
+            SymbolType::Triangle => {
+                let y = -(size / (SQRT3 * 3.0)).sqrt();
+                vec![(0.0, y * 2.0), (-SQRT3 * y, -y), (SQRT3 * y, -y)]
+            }
+            SymbolType::Wye => {
+                let c = -0.5;
+                let s = SQRT3 / 2.0;
+                let k = 1.0 / 12.0_f64.sqrt();
+                let a = (k / 2.0 + 1.0) * 3.0;
+                let r = (size / a).sqrt();
+                let (x0, y0) = (r / 2.0, r * k);
+                let (x1, y1) = (x0, r * k + r);
+                let (x2, y2) = (-x1, y1);
+                vec![
+                    (x0, y0),
+                    (x1, y1),
+                    (x2, y2),
+                    (c * x0 - s * y0, s * x0 + c * y0),
+                    (c * x1 - s * y1, s * x1 + c * y1),
+                    (c * x2 - s * y2, s * x2 + c * y2),
+                    (c * x0 + s * y0, c * y0 - s * x0),
+                    (c * x1 + s * y1, c * y1 - s * x1),
+                    (c * x2 + s * y2, c * y2 - s * x2),
+                ]
+            }
+            SymbolType::Asterisk => {
+                let r = (size + (size / 28.0).min(0.75)).sqrt() * 0.59436;
+                let t = r / 2.0;
+                let u = t * SQRT3;
+                return vec![
+                    PathSegment::move_to(0.0, r),
+                    PathSegment::line_to(0.0, -r),
+                    PathSegment::move_to(-u, -t),
+                    PathSegment::line_to(u, t),
+                    PathSegment::move_to(-u, t),
+                    PathSegment::line_to(u, -t),
+                ];
+            }
+            SymbolType::Diamond2 => {
+                let r = size.sqrt() * 0.62625;
+                vec![(0.0, -r), (r, 0.0), (0.0, r), (-r, 0.0)]
+            }
+            SymbolType::Plus => {
+                let r = (size - (size / 7.0).min(2.0)).max(0.0).sqrt() * 0.87559;
+                return vec![
+                    PathSegment::move_to(-r, 0.0),
+                    PathSegment::line_to(r, 0.0),
+                    PathSegment::move_to(0.0, r),
+                    PathSegment::line_to(0.0, -r),
+                ];
+            }
+            SymbolType::Square2 => {
+                let r = size.sqrt() * 0.4431;
+                vec![(r, r), (r, -r), (-r, -r), (-r, r)]
+            }
+            SymbolType::Times => {
+                let r = (size - (size / 6.0).min(1.7)).max(0.0).sqrt() * 0.6189;
+                return vec![
+                    PathSegment::move_to(-r, -r),
+                    PathSegment::line_to(r, r),
+                    PathSegment::move_to(-r, r),
+                    PathSegment::line_to(r, -r),
+                ];
+            }
+            SymbolType::Triangle2 => {
+                let s = size.sqrt() * 0.6824;
+                let t = s / 2.0;
+                let u = (s * SQRT3) / 2.0;
+                vec![(0.0, -s), (u, t), (-u, t)]
+            }
+        };
+
+        let mut segments: Vec<PathSegment> = points
+            .into_iter()
+            .enumerate()
+            .map(|(i, (x, y))| {
+                if i == 0 {
+                    PathSegment::move_to(x, y)
+                } else {
+                    PathSegment::line_to(x, y)
+                }
+            })
+            .collect();
+        segments.push(PathSegment::ClosePath);
+        segments
+    }
+}
+
+/// Symbol generator
+///
+/// Generates a symbol of a given type and area, centered at the origin or
+/// at an arbitrary position.
+///
+/// # Example
+/// ```
+/// use makepad_d3::shape::{Path, SymbolGenerator, SymbolType};
+///
+/// let symbol = SymbolGenerator::new()
+///     .symbol_type(SymbolType::Square)
+///     .size(100.0);
+///
+/// let path: Path = symbol.generate().into_iter().collect();
+/// assert_eq!(path.to_svg_d(), "M-5,-5L5,-5L5,5L-5,5Z");
+///
+/// let moved = symbol.generate_at(20.0, 30.0);
+/// assert_eq!(moved.len(), 5);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SymbolGenerator {
+    /// Symbol shape
+    symbol_type: SymbolType,
+    /// Area in square pixels
+    size: f64,
+}
+
+impl Default for SymbolGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SymbolGenerator {
+    /// Create a new generator producing 64px² circles (d3's defaults)
+    pub fn new() -> Self {
+        Self {
+            symbol_type: SymbolType::Circle,
+            size: 64.0,
+        }
+    }
+
+    /// Set the symbol type
+    pub fn symbol_type(mut self, symbol_type: SymbolType) -> Self {
+        self.symbol_type = symbol_type;
+        self
+    }
+
+    /// Set the symbol area in square pixels
+    pub fn size(mut self, size: f64) -> Self {
+        self.size = size.max(0.0);
+        self
+    }
+
+    /// Get the symbol type
+    pub fn get_symbol_type(&self) -> SymbolType {
+        self.symbol_type
+    }
+
+    /// Get the symbol area
+    pub fn get_size(&self) -> f64 {
+        self.size
+    }
+
+    /// Generate path segments centered at the origin
+    pub fn generate(&self) -> Vec<PathSegment> {
+        self.symbol_type.path(self.size)
+    }
+
+    /// Generate path segments centered at `(x, y)`
+    pub fn generate_at(&self, x: f64, y: f64) -> Vec<PathSegment> {
+        let offset = Point::new(x, y);
+        self.generate()
+            .into_iter()
+            .map(|segment| translate(segment, offset))
+            .collect()
+    }
+}
+
+fn translate(segment: PathSegment, offset: Point) -> PathSegment {
+    match segment {
+        PathSegment::MoveTo(p) => PathSegment::MoveTo(p.add(&offset)),
+        PathSegment::LineTo(p) => PathSegment::LineTo(p.add(&offset)),
+        PathSegment::QuadTo { cp, end } => PathSegment::QuadTo {
+            cp: cp.add(&offset),
+            end: end.add(&offset),
+        },
+        PathSegment::CurveTo { cp1, cp2, end } => PathSegment::CurveTo {
+            cp1: cp1.add(&offset),
+            cp2: cp2.add(&offset),
+            end: end.add(&offset),
+        },
+        PathSegment::ArcTo {
+            center,
+            radius,
+            start_angle,
+            end_angle,
+            counterclockwise,
+        } => PathSegment::ArcTo {
+            center: center.add(&offset),
+            radius,
+            start_angle,
+            end_angle,
+            counterclockwise,
+        },
+        PathSegment::ClosePath => PathSegment::ClosePath,
+    }
+}
+
+/// Categorical scale mapping series to symbol types
+///
+/// Works like an ordinal scale: categories are assigned symbols in domain
+/// order, cycling when there are more categories than symbols.
+///
+/// # Example
+/// ```
+/// use makepad_d3::shape::{SymbolScale, SymbolType};
+///
+/// let scale = SymbolScale::fill().with_domain(["apples", "pears"]);
+///
+/// assert_eq!(scale.get(0), SymbolType::Circle);
+/// assert_eq!(scale.symbol_for("pears"), Some(SymbolType::Cross));
+/// assert_eq!(scale.symbol_for("plums"), None);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct SymbolScale {
+    /// Symbols in assignment order
+    symbols: Vec<SymbolType>,
+    /// Category keys
+    domain: Vec<String>,
+}
+
+impl SymbolScale {
+    /// Create a new symbol scale
+    ///
+    /// An empty symbol list falls back to d3's fill symbol set.
+    pub fn new(symbols: Vec<SymbolType>) -> Self {
+        let symbols = if symbols.is_empty() {
+            SymbolType::FILL.to_vec()
+        } else {
+            symbols
+        };
+        Self {
+            symbols,
+            domain: Vec::new(),
+        }
+    }
+
+    /// Scale over d3's fill symbol set
+    pub fn fill() -> Self {
+        Self::new(SymbolType::FILL.to_vec())
+    }
+
+    /// Scale over d3's stroke symbol set
+    pub fn stroke() -> Self {
+        Self::new(SymbolType::STROKE.to_vec())
+    }
+
+    /// Set the category keys
+    pub fn with_domain<S: Into<String>>(mut self, keys: impl IntoIterator<Item = S>) -> Self {
+        self.domain = keys.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Get symbol by index (wraps around)
+    pub fn get(&self, index: usize) -> SymbolType {
+        self.symbols[index % self.symbols.len()]
+    }
+
+    /// Get the symbol assigned to a category key
+    pub fn symbol_for(&self, key: &str) -> Option<SymbolType> {
+        self.domain
+            .iter()
+            .position(|k| k == key)
+            .map(|index| self.get(index))
+    }
+
+    /// Generator for the symbol at `index` with the given area
+    pub fn generator(&self, index: usize, size: f64) -> SymbolGenerator {
+        SymbolGenerator::new()
+            .symbol_type(self.get(index))
+            .size(size)
+    }
+
+    /// Get all symbols
+    pub fn symbols(&self) -> &[SymbolType] {
+        &self.symbols
+    }
+
+    /// Get the category keys
+    pub fn domain(&self) -> &[String] {
+        &self.domain
+    }
+
+    /// Get the number of symbols
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    /// Check if empty
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+}
+
+impl Default for SymbolScale {
+    fn default() -> Self {
+        Self::fill()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shape::Path;
+
+    fn polygon_area(segments: &[PathSegment]) -> f64 {
+        let points: Vec<Point> = segments.iter().filter_map(|s| s.end_point()).collect();
+        let mut sum = 0.0;
+        for i in 0..points.len() {
+            let a = points[i];
+            let b = points[(i + 1) % points.len()];
+            sum += a.x * b.y - b.x * a.y;
+        }
+        sum.abs() / 2.0
+    }
+
+    #[test]
+    fn test_fill_symbols_are_area_sized() {
+        for symbol in SymbolType::FILL {
+            if symbol == SymbolType::Circle {
+                continue;
+            }
+            let area = polygon_area(&symbol.path(200.0));
+            assert!(
+                (area - 200.0).abs() < 200.0 * 0.02,
+                "{}: {}",
+                symbol.name(),
+                area
+            );
+        }
+
+        let path: Path = SymbolType::Circle.path(100.0 * PI).into_iter().collect();
+        assert!((path.length() - 20.0 * PI).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_matches_d3_path_data() {
+        let d = |symbol: SymbolType, size: f64| {
+            symbol
+                .path(size)
+                .into_iter()
+                .collect::<Path>()
+                .to_svg_d_with_precision(3)
+        };
+        assert_eq!(
+            d(SymbolType::Cross, 20.0),
+            "M-3,-1L-1,-1L-1,-3L1,-3L1,-1L3,-1L3,1L1,1L1,3L-1,3L-1,1L-3,1Z"
+        );
+        assert_eq!(
+            d(SymbolType::Diamond, 10.0),
+            "M0,-2.943L1.699,0L0,2.943L-1.699,0Z"
+        );
+        assert_eq!(
+            d(SymbolType::Triangle, 10.0),
+            "M0,-2.775L2.403,1.387L-2.403,1.387Z"
+        );
+        assert_eq!(
+            d(SymbolType::Plus, 64.0),
+            "M-6.894,0L6.894,0M0,6.894L0,-6.894"
+        );
+        assert_eq!(
+            d(SymbolType::Square2, 64.0),
+            "M3.545,3.545L3.545,-3.545L-3.545,-3.545L-3.545,3.545Z"
+        );
+    }
+
+    #[test]
+    fn test_generate_at_translates() {
+        let symbol = SymbolGenerator::new()
+            .symbol_type(SymbolType::Circle)
+            .size(PI * 4.0);
+        let segments = symbol.generate_at(10.0, 20.0);
+        assert_eq!(segments[0], PathSegment::move_to(12.0, 20.0));
+        match &segments[1] {
+            PathSegment::ArcTo { center, radius, .. } => {
+                assert_eq!(*center, Point::new(10.0, 20.0));
+                assert!((radius - 2.0).abs() < 1e-12);
+            }
+            other => panic!("expected arc, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_names_round_trip() {
+        for symbol in SymbolType::FILL.iter().chain(SymbolType::STROKE.iter()) {
+            assert_eq!(SymbolType::from_name(symbol.name()), Some(*symbol));
+        }
+        assert_eq!(
+            SymbolType::from_name("symbolTriangle2"),
+            Some(SymbolType::Triangle2)
+        );
+        assert_eq!(SymbolType::from_name("hexagon"), None);
+        assert!(SymbolType::Times.is_stroke_only());
+        assert!(!SymbolType::Square2.is_stroke_only());
+    }
+
+    #[test]
+    fn test_symbol_scale_empty_falls_back() {
+        let scale = SymbolScale::new(Vec::new());
+        assert!(!scale.is_empty());
+        assert_eq!(scale.symbols(), SymbolScale::fill().symbols());
+    }
+
+    #[test]
+    fn test_symbol_scale_cycles() {
+        let scale = SymbolScale::stroke().with_domain((0..9).map(|i| format!("s{}", i)));
+        assert_eq!(scale.len(), 7);
+        assert_eq!(scale.get(1), SymbolType::Plus);
+        assert_eq!(scale.symbol_for("s7"), Some(SymbolType::Circle));
+        assert_eq!(scale.symbol_for("s8"), Some(SymbolType::Plus));
+        assert_eq!(
+            scale.generator(3, 50.0).get_symbol_type(),
+            SymbolType::Triangle2
+        );
+    }
+}
@@ -19,9 +19,10 @@
 // registration items that cannot carry doc comments.
 #![allow(missing_docs)]
 
-use crate::color::CategoricalScale;
+use crate::color::{CategoricalScale, Rgba, TextColorPicker};
 use crate::scale::{CategoryScale, DiscreteScale, LinearScale, Scale, ScaleExt, TickOptions};
-use crate::shape::{PieLayout, PieSlice};
+use crate::shape::curve::{self, Curve, LinearCurve};
+use crate::shape::{ArcDatum, Path, PathSegment, PieLabelLayout, PieLayout, PieSlice, Point};
 
 use super::vm_data;
 use makepad_widgets::makepad_script::ScriptFnRef;
@@ -53,6 +54,7 @@ script_mod! {
         plot_margin: Inset{left: 46.0, top: 12.0, right: 12.0, bottom: 30.0}
         line_color: #x1f77b4
         line_width: 2.0
+        curve: "linear"
         dot_color: #xff7f0e
         dot_radius: 3.0
         grid_color: #x3a3f52
@@ -70,6 +72,7 @@ script_mod! {
         plot_margin: Inset{left: 46.0, top: 12.0, right: 12.0, bottom: 30.0}
         line_color: #x1f77b4
         line_width: 2.0
+        curve: "linear"
         fill_color: #x1f77b455
         grid_color: #x3a3f52
         label_color: #x9aa0b0
@@ -101,6 +104,9 @@ script_mod! {
         height: 300
         plot_margin: Inset{left: 12.0, top: 12.0, right: 12.0, bottom: 12.0}
         inner_radius: 0.0
+        corner_radius: 0.0
+        pad_angle: 0.0
+        outside_labels: false
         label_color: #x9aa0b0
         draw_bg +: { draw_depth: 0.0 color: #x00000000 }
         draw_grid +: { draw_depth: 0.1 }
@@ -116,7 +122,7 @@ script_mod! {
 
 // ---- Shared helpers ----
 
-pub(crate) fn rgba_to_vec4f(c: crate::color::Rgba) -> Vec4f {
+pub(crate) fn rgba_to_vec4f(c: Rgba) -> Vec4f {
     Vec4f {
         x: c.r,
         y: c.g,
@@ -125,6 +131,31 @@ pub(crate) fn rgba_to_vec4f(c: crate::color::Rgba) -> Vec4f {
     }
 }
 
+pub(crate) fn vec4f_to_rgba(c: Vec4f) -> Rgba {
+    Rgba::new(c.x, c.y, c.z, c.w)
+}
+
+/// Draw `label` at `pos` over a data-colored `fill`, picking the most
+/// readable text color and drawing a 1px halo when the fill is too
+/// mid-toned for the text alone.
+pub(crate) fn draw_label_on_fill(
+    text: &mut DrawText,
+    cx: &mut Cx2d,
+    pos: DVec2,
+    label: &str,
+    fill: Vec4f,
+) {
+    let choice = TextColorPicker::new().pick(&vec4f_to_rgba(fill));
+    if let Some(halo) = choice.halo {
+        text.color = rgba_to_vec4f(halo);
+        for (dx, dy) in [(-1.0, 0.0), (1.0, 0.0), (0.0, -1.0), (0.0, 1.0)] {
+            text.draw_abs(cx, dvec2(pos.x + dx, pos.y + dy), label);
+        }
+    }
+    text.color = rgba_to_vec4f(choice.color);
+    text.draw_abs(cx, pos, label);
+}
+
 pub(crate) fn compute_plot_rect(rect: &Rect, m: &Inset) -> Rect {
     Rect {
         pos: DVec2 {
@@ -325,6 +356,67 @@ pub(crate) fn demo_xy(n: usize) -> Vec<(f64, f64)> {
         .collect()
 }
 
+/// Resolve a d3 curve name (`"linear"`, `"monotoneX"`, `"bumpX"`, ...).
+///
+/// Empty or unknown names fall back to linear rather than failing the
+/// draw, so a misspelled `curve` property shows straight segments.
+pub(crate) fn curve_for_name(name: &str) -> Box<dyn Curve> {
+    if name.is_empty() {
+        return Box::new(LinearCurve);
+    }
+    curve::from_name(name).unwrap_or_else(|_| Box::new(LinearCurve))
+}
+
+/// Resolve a curve name for a filled area, like [`curve_for_name`].
+///
+/// Closed curves also fall back to linear, since they would close the
+/// top line before the fill reaches the baseline.
+pub(crate) fn area_curve_for_name(name: &str) -> Box<dyn Curve> {
+    let curve = curve_for_name(name);
+    if curve.is_closed() {
+        return Box::new(LinearCurve);
+    }
+    curve
+}
+
+/// Append path segments to the current vector path.
+///
+/// Arcs are not produced by curves and are skipped.
+pub(crate) fn trace_segments(vector: &mut DrawVector, segments: &[PathSegment]) {
+    let mut current = Point::zero();
+    for segment in segments {
+        match *segment {
+            PathSegment::MoveTo(p) => vector.move_to(p.x as f32, p.y as f32),
+            PathSegment::LineTo(p) => vector.line_to(p.x as f32, p.y as f32),
+            PathSegment::QuadTo { cp, end } => {
+                let c1 = current.lerp(&cp, 2.0 / 3.0);
+                let c2 = end.lerp(&cp, 2.0 / 3.0);
+                vector.bezier_to(
+                    c1.x as f32,
+                    c1.y as f32,
+                    c2.x as f32,
+                    c2.y as f32,
+                    end.x as f32,
+                    end.y as f32,
+                );
+            }
+            PathSegment::CurveTo { cp1, cp2, end } => vector.bezier_to(
+                cp1.x as f32,
+                cp1.y as f32,
+                cp2.x as f32,
+                cp2.y as f32,
+                end.x as f32,
+                end.y as f32,
+            ),
+            PathSegment::ClosePath => vector.close(),
+            PathSegment::ArcTo { .. } => {}
+        }
+        if let Some(p) = segment.end_point() {
+            current = p;
+        }
+    }
+}
+
 // ================= Bar chart =================
 
 /// A categorical bar chart, exposed to Splash as `d3.BarChart`.
@@ -616,6 +708,10 @@ pub struct D3LineChart {
     /// Stroke width of the line.
     #[live(2.0)]
     pub line_width: f32,
+    /// d3 curve name, e.g. `"linear"`, `"monotoneX"`, `"basis"`, `"bumpX"`.
+    /// Unknown names draw linear segments.
+    #[live]
+    pub curve: String,
     /// Fill color of the hovered point marker.
     #[live]
     pub dot_color: Vec4f,
@@ -697,15 +793,13 @@ impl D3LineChart {
         }
         let c = self.line_color;
         self.draw_vector.set_color(c.x, c.y, c.z, c.w);
-        let (px, py) = (
-            x_scale.scale(self.points[0].0) as f32,
-            y_scale.scale(self.points[0].1) as f32,
-        );
-        self.draw_vector.move_to(px, py);
-        for p in &self.points[1..] {
-            self.draw_vector
-                .line_to(x_scale.scale(p.0) as f32, y_scale.scale(p.1) as f32);
-        }
+        let screen: Vec<Point> = self
+            .points
+            .iter()
+            .map(|p| Point::new(x_scale.scale(p.0), y_scale.scale(p.1)))
+            .collect();
+        let segments = curve_for_name(&self.curve).generate(&screen);
+        trace_segments(&mut self.draw_vector, &segments);
         self.draw_vector.stroke(self.line_width);
 
         if let Some(i) = self.hovered {
@@ -867,6 +961,10 @@ pub struct D3AreaChart {
     /// Stroke width of the top line.
     #[live(2.0)]
     pub line_width: f32,
+    /// d3 curve name used for the top line and the fill (see `d3.LineChart`).
+    /// Closed curves draw linear segments here.
+    #[live]
+    pub curve: String,
     /// Fill color of the area below the line.
     #[live]
     pub fill_color: Vec4f,
@@ -1017,31 +1115,31 @@ impl Widget for D3AreaChart {
             let (dmin, _) = y_scale.domain();
             let base = y_scale.scale(dmin) as f32;
 
+            let screen: Vec<Point> = self
+                .points
+                .iter()
+                .map(|p| Point::new(x_scale.scale(p.0), y_scale.scale(p.1)))
+                .collect();
+            let segments = area_curve_for_name(&self.curve).generate(&screen);
+
             // Filled area down to the domain floor
             let c = self.fill_color;
             self.draw_vector.set_color(c.x, c.y, c.z, c.w);
-            let first_x = x_scale.scale(self.points[0].0) as f32;
-            self.draw_vector.move_to(first_x, base);
-            for p in &self.points {
-                self.draw_vector
-                    .line_to(x_scale.scale(p.0) as f32, y_scale.scale(p.1) as f32);
+            let first = segments.first().and_then(|s| s.end_point());
+            let last = segments.iter().rev().find_map(|s| s.end_point());
+            if let (Some(first), Some(last)) = (first, last) {
+                self.draw_vector.move_to(first.x as f32, base);
+                self.draw_vector.line_to(first.x as f32, first.y as f32);
+                trace_segments(&mut self.draw_vector, &segments[1..]);
+                self.draw_vector.line_to(last.x as f32, base);
             }
-            let last_x = x_scale.scale(self.points[self.points.len() - 1].0) as f32;
-            self.draw_vector.line_to(last_x, base);
             self.draw_vector.close();
             self.draw_vector.fill();
 
             // Line on top
             let c = self.line_color;
             self.draw_vector.set_color(c.x, c.y, c.z, c.w);
-            self.draw_vector.move_to(
-                x_scale.scale(self.points[0].0) as f32,
-                y_scale.scale(self.points[0].1) as f32,
-            );
-            for p in &self.points[1..] {
-                self.draw_vector
-                    .line_to(x_scale.scale(p.0) as f32, y_scale.scale(p.1) as f32);
-            }
+            trace_segments(&mut self.draw_vector, &segments);
             self.draw_vector.stroke(self.line_width);
         }
 
@@ -1332,6 +1430,15 @@ pub struct D3PieChart {
     /// Donut hole radius as a fraction of the outer radius (0 = pie).
     #[live(0.0)]
     pub inner_radius: f32,
+    /// Rounded slice corners, in pixels.
+    #[live(0.0)]
+    pub corner_radius: f32,
+    /// Gap between slices, in radians.
+    #[live(0.0)]
+    pub pad_angle: f32,
+    /// Place labels outside the ring with leader lines.
+    #[live]
+    pub outside_labels: bool,
     /// Slice label color.
     #[live]
     pub label_color: Vec4f,
@@ -1386,33 +1493,51 @@ impl D3PieChart {
             x: self.plot.pos.x + self.plot.size.x * 0.5,
             y: self.plot.pos.y + self.plot.size.y * 0.5,
         };
-        let radius = (self.plot.size.x.min(self.plot.size.y) * 0.5 - 4.0).max(4.0);
+        let (w, h) = if self.outside_labels {
+            // Leave room for leader lines and label text
+            (
+                self.plot.size.x - 2.0 * PIE_LABEL_ROOM,
+                self.plot.size.y - 24.0,
+            )
+        } else {
+            (self.plot.size.x, self.plot.size.y)
+        };
+        let radius = (w.min(h) * 0.5 - 4.0).max(4.0);
         (center, radius)
     }
 
+    fn arcs(&self, radius: f64) -> Vec<ArcDatum> {
+        let inner = radius * self.inner_radius.clamp(0.0, 0.95) as f64;
+        self.slices()
+            .iter()
+            .map(|s| ArcDatum {
+                inner_radius: inner,
+                outer_radius: radius,
+                start_angle: s.start_angle,
+                end_angle: s.end_angle,
+                corner_radius: self.corner_radius.max(0.0) as f64,
+                pad_angle: self.pad_angle.max(0.0) as f64,
+                pad_radius: None,
+            })
+            .collect()
+    }
+
     fn slice_at(&self, abs: DVec2) -> Option<usize> {
         if self.values.is_empty() {
             return None;
         }
         let (center, radius) = self.geometry();
-        let dx = abs.x - center.x;
-        let dy = abs.y - center.y;
-        let dist = (dx * dx + dy * dy).sqrt();
-        let inner = radius * self.inner_radius.clamp(0.0, 0.95) as f64;
-        if dist > radius || dist < inner {
-            return None;
-        }
-        // d3 angle convention: 0 at 12 o'clock, increasing clockwise
-        let mut angle = dx.atan2(-dy);
-        if angle < 0.0 {
-            angle += std::f64::consts::TAU;
-        }
-        self.slices()
+        let slices = self.slices();
+        self.arcs(radius)
             .iter()
-            .position(|s| angle >= s.start_angle && angle <= s.end_angle)
+            .position(|a| a.contains(abs.x - center.x, abs.y - center.y))
+            .map(|i| slices[i].index)
     }
 }
 
+/// Horizontal space reserved on each side for outside pie labels.
+const PIE_LABEL_ROOM: f64 = 64.0;
+
 impl ScriptHook for D3PieChart {
     fn on_after_apply(
         &mut self,
@@ -1499,19 +1624,12 @@ impl Widget for D3PieChart {
         begin_plot(cx, &self.rect, &self.plot_margin, &mut self.draw_vector);
 
         let (center, radius) = self.geometry();
-        let inner = radius * self.inner_radius.clamp(0.0, 0.95) as f64;
         let palette = CategoricalScale::category10();
         let hovered = self.hovered;
+        let slices = self.slices();
+        let arcs = self.arcs(radius);
 
-        // d3 angle convention: 0 at 12 o'clock, clockwise.
-        let point_at = |angle: f64, r: f64| -> (f32, f32) {
-            (
-                (center.x + r * angle.sin()) as f32,
-                (center.y - r * angle.cos()) as f32,
-            )
-        };
-
-        for slice in self.slices() {
+        for (slice, arc) in slices.iter().zip(&arcs) {
             let i = slice.index;
             let mut color = rgba_to_vec4f(palette.get(i));
             if hovered == Some(i) {
@@ -1522,47 +1640,78 @@ impl Widget for D3PieChart {
             self.draw_vector
                 .set_color(color.x, color.y, color.z, color.w);
 
-            let sweep = (slice.end_angle - slice.start_angle).max(0.0);
-            let segs = ((sweep / 0.05).ceil() as usize).clamp(2, 256);
-            let step = sweep / segs as f64;
-
-            // Outer arc
-            let (sx, sy) = point_at(slice.start_angle, radius);
-            self.draw_vector.move_to(sx, sy);
-            for k in 1..=segs {
-                let (px, py) = point_at(slice.start_angle + step * k as f64, radius);
-                self.draw_vector.line_to(px, py);
-            }
-            if inner > 0.5 {
-                // Inner arc back (donut)
-                for k in (0..=segs).rev() {
-                    let (px, py) = point_at(slice.start_angle + step * k as f64, inner);
-                    self.draw_vector.line_to(px, py);
+            let path: Path = arc.generate().into_iter().collect();
+            for polygon in path.flatten(0.25) {
+                let Some(first) = polygon.points.first() else {
+                    continue;
+                };
+                self.draw_vector
+                    .move_to((center.x + first.x) as f32, (center.y + first.y) as f32);
+                for p in &polygon.points[1..] {
+                    self.draw_vector
+                        .line_to((center.x + p.x) as f32, (center.y + p.y) as f32);
                 }
-            } else {
-                let (cx_px, cy_px) = point_at(0.0, 0.0);
-                self.draw_vector.line_to(cx_px, cy_px);
+                self.draw_vector.close();
             }
-            self.draw_vector.close();
             self.draw_vector.fill();
         }
 
-        // Labels at mid-angle, just outside the arc midpoint
         self.draw_text.color = self.label_color;
-        for slice in self.slices() {
-            let i = slice.index;
-            let Some(label) = self.label_strs.get(i) else {
-                continue;
-            };
-            if label.is_empty() {
-                continue;
+        if self.outside_labels {
+            // Outside labels with leader lines, stacked so they never overlap
+            let half_h = self.plot.size.y * 0.5;
+            let labels = PieLabelLayout::new()
+                .radius(radius + 10.0)
+                .line_length(8.0)
+                .spacing(14.0)
+                .extent(-half_h + 7.0, half_h - 7.0)
+                .layout(&arcs);
+            let c = self.label_color;
+            for label in &labels {
+                let i = slices[label.index].index;
+                let Some(text) = self.label_strs.get(i).filter(|t| !t.is_empty()) else {
+                    continue;
+                };
+                self.draw_vector.set_color(c.x, c.y, c.z, c.w);
+                let [a, b, e] = [label.anchor, label.elbow, label.position];
+                self.draw_vector
+                    .move_to((center.x + a.x) as f32, (center.y + a.y) as f32);
+                self.draw_vector
+                    .line_to((center.x + b.x) as f32, (center.y + b.y) as f32);
+                self.draw_vector
+                    .line_to((center.x + e.x) as f32, (center.y + e.y) as f32);
+                self.draw_vector.stroke(1.0);
+
+                let width = 6.0 * text.len() as f64;
+                let x = if label.is_left() {
+                    center.x + e.x - 3.0 - width
+                } else {
+                    center.x + e.x + 3.0
+                };
+                self.draw_text
+                    .draw_abs(cx, dvec2(x, center.y + e.y - 6.0), text);
+            }
+        } else {
+            // Labels at the slice centroid, colored to read on the slice
+            for (slice, arc) in slices.iter().zip(&arcs) {
+                let Some(label) = self.label_strs.get(slice.index) else {
+                    continue;
+                };
+                if label.is_empty() {
+                    continue;
+                }
+                let c = arc.centroid();
+                draw_label_on_fill(
+                    &mut self.draw_text,
+                    cx,
+                    dvec2(
+                        center.x + c.x - 3.0 * label.len() as f64,
+                        center.y + c.y - 6.0,
+                    ),
+                    label,
+                    rgba_to_vec4f(palette.get(slice.index)),
+                );
             }
-            let mid = (slice.start_angle + slice.end_angle) * 0.5;
-            let r = (radius + inner) * 0.5;
-            let px = center.x + r * mid.sin();
-            let py = center.y - r * mid.cos();
-            self.draw_text
-                .draw_abs(cx, dvec2(px - 3.0 * label.len() as f64, py - 6.0), label);
         }
 
         end_plot(cx, &mut self.draw_vector);
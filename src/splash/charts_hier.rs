@@ -12,7 +12,9 @@
 //! ```
 //!
 //! A flat array of numbers is accepted as a single-level shorthand.
-//! Leaves are colored by their top-level branch (d3 Category10).
+//! Leaves are colored by their top-level branch (d3 Category10). Treemap
+//! and sunburst labels are off by default; with `show_labels: true` they
+//! pick black or white text per tile for contrast.
 
 // The `script_mod!` and `derive(Script, Widget)` macros generate public
 // registration items that cannot carry doc comments.
@@ -21,8 +23,11 @@
 use crate::color::CategoricalScale;
 use crate::layout::hierarchy::{PartitionLayout, PartitionNode};
 use crate::layout::{HierarchyNode, TreeLayout};
+use crate::shape::{LinkGenerator, PathSegment, Point};
 
-use super::charts::{begin_plot, compute_plot_rect, end_plot, fire_index_callback, rgba_to_vec4f};
+use super::charts::{
+    begin_plot, compute_plot_rect, draw_label_on_fill, end_plot, fire_index_callback, rgba_to_vec4f,
+};
 use super::vm_data;
 use makepad_widgets::makepad_script::ScriptFnRef;
 use makepad_widgets::*;
@@ -36,6 +41,7 @@ script_mod! {
         width: Fill
         height: 300
         plot_margin: Inset{left: 12.0, top: 12.0, right: 12.0, bottom: 12.0}
+        show_labels: false
         draw_bg +: { draw_depth: 0.0 color: #x00000000 }
         draw_grid +: { draw_depth: 0.1 }
         draw_vector +: { draw_depth: 2.0 }
@@ -47,6 +53,7 @@ script_mod! {
         width: Fill
         height: 300
         plot_margin: Inset{left: 12.0, top: 12.0, right: 12.0, bottom: 12.0}
+        show_labels: false
         draw_bg +: { draw_depth: 0.0 color: #x00000000 }
         draw_grid +: { draw_depth: 0.1 }
         draw_vector +: { draw_depth: 2.0 }
@@ -340,6 +347,9 @@ pub struct D3Treemap {
     /// Margins around the plot area.
     #[live]
     pub plot_margin: Inset,
+    /// Draw leaf names inside tiles large enough to hold them.
+    #[live]
+    pub show_labels: bool,
 
     /// Fired with the leaf index (depth-first order) on click.
     #[live]
@@ -462,6 +472,13 @@ impl Widget for D3Treemap {
                 r.size.y as f32,
             );
             self.draw_vector.fill();
+
+            let name = &src_leaves[i].0.data;
+            let width = 6.0 * name.len() as f64;
+            if self.show_labels && r.size.x >= width + 8.0 && r.size.y >= 18.0 {
+                let pos = dvec2(r.pos.x + 4.0, r.pos.y + 3.0);
+                draw_label_on_fill(&mut self.draw_text, cx, pos, name, color);
+            }
         }
 
         end_plot(cx, &mut self.draw_vector);
@@ -499,6 +516,9 @@ pub struct D3Sunburst {
     /// Margins around the plot area.
     #[live]
     pub plot_margin: Inset,
+    /// Draw node names along segments large enough to hold them.
+    #[live]
+    pub show_labels: bool,
 
     #[rust]
     root: Option<HierarchyNode<String>>,
@@ -577,9 +597,12 @@ impl Widget for D3Sunburst {
             .map(|n| n.y1)
             .fold(1.0_f64, f64::max);
 
-        // Draw ring segments; nodes carry (angle0, angle1, r0, r1)
+        // Draw ring segments; nodes carry (angle0, angle1, r0, r1).
+        // Labels that fit are collected with their fill and drawn on top.
+        #[allow(clippy::too_many_arguments)]
         fn draw_node(
             dv: &mut DrawVector,
+            labels: &mut Vec<(DVec2, String, Vec4f)>,
             node: &PartitionNode<String>,
             center: DVec2,
             radius: f64,
@@ -621,14 +644,40 @@ impl Widget for D3Sunburst {
                     }
                     dv.close();
                     dv.fill();
+
+                    let mid = a0 + sweep * 0.5;
+                    let rm = (r0 + r1) * 0.5;
+                    let width = 6.0 * node.data.len() as f64;
+                    if r1 - r0 >= 14.0 && sweep * rm >= width + 4.0 {
+                        let pos = dvec2(
+                            center.x + rm * mid.sin() - width * 0.5,
+                            center.y - rm * mid.cos() - 6.0,
+                        );
+                        labels.push((pos, node.data.clone(), color));
+                    }
                 }
             }
             for (i, child) in node.children.iter().enumerate() {
                 let t = if depth == 0 { i } else { top };
-                draw_node(dv, child, center, radius, max_y, t, depth + 1);
+                draw_node(dv, labels, child, center, radius, max_y, t, depth + 1);
+            }
+        }
+        let mut labels = Vec::new();
+        draw_node(
+            &mut self.draw_vector,
+            &mut labels,
+            &part,
+            center,
+            radius,
+            max_y,
+            0,
+            0,
+        );
+        if self.show_labels {
+            for (pos, name, fill) in &labels {
+                draw_label_on_fill(&mut self.draw_text, cx, *pos, name, *fill);
             }
         }
-        draw_node(&mut self.draw_vector, &part, center, radius, max_y, 0, 0);
 
         end_plot(cx, &mut self.draw_vector);
         DrawStep::done()
@@ -885,26 +934,33 @@ impl Widget for D3TreeChart {
         let oy = self.plot.pos.y;
 
         // Links (cubic horizontal beziers), then nodes on top.
-        fn draw_links(
-            dv: &mut DrawVector,
-            node: &HierarchyNode<String>,
-            ox: f64,
-            oy: f64,
-            c: Vec4f,
-        ) {
-            for child in &node.children {
-                let (x0, y0) = (ox + node.y, oy + node.x);
-                let (x1, y1) = (ox + child.y, oy + child.x);
-                let mx = (x0 + x1) * 0.5;
-                dv.set_color(c.x, c.y, c.z, c.w);
-                dv.move_to(x0 as f32, y0 as f32);
-                dv.bezier_to(
-                    mx as f32, y0 as f32, mx as f32, y1 as f32, x1 as f32, y1 as f32,
-                );
-                dv.stroke(1.5);
-                draw_links(dv, child, ox, oy, c);
+        let links = LinkGenerator::horizontal()
+            .position(move |x, y| Point::new(ox + y, oy + x))
+            .generate_tree(&laid);
+        let c = self.link_color;
+        for link in &links {
+            self.draw_vector.set_color(c.x, c.y, c.z, c.w);
+            for segment in link {
+                match segment {
+                    PathSegment::MoveTo(p) => {
+                        self.draw_vector.move_to(p.x as f32, p.y as f32);
+                    }
+                    PathSegment::CurveTo { cp1, cp2, end } => {
+                        self.draw_vector.bezier_to(
+                            cp1.x as f32,
+                            cp1.y as f32,
+                            cp2.x as f32,
+                            cp2.y as f32,
+                            end.x as f32,
+                            end.y as f32,
+                        );
+                    }
+                    _ => {}
+                }
             }
+            self.draw_vector.stroke(1.5);
         }
+
         fn draw_nodes(
             dv: &mut DrawVector,
             node: &HierarchyNode<String>,
@@ -922,7 +978,6 @@ impl Widget for D3TreeChart {
                 draw_nodes(dv, child, ox, oy, branch, leaf, r);
             }
         }
-        draw_links(&mut self.draw_vector, &laid, ox, oy, self.link_color);
         draw_nodes(
             &mut self.draw_vector,
             &laid,
@@ -7,6 +7,10 @@
 // Marching-squares segment tables read clearer as plain nested tuples.
 #![allow(clippy::type_complexity)]
 
+use crate::geo::{
+    GeoJson, GeoPath, GeoPathSegment, Geometry, OrthographicProjection, Position, Projection,
+    ProjectionBuilder,
+};
 use crate::layout::{
     CenterForce, ForceSimulation, LinkForce, ManyBodyForce, SimulationLink, SimulationNode,
 };
@@ -1094,35 +1098,6 @@ pub struct D3Globe {
     drag_start: Option<(DVec2, f32, f32)>,
 }
 
-/// Orthographic projection with backside culling.
-fn globe_project(
-    lon_deg: f64,
-    lat_deg: f64,
-    rot_lon_deg: f64,
-    rot_lat_deg: f64,
-    center: DVec2,
-    radius: f64,
-) -> Option<(f64, f64)> {
-    let lon = (lon_deg + rot_lon_deg).to_radians();
-    let lat = lat_deg.to_radians();
-    let tilt = rot_lat_deg.to_radians();
-    // Sphere point
-    let x = lat.cos() * lon.sin();
-    let mut y = lat.sin();
-    let mut z = lat.cos() * lon.cos();
-    // Tilt around the x-axis
-    let (y2, z2) = (
-        y * tilt.cos() - z * tilt.sin(),
-        y * tilt.sin() + z * tilt.cos(),
-    );
-    y = y2;
-    z = z2;
-    if z < 0.0 {
-        return None;
-    }
-    Some((center.x + x * radius, center.y - y * radius))
-}
-
 impl D3Globe {
     /// Replace the marker points from Rust ((lon, lat) degrees).
     pub fn set_points(&mut self, points: Vec<(f64, f64)>) {
@@ -1151,31 +1126,39 @@ impl D3Globe {
         (center, radius)
     }
 
-    fn draw_arc(&mut self, samples: &[(f64, f64)], center: DVec2, radius: f64) {
+    /// Orthographic projection for the current rotation, clipped at the
+    /// limb and resampled to sub-pixel precision.
+    fn projection(&self) -> OrthographicProjection {
+        let (center, radius) = self.geometry();
+        OrthographicProjection::new()
+            .scale(radius)
+            .translate(center.x, center.y)
+            .rotate(self.rotation_lon as f64, -self.rotation_lat as f64, 0.0)
+    }
+
+    /// Screen position of a visible (lon, lat) point.
+    fn project_point(projection: &OrthographicProjection, lon: f64, lat: f64) -> Option<DVec2> {
+        projection.is_visible(lon, lat).then(|| {
+            let (x, y) = projection.project(lon, lat);
+            dvec2(x, y)
+        })
+    }
+
+    /// Stroke a line string through the projection.
+    fn draw_line(&mut self, projection: &OrthographicProjection, coordinates: Vec<Position>) {
+        let geometry = GeoJson::Geometry(Geometry::LineString { coordinates });
         let mut pen_down = false;
-        for &(lon, lat) in samples {
-            match globe_project(
-                lon,
-                lat,
-                self.rotation_lon as f64,
-                self.rotation_lat as f64,
-                center,
-                radius,
-            ) {
-                Some((px, py)) => {
-                    if pen_down {
-                        self.draw_vector.line_to(px as f32, py as f32);
-                    } else {
-                        self.draw_vector.move_to(px as f32, py as f32);
-                        pen_down = true;
-                    }
-                }
-                None => {
+        for segment in GeoPath::new(projection).generate(&geometry) {
+            match segment {
+                GeoPathSegment::MoveTo(x, y) => {
                     if pen_down {
                         self.draw_vector.stroke(1.0);
                     }
-                    pen_down = false;
+                    self.draw_vector.move_to(x as f32, y as f32);
+                    pen_down = true;
                 }
+                GeoPathSegment::LineTo(x, y) => self.draw_vector.line_to(x as f32, y as f32),
+                GeoPathSegment::ClosePath => self.draw_vector.close(),
             }
         }
         if pen_down {
@@ -1256,19 +1239,12 @@ impl Widget for D3Globe {
                 cx.set_cursor(MouseCursor::Grab);
                 if !was_drag && fe.is_over && fe.is_primary_hit() {
                     // Click: nearest visible marker
-                    let (center, radius) = self.geometry();
+                    let projection = self.projection();
                     let mut best = None;
                     let mut best_d = 14.0_f64.powi(2);
                     for (i, &(lon, lat)) in self.points.iter().enumerate() {
-                        if let Some((px, py)) = globe_project(
-                            lon,
-                            lat,
-                            self.rotation_lon as f64,
-                            self.rotation_lat as f64,
-                            center,
-                            radius,
-                        ) {
-                            let d = (px - fe.abs.x).powi(2) + (py - fe.abs.y).powi(2);
+                        if let Some(p) = Self::project_point(&projection, lon, lat) {
+                            let d = (p.x - fe.abs.x).powi(2) + (p.y - fe.abs.y).powi(2);
                             if d < best_d {
                                 best_d = d;
                                 best = Some(i);
@@ -1304,20 +1280,20 @@ impl Widget for D3Globe {
             .circle(center.x as f32, center.y as f32, radius as f32);
         self.draw_vector.fill();
 
-        // Graticule
+        // Graticule: meridians are great circles and resample smoothly
+        // from sparse vertices; parallels are sampled densely.
+        let projection = self.projection();
         let g = self.graticule_color;
         self.draw_vector.set_color(g.x, g.y, g.z, g.w);
         for m in 0..12 {
             let lon = m as f64 * 30.0;
-            let samples: Vec<(f64, f64)> =
-                (-88..=88).step_by(4).map(|lat| (lon, lat as f64)).collect();
-            self.draw_arc(&samples, center, radius);
+            let line = (-88..=88).step_by(8).map(|lat| [lon, lat as f64]).collect();
+            self.draw_line(&projection, line);
         }
         for p in -2..=2 {
             let lat = p as f64 * 30.0;
-            let samples: Vec<(f64, f64)> =
-                (0..=360).step_by(4).map(|lon| (lon as f64, lat)).collect();
-            self.draw_arc(&samples, center, radius);
+            let line = (0..=360).step_by(4).map(|lon| [lon as f64, lat]).collect();
+            self.draw_line(&projection, line);
         }
 
         // Outline
@@ -1329,16 +1305,9 @@ impl Widget for D3Globe {
         // Markers
         let p = self.point_color;
         for &(lon, lat) in &self.points {
-            if let Some((px, py)) = globe_project(
-                lon,
-                lat,
-                self.rotation_lon as f64,
-                self.rotation_lat as f64,
-                center,
-                radius,
-            ) {
+            if let Some(pos) = Self::project_point(&projection, lon, lat) {
                 self.draw_vector.set_color(p.x, p.y, p.z, p.w);
-                self.draw_vector.circle(px as f32, py as f32, 4.0);
+                self.draw_vector.circle(pos.x as f32, pos.y as f32, 4.0);
                 self.draw_vector.fill();
             }
         }
@@ -8,12 +8,13 @@
 // registration items that cannot carry doc comments.
 #![allow(missing_docs)]
 
+use crate::axis::format_si;
 use crate::render3d::Colormap;
 use crate::scale::{CategoryScale, DiscreteScale, LinearScale, Scale, ScaleExt};
 
 use super::charts::{
-    begin_plot, compute_plot_rect, draw_x_grid_linear, draw_x_labels_category, draw_y_grid,
-    end_plot, fire_index_callback, min_max, y_scale_for,
+    begin_plot, compute_plot_rect, draw_label_on_fill, draw_x_grid_linear, draw_x_labels_category,
+    draw_y_grid, end_plot, fire_index_callback, min_max, rgba_to_vec4f, y_scale_for,
 };
 use super::vm_data;
 use makepad_widgets::makepad_script::ScriptFnRef;
@@ -46,6 +47,7 @@ script_mod! {
         plot_margin: Inset{left: 12.0, top: 12.0, right: 12.0, bottom: 12.0}
         colormap: "viridis"
         cell_gap: 1.0
+        show_values: false
         draw_bg +: { draw_depth: 0.0 color: #x00000000 }
         draw_grid +: { draw_depth: 0.1 }
         draw_vector +: { draw_depth: 2.0 }
@@ -87,25 +89,11 @@ script_mod! {
 }
 
 pub(crate) fn colormap_from_name(name: &str) -> Colormap {
-    match name {
-        "plasma" => Colormap::Plasma,
-        "inferno" => Colormap::Inferno,
-        "magma" => Colormap::Magma,
-        "coolwarm" | "cool_warm" => Colormap::CoolWarm,
-        "turbo" => Colormap::Turbo,
-        "gray" | "grayscale" => Colormap::Grayscale,
-        _ => Colormap::Viridis,
-    }
+    Colormap::from_name(name).unwrap_or_default()
 }
 
 pub(crate) fn colormap_vec4f(cm: Colormap, t: f64) -> Vec4f {
-    let c = cm.sample_rgba(t as f32);
-    Vec4f {
-        x: c[0],
-        y: c[1],
-        z: c[2],
-        w: c[3],
-    }
+    rgba_to_vec4f(cm.sample_color(t as f32))
 }
 
 /// Parse `data:` as rows of numbers (2D grid).
@@ -392,12 +380,16 @@ pub struct D3Heatmap {
     #[live]
     data: ScriptValue,
 
-    /// Colormap name: viridis, plasma, inferno, magma, coolwarm, turbo, gray.
+    /// Colormap name: viridis, plasma, inferno, magma, coolwarm, turbo, gray,
+    /// or any d3-scale-chromatic scheme such as `RdYlBu` or `Blues`.
     #[live]
     pub colormap: String,
     /// Gap between cells in pixels.
     #[live(1.0)]
     pub cell_gap: f32,
+    /// Print each cell's value, in black or white for contrast with the cell.
+    #[live]
+    pub show_values: bool,
     /// Margins around the plot area.
     #[live]
     pub plot_margin: Inset,
@@ -565,6 +557,18 @@ impl Widget for D3Heatmap {
                         (ch - gap).max(1.0) as f32,
                     );
                     self.draw_vector.fill();
+
+                    if self.show_values {
+                        let label = format_si(v);
+                        let width = 6.0 * label.len() as f64;
+                        if cw - gap >= width + 4.0 && ch - gap >= 14.0 {
+                            let pos = dvec2(
+                                self.plot.pos.x + c as f64 * cw + (cw - gap - width) * 0.5,
+                                self.plot.pos.y + r as f64 * ch + (ch - gap) * 0.5 - 6.0,
+                            );
+                            draw_label_on_fill(&mut self.draw_text, cx, pos, &label, color);
+                        }
+                    }
                 }
             }
         }